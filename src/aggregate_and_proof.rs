@@ -0,0 +1,166 @@
+use crate::beacon_block::{Attestation, AttestationElectra};
+use crate::{Mainnet, Preset, SignatureBytes, SignedRoot, ValidatorIndex};
+use ssz_derive::{Decode, Encode};
+use tree_hash_derive::TreeHash;
+
+/// The number of aggregators that are expected to be selected in each committee.
+pub const TARGET_AGGREGATORS_PER_COMMITTEE: u64 = 16;
+
+/// An aggregate attestation, with the proof that the aggregator was selected to produce it.
+#[derive(Clone, Default, Encode, Decode, TreeHash, PartialEq, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(bound = "P: Preset"))]
+#[cfg_attr(
+    feature = "schemars",
+    schemars(bound = "P: Preset + schemars::JsonSchema")
+)]
+#[ssz(struct_behaviour = "container")]
+pub struct AggregateAndProof<P: Preset = Mainnet> {
+    pub aggregator_index: ValidatorIndex,
+    pub aggregate: Attestation<P>,
+    pub selection_proof: SignatureBytes,
+}
+
+#[derive(Clone, Default, Encode, Decode, TreeHash, PartialEq, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(bound = "P: Preset"))]
+#[cfg_attr(
+    feature = "schemars",
+    schemars(bound = "P: Preset + schemars::JsonSchema")
+)]
+#[ssz(struct_behaviour = "container")]
+pub struct SignedAggregateAndProof<P: Preset = Mainnet> {
+    pub message: AggregateAndProof<P>,
+    pub signature: SignatureBytes,
+}
+
+/// An `AggregateAndProof` with an attestation in the format of Electra.
+#[derive(Clone, Default, Encode, Decode, TreeHash, PartialEq, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(bound = "P: Preset"))]
+#[cfg_attr(
+    feature = "schemars",
+    schemars(bound = "P: Preset + schemars::JsonSchema")
+)]
+#[ssz(struct_behaviour = "container")]
+pub struct AggregateAndProofElectra<P: Preset = Mainnet> {
+    pub aggregator_index: ValidatorIndex,
+    pub aggregate: AttestationElectra<P>,
+    pub selection_proof: SignatureBytes,
+}
+
+#[derive(Clone, Default, Encode, Decode, TreeHash, PartialEq, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(bound = "P: Preset"))]
+#[cfg_attr(
+    feature = "schemars",
+    schemars(bound = "P: Preset + schemars::JsonSchema")
+)]
+#[ssz(struct_behaviour = "container")]
+pub struct SignedAggregateAndProofElectra<P: Preset = Mainnet> {
+    pub message: AggregateAndProofElectra<P>,
+    pub signature: SignatureBytes,
+}
+
+/// True if `selection_proof` selects its signer as an aggregator of a committee with
+/// `committee_len` members, as per `is_aggregator` in the consensus specs.
+pub fn is_aggregator(committee_len: usize, selection_proof: &SignatureBytes) -> bool {
+    let modulo = std::cmp::max(1, committee_len as u64 / TARGET_AGGREGATORS_PER_COMMITTEE);
+    let hash = ethereum_hashing::hash(selection_proof.as_bytes());
+    let mut bytes = [0; 8];
+    bytes.copy_from_slice(&hash[..8]);
+
+    u64::from_le_bytes(bytes) % modulo == 0
+}
+
+impl_memory_size_for_container!(
+    AggregateAndProof<P: Preset>,
+    aggregator_index,
+    aggregate,
+    selection_proof
+);
+impl_memory_size_for_container!(SignedAggregateAndProof<P: Preset>, message, signature);
+impl_memory_size_for_container!(
+    AggregateAndProofElectra<P: Preset>,
+    aggregator_index,
+    aggregate,
+    selection_proof,
+);
+impl_memory_size_for_container!(SignedAggregateAndProofElectra<P: Preset>, message, signature);
+
+impl_merkle_proof_for_container!(
+    AggregateAndProof<P: Preset>,
+    aggregator_index,
+    aggregate,
+    selection_proof
+);
+impl_merkle_proof_for_container!(SignedAggregateAndProof<P: Preset>, message, signature);
+impl_merkle_proof_for_container!(
+    AggregateAndProofElectra<P: Preset>,
+    aggregator_index,
+    aggregate,
+    selection_proof,
+);
+impl_merkle_proof_for_container!(SignedAggregateAndProofElectra<P: Preset>, message, signature);
+
+impl<P: Preset> SignedRoot for AggregateAndProof<P> {}
+impl<P: Preset> SignedRoot for AggregateAndProofElectra<P> {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ssz::{Decode, Encode};
+
+    #[test]
+    fn round_trip() {
+        let signed = SignedAggregateAndProof::<Mainnet> {
+            message: AggregateAndProof {
+                aggregator_index: ValidatorIndex::new(12),
+                ..AggregateAndProof::default()
+            },
+            signature: SignatureBytes::new([3; 96]),
+        };
+        let bytes = signed.as_ssz_bytes();
+        assert_eq!(SignedAggregateAndProof::from_ssz_bytes(&bytes), Ok(signed));
+        // The attestation of Electra has an additional field.
+        assert!(SignedAggregateAndProofElectra::<Mainnet>::from_ssz_bytes(&bytes).is_err());
+
+        let signed = SignedAggregateAndProofElectra::<Mainnet>::default();
+        let bytes = signed.as_ssz_bytes();
+        assert_eq!(
+            SignedAggregateAndProofElectra::from_ssz_bytes(&bytes),
+            Ok(signed)
+        );
+    }
+
+    #[test]
+    fn aggregator_selection() {
+        // Every member of a small committee is an aggregator.
+        assert!((0..=255).all(|byte| is_aggregator(31, &SignatureBytes::new([byte; 96]))));
+
+        let selected = (0..=255)
+            .filter(|&byte| is_aggregator(512, &SignatureBytes::new([byte; 96])))
+            .count();
+        assert!(selected > 0 && selected < 64, "{}", selected);
+    }
+}
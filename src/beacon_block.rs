@@ -1,217 +1,5202 @@
 use std::fmt::Debug;
-use std::sync::Arc;
 
-use crate::{length::Variable, BitList, BitVector, Bitfield, FixedVector, VariableList};
-use ssz::{Decode, DecodeError, Encode};
+use crate::{
+    length::Variable, verify_deposit_branch, verify_merkle_branch, BitList, BitVector, Bitfield,
+    CommitteeIndex, CowList, Epoch, Error, ExecutionAddress, FixedVector, ForkName, ForkSchedule,
+    Graffiti, Gwei, Mainnet, MemorySize, MerklePath, MerkleProof, Preset, PublicKeyBytes,
+    SignatureBytes, SignedRoot, Slot, SszReflect, SszSchema, ValidatorIndex, VariableList,
+};
+use ssz::{Decode, DecodeError, Encode, BYTES_PER_LENGTH_OFFSET};
 use ssz_derive::{Decode, Encode};
+use tree_hash::{Hash256, TreeHash as _};
+use tree_hash_derive::TreeHash;
 use typenum::Unsigned;
 
-type ByteVector<N> = FixedVector<u8, N>;
+pub(crate) type ByteVector<N> = FixedVector<u8, N>;
 type ByteList<N> = VariableList<u8, N>;
-type SignatureBytes = ByteVector<typenum::U96>;
-type PublicKeyBytes = ByteVector<typenum::U48>;
-type H160 = ByteVector<typenum::U20>;
-type H256 = ByteVector<typenum::U32>;
-type U256 = FixedVector<u64, typenum::U4>;
+pub(crate) type H256 = ByteVector<typenum::U32>;
+pub(crate) type U256 = FixedVector<u64, typenum::U4>;
+
+/// A KZG commitment to a blob, i.e. a compressed BLS12-381 G1 point.
+pub type KzgCommitment = ByteVector<typenum::U48>;
+/// A KZG proof, i.e. a compressed BLS12-381 G1 point.
+pub type KzgProof = ByteVector<typenum::U48>;
+/// The data of a blob: 4096 field elements of 32 bytes.
+pub type Blob<P = Mainnet> = ByteVector<<P as Preset>::BytesPerBlob>;
+
+/// Converts a root into the representation of roots in the containers of this module.
+pub(crate) fn h256(root: Hash256) -> H256 {
+    FixedVector::new(root.as_bytes().to_vec()).expect("roots are 32 bytes")
+}
 
 #[derive(Clone, PartialEq, Encode, Decode, Debug)]
 #[ssz(struct_behaviour = "transparent")]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(transparent, bound = ""))]
+#[cfg_attr(feature = "schemars", schemars(bound = "N: Unsigned + Clone"))]
 pub struct CustomBitList<N: Unsigned + Clone>(BitList<N>);
 
+impl<N: Unsigned + Clone> CustomBitList<N> {
+    pub(crate) fn bits(&self) -> &BitList<N> {
+        &self.0
+    }
+
+    /// Returns `Err` unless `other` has the length of `self`, i.e. is of the same committee, and
+    /// has no bits in common with `self` unless `allow_overlap`.
+    fn check_aggregatable(&self, other: &Self, allow_overlap: bool) -> Result<(), Error> {
+        if self.0.len() != other.0.len() {
+            return Err(Error::OutOfBounds {
+                i: other.0.len(),
+                len: self.0.len(),
+            });
+        }
+        if !allow_overlap && self.0.intersects(&other.0) {
+            return Err(Error::OverlappingAggregationBits);
+        }
+        Ok(())
+    }
+}
+
 impl<N: typenum::Unsigned + Clone> Default for CustomBitList<N> {
     fn default() -> Self {
         CustomBitList(BitList::with_capacity(0 as usize).unwrap())
     }
 }
 
-#[derive(Clone, Default, Encode, Decode, PartialEq, Debug)]
-#[ssz(struct_behaviour = "container")]
-pub struct SignedBeaconBlock {
-    pub message: BeaconBlock,
-    pub signature: SignatureBytes,
+#[cfg(feature = "arbitrary")]
+impl<N: 'static + Unsigned + Clone> arbitrary::Arbitrary<'_> for CustomBitList<N> {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'_>) -> arbitrary::Result<Self> {
+        BitList::arbitrary(u).map(CustomBitList)
+    }
+}
+
+impl<N: Unsigned + Clone> tree_hash::TreeHash for CustomBitList<N> {
+    fn tree_hash_type() -> tree_hash::TreeHashType {
+        BitList::<N>::tree_hash_type()
+    }
+
+    fn tree_hash_packed_encoding(&self) -> tree_hash::PackedEncoding {
+        self.0.tree_hash_packed_encoding()
+    }
+
+    fn tree_hash_packing_factor() -> usize {
+        BitList::<N>::tree_hash_packing_factor()
+    }
+
+    fn tree_hash_root(&self) -> Hash256 {
+        self.0.tree_hash_root()
+    }
+}
+
+impl<N: Unsigned + Clone> MerkleProof for CustomBitList<N> {
+    fn tree_hash_node(&self, gindex: u64) -> Result<Hash256, Error> {
+        self.0.tree_hash_node(gindex)
+    }
+}
+
+impl<N: Unsigned + Clone> MerklePath for CustomBitList<N> {
+    fn gindex_of(path: &[&str]) -> Result<u64, Error> {
+        BitList::<N>::gindex_of(path)
+    }
+}
+
+impl<N: Unsigned + Clone> SszReflect for CustomBitList<N> {
+    fn ssz_schema() -> SszSchema {
+        BitList::<N>::ssz_schema()
+    }
+}
+
+impl<N: Unsigned + Clone> MemorySize for CustomBitList<N> {
+    fn heap_size(&self) -> usize {
+        self.0.heap_size()
+    }
 }
 
-#[derive(Clone, Default, Encode, Decode, PartialEq, Debug)]
+#[derive(Clone, Default, Encode, Decode, TreeHash, PartialEq, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[ssz(struct_behaviour = "container")]
 pub struct SignedBeaconBlockHeader {
     pub message: BeaconBlockHeader,
     pub signature: SignatureBytes,
 }
 
-#[derive(Clone, Default, Encode, Decode, PartialEq, Debug)]
+#[derive(Clone, Default, Encode, Decode, TreeHash, PartialEq, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[ssz(struct_behaviour = "container")]
 pub struct BeaconBlockHeader {
-    pub slot: u64,
-    pub proposer_index: u64,
+    pub slot: Slot,
+    pub proposer_index: ValidatorIndex,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::hex_fixed_vec"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::json_schema::Hex<H256>"))]
     pub parent_root: H256,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::hex_fixed_vec"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::json_schema::Hex<H256>"))]
     pub state_root: H256,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::hex_fixed_vec"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::json_schema::Hex<H256>"))]
     pub body_root: H256,
 }
 
-#[derive(Clone, Default, Encode, Decode, PartialEq, Debug)]
+/// Implements the methods shared by the blocks of all forks.
+macro_rules! impl_beacon_block {
+    ($signed_block: ident, $block: ident) => {
+        impl<P: Preset> $signed_block<P> {
+            /// Returns the root of the block, which is the root that other blocks refer to as
+            /// their `parent_root`.
+            pub fn message_root(&self) -> Hash256 {
+                self.message.canonical_root()
+            }
+
+            /// Returns the header of the block, signed with the signature of the block.
+            pub fn signed_block_header(&self) -> SignedBeaconBlockHeader {
+                SignedBeaconBlockHeader {
+                    message: self.message.block_header(),
+                    signature: self.signature.clone(),
+                }
+            }
+        }
+
+        impl<P: Preset> $block<P> {
+            /// Returns the header of the block, which has the same root as the block.
+            pub fn block_header(&self) -> BeaconBlockHeader {
+                BeaconBlockHeader {
+                    slot: self.slot,
+                    proposer_index: self.proposer_index,
+                    parent_root: self.parent_root.clone(),
+                    state_root: self.state_root.clone(),
+                    body_root: h256(self.body.tree_hash_root()),
+                }
+            }
+
+            /// Returns the root of the block.
+            pub fn canonical_root(&self) -> Hash256 {
+                self.tree_hash_root()
+            }
+        }
+
+        impl<P: Preset> SignedRoot for $block<P> {}
+    };
+}
+
+/// Implements `to_header` and the roots of the lists for the execution payload of a fork.
+macro_rules! impl_execution_payload {
+    ($payload: ident, $header: ident, [$($field: ident),*], [$($list: ident => $root: ident),*]) => {
+        impl<P: Preset> $payload<P> {
+            /// Returns the header of the payload, which holds the roots of its lists in place of
+            /// the lists and has the same root as the payload.
+            pub fn to_header(&self) -> $header<P> {
+                $header {
+                    $($field: self.$field.clone(),)*
+                    $($root: h256(self.$root()),)*
+                }
+            }
+
+            $(
+                /// Returns the root of the list of the payload that the header holds under the
+                /// name of this method.
+                pub fn $root(&self) -> Hash256 {
+                    self.$list.tree_hash_root()
+                }
+            )*
+        }
+    };
+}
+
+/// Implements the conversions between the full and blinded blocks of a fork. `$field`s are the
+/// fields of the body other than the execution payload.
+macro_rules! impl_blinded_block {
+    (
+        $signed_block: ident,
+        $block: ident,
+        $body: ident,
+        $signed_blinded_block: ident,
+        $blinded_block: ident,
+        $blinded_body: ident,
+        $payload: ident,
+        [$($field: ident),*]
+    ) => {
+        impl<P: Preset> $signed_block<P> {
+            /// Returns the blinded block, which holds the header of the execution payload in
+            /// place of the payload. It has the same root as the block, so that the signature
+            /// remains valid.
+            pub fn into_blinded(self) -> $signed_blinded_block<P> {
+                let Self { message, signature } = self;
+                let $block {
+                    slot,
+                    proposer_index,
+                    parent_root,
+                    state_root,
+                    body,
+                } = message;
+                let $body {
+                    execution_payload,
+                    $($field,)*
+                } = body;
+
+                $signed_blinded_block {
+                    message: $blinded_block {
+                        slot,
+                        proposer_index,
+                        parent_root,
+                        state_root,
+                        body: $blinded_body {
+                            execution_payload_header: execution_payload.to_header(),
+                            $($field,)*
+                        },
+                    },
+                    signature,
+                }
+            }
+        }
+
+        impl<P: Preset> $signed_blinded_block<P> {
+            /// Returns the full block, with `execution_payload` in place of the header.
+            ///
+            /// Returns `Err` if the payload is not the one the header was made from, i.e. if
+            /// their roots differ.
+            pub fn try_into_full(
+                self,
+                execution_payload: $payload<P>,
+            ) -> Result<$signed_block<P>, Error> {
+                let Self { message, signature } = self;
+                let $blinded_block {
+                    slot,
+                    proposer_index,
+                    parent_root,
+                    state_root,
+                    body,
+                } = message;
+                let $blinded_body {
+                    execution_payload_header,
+                    $($field,)*
+                } = body;
+
+                let expected = execution_payload_header.tree_hash_root();
+                let found = execution_payload.tree_hash_root();
+                if expected != found {
+                    return Err(Error::ExecutionPayloadMismatch { expected, found });
+                }
+
+                Ok($signed_block {
+                    message: $block {
+                        slot,
+                        proposer_index,
+                        parent_root,
+                        state_root,
+                        body: $body {
+                            execution_payload,
+                            $($field,)*
+                        },
+                    },
+                    signature,
+                })
+            }
+        }
+    };
+}
+
+/// Implements `blob_sidecar` for the signed blocks of the forks with blobs.
+macro_rules! impl_blob_sidecar {
+    ($signed_block: ident) => {
+        impl<P: Preset> $signed_block<P> {
+            /// Returns the sidecar of the blob at `index`, with the inclusion proof of its
+            /// commitment in the body of the block.
+            ///
+            /// Returns `Err` if the block has no commitment at `index`.
+            pub fn blob_sidecar(
+                &self,
+                index: u64,
+                blob: Blob<P>,
+                kzg_proof: KzgProof,
+            ) -> Result<BlobSidecar<P>, Error> {
+                let body = &self.message.body;
+                let kzg_commitment = body
+                    .blob_kzg_commitments
+                    .get(index as usize)
+                    .ok_or(Error::OutOfBounds {
+                        i: index as usize,
+                        len: body.blob_kzg_commitments.len(),
+                    })?
+                    .clone();
+                let proof = body.merkle_proof(kzg_commitment_gindex::<P>(index)?)?;
+
+                Ok(BlobSidecar {
+                    index,
+                    blob,
+                    kzg_commitment,
+                    kzg_proof,
+                    signed_block_header: self.signed_block_header(),
+                    kzg_commitment_inclusion_proof: FixedVector::new(
+                        proof.into_iter().map(h256).collect(),
+                    )?,
+                })
+            }
+        }
+    };
+}
+
+/// Evaluates `$body` with `$inner` bound to the value of whichever fork variant `$value` holds.
+///
+/// The variants default to those of all forks.
+macro_rules! map_fork {
+    ($value: expr, $enum: ident, $inner: ident => $body: expr) => {
+        map_fork!(
+            $value,
+            $enum,
+            [Phase0, Altair, Bellatrix, Capella, Deneb, Electra],
+            $inner => $body
+        )
+    };
+    ($value: expr, $enum: ident, [$($variant: ident),*], $inner: ident => $body: expr) => {
+        match $value {
+            $($enum::$variant($inner) => $body,)*
+        }
+    };
+}
+
+/// Implements the methods and traits shared by the enums over the signed blocks of several forks.
+macro_rules! impl_signed_block_enum {
+    ($enum: ident, [$($variant: ident($signed_block: ident)),*]) => {
+        impl<P: Preset> $enum<P> {
+            /// Decodes the block of `fork` from `bytes`.
+            pub fn from_ssz_bytes_for_fork(
+                bytes: &[u8],
+                fork: ForkName,
+            ) -> Result<Self, DecodeError> {
+                #[allow(unreachable_patterns)]
+                match fork {
+                    $(ForkName::$variant => $signed_block::from_ssz_bytes(bytes).map(Self::$variant),)*
+                    _ => Err(DecodeError::BytesInvalid(format!(
+                        "{} has no {} variant",
+                        stringify!($enum),
+                        fork
+                    ))),
+                }
+            }
+
+            /// Decodes a block from `bytes`, as the block of the fork that is active at its slot
+            /// in `schedule`.
+            ///
+            /// The slot is read from the same position in the blocks of all forks, before the rest
+            /// of the block is decoded.
+            pub fn from_ssz_bytes_for_schedule(
+                bytes: &[u8],
+                schedule: &ForkSchedule,
+            ) -> Result<Self, DecodeError> {
+                // The offset of `message`, followed by `signature` and the start of `message`,
+                // which is `slot`.
+                let slot_start =
+                    BYTES_PER_LENGTH_OFFSET + <SignatureBytes as Decode>::ssz_fixed_len();
+                let slot_bytes = bytes.get(slot_start..slot_start + 8).ok_or(
+                    DecodeError::InvalidByteLength {
+                        len: bytes.len(),
+                        expected: slot_start + 8,
+                    },
+                )?;
+                let slot = Slot::from_ssz_bytes(slot_bytes)?;
+
+                Self::from_ssz_bytes_for_fork(bytes, schedule.fork_at_slot(slot))
+            }
+
+            /// Returns the fork of the block.
+            pub fn fork_name(&self) -> ForkName {
+                match self {
+                    $(Self::$variant(_) => ForkName::$variant,)*
+                }
+            }
+
+            /// Returns the slot of the block.
+            pub fn slot(&self) -> Slot {
+                map_fork!(self, Self, [$($variant),*], block => block.message.slot)
+            }
+
+            /// Returns the index of the validator that proposed the block.
+            pub fn proposer_index(&self) -> ValidatorIndex {
+                map_fork!(self, Self, [$($variant),*], block => block.message.proposer_index)
+            }
+
+            /// Returns the root of the parent of the block.
+            pub fn parent_root(&self) -> &H256 {
+                map_fork!(self, Self, [$($variant),*], block => &block.message.parent_root)
+            }
+
+            /// Returns the root of the state after the block.
+            pub fn state_root(&self) -> &H256 {
+                map_fork!(self, Self, [$($variant),*], block => &block.message.state_root)
+            }
+
+            /// Returns the signature of the proposer over the block.
+            pub fn signature(&self) -> &SignatureBytes {
+                map_fork!(self, Self, [$($variant),*], block => &block.signature)
+            }
+
+            /// Returns the root of the block, which is the root that other blocks refer to as
+            /// their `parent_root`.
+            pub fn message_root(&self) -> Hash256 {
+                map_fork!(self, Self, [$($variant),*], block => block.message_root())
+            }
+
+            /// Returns the header of the block, signed with the signature of the block.
+            pub fn signed_block_header(&self) -> SignedBeaconBlockHeader {
+                map_fork!(self, Self, [$($variant),*], block => block.signed_block_header())
+            }
+        }
+
+        $(
+            impl<P: Preset> From<$signed_block<P>> for $enum<P> {
+                fn from(block: $signed_block<P>) -> Self {
+                    Self::$variant(block)
+                }
+            }
+        )*
+
+        impl<P: Preset> Encode for $enum<P> {
+            fn is_ssz_fixed_len() -> bool {
+                false
+            }
+
+            fn ssz_append(&self, buf: &mut Vec<u8>) {
+                map_fork!(self, Self, [$($variant),*], block => block.ssz_append(buf))
+            }
+
+            fn ssz_bytes_len(&self) -> usize {
+                map_fork!(self, Self, [$($variant),*], block => block.ssz_bytes_len())
+            }
+        }
+
+        impl<P: Preset> tree_hash::TreeHash for $enum<P> {
+            fn tree_hash_type() -> tree_hash::TreeHashType {
+                tree_hash::TreeHashType::Container
+            }
+
+            fn tree_hash_packed_encoding(&self) -> tree_hash::PackedEncoding {
+                unreachable!("Container should never be packed.")
+            }
+
+            fn tree_hash_packing_factor() -> usize {
+                unreachable!("Container should never be packed.")
+            }
+
+            fn tree_hash_root(&self) -> Hash256 {
+                map_fork!(self, Self, [$($variant),*], block => block.tree_hash_root())
+            }
+        }
+
+        impl<P: Preset> MerkleProof for $enum<P> {
+            fn tree_hash_node(&self, gindex: u64) -> Result<Hash256, Error> {
+                map_fork!(self, Self, [$($variant),*], block => block.tree_hash_node(gindex))
+            }
+        }
+
+        impl<P: Preset> MemorySize for $enum<P> {
+            fn heap_size(&self) -> usize {
+                map_fork!(self, Self, [$($variant),*], block => block.heap_size())
+            }
+        }
+    };
+}
+
+/// A signed block of any fork.
+///
+/// The fields shared by all forks can be accessed without matching on the fork, and the block is
+/// encoded and merkleized as the block of its fork. Decoding requires knowing the fork, since the
+/// encoding does not identify it: see `from_ssz_bytes_for_fork` and `from_ssz_bytes_for_schedule`.
+///
+/// ## Example
+///
+/// ```
+/// use ssz::Encode;
+/// use ssz_types::{Mainnet, SignedBeaconBlock, SignedBeaconBlockDeneb, Slot};
+/// use tree_hash::TreeHash;
+///
+/// let mut deneb = SignedBeaconBlockDeneb::<Mainnet>::default();
+/// deneb.message.slot = Slot::new(42);
+/// let block = SignedBeaconBlock::from(deneb.clone());
+///
+/// assert_eq!(block.slot(), Slot::new(42));
+/// assert!(block.body().sync_aggregate().is_some());
+/// assert_eq!(block.as_ssz_bytes(), deneb.as_ssz_bytes());
+/// assert_eq!(block.tree_hash_root(), deneb.tree_hash_root());
+/// ```
+///
+/// Decoding a block as the block of the fork that is active at its slot:
+///
+/// ```
+/// use ssz::Encode;
+/// use ssz_types::{
+///     ForkName, ForkSchedule, Mainnet, SignedBeaconBlock, SignedBeaconBlockCapella, Slot,
+/// };
+///
+/// let mut block = SignedBeaconBlockCapella::<Mainnet>::default();
+/// block.message.slot = Slot::new(200_000 * 32);
+///
+/// let bytes = block.as_ssz_bytes();
+/// let decoded =
+///     SignedBeaconBlock::from_ssz_bytes_for_schedule(&bytes, &ForkSchedule::mainnet()).unwrap();
+/// assert_eq!(decoded.fork_name(), ForkName::Capella);
+/// assert_eq!(decoded, SignedBeaconBlock::Capella(block));
+/// ```
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum SignedBeaconBlock<P: Preset = Mainnet> {
+    Phase0(SignedBeaconBlockPhase0<P>),
+    Altair(SignedBeaconBlockAltair<P>),
+    Bellatrix(SignedBeaconBlockBellatrix<P>),
+    Capella(SignedBeaconBlockCapella<P>),
+    Deneb(SignedBeaconBlockDeneb<P>),
+    Electra(SignedBeaconBlockElectra<P>),
+}
+
+impl_signed_block_enum!(
+    SignedBeaconBlock,
+    [
+        Phase0(SignedBeaconBlockPhase0),
+        Altair(SignedBeaconBlockAltair),
+        Bellatrix(SignedBeaconBlockBellatrix),
+        Capella(SignedBeaconBlockCapella),
+        Deneb(SignedBeaconBlockDeneb),
+        Electra(SignedBeaconBlockElectra)
+    ]
+);
+
+impl<P: Preset> SignedBeaconBlock<P> {
+    /// Returns the body of the block.
+    pub fn body(&self) -> BeaconBlockBodyRef<'_, P> {
+        match self {
+            Self::Phase0(block) => BeaconBlockBodyRef::Phase0(&block.message.body),
+            Self::Altair(block) => BeaconBlockBodyRef::Altair(&block.message.body),
+            Self::Bellatrix(block) => BeaconBlockBodyRef::Bellatrix(&block.message.body),
+            Self::Capella(block) => BeaconBlockBodyRef::Capella(&block.message.body),
+            Self::Deneb(block) => BeaconBlockBodyRef::Deneb(&block.message.body),
+            Self::Electra(block) => BeaconBlockBodyRef::Electra(&block.message.body),
+        }
+    }
+}
+
+/// A signed blinded block of any fork since Bellatrix, the first fork with execution payloads.
+///
+/// A blinded block holds the header of its execution payload in place of the payload. As the
+/// header has the same root as the payload, the blinded block has the same root as the full
+/// block, so that a proposer can sign a block without knowing its transactions, as builders
+/// require.
+///
+/// ## Example
+///
+/// ```
+/// use ssz_types::{
+///     FixedVector, Mainnet, SignedBeaconBlockCapella, SignedBlindedBeaconBlock,
+///     SignedBlindedBeaconBlockCapella,
+/// };
+/// use tree_hash::TreeHash;
+///
+/// let full = SignedBeaconBlockCapella::<Mainnet>::default();
+/// let payload = &full.message.body.execution_payload;
+///
+/// let mut blinded = SignedBlindedBeaconBlockCapella::<Mainnet>::default();
+/// let header = &mut blinded.message.body.execution_payload_header;
+/// let root = |root: tree_hash::Hash256| FixedVector::new(root.as_bytes().to_vec()).unwrap();
+/// header.transactions_root = root(payload.transactions_root());
+/// header.withdrawals_root = root(payload.withdrawals_root());
+///
+/// let block = SignedBlindedBeaconBlock::from(blinded);
+/// assert_eq!(block.tree_hash_root(), full.tree_hash_root());
+/// assert_eq!(block.execution_block_hash(), &payload.block_hash);
+/// ```
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum SignedBlindedBeaconBlock<P: Preset = Mainnet> {
+    Bellatrix(SignedBlindedBeaconBlockBellatrix<P>),
+    Capella(SignedBlindedBeaconBlockCapella<P>),
+    Deneb(SignedBlindedBeaconBlockDeneb<P>),
+    Electra(SignedBlindedBeaconBlockElectra<P>),
+}
+
+impl_signed_block_enum!(
+    SignedBlindedBeaconBlock,
+    [
+        Bellatrix(SignedBlindedBeaconBlockBellatrix),
+        Capella(SignedBlindedBeaconBlockCapella),
+        Deneb(SignedBlindedBeaconBlockDeneb),
+        Electra(SignedBlindedBeaconBlockElectra)
+    ]
+);
+
+impl<P: Preset> SignedBlindedBeaconBlock<P> {
+    /// Returns the hash of the execution block that the block commits to.
+    pub fn execution_block_hash(&self) -> &H256 {
+        map_fork!(
+            self,
+            Self,
+            [Bellatrix, Capella, Deneb, Electra],
+            block => &block.message.body.execution_payload_header.block_hash
+        )
+    }
+
+    /// Returns the full block, with `execution_payload` in place of the header of the payload.
+    ///
+    /// Returns `Err` if the payload is not the one the header was made from, which includes
+    /// payloads of a different fork than the block.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use ssz_types::{
+    ///     Error, ExecutionPayloadCapella, Mainnet, SignedBeaconBlock, SignedBeaconBlockCapella,
+    /// };
+    ///
+    /// let mut block = SignedBeaconBlockCapella::<Mainnet>::default();
+    /// block.message.body.execution_payload.gas_used = 21_000;
+    /// let payload = block.message.body.execution_payload.clone();
+    ///
+    /// let blinded = SignedBeaconBlock::from(block.clone()).into_blinded().unwrap();
+    /// assert!(matches!(
+    ///     blinded.clone().try_into_full(ExecutionPayloadCapella::default().into()),
+    ///     Err(Error::ExecutionPayloadMismatch { .. })
+    /// ));
+    /// assert_eq!(
+    ///     blinded.try_into_full(payload.into()),
+    ///     Ok(SignedBeaconBlock::Capella(block))
+    /// );
+    /// ```
+    pub fn try_into_full(
+        self,
+        execution_payload: ExecutionPayload<P>,
+    ) -> Result<SignedBeaconBlock<P>, Error> {
+        match (self, execution_payload) {
+            (Self::Bellatrix(block), ExecutionPayload::Bellatrix(payload)) => block
+                .try_into_full(payload)
+                .map(SignedBeaconBlock::Bellatrix),
+            (Self::Capella(block), ExecutionPayload::Capella(payload)) => {
+                block.try_into_full(payload).map(SignedBeaconBlock::Capella)
+            }
+            (Self::Deneb(block), ExecutionPayload::Deneb(payload)) => {
+                block.try_into_full(payload).map(SignedBeaconBlock::Deneb)
+            }
+            (Self::Electra(block), ExecutionPayload::Deneb(payload)) => {
+                block.try_into_full(payload).map(SignedBeaconBlock::Electra)
+            }
+            (block, payload) => Err(Error::ExecutionPayloadMismatch {
+                expected: map_fork!(
+                    &block,
+                    Self,
+                    [Bellatrix, Capella, Deneb, Electra],
+                    block => block.message.body.execution_payload_header.tree_hash_root()
+                ),
+                found: payload.tree_hash_root(),
+            }),
+        }
+    }
+}
+
+impl<P: Preset> SignedBeaconBlock<P> {
+    /// Returns the blinded block, which holds the header of the execution payload in place of the
+    /// payload, and has the same root and signature as the block.
+    ///
+    /// Returns `None` if the fork of the block precedes Bellatrix, as such blocks have no
+    /// execution payload.
+    pub fn into_blinded(self) -> Option<SignedBlindedBeaconBlock<P>> {
+        match self {
+            Self::Phase0(_) | Self::Altair(_) => None,
+            Self::Bellatrix(block) => Some(block.into_blinded().into()),
+            Self::Capella(block) => Some(block.into_blinded().into()),
+            Self::Deneb(block) => Some(block.into_blinded().into()),
+            Self::Electra(block) => Some(block.into_blinded().into()),
+        }
+    }
+}
+
+/// The execution payload of any fork since Bellatrix, as revealed by a builder for a blinded
+/// block. The blocks of Electra hold the payloads of Deneb.
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum ExecutionPayload<P: Preset = Mainnet> {
+    Bellatrix(ExecutionPayloadBellatrix<P>),
+    Capella(ExecutionPayloadCapella<P>),
+    Deneb(ExecutionPayloadDeneb<P>),
+}
+
+impl<P: Preset> ExecutionPayload<P> {
+    /// Returns the hash of the execution block.
+    pub fn block_hash(&self) -> &H256 {
+        map_fork!(self, Self, [Bellatrix, Capella, Deneb], payload => &payload.block_hash)
+    }
+
+    /// Returns the root of the transactions of the payload, as held by its header.
+    pub fn transactions_root(&self) -> Hash256 {
+        map_fork!(self, Self, [Bellatrix, Capella, Deneb], payload => payload.transactions_root())
+    }
+
+    /// Returns the root of the withdrawals of the payload, as held by its header.
+    ///
+    /// Returns `None` for payloads of Bellatrix, which precede withdrawals.
+    pub fn withdrawals_root(&self) -> Option<Hash256> {
+        match self {
+            Self::Bellatrix(_) => None,
+            Self::Capella(payload) => Some(payload.withdrawals_root()),
+            Self::Deneb(payload) => Some(payload.withdrawals_root()),
+        }
+    }
+}
+
+impl<P: Preset> From<ExecutionPayloadBellatrix<P>> for ExecutionPayload<P> {
+    fn from(payload: ExecutionPayloadBellatrix<P>) -> Self {
+        Self::Bellatrix(payload)
+    }
+}
+
+impl<P: Preset> From<ExecutionPayloadCapella<P>> for ExecutionPayload<P> {
+    fn from(payload: ExecutionPayloadCapella<P>) -> Self {
+        Self::Capella(payload)
+    }
+}
+
+impl<P: Preset> From<ExecutionPayloadDeneb<P>> for ExecutionPayload<P> {
+    fn from(payload: ExecutionPayloadDeneb<P>) -> Self {
+        Self::Deneb(payload)
+    }
+}
+
+impl<P: Preset> Encode for ExecutionPayload<P> {
+    fn is_ssz_fixed_len() -> bool {
+        false
+    }
+
+    fn ssz_append(&self, buf: &mut Vec<u8>) {
+        map_fork!(self, Self, [Bellatrix, Capella, Deneb], payload => payload.ssz_append(buf))
+    }
+
+    fn ssz_bytes_len(&self) -> usize {
+        map_fork!(self, Self, [Bellatrix, Capella, Deneb], payload => payload.ssz_bytes_len())
+    }
+}
+
+impl<P: Preset> tree_hash::TreeHash for ExecutionPayload<P> {
+    fn tree_hash_type() -> tree_hash::TreeHashType {
+        tree_hash::TreeHashType::Container
+    }
+
+    fn tree_hash_packed_encoding(&self) -> tree_hash::PackedEncoding {
+        unreachable!("Container should never be packed.")
+    }
+
+    fn tree_hash_packing_factor() -> usize {
+        unreachable!("Container should never be packed.")
+    }
+
+    fn tree_hash_root(&self) -> Hash256 {
+        map_fork!(self, Self, [Bellatrix, Capella, Deneb], payload => payload.tree_hash_root())
+    }
+}
+
+/// A reference to the body of a block of any fork. Returned by `SignedBeaconBlock::body`.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum BeaconBlockBodyRef<'a, P: Preset = Mainnet> {
+    Phase0(&'a BeaconBlockBodyPhase0<P>),
+    Altair(&'a BeaconBlockBodyAltair<P>),
+    Bellatrix(&'a BeaconBlockBodyBellatrix<P>),
+    Capella(&'a BeaconBlockBodyCapella<P>),
+    Deneb(&'a BeaconBlockBodyDeneb<P>),
+    Electra(&'a BeaconBlockBodyElectra<P>),
+}
+
+impl<'a, P: Preset> BeaconBlockBodyRef<'a, P> {
+    pub fn randao_reveal(self) -> &'a SignatureBytes {
+        map_fork!(self, Self, body => &body.randao_reveal)
+    }
+
+    pub fn eth1_data(self) -> &'a Eth1Data {
+        map_fork!(self, Self, body => &body.eth1_data)
+    }
+
+    pub fn graffiti(self) -> &'a Graffiti {
+        map_fork!(self, Self, body => &body.graffiti)
+    }
+
+    pub fn proposer_slashings(self) -> &'a [ProposerSlashing] {
+        map_fork!(self, Self, body => &body.proposer_slashings)
+    }
+
+    pub fn deposits(self) -> &'a [Deposit] {
+        map_fork!(self, Self, body => &body.deposits)
+    }
+
+    pub fn voluntary_exits(self) -> &'a [SignedVoluntaryExit] {
+        map_fork!(self, Self, body => &body.voluntary_exits)
+    }
+
+    /// Returns the sync aggregate of the body, which every body has since Altair.
+    pub fn sync_aggregate(self) -> Option<&'a SyncAggregate<P>> {
+        match self {
+            Self::Phase0(_) => None,
+            Self::Altair(body) => Some(&body.sync_aggregate),
+            Self::Bellatrix(body) => Some(&body.sync_aggregate),
+            Self::Capella(body) => Some(&body.sync_aggregate),
+            Self::Deneb(body) => Some(&body.sync_aggregate),
+            Self::Electra(body) => Some(&body.sync_aggregate),
+        }
+    }
+
+    /// Returns the root of the body, i.e. the `body_root` of the header of its block.
+    pub fn body_root(self) -> Hash256 {
+        map_fork!(self, Self, body => body.tree_hash_root())
+    }
+}
+
+#[derive(Clone, Default, Encode, Decode, TreeHash, PartialEq, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(bound = "P: Preset"))]
+#[cfg_attr(
+    feature = "schemars",
+    schemars(bound = "P: Preset + schemars::JsonSchema")
+)]
 #[ssz(struct_behaviour = "container")]
-pub struct BeaconBlock {
-    pub slot: u64,
-    pub proposer_index: u64,
+pub struct SignedBeaconBlockPhase0<P: Preset = Mainnet> {
+    pub message: BeaconBlockPhase0<P>,
+    pub signature: SignatureBytes,
+}
+
+#[derive(Clone, Default, Encode, Decode, TreeHash, PartialEq, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(bound = "P: Preset"))]
+#[cfg_attr(
+    feature = "schemars",
+    schemars(bound = "P: Preset + schemars::JsonSchema")
+)]
+#[ssz(struct_behaviour = "container")]
+pub struct BeaconBlockPhase0<P: Preset = Mainnet> {
+    pub slot: Slot,
+    pub proposer_index: ValidatorIndex,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::hex_fixed_vec"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::json_schema::Hex<H256>"))]
     pub parent_root: H256,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::hex_fixed_vec"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::json_schema::Hex<H256>"))]
     pub state_root: H256,
-    pub body: BeaconBlockBody,
+    pub body: BeaconBlockBodyPhase0<P>,
 }
 
-#[derive(Clone, Default, Encode, Decode, PartialEq, Debug)]
+#[derive(Clone, Default, Encode, Decode, TreeHash, PartialEq, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(bound = "P: Preset"))]
+#[cfg_attr(
+    feature = "schemars",
+    schemars(bound = "P: Preset + schemars::JsonSchema")
+)]
 #[ssz(struct_behaviour = "container")]
-pub struct BeaconBlockBody {
+pub struct BeaconBlockBodyPhase0<P: Preset = Mainnet> {
     pub randao_reveal: SignatureBytes,
     pub eth1_data: Eth1Data,
-    pub graffiti: H256,
-    pub proposer_slashings: VariableList<ProposerSlashing, typenum::U16>,
-    pub attester_slashings: VariableList<AttesterSlashing, typenum::U2>,
-    pub attestations: VariableList<Attestation, typenum::U128>,
-    pub deposits: VariableList<Deposit, typenum::U16>,
-    pub voluntary_exits: VariableList<SignedVoluntaryExit, typenum::U16>,
-    pub sync_aggregate: SyncAggregate,
-    pub execution_payload: ExecutionPayload,
-    pub bls_to_execution_changes: VariableList<SignedBlsToExecutionChange, typenum::U16>,
+    pub graffiti: Graffiti,
+    pub proposer_slashings: VariableList<ProposerSlashing, P::MaxProposerSlashings>,
+    pub attester_slashings: VariableList<AttesterSlashing<P>, P::MaxAttesterSlashings>,
+    pub attestations: VariableList<Attestation<P>, P::MaxAttestations>,
+    pub deposits: VariableList<Deposit, P::MaxDeposits>,
+    pub voluntary_exits: VariableList<SignedVoluntaryExit, P::MaxVoluntaryExits>,
 }
 
-#[derive(Clone, Default, Encode, Decode, PartialEq, Debug)]
+#[derive(Clone, Default, Encode, Decode, TreeHash, PartialEq, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(bound = "P: Preset"))]
+#[cfg_attr(
+    feature = "schemars",
+    schemars(bound = "P: Preset + schemars::JsonSchema")
+)]
 #[ssz(struct_behaviour = "container")]
-pub struct Eth1Data {
-    pub deposit_root: H256,
-    pub deposit_count: u64,
-    pub block_hash: H256,
+pub struct SignedBeaconBlockAltair<P: Preset = Mainnet> {
+    pub message: BeaconBlockAltair<P>,
+    pub signature: SignatureBytes,
 }
 
-#[derive(Clone, Default, Encode, Decode, PartialEq, Debug)]
+#[derive(Clone, Default, Encode, Decode, TreeHash, PartialEq, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(bound = "P: Preset"))]
+#[cfg_attr(
+    feature = "schemars",
+    schemars(bound = "P: Preset + schemars::JsonSchema")
+)]
 #[ssz(struct_behaviour = "container")]
-pub struct ProposerSlashing {
-    pub signed_header_1: SignedBeaconBlockHeader,
-    pub signed_header_2: SignedBeaconBlockHeader,
+pub struct BeaconBlockAltair<P: Preset = Mainnet> {
+    pub slot: Slot,
+    pub proposer_index: ValidatorIndex,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::hex_fixed_vec"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::json_schema::Hex<H256>"))]
+    pub parent_root: H256,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::hex_fixed_vec"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::json_schema::Hex<H256>"))]
+    pub state_root: H256,
+    pub body: BeaconBlockBodyAltair<P>,
 }
 
-#[derive(Clone, Default, Encode, Decode, PartialEq, Debug)]
+#[derive(Clone, Default, Encode, Decode, TreeHash, PartialEq, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(bound = "P: Preset"))]
+#[cfg_attr(
+    feature = "schemars",
+    schemars(bound = "P: Preset + schemars::JsonSchema")
+)]
 #[ssz(struct_behaviour = "container")]
-pub struct Checkpoint {
-    pub epoch: u64,
-    pub root: H256,
+pub struct BeaconBlockBodyAltair<P: Preset = Mainnet> {
+    pub randao_reveal: SignatureBytes,
+    pub eth1_data: Eth1Data,
+    pub graffiti: Graffiti,
+    pub proposer_slashings: VariableList<ProposerSlashing, P::MaxProposerSlashings>,
+    pub attester_slashings: VariableList<AttesterSlashing<P>, P::MaxAttesterSlashings>,
+    pub attestations: VariableList<Attestation<P>, P::MaxAttestations>,
+    pub deposits: VariableList<Deposit, P::MaxDeposits>,
+    pub voluntary_exits: VariableList<SignedVoluntaryExit, P::MaxVoluntaryExits>,
+    pub sync_aggregate: SyncAggregate<P>,
 }
 
-#[derive(Clone, Default, Encode, Decode, PartialEq, Debug)]
+#[derive(Clone, Default, Encode, Decode, TreeHash, PartialEq, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(bound = "P: Preset"))]
+#[cfg_attr(
+    feature = "schemars",
+    schemars(bound = "P: Preset + schemars::JsonSchema")
+)]
 #[ssz(struct_behaviour = "container")]
-pub struct AttestationData {
-    pub slot: u64,
-    pub index: u64,
-    pub beacon_block_root: H256,
-    pub source: Checkpoint,
-    pub target: Checkpoint,
+pub struct SignedBeaconBlockBellatrix<P: Preset = Mainnet> {
+    pub message: BeaconBlockBellatrix<P>,
+    pub signature: SignatureBytes,
 }
 
-#[derive(Clone, Default, Encode, Decode, PartialEq, Debug)]
+#[derive(Clone, Default, Encode, Decode, TreeHash, PartialEq, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(bound = "P: Preset"))]
+#[cfg_attr(
+    feature = "schemars",
+    schemars(bound = "P: Preset + schemars::JsonSchema")
+)]
 #[ssz(struct_behaviour = "container")]
-pub struct IndexedAttestation {
-    pub attesting_indices: VariableList<u64, typenum::U2048>,
-    pub data: AttestationData,
+pub struct BeaconBlockBellatrix<P: Preset = Mainnet> {
+    pub slot: Slot,
+    pub proposer_index: ValidatorIndex,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::hex_fixed_vec"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::json_schema::Hex<H256>"))]
+    pub parent_root: H256,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::hex_fixed_vec"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::json_schema::Hex<H256>"))]
+    pub state_root: H256,
+    pub body: BeaconBlockBodyBellatrix<P>,
+}
+
+#[derive(Clone, Default, Encode, Decode, TreeHash, PartialEq, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(bound = "P: Preset"))]
+#[cfg_attr(
+    feature = "schemars",
+    schemars(bound = "P: Preset + schemars::JsonSchema")
+)]
+#[ssz(struct_behaviour = "container")]
+pub struct BeaconBlockBodyBellatrix<P: Preset = Mainnet> {
+    pub randao_reveal: SignatureBytes,
+    pub eth1_data: Eth1Data,
+    pub graffiti: Graffiti,
+    pub proposer_slashings: VariableList<ProposerSlashing, P::MaxProposerSlashings>,
+    pub attester_slashings: VariableList<AttesterSlashing<P>, P::MaxAttesterSlashings>,
+    pub attestations: VariableList<Attestation<P>, P::MaxAttestations>,
+    pub deposits: VariableList<Deposit, P::MaxDeposits>,
+    pub voluntary_exits: VariableList<SignedVoluntaryExit, P::MaxVoluntaryExits>,
+    pub sync_aggregate: SyncAggregate<P>,
+    pub execution_payload: ExecutionPayloadBellatrix<P>,
+}
+
+#[derive(Clone, Default, Encode, Decode, TreeHash, PartialEq, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(bound = "P: Preset"))]
+#[cfg_attr(
+    feature = "schemars",
+    schemars(bound = "P: Preset + schemars::JsonSchema")
+)]
+#[ssz(struct_behaviour = "container")]
+pub struct SignedBeaconBlockCapella<P: Preset = Mainnet> {
+    pub message: BeaconBlockCapella<P>,
     pub signature: SignatureBytes,
 }
 
-#[derive(Clone, Default, Encode, Decode, PartialEq, Debug)]
+#[derive(Clone, Default, Encode, Decode, TreeHash, PartialEq, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(bound = "P: Preset"))]
+#[cfg_attr(
+    feature = "schemars",
+    schemars(bound = "P: Preset + schemars::JsonSchema")
+)]
 #[ssz(struct_behaviour = "container")]
-pub struct AttesterSlashing {
-    pub attestation_1: IndexedAttestation,
-    pub attestation_2: IndexedAttestation,
+pub struct BeaconBlockCapella<P: Preset = Mainnet> {
+    pub slot: Slot,
+    pub proposer_index: ValidatorIndex,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::hex_fixed_vec"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::json_schema::Hex<H256>"))]
+    pub parent_root: H256,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::hex_fixed_vec"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::json_schema::Hex<H256>"))]
+    pub state_root: H256,
+    pub body: BeaconBlockBodyCapella<P>,
 }
 
-#[derive(Clone, Default, Encode, Decode, PartialEq, Debug)]
+#[derive(Clone, Default, Encode, Decode, TreeHash, PartialEq, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(bound = "P: Preset"))]
+#[cfg_attr(
+    feature = "schemars",
+    schemars(bound = "P: Preset + schemars::JsonSchema")
+)]
 #[ssz(struct_behaviour = "container")]
-pub struct Attestation {
-    pub aggregation_bits: CustomBitList<typenum::U2048>,
-    pub data: AttestationData,
+pub struct BeaconBlockBodyCapella<P: Preset = Mainnet> {
+    pub randao_reveal: SignatureBytes,
+    pub eth1_data: Eth1Data,
+    pub graffiti: Graffiti,
+    pub proposer_slashings: VariableList<ProposerSlashing, P::MaxProposerSlashings>,
+    pub attester_slashings: VariableList<AttesterSlashing<P>, P::MaxAttesterSlashings>,
+    pub attestations: VariableList<Attestation<P>, P::MaxAttestations>,
+    pub deposits: VariableList<Deposit, P::MaxDeposits>,
+    pub voluntary_exits: VariableList<SignedVoluntaryExit, P::MaxVoluntaryExits>,
+    pub sync_aggregate: SyncAggregate<P>,
+    pub execution_payload: ExecutionPayloadCapella<P>,
+    pub bls_to_execution_changes:
+        VariableList<SignedBlsToExecutionChange, P::MaxBlsToExecutionChanges>,
+}
+
+#[derive(Clone, Default, Encode, Decode, TreeHash, PartialEq, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(bound = "P: Preset"))]
+#[cfg_attr(
+    feature = "schemars",
+    schemars(bound = "P: Preset + schemars::JsonSchema")
+)]
+#[ssz(struct_behaviour = "container")]
+pub struct SignedBeaconBlockDeneb<P: Preset = Mainnet> {
+    pub message: BeaconBlockDeneb<P>,
     pub signature: SignatureBytes,
 }
 
-#[derive(Clone, Default, Encode, Decode, PartialEq, Debug)]
+#[derive(Clone, Default, Encode, Decode, TreeHash, PartialEq, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(bound = "P: Preset"))]
+#[cfg_attr(
+    feature = "schemars",
+    schemars(bound = "P: Preset + schemars::JsonSchema")
+)]
 #[ssz(struct_behaviour = "container")]
-pub struct DepositData {
-    pub pubkey: PublicKeyBytes,
-    pub withdrawal_credentials: H256,
-    pub amount: u64,
+pub struct BeaconBlockDeneb<P: Preset = Mainnet> {
+    pub slot: Slot,
+    pub proposer_index: ValidatorIndex,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::hex_fixed_vec"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::json_schema::Hex<H256>"))]
+    pub parent_root: H256,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::hex_fixed_vec"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::json_schema::Hex<H256>"))]
+    pub state_root: H256,
+    pub body: BeaconBlockBodyDeneb<P>,
+}
+
+#[derive(Clone, Default, Encode, Decode, TreeHash, PartialEq, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(bound = "P: Preset"))]
+#[cfg_attr(
+    feature = "schemars",
+    schemars(bound = "P: Preset + schemars::JsonSchema")
+)]
+#[ssz(struct_behaviour = "container")]
+pub struct BeaconBlockBodyDeneb<P: Preset = Mainnet> {
+    pub randao_reveal: SignatureBytes,
+    pub eth1_data: Eth1Data,
+    pub graffiti: Graffiti,
+    pub proposer_slashings: VariableList<ProposerSlashing, P::MaxProposerSlashings>,
+    pub attester_slashings: VariableList<AttesterSlashing<P>, P::MaxAttesterSlashings>,
+    pub attestations: VariableList<Attestation<P>, P::MaxAttestations>,
+    pub deposits: VariableList<Deposit, P::MaxDeposits>,
+    pub voluntary_exits: VariableList<SignedVoluntaryExit, P::MaxVoluntaryExits>,
+    pub sync_aggregate: SyncAggregate<P>,
+    pub execution_payload: ExecutionPayloadDeneb<P>,
+    pub bls_to_execution_changes:
+        VariableList<SignedBlsToExecutionChange, P::MaxBlsToExecutionChanges>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_utils::list_of_hex_fixed_vec")
+    )]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(
+            with = "crate::json_schema::HexItems<VariableList<KzgCommitment, P::MaxBlobCommitmentsPerBlock>>"
+        )
+    )]
+    pub blob_kzg_commitments: VariableList<KzgCommitment, P::MaxBlobCommitmentsPerBlock>,
+}
+
+#[derive(Clone, Default, Encode, Decode, TreeHash, PartialEq, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(bound = "P: Preset"))]
+#[cfg_attr(
+    feature = "schemars",
+    schemars(bound = "P: Preset + schemars::JsonSchema")
+)]
+#[ssz(struct_behaviour = "container")]
+pub struct SignedBeaconBlockElectra<P: Preset = Mainnet> {
+    pub message: BeaconBlockElectra<P>,
     pub signature: SignatureBytes,
 }
 
-#[derive(Clone, Default, Encode, Decode, PartialEq, Debug)]
+#[derive(Clone, Default, Encode, Decode, TreeHash, PartialEq, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(bound = "P: Preset"))]
+#[cfg_attr(
+    feature = "schemars",
+    schemars(bound = "P: Preset + schemars::JsonSchema")
+)]
 #[ssz(struct_behaviour = "container")]
-pub struct Deposit {
-    pub proof: FixedVector<H256, typenum::U32>,
-    pub data: DepositData,
+pub struct BeaconBlockElectra<P: Preset = Mainnet> {
+    pub slot: Slot,
+    pub proposer_index: ValidatorIndex,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::hex_fixed_vec"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::json_schema::Hex<H256>"))]
+    pub parent_root: H256,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::hex_fixed_vec"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::json_schema::Hex<H256>"))]
+    pub state_root: H256,
+    pub body: BeaconBlockBodyElectra<P>,
 }
 
-#[derive(Clone, Default, Encode, Decode, PartialEq, Debug)]
+#[derive(Clone, Default, Encode, Decode, TreeHash, PartialEq, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(bound = "P: Preset"))]
+#[cfg_attr(
+    feature = "schemars",
+    schemars(bound = "P: Preset + schemars::JsonSchema")
+)]
 #[ssz(struct_behaviour = "container")]
-pub struct VoluntaryExit {
-    pub epoch: u64,
-    pub validator_index: u64,
+pub struct BeaconBlockBodyElectra<P: Preset = Mainnet> {
+    pub randao_reveal: SignatureBytes,
+    pub eth1_data: Eth1Data,
+    pub graffiti: Graffiti,
+    pub proposer_slashings: VariableList<ProposerSlashing, P::MaxProposerSlashings>,
+    pub attester_slashings:
+        VariableList<AttesterSlashingElectra<P>, P::MaxAttesterSlashingsElectra>,
+    pub attestations: VariableList<AttestationElectra<P>, P::MaxAttestationsElectra>,
+    pub deposits: VariableList<Deposit, P::MaxDeposits>,
+    pub voluntary_exits: VariableList<SignedVoluntaryExit, P::MaxVoluntaryExits>,
+    pub sync_aggregate: SyncAggregate<P>,
+    pub execution_payload: ExecutionPayloadDeneb<P>,
+    pub bls_to_execution_changes:
+        VariableList<SignedBlsToExecutionChange, P::MaxBlsToExecutionChanges>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_utils::list_of_hex_fixed_vec")
+    )]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(
+            with = "crate::json_schema::HexItems<VariableList<KzgCommitment, P::MaxBlobCommitmentsPerBlock>>"
+        )
+    )]
+    pub blob_kzg_commitments: VariableList<KzgCommitment, P::MaxBlobCommitmentsPerBlock>,
+    pub execution_requests: ExecutionRequests<P>,
 }
 
-#[derive(Clone, Default, Encode, Decode, PartialEq, Debug)]
+#[derive(Clone, Default, Encode, Decode, TreeHash, PartialEq, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(bound = "P: Preset"))]
+#[cfg_attr(
+    feature = "schemars",
+    schemars(bound = "P: Preset + schemars::JsonSchema")
+)]
 #[ssz(struct_behaviour = "container")]
-pub struct SignedVoluntaryExit {
-    pub message: VoluntaryExit,
+pub struct SignedBlindedBeaconBlockBellatrix<P: Preset = Mainnet> {
+    pub message: BlindedBeaconBlockBellatrix<P>,
     pub signature: SignatureBytes,
 }
 
-#[derive(Clone, Default, Encode, Decode, PartialEq, Debug)]
+#[derive(Clone, Default, Encode, Decode, TreeHash, PartialEq, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(bound = "P: Preset"))]
+#[cfg_attr(
+    feature = "schemars",
+    schemars(bound = "P: Preset + schemars::JsonSchema")
+)]
 #[ssz(struct_behaviour = "container")]
-pub struct SyncAggregate {
-    pub sync_committee_bits: BitVector<typenum::U512>,
-    pub sync_committee_signature: SignatureBytes,
+pub struct BlindedBeaconBlockBellatrix<P: Preset = Mainnet> {
+    pub slot: Slot,
+    pub proposer_index: ValidatorIndex,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::hex_fixed_vec"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::json_schema::Hex<H256>"))]
+    pub parent_root: H256,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::hex_fixed_vec"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::json_schema::Hex<H256>"))]
+    pub state_root: H256,
+    pub body: BlindedBeaconBlockBodyBellatrix<P>,
 }
 
-pub type Transaction = ByteList<typenum::U1073741824>;
+#[derive(Clone, Default, Encode, Decode, TreeHash, PartialEq, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(bound = "P: Preset"))]
+#[cfg_attr(
+    feature = "schemars",
+    schemars(bound = "P: Preset + schemars::JsonSchema")
+)]
+#[ssz(struct_behaviour = "container")]
+pub struct BlindedBeaconBlockBodyBellatrix<P: Preset = Mainnet> {
+    pub randao_reveal: SignatureBytes,
+    pub eth1_data: Eth1Data,
+    pub graffiti: Graffiti,
+    pub proposer_slashings: VariableList<ProposerSlashing, P::MaxProposerSlashings>,
+    pub attester_slashings: VariableList<AttesterSlashing<P>, P::MaxAttesterSlashings>,
+    pub attestations: VariableList<Attestation<P>, P::MaxAttestations>,
+    pub deposits: VariableList<Deposit, P::MaxDeposits>,
+    pub voluntary_exits: VariableList<SignedVoluntaryExit, P::MaxVoluntaryExits>,
+    pub sync_aggregate: SyncAggregate<P>,
+    pub execution_payload_header: ExecutionPayloadHeaderBellatrix<P>,
+}
 
-#[derive(Clone, Default, Encode, Decode, PartialEq, Debug)]
+#[derive(Clone, Default, Encode, Decode, TreeHash, PartialEq, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(bound = "P: Preset"))]
+#[cfg_attr(
+    feature = "schemars",
+    schemars(bound = "P: Preset + schemars::JsonSchema")
+)]
 #[ssz(struct_behaviour = "container")]
-pub struct Withdrawal {
-    pub index: u64,
-    pub validator_index: u64,
-    pub address: H160,
-    pub amount: u64,
+pub struct SignedBlindedBeaconBlockCapella<P: Preset = Mainnet> {
+    pub message: BlindedBeaconBlockCapella<P>,
+    pub signature: SignatureBytes,
 }
 
-#[derive(Clone, Default, Encode, Decode, PartialEq, Debug)]
+#[derive(Clone, Default, Encode, Decode, TreeHash, PartialEq, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(bound = "P: Preset"))]
+#[cfg_attr(
+    feature = "schemars",
+    schemars(bound = "P: Preset + schemars::JsonSchema")
+)]
 #[ssz(struct_behaviour = "container")]
-pub struct ExecutionPayload {
-    pub parent_hash: H256,
-    pub fee_recipient: H160,
+pub struct BlindedBeaconBlockCapella<P: Preset = Mainnet> {
+    pub slot: Slot,
+    pub proposer_index: ValidatorIndex,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::hex_fixed_vec"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::json_schema::Hex<H256>"))]
+    pub parent_root: H256,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::hex_fixed_vec"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::json_schema::Hex<H256>"))]
     pub state_root: H256,
-    pub receipts_root: H256,
-    pub logs_bloom: ByteVector<typenum::U256>,
-    pub prev_randao: H256,
-    pub block_number: u64,
-    pub gas_limit: u64,
-    pub gas_used: u64,
-    pub timestamp: u64,
-    // TODO(Grandine Team): Try removing the `Arc` when we have data for benchmarking Bellatrix.
-    //                      The cost of cloning `ByteList<MaxExtraDataBytes>` may be negligible.
-    pub extra_data: Arc<ByteList<typenum::U32>>,
-    pub base_fee_per_gas: U256,
-    pub block_hash: H256,
-    // TODO(Grandine Team): Consider removing the `Arc`. It can be removed with no loss of performance
-    //                      at the cost of making `ExecutionPayloadV1` more complicated.
-    pub transactions: Arc<VariableList<Transaction, typenum::U1048576>>,
-    pub withdrawals: VariableList<Withdrawal, typenum::U16>,
+    pub body: BlindedBeaconBlockBodyCapella<P>,
 }
 
-#[derive(Clone, Default, Encode, Decode, PartialEq, Debug)]
+#[derive(Clone, Default, Encode, Decode, TreeHash, PartialEq, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(bound = "P: Preset"))]
+#[cfg_attr(
+    feature = "schemars",
+    schemars(bound = "P: Preset + schemars::JsonSchema")
+)]
 #[ssz(struct_behaviour = "container")]
-pub struct SignedBlsToExecutionChange {
-    pub message: BlsToExecutionChange,
+pub struct BlindedBeaconBlockBodyCapella<P: Preset = Mainnet> {
+    pub randao_reveal: SignatureBytes,
+    pub eth1_data: Eth1Data,
+    pub graffiti: Graffiti,
+    pub proposer_slashings: VariableList<ProposerSlashing, P::MaxProposerSlashings>,
+    pub attester_slashings: VariableList<AttesterSlashing<P>, P::MaxAttesterSlashings>,
+    pub attestations: VariableList<Attestation<P>, P::MaxAttestations>,
+    pub deposits: VariableList<Deposit, P::MaxDeposits>,
+    pub voluntary_exits: VariableList<SignedVoluntaryExit, P::MaxVoluntaryExits>,
+    pub sync_aggregate: SyncAggregate<P>,
+    pub execution_payload_header: ExecutionPayloadHeaderCapella<P>,
+    pub bls_to_execution_changes:
+        VariableList<SignedBlsToExecutionChange, P::MaxBlsToExecutionChanges>,
+}
+
+#[derive(Clone, Default, Encode, Decode, TreeHash, PartialEq, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(bound = "P: Preset"))]
+#[cfg_attr(
+    feature = "schemars",
+    schemars(bound = "P: Preset + schemars::JsonSchema")
+)]
+#[ssz(struct_behaviour = "container")]
+pub struct SignedBlindedBeaconBlockDeneb<P: Preset = Mainnet> {
+    pub message: BlindedBeaconBlockDeneb<P>,
     pub signature: SignatureBytes,
 }
 
-#[derive(Clone, Default, Encode, Decode, PartialEq, Debug)]
+#[derive(Clone, Default, Encode, Decode, TreeHash, PartialEq, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(bound = "P: Preset"))]
+#[cfg_attr(
+    feature = "schemars",
+    schemars(bound = "P: Preset + schemars::JsonSchema")
+)]
 #[ssz(struct_behaviour = "container")]
-pub struct BlsToExecutionChange {
-    pub validator_index: u64,
-    pub from_bls_pubkey: PublicKeyBytes,
-    pub to_execution_address: H160,
+pub struct BlindedBeaconBlockDeneb<P: Preset = Mainnet> {
+    pub slot: Slot,
+    pub proposer_index: ValidatorIndex,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::hex_fixed_vec"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::json_schema::Hex<H256>"))]
+    pub parent_root: H256,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::hex_fixed_vec"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::json_schema::Hex<H256>"))]
+    pub state_root: H256,
+    pub body: BlindedBeaconBlockBodyDeneb<P>,
+}
+
+#[derive(Clone, Default, Encode, Decode, TreeHash, PartialEq, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(bound = "P: Preset"))]
+#[cfg_attr(
+    feature = "schemars",
+    schemars(bound = "P: Preset + schemars::JsonSchema")
+)]
+#[ssz(struct_behaviour = "container")]
+pub struct BlindedBeaconBlockBodyDeneb<P: Preset = Mainnet> {
+    pub randao_reveal: SignatureBytes,
+    pub eth1_data: Eth1Data,
+    pub graffiti: Graffiti,
+    pub proposer_slashings: VariableList<ProposerSlashing, P::MaxProposerSlashings>,
+    pub attester_slashings: VariableList<AttesterSlashing<P>, P::MaxAttesterSlashings>,
+    pub attestations: VariableList<Attestation<P>, P::MaxAttestations>,
+    pub deposits: VariableList<Deposit, P::MaxDeposits>,
+    pub voluntary_exits: VariableList<SignedVoluntaryExit, P::MaxVoluntaryExits>,
+    pub sync_aggregate: SyncAggregate<P>,
+    pub execution_payload_header: ExecutionPayloadHeaderDeneb<P>,
+    pub bls_to_execution_changes:
+        VariableList<SignedBlsToExecutionChange, P::MaxBlsToExecutionChanges>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_utils::list_of_hex_fixed_vec")
+    )]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(
+            with = "crate::json_schema::HexItems<VariableList<KzgCommitment, P::MaxBlobCommitmentsPerBlock>>"
+        )
+    )]
+    pub blob_kzg_commitments: VariableList<KzgCommitment, P::MaxBlobCommitmentsPerBlock>,
+}
+
+#[derive(Clone, Default, Encode, Decode, TreeHash, PartialEq, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(bound = "P: Preset"))]
+#[cfg_attr(
+    feature = "schemars",
+    schemars(bound = "P: Preset + schemars::JsonSchema")
+)]
+#[ssz(struct_behaviour = "container")]
+pub struct SignedBlindedBeaconBlockElectra<P: Preset = Mainnet> {
+    pub message: BlindedBeaconBlockElectra<P>,
+    pub signature: SignatureBytes,
+}
+
+#[derive(Clone, Default, Encode, Decode, TreeHash, PartialEq, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(bound = "P: Preset"))]
+#[cfg_attr(
+    feature = "schemars",
+    schemars(bound = "P: Preset + schemars::JsonSchema")
+)]
+#[ssz(struct_behaviour = "container")]
+pub struct BlindedBeaconBlockElectra<P: Preset = Mainnet> {
+    pub slot: Slot,
+    pub proposer_index: ValidatorIndex,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::hex_fixed_vec"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::json_schema::Hex<H256>"))]
+    pub parent_root: H256,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::hex_fixed_vec"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::json_schema::Hex<H256>"))]
+    pub state_root: H256,
+    pub body: BlindedBeaconBlockBodyElectra<P>,
+}
+
+#[derive(Clone, Default, Encode, Decode, TreeHash, PartialEq, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(bound = "P: Preset"))]
+#[cfg_attr(
+    feature = "schemars",
+    schemars(bound = "P: Preset + schemars::JsonSchema")
+)]
+#[ssz(struct_behaviour = "container")]
+pub struct BlindedBeaconBlockBodyElectra<P: Preset = Mainnet> {
+    pub randao_reveal: SignatureBytes,
+    pub eth1_data: Eth1Data,
+    pub graffiti: Graffiti,
+    pub proposer_slashings: VariableList<ProposerSlashing, P::MaxProposerSlashings>,
+    pub attester_slashings:
+        VariableList<AttesterSlashingElectra<P>, P::MaxAttesterSlashingsElectra>,
+    pub attestations: VariableList<AttestationElectra<P>, P::MaxAttestationsElectra>,
+    pub deposits: VariableList<Deposit, P::MaxDeposits>,
+    pub voluntary_exits: VariableList<SignedVoluntaryExit, P::MaxVoluntaryExits>,
+    pub sync_aggregate: SyncAggregate<P>,
+    pub execution_payload_header: ExecutionPayloadHeaderDeneb<P>,
+    pub bls_to_execution_changes:
+        VariableList<SignedBlsToExecutionChange, P::MaxBlsToExecutionChanges>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_utils::list_of_hex_fixed_vec")
+    )]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(
+            with = "crate::json_schema::HexItems<VariableList<KzgCommitment, P::MaxBlobCommitmentsPerBlock>>"
+        )
+    )]
+    pub blob_kzg_commitments: VariableList<KzgCommitment, P::MaxBlobCommitmentsPerBlock>,
+    pub execution_requests: ExecutionRequests<P>,
+}
+
+#[derive(Clone, Default, Encode, Decode, TreeHash, PartialEq, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[ssz(struct_behaviour = "container")]
+pub struct Eth1Data {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::hex_fixed_vec"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::json_schema::Hex<H256>"))]
+    pub deposit_root: H256,
+    #[cfg_attr(feature = "serde", serde(with = "serde_utils::quoted_u64"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "crate::json_schema::Quoted<u64>")
+    )]
+    pub deposit_count: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::hex_fixed_vec"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::json_schema::Hex<H256>"))]
+    pub block_hash: H256,
+}
+
+#[derive(Clone, Default, Encode, Decode, TreeHash, PartialEq, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[ssz(struct_behaviour = "container")]
+pub struct ProposerSlashing {
+    pub signed_header_1: SignedBeaconBlockHeader,
+    pub signed_header_2: SignedBeaconBlockHeader,
+}
+
+impl ProposerSlashing {
+    /// True if the headers are of two different blocks proposed by the same proposer for the
+    /// same slot, as checked by `process_proposer_slashing` in the consensus specs.
+    ///
+    /// The signatures of the headers, and whether the proposer is slashable, are not checked.
+    pub fn is_valid_structure(&self) -> bool {
+        let header_1 = &self.signed_header_1.message;
+        let header_2 = &self.signed_header_2.message;
+
+        header_1.slot == header_2.slot
+            && header_1.proposer_index == header_2.proposer_index
+            && header_1 != header_2
+    }
+}
+
+#[derive(Clone, Default, Encode, Decode, TreeHash, PartialEq, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[ssz(struct_behaviour = "container")]
+pub struct Checkpoint {
+    pub epoch: Epoch,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::hex_fixed_vec"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::json_schema::Hex<H256>"))]
+    pub root: H256,
+}
+
+#[derive(Clone, Default, Encode, Decode, TreeHash, PartialEq, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[ssz(struct_behaviour = "container")]
+pub struct AttestationData {
+    pub slot: Slot,
+    pub index: CommitteeIndex,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::hex_fixed_vec"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::json_schema::Hex<H256>"))]
+    pub beacon_block_root: H256,
+    pub source: Checkpoint,
+    pub target: Checkpoint,
+}
+
+impl AttestationData {
+    /// True if `self` and `other` are different votes for the same target epoch.
+    pub fn is_double_vote(&self, other: &Self) -> bool {
+        self != other && self.target.epoch == other.target.epoch
+    }
+
+    /// True if the vote of `self` surrounds that of `other`, i.e. has an earlier source and a
+    /// later target.
+    pub fn is_surround_vote(&self, other: &Self) -> bool {
+        self.source.epoch < other.source.epoch && other.target.epoch < self.target.epoch
+    }
+
+    /// True if attesting to both `self` and `other` is slashable, as per
+    /// `is_slashable_attestation_data` in the consensus specs.
+    ///
+    /// The order matters: `other` surrounding `self` is not slashable, as the specs expect the
+    /// surrounding vote first.
+    pub fn is_slashable_with(&self, other: &Self) -> bool {
+        self.is_double_vote(other) || self.is_surround_vote(other)
+    }
+}
+
+#[derive(Clone, Default, Encode, Decode, TreeHash, PartialEq, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(bound = "P: Preset"))]
+#[cfg_attr(
+    feature = "schemars",
+    schemars(bound = "P: Preset + schemars::JsonSchema")
+)]
+#[ssz(struct_behaviour = "container")]
+pub struct IndexedAttestation<P: Preset = Mainnet> {
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_utils::quoted_u64_var_list")
+    )]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(
+            with = "crate::json_schema::QuotedItems<VariableList<u64, P::MaxValidatorsPerCommittee>>"
+        )
+    )]
+    pub attesting_indices: VariableList<u64, P::MaxValidatorsPerCommittee>,
+    pub data: AttestationData,
+    pub signature: SignatureBytes,
+}
+
+#[derive(Clone, Default, Encode, Decode, TreeHash, PartialEq, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(bound = "P: Preset"))]
+#[cfg_attr(
+    feature = "schemars",
+    schemars(bound = "P: Preset + schemars::JsonSchema")
+)]
+#[ssz(struct_behaviour = "container")]
+pub struct AttesterSlashing<P: Preset = Mainnet> {
+    pub attestation_1: IndexedAttestation<P>,
+    pub attestation_2: IndexedAttestation<P>,
+}
+
+#[derive(Clone, Default, Encode, Decode, TreeHash, PartialEq, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(bound = "P: Preset"))]
+#[cfg_attr(
+    feature = "schemars",
+    schemars(bound = "P: Preset + schemars::JsonSchema")
+)]
+#[ssz(struct_behaviour = "container")]
+pub struct Attestation<P: Preset = Mainnet> {
+    pub aggregation_bits: CustomBitList<P::MaxValidatorsPerCommittee>,
+    pub data: AttestationData,
+    pub signature: SignatureBytes,
+}
+
+#[derive(Clone, Default, Encode, Decode, TreeHash, PartialEq, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(bound = "P: Preset"))]
+#[cfg_attr(
+    feature = "schemars",
+    schemars(bound = "P: Preset + schemars::JsonSchema")
+)]
+#[ssz(struct_behaviour = "container")]
+pub struct AttestationElectra<P: Preset = Mainnet> {
+    pub aggregation_bits: CustomBitList<P::MaxValidatorsPerSlot>,
+    pub data: AttestationData,
+    pub signature: SignatureBytes,
+    pub committee_bits: BitVector<P::MaxCommitteesPerSlot>,
+}
+
+#[derive(Clone, Default, Encode, Decode, TreeHash, PartialEq, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(bound = "P: Preset"))]
+#[cfg_attr(
+    feature = "schemars",
+    schemars(bound = "P: Preset + schemars::JsonSchema")
+)]
+#[ssz(struct_behaviour = "container")]
+pub struct IndexedAttestationElectra<P: Preset = Mainnet> {
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_utils::quoted_u64_var_list")
+    )]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(
+            with = "crate::json_schema::QuotedItems<VariableList<u64, P::MaxValidatorsPerSlot>>"
+        )
+    )]
+    pub attesting_indices: VariableList<u64, P::MaxValidatorsPerSlot>,
+    pub data: AttestationData,
+    pub signature: SignatureBytes,
+}
+
+#[derive(Clone, Default, Encode, Decode, TreeHash, PartialEq, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(bound = "P: Preset"))]
+#[cfg_attr(
+    feature = "schemars",
+    schemars(bound = "P: Preset + schemars::JsonSchema")
+)]
+#[ssz(struct_behaviour = "container")]
+pub struct AttesterSlashingElectra<P: Preset = Mainnet> {
+    pub attestation_1: IndexedAttestationElectra<P>,
+    pub attestation_2: IndexedAttestationElectra<P>,
+}
+
+impl<P: Preset> AttesterSlashing<P> {
+    /// True if the data of the attestations is slashable, i.e. they are a double vote or the
+    /// first surrounds the second, as checked by `process_attester_slashing` in the consensus
+    /// specs.
+    ///
+    /// The signatures, and whether any attester is in both attestations, are not checked.
+    pub fn is_slashable(&self) -> bool {
+        self.attestation_1
+            .data
+            .is_slashable_with(&self.attestation_2.data)
+    }
+}
+
+impl<P: Preset> AttesterSlashingElectra<P> {
+    /// True if the data of the attestations is slashable, as `AttesterSlashing::is_slashable`.
+    pub fn is_slashable(&self) -> bool {
+        self.attestation_1
+            .data
+            .is_slashable_with(&self.attestation_2.data)
+    }
+}
+
+impl<P: Preset> Attestation<P> {
+    /// Returns the attestation with the indices of its attesters in place of its bits, as per
+    /// `get_indexed_attestation` in the consensus specs. `committee` is the committee of the
+    /// attestation, whose members are attesters if their bits are set.
+    ///
+    /// Returns `Err` if the number of bits differs from the size of the committee.
+    pub fn to_indexed(&self, committee: &[ValidatorIndex]) -> Result<IndexedAttestation<P>, Error> {
+        let bits = self.aggregation_bits.bits();
+        if bits.len() != committee.len() {
+            return Err(Error::OutOfBounds {
+                i: bits.len(),
+                len: committee.len(),
+            });
+        }
+
+        Ok(IndexedAttestation {
+            attesting_indices: sorted_attesting_indices(committee.iter().zip(bits.iter()))?,
+            data: self.data.clone(),
+            signature: self.signature.clone(),
+        })
+    }
+
+    /// Aggregates `other` into the attestation, which then has the attesters of both and the
+    /// aggregate of their signatures.
+    ///
+    /// Returns `Err(Error::AttestationMismatch)` if the attestations are of different data,
+    /// `Err(Error::OutOfBounds)` if their bits are of committees of different sizes and
+    /// `Err(Error::OverlappingAggregationBits)` if they have attesters in common, whose
+    /// signatures would be aggregated twice. The attestation is unchanged on error.
+    #[cfg(feature = "bls")]
+    pub fn aggregate(&mut self, other: &Self) -> Result<(), Error> {
+        self.aggregate_with(other, false)
+    }
+
+    /// As per `aggregate`, but allows the attestations to have attesters in common.
+    ///
+    /// The signatures of the attesters in common are then aggregated twice, so the signature no
+    /// longer verifies against the attesting indices. This suits pools which only track which
+    /// attesters are covered.
+    #[cfg(feature = "bls")]
+    pub fn aggregate_overlapping(&mut self, other: &Self) -> Result<(), Error> {
+        self.aggregate_with(other, true)
+    }
+
+    /// Adds the attesters of `other` to the attestation, which may have attesters in common,
+    /// without aggregating their signatures.
+    ///
+    /// The signature is left unchanged, so it no longer covers the attesters: the attestation
+    /// must not be published or included in a block. Use `aggregate` with the `bls` feature for
+    /// that.
+    ///
+    /// Returns `Err` as per `aggregate`, except for attesters in common.
+    pub fn union_bits(&mut self, other: &Self) -> Result<(), Error> {
+        self.check_aggregatable(other, true)?;
+        self.aggregation_bits =
+            CustomBitList(self.aggregation_bits.0.union(&other.aggregation_bits.0));
+        Ok(())
+    }
+
+    #[cfg(feature = "bls")]
+    fn aggregate_with(&mut self, other: &Self, allow_overlap: bool) -> Result<(), Error> {
+        self.check_aggregatable(other, allow_overlap)?;
+        self.signature.aggregate(&other.signature)?;
+        self.union_bits(other)
+    }
+
+    fn check_aggregatable(&self, other: &Self, allow_overlap: bool) -> Result<(), Error> {
+        if self.data != other.data {
+            return Err(Error::AttestationMismatch);
+        }
+        self.aggregation_bits
+            .check_aggregatable(&other.aggregation_bits, allow_overlap)
+    }
+}
+
+impl<P: Preset> AttestationElectra<P> {
+    /// Aggregates `other` into the attestation, as `Attestation::aggregate` does.
+    ///
+    /// The attestations must also be of the same committees, which gossip restricts to one
+    /// committee per attestation until they are aggregated on chain.
+    #[cfg(feature = "bls")]
+    pub fn aggregate(&mut self, other: &Self) -> Result<(), Error> {
+        self.aggregate_with(other, false)
+    }
+
+    /// As per `aggregate`, but allows the attestations to have attesters in common, as
+    /// `Attestation::aggregate_overlapping` does.
+    #[cfg(feature = "bls")]
+    pub fn aggregate_overlapping(&mut self, other: &Self) -> Result<(), Error> {
+        self.aggregate_with(other, true)
+    }
+
+    /// Adds the attesters of `other` to the attestation without aggregating their signatures,
+    /// leaving a signature which no longer covers the attesters, as `Attestation::union_bits`
+    /// does.
+    pub fn union_bits(&mut self, other: &Self) -> Result<(), Error> {
+        self.check_aggregatable(other, true)?;
+        self.aggregation_bits =
+            CustomBitList(self.aggregation_bits.0.union(&other.aggregation_bits.0));
+        Ok(())
+    }
+
+    #[cfg(feature = "bls")]
+    fn aggregate_with(&mut self, other: &Self, allow_overlap: bool) -> Result<(), Error> {
+        self.check_aggregatable(other, allow_overlap)?;
+        self.signature.aggregate(&other.signature)?;
+        self.union_bits(other)
+    }
+
+    fn check_aggregatable(&self, other: &Self, allow_overlap: bool) -> Result<(), Error> {
+        if self.data != other.data || self.committee_bits != other.committee_bits {
+            return Err(Error::AttestationMismatch);
+        }
+        self.aggregation_bits
+            .check_aggregatable(&other.aggregation_bits, allow_overlap)
+    }
+
+    /// Returns the attestation with the indices of its attesters in place of its bits, as per
+    /// `get_indexed_attestation` in the Electra specs. `committees` are the committees of
+    /// `committee_indices`, in that order, whose members have their bits one committee after
+    /// the other.
+    ///
+    /// Returns `Err` if there is not one committee per committee index, or if the number of bits
+    /// differs from the total size of the committees.
+    pub fn to_indexed(
+        &self,
+        committees: &[&[ValidatorIndex]],
+    ) -> Result<IndexedAttestationElectra<P>, Error> {
+        let committee_count = self.committee_bits.num_set_bits();
+        if committees.len() != committee_count {
+            return Err(Error::OutOfBounds {
+                i: committees.len(),
+                len: committee_count,
+            });
+        }
+        let bits = self.aggregation_bits.bits();
+        let members = committees.iter().map(|committee| committee.len()).sum();
+        if bits.len() != members {
+            return Err(Error::OutOfBounds {
+                i: bits.len(),
+                len: members,
+            });
+        }
+
+        let members = committees.iter().flat_map(|committee| committee.iter());
+        Ok(IndexedAttestationElectra {
+            attesting_indices: sorted_attesting_indices(members.zip(bits.iter()))?,
+            data: self.data.clone(),
+            signature: self.signature.clone(),
+        })
+    }
+
+    /// Returns the indices of the committees whose members may have attested, in ascending order.
+    pub fn committee_indices(&self) -> Vec<CommitteeIndex> {
+        self.committee_bits
+            .iter()
+            .enumerate()
+            .filter(|(_, bit)| *bit)
+            .map(|(index, _)| CommitteeIndex::new(index as u64))
+            .collect()
+    }
+}
+
+/// Returns the sorted and deduplicated indices of the validators whose bits are set.
+fn sorted_attesting_indices<'a, N: Unsigned>(
+    members: impl Iterator<Item = (&'a ValidatorIndex, bool)>,
+) -> Result<VariableList<u64, N>, Error> {
+    let mut indices = members
+        .filter(|(_, bit)| *bit)
+        .map(|(index, _)| index.as_u64())
+        .collect::<Vec<_>>();
+    indices.sort_unstable();
+    indices.dedup();
+    VariableList::new(indices)
+}
+
+/// Converts an attestation of a single committee to the format of Electra, which moves the index
+/// of the committee from `data.index` into `committee_bits` and leaves `data.index` as zero.
+///
+/// Returns `Err` if the committee index is not below the maximum number of committees per slot.
+impl<P: Preset> TryFrom<Attestation<P>> for AttestationElectra<P> {
+    type Error = Error;
+
+    fn try_from(attestation: Attestation<P>) -> Result<Self, Error> {
+        let Attestation {
+            aggregation_bits,
+            mut data,
+            signature,
+        } = attestation;
+
+        let mut committee_bits = BitVector::new();
+        committee_bits.set(data.index.as_u64() as usize, true)?;
+        data.index = CommitteeIndex::new(0);
+
+        Ok(Self {
+            aggregation_bits: CustomBitList(BitList::from_bytes(aggregation_bits.0.into_bytes())?),
+            data,
+            signature,
+            committee_bits,
+        })
+    }
+}
+
+impl<P: Preset> From<IndexedAttestation<P>> for IndexedAttestationElectra<P> {
+    fn from(attestation: IndexedAttestation<P>) -> Self {
+        Self {
+            attesting_indices: VariableList::new(attestation.attesting_indices.into())
+                .expect("the attesting indices of Electra have a greater limit"),
+            data: attestation.data,
+            signature: attestation.signature,
+        }
+    }
+}
+
+impl<P: Preset> From<AttesterSlashing<P>> for AttesterSlashingElectra<P> {
+    fn from(slashing: AttesterSlashing<P>) -> Self {
+        Self {
+            attestation_1: slashing.attestation_1.into(),
+            attestation_2: slashing.attestation_2.into(),
+        }
+    }
+}
+
+#[derive(Clone, Default, Encode, Decode, TreeHash, PartialEq, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[ssz(struct_behaviour = "container")]
+pub struct DepositData {
+    pub pubkey: PublicKeyBytes,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::hex_fixed_vec"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::json_schema::Hex<H256>"))]
+    pub withdrawal_credentials: H256,
+    pub amount: Gwei,
+    pub signature: SignatureBytes,
+}
+
+#[derive(Clone, Default, Encode, Decode, TreeHash, PartialEq, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[ssz(struct_behaviour = "container")]
+pub struct DepositMessage {
+    pub pubkey: PublicKeyBytes,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::hex_fixed_vec"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::json_schema::Hex<H256>"))]
+    pub withdrawal_credentials: H256,
+    pub amount: Gwei,
+}
+
+#[derive(Clone, Default, Encode, Decode, TreeHash, PartialEq, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[ssz(struct_behaviour = "container")]
+pub struct Deposit {
+    /// The branch of `data` in the deposit contract, including the mixed in deposit count.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_utils::fixed_vec_of_hex_fixed_vec")
+    )]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "crate::json_schema::HexItems<FixedVector<H256, typenum::U33>>")
+    )]
+    pub proof: FixedVector<H256, typenum::U33>,
+    pub data: DepositData,
+}
+
+impl DepositData {
+    /// Returns the message that the signature of the deposit signs, i.e. the deposit without its
+    /// signature.
+    pub fn deposit_message(&self) -> DepositMessage {
+        DepositMessage {
+            pubkey: self.pubkey.clone(),
+            withdrawal_credentials: self.withdrawal_credentials.clone(),
+            amount: self.amount,
+        }
+    }
+}
+
+impl Deposit {
+    /// Returns `true` if `proof` proves `data` as the deposit at `index` in the deposit contract
+    /// with the root `deposit_root`, e.g. `Eth1Data::deposit_root`.
+    ///
+    /// `index` is the index of the deposit in the contract, i.e. `eth1_deposit_index` of the state
+    /// processing it.
+    pub fn verify_proof(&self, deposit_root: &H256, index: u64) -> bool {
+        let branch = self
+            .proof
+            .iter()
+            .map(|node| Hash256::from_slice(node))
+            .collect::<Vec<_>>();
+
+        verify_deposit_branch(
+            self.data.tree_hash_root(),
+            &branch,
+            index,
+            Hash256::from_slice(deposit_root),
+        )
+    }
+}
+
+#[derive(Clone, Default, Encode, Decode, TreeHash, PartialEq, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[ssz(struct_behaviour = "container")]
+pub struct VoluntaryExit {
+    pub epoch: Epoch,
+    pub validator_index: ValidatorIndex,
+}
+
+#[derive(Clone, Default, Encode, Decode, TreeHash, PartialEq, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[ssz(struct_behaviour = "container")]
+pub struct SignedVoluntaryExit {
+    pub message: VoluntaryExit,
+    pub signature: SignatureBytes,
+}
+
+#[derive(Clone, Default, Encode, Decode, TreeHash, PartialEq, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(bound = "P: Preset"))]
+#[cfg_attr(
+    feature = "schemars",
+    schemars(bound = "P: Preset + schemars::JsonSchema")
+)]
+#[ssz(struct_behaviour = "container")]
+pub struct SyncAggregate<P: Preset = Mainnet> {
+    pub sync_committee_bits: BitVector<P::SyncCommitteeSize>,
+    pub sync_committee_signature: SignatureBytes,
+}
+
+pub type Transaction<P = Mainnet> = ByteList<<P as Preset>::MaxBytesPerTransaction>;
+
+#[derive(Clone, Default, Encode, Decode, TreeHash, PartialEq, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[ssz(struct_behaviour = "container")]
+pub struct Withdrawal {
+    #[cfg_attr(feature = "serde", serde(with = "serde_utils::quoted_u64"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "crate::json_schema::Quoted<u64>")
+    )]
+    pub index: u64,
+    pub validator_index: ValidatorIndex,
+    pub address: ExecutionAddress,
+    pub amount: Gwei,
+}
+
+#[derive(Clone, Default, Encode, Decode, TreeHash, PartialEq, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(bound = "P: Preset"))]
+#[cfg_attr(
+    feature = "schemars",
+    schemars(bound = "P: Preset + schemars::JsonSchema")
+)]
+#[ssz(struct_behaviour = "container")]
+pub struct ExecutionPayloadBellatrix<P: Preset = Mainnet> {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::hex_fixed_vec"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::json_schema::Hex<H256>"))]
+    pub parent_hash: H256,
+    pub fee_recipient: ExecutionAddress,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::hex_fixed_vec"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::json_schema::Hex<H256>"))]
+    pub state_root: H256,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::hex_fixed_vec"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::json_schema::Hex<H256>"))]
+    pub receipts_root: H256,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::hex_fixed_vec"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "crate::json_schema::Hex<ByteVector<P::BytesPerLogsBloom>>")
+    )]
+    pub logs_bloom: ByteVector<P::BytesPerLogsBloom>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::hex_fixed_vec"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::json_schema::Hex<H256>"))]
+    pub prev_randao: H256,
+    #[cfg_attr(feature = "serde", serde(with = "serde_utils::quoted_u64"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "crate::json_schema::Quoted<u64>")
+    )]
+    pub block_number: u64,
+    #[cfg_attr(feature = "serde", serde(with = "serde_utils::quoted_u64"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "crate::json_schema::Quoted<u64>")
+    )]
+    pub gas_limit: u64,
+    #[cfg_attr(feature = "serde", serde(with = "serde_utils::quoted_u64"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "crate::json_schema::Quoted<u64>")
+    )]
+    pub gas_used: u64,
+    #[cfg_attr(feature = "serde", serde(with = "serde_utils::quoted_u64"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "crate::json_schema::Quoted<u64>")
+    )]
+    pub timestamp: u64,
+    // TODO(Grandine Team): Try removing the `CowList` when we have data for benchmarking Bellatrix.
+    //                      The cost of cloning `ByteList<MaxExtraDataBytes>` may be negligible.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::hex_cow_list"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "crate::json_schema::Hex<CowList<u8, P::MaxExtraDataBytes>>")
+    )]
+    pub extra_data: CowList<u8, P::MaxExtraDataBytes>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::quoted_u256"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "crate::json_schema::Quoted<U256>")
+    )]
+    pub base_fee_per_gas: U256,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::hex_fixed_vec"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::json_schema::Hex<H256>"))]
+    pub block_hash: H256,
+    // TODO(Grandine Team): Consider removing the `CowList`. It can be removed with no loss of
+    //                      performance at the cost of making `ExecutionPayloadV1` more complicated.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_utils::list_of_hex_cow_list")
+    )]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(
+            with = "crate::json_schema::HexItems<CowList<Transaction<P>, P::MaxTransactionsPerPayload>>"
+        )
+    )]
+    pub transactions: CowList<Transaction<P>, P::MaxTransactionsPerPayload>,
+}
+
+#[derive(Clone, Default, Encode, Decode, TreeHash, PartialEq, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(bound = "P: Preset"))]
+#[cfg_attr(
+    feature = "schemars",
+    schemars(bound = "P: Preset + schemars::JsonSchema")
+)]
+#[ssz(struct_behaviour = "container")]
+pub struct ExecutionPayloadCapella<P: Preset = Mainnet> {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::hex_fixed_vec"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::json_schema::Hex<H256>"))]
+    pub parent_hash: H256,
+    pub fee_recipient: ExecutionAddress,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::hex_fixed_vec"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::json_schema::Hex<H256>"))]
+    pub state_root: H256,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::hex_fixed_vec"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::json_schema::Hex<H256>"))]
+    pub receipts_root: H256,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::hex_fixed_vec"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "crate::json_schema::Hex<ByteVector<P::BytesPerLogsBloom>>")
+    )]
+    pub logs_bloom: ByteVector<P::BytesPerLogsBloom>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::hex_fixed_vec"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::json_schema::Hex<H256>"))]
+    pub prev_randao: H256,
+    #[cfg_attr(feature = "serde", serde(with = "serde_utils::quoted_u64"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "crate::json_schema::Quoted<u64>")
+    )]
+    pub block_number: u64,
+    #[cfg_attr(feature = "serde", serde(with = "serde_utils::quoted_u64"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "crate::json_schema::Quoted<u64>")
+    )]
+    pub gas_limit: u64,
+    #[cfg_attr(feature = "serde", serde(with = "serde_utils::quoted_u64"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "crate::json_schema::Quoted<u64>")
+    )]
+    pub gas_used: u64,
+    #[cfg_attr(feature = "serde", serde(with = "serde_utils::quoted_u64"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "crate::json_schema::Quoted<u64>")
+    )]
+    pub timestamp: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::hex_cow_list"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "crate::json_schema::Hex<CowList<u8, P::MaxExtraDataBytes>>")
+    )]
+    pub extra_data: CowList<u8, P::MaxExtraDataBytes>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::quoted_u256"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "crate::json_schema::Quoted<U256>")
+    )]
+    pub base_fee_per_gas: U256,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::hex_fixed_vec"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::json_schema::Hex<H256>"))]
+    pub block_hash: H256,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_utils::list_of_hex_cow_list")
+    )]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(
+            with = "crate::json_schema::HexItems<CowList<Transaction<P>, P::MaxTransactionsPerPayload>>"
+        )
+    )]
+    pub transactions: CowList<Transaction<P>, P::MaxTransactionsPerPayload>,
+    pub withdrawals: VariableList<Withdrawal, P::MaxWithdrawalsPerPayload>,
+}
+
+#[derive(Clone, Default, Encode, Decode, TreeHash, PartialEq, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(bound = "P: Preset"))]
+#[cfg_attr(
+    feature = "schemars",
+    schemars(bound = "P: Preset + schemars::JsonSchema")
+)]
+#[ssz(struct_behaviour = "container")]
+pub struct ExecutionPayloadDeneb<P: Preset = Mainnet> {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::hex_fixed_vec"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::json_schema::Hex<H256>"))]
+    pub parent_hash: H256,
+    pub fee_recipient: ExecutionAddress,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::hex_fixed_vec"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::json_schema::Hex<H256>"))]
+    pub state_root: H256,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::hex_fixed_vec"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::json_schema::Hex<H256>"))]
+    pub receipts_root: H256,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::hex_fixed_vec"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "crate::json_schema::Hex<ByteVector<P::BytesPerLogsBloom>>")
+    )]
+    pub logs_bloom: ByteVector<P::BytesPerLogsBloom>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::hex_fixed_vec"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::json_schema::Hex<H256>"))]
+    pub prev_randao: H256,
+    #[cfg_attr(feature = "serde", serde(with = "serde_utils::quoted_u64"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "crate::json_schema::Quoted<u64>")
+    )]
+    pub block_number: u64,
+    #[cfg_attr(feature = "serde", serde(with = "serde_utils::quoted_u64"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "crate::json_schema::Quoted<u64>")
+    )]
+    pub gas_limit: u64,
+    #[cfg_attr(feature = "serde", serde(with = "serde_utils::quoted_u64"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "crate::json_schema::Quoted<u64>")
+    )]
+    pub gas_used: u64,
+    #[cfg_attr(feature = "serde", serde(with = "serde_utils::quoted_u64"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "crate::json_schema::Quoted<u64>")
+    )]
+    pub timestamp: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::hex_cow_list"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "crate::json_schema::Hex<CowList<u8, P::MaxExtraDataBytes>>")
+    )]
+    pub extra_data: CowList<u8, P::MaxExtraDataBytes>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::quoted_u256"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "crate::json_schema::Quoted<U256>")
+    )]
+    pub base_fee_per_gas: U256,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::hex_fixed_vec"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::json_schema::Hex<H256>"))]
+    pub block_hash: H256,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_utils::list_of_hex_cow_list")
+    )]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(
+            with = "crate::json_schema::HexItems<CowList<Transaction<P>, P::MaxTransactionsPerPayload>>"
+        )
+    )]
+    pub transactions: CowList<Transaction<P>, P::MaxTransactionsPerPayload>,
+    pub withdrawals: VariableList<Withdrawal, P::MaxWithdrawalsPerPayload>,
+    #[cfg_attr(feature = "serde", serde(with = "serde_utils::quoted_u64"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "crate::json_schema::Quoted<u64>")
+    )]
+    pub blob_gas_used: u64,
+    #[cfg_attr(feature = "serde", serde(with = "serde_utils::quoted_u64"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "crate::json_schema::Quoted<u64>")
+    )]
+    pub excess_blob_gas: u64,
+}
+
+#[derive(Clone, Default, Encode, Decode, TreeHash, PartialEq, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(bound = "P: Preset"))]
+#[cfg_attr(
+    feature = "schemars",
+    schemars(bound = "P: Preset + schemars::JsonSchema")
+)]
+#[ssz(struct_behaviour = "container")]
+pub struct ExecutionPayloadHeaderBellatrix<P: Preset = Mainnet> {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::hex_fixed_vec"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::json_schema::Hex<H256>"))]
+    pub parent_hash: H256,
+    pub fee_recipient: ExecutionAddress,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::hex_fixed_vec"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::json_schema::Hex<H256>"))]
+    pub state_root: H256,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::hex_fixed_vec"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::json_schema::Hex<H256>"))]
+    pub receipts_root: H256,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::hex_fixed_vec"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "crate::json_schema::Hex<ByteVector<P::BytesPerLogsBloom>>")
+    )]
+    pub logs_bloom: ByteVector<P::BytesPerLogsBloom>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::hex_fixed_vec"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::json_schema::Hex<H256>"))]
+    pub prev_randao: H256,
+    #[cfg_attr(feature = "serde", serde(with = "serde_utils::quoted_u64"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "crate::json_schema::Quoted<u64>")
+    )]
+    pub block_number: u64,
+    #[cfg_attr(feature = "serde", serde(with = "serde_utils::quoted_u64"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "crate::json_schema::Quoted<u64>")
+    )]
+    pub gas_limit: u64,
+    #[cfg_attr(feature = "serde", serde(with = "serde_utils::quoted_u64"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "crate::json_schema::Quoted<u64>")
+    )]
+    pub gas_used: u64,
+    #[cfg_attr(feature = "serde", serde(with = "serde_utils::quoted_u64"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "crate::json_schema::Quoted<u64>")
+    )]
+    pub timestamp: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::hex_cow_list"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "crate::json_schema::Hex<CowList<u8, P::MaxExtraDataBytes>>")
+    )]
+    pub extra_data: CowList<u8, P::MaxExtraDataBytes>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::quoted_u256"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "crate::json_schema::Quoted<U256>")
+    )]
+    pub base_fee_per_gas: U256,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::hex_fixed_vec"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::json_schema::Hex<H256>"))]
+    pub block_hash: H256,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::hex_fixed_vec"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::json_schema::Hex<H256>"))]
+    pub transactions_root: H256,
+}
+
+#[derive(Clone, Default, Encode, Decode, TreeHash, PartialEq, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(bound = "P: Preset"))]
+#[cfg_attr(
+    feature = "schemars",
+    schemars(bound = "P: Preset + schemars::JsonSchema")
+)]
+#[ssz(struct_behaviour = "container")]
+pub struct ExecutionPayloadHeaderCapella<P: Preset = Mainnet> {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::hex_fixed_vec"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::json_schema::Hex<H256>"))]
+    pub parent_hash: H256,
+    pub fee_recipient: ExecutionAddress,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::hex_fixed_vec"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::json_schema::Hex<H256>"))]
+    pub state_root: H256,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::hex_fixed_vec"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::json_schema::Hex<H256>"))]
+    pub receipts_root: H256,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::hex_fixed_vec"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "crate::json_schema::Hex<ByteVector<P::BytesPerLogsBloom>>")
+    )]
+    pub logs_bloom: ByteVector<P::BytesPerLogsBloom>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::hex_fixed_vec"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::json_schema::Hex<H256>"))]
+    pub prev_randao: H256,
+    #[cfg_attr(feature = "serde", serde(with = "serde_utils::quoted_u64"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "crate::json_schema::Quoted<u64>")
+    )]
+    pub block_number: u64,
+    #[cfg_attr(feature = "serde", serde(with = "serde_utils::quoted_u64"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "crate::json_schema::Quoted<u64>")
+    )]
+    pub gas_limit: u64,
+    #[cfg_attr(feature = "serde", serde(with = "serde_utils::quoted_u64"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "crate::json_schema::Quoted<u64>")
+    )]
+    pub gas_used: u64,
+    #[cfg_attr(feature = "serde", serde(with = "serde_utils::quoted_u64"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "crate::json_schema::Quoted<u64>")
+    )]
+    pub timestamp: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::hex_cow_list"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "crate::json_schema::Hex<CowList<u8, P::MaxExtraDataBytes>>")
+    )]
+    pub extra_data: CowList<u8, P::MaxExtraDataBytes>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::quoted_u256"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "crate::json_schema::Quoted<U256>")
+    )]
+    pub base_fee_per_gas: U256,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::hex_fixed_vec"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::json_schema::Hex<H256>"))]
+    pub block_hash: H256,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::hex_fixed_vec"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::json_schema::Hex<H256>"))]
+    pub transactions_root: H256,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::hex_fixed_vec"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::json_schema::Hex<H256>"))]
+    pub withdrawals_root: H256,
+}
+
+#[derive(Clone, Default, Encode, Decode, TreeHash, PartialEq, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(bound = "P: Preset"))]
+#[cfg_attr(
+    feature = "schemars",
+    schemars(bound = "P: Preset + schemars::JsonSchema")
+)]
+#[ssz(struct_behaviour = "container")]
+pub struct ExecutionPayloadHeaderDeneb<P: Preset = Mainnet> {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::hex_fixed_vec"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::json_schema::Hex<H256>"))]
+    pub parent_hash: H256,
+    pub fee_recipient: ExecutionAddress,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::hex_fixed_vec"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::json_schema::Hex<H256>"))]
+    pub state_root: H256,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::hex_fixed_vec"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::json_schema::Hex<H256>"))]
+    pub receipts_root: H256,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::hex_fixed_vec"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "crate::json_schema::Hex<ByteVector<P::BytesPerLogsBloom>>")
+    )]
+    pub logs_bloom: ByteVector<P::BytesPerLogsBloom>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::hex_fixed_vec"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::json_schema::Hex<H256>"))]
+    pub prev_randao: H256,
+    #[cfg_attr(feature = "serde", serde(with = "serde_utils::quoted_u64"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "crate::json_schema::Quoted<u64>")
+    )]
+    pub block_number: u64,
+    #[cfg_attr(feature = "serde", serde(with = "serde_utils::quoted_u64"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "crate::json_schema::Quoted<u64>")
+    )]
+    pub gas_limit: u64,
+    #[cfg_attr(feature = "serde", serde(with = "serde_utils::quoted_u64"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "crate::json_schema::Quoted<u64>")
+    )]
+    pub gas_used: u64,
+    #[cfg_attr(feature = "serde", serde(with = "serde_utils::quoted_u64"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "crate::json_schema::Quoted<u64>")
+    )]
+    pub timestamp: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::hex_cow_list"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "crate::json_schema::Hex<CowList<u8, P::MaxExtraDataBytes>>")
+    )]
+    pub extra_data: CowList<u8, P::MaxExtraDataBytes>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::quoted_u256"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "crate::json_schema::Quoted<U256>")
+    )]
+    pub base_fee_per_gas: U256,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::hex_fixed_vec"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::json_schema::Hex<H256>"))]
+    pub block_hash: H256,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::hex_fixed_vec"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::json_schema::Hex<H256>"))]
+    pub transactions_root: H256,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::hex_fixed_vec"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::json_schema::Hex<H256>"))]
+    pub withdrawals_root: H256,
+    #[cfg_attr(feature = "serde", serde(with = "serde_utils::quoted_u64"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "crate::json_schema::Quoted<u64>")
+    )]
+    pub blob_gas_used: u64,
+    #[cfg_attr(feature = "serde", serde(with = "serde_utils::quoted_u64"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "crate::json_schema::Quoted<u64>")
+    )]
+    pub excess_blob_gas: u64,
+}
+
+#[derive(Clone, Default, Encode, Decode, TreeHash, PartialEq, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[ssz(struct_behaviour = "container")]
+pub struct SignedBlsToExecutionChange {
+    pub message: BlsToExecutionChange,
+    pub signature: SignatureBytes,
+}
+
+#[derive(Clone, Default, Encode, Decode, TreeHash, PartialEq, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[ssz(struct_behaviour = "container")]
+pub struct BlsToExecutionChange {
+    pub validator_index: ValidatorIndex,
+    pub from_bls_pubkey: PublicKeyBytes,
+    pub to_execution_address: ExecutionAddress,
+}
+
+#[derive(Clone, Default, Encode, Decode, TreeHash, PartialEq, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(bound = "P: Preset"))]
+#[cfg_attr(
+    feature = "schemars",
+    schemars(bound = "P: Preset + schemars::JsonSchema")
+)]
+#[ssz(struct_behaviour = "container")]
+pub struct ExecutionRequests<P: Preset = Mainnet> {
+    pub deposits: VariableList<DepositRequest, P::MaxDepositRequestsPerPayload>,
+    pub withdrawals: VariableList<WithdrawalRequest, P::MaxWithdrawalRequestsPerPayload>,
+    pub consolidations: VariableList<ConsolidationRequest, P::MaxConsolidationRequestsPerPayload>,
+}
+
+/// The type of deposit requests in the requests of EIP-7685.
+pub const DEPOSIT_REQUEST_TYPE: u8 = 0x00;
+/// The type of withdrawal requests in the requests of EIP-7685.
+pub const WITHDRAWAL_REQUEST_TYPE: u8 = 0x01;
+/// The type of consolidation requests in the requests of EIP-7685.
+pub const CONSOLIDATION_REQUEST_TYPE: u8 = 0x02;
+
+impl<P: Preset> ExecutionRequests<P> {
+    /// Returns the requests in the format of EIP-7685, as exchanged with the execution layer: the
+    /// type of each non-empty list of requests followed by the encoding of the list, in order of
+    /// type.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use ssz_types::{ExecutionRequests, Mainnet, WithdrawalRequest, CONSOLIDATION_REQUEST_TYPE};
+    ///
+    /// let mut requests = ExecutionRequests::<Mainnet>::default();
+    /// requests.withdrawals.push(WithdrawalRequest::default()).unwrap();
+    ///
+    /// let list = requests.to_requests_list();
+    /// assert_eq!(list.len(), 1);
+    /// assert_eq!(list[0].len(), 1 + 20 + 48 + 8);
+    /// assert_eq!(ExecutionRequests::from_requests_list(&list), Ok(requests));
+    ///
+    /// // Requests must not be empty.
+    /// let empty = [[CONSOLIDATION_REQUEST_TYPE]];
+    /// assert!(ExecutionRequests::<Mainnet>::from_requests_list(&empty).is_err());
+    /// ```
+    pub fn to_requests_list(&self) -> Vec<Vec<u8>> {
+        let mut requests = vec![];
+        for (request_type, data) in [
+            (DEPOSIT_REQUEST_TYPE, self.deposits.as_ssz_bytes()),
+            (WITHDRAWAL_REQUEST_TYPE, self.withdrawals.as_ssz_bytes()),
+            (
+                CONSOLIDATION_REQUEST_TYPE,
+                self.consolidations.as_ssz_bytes(),
+            ),
+        ] {
+            if !data.is_empty() {
+                requests.push([&[request_type][..], &data].concat());
+            }
+        }
+        requests
+    }
+
+    /// Returns the commitment of EIP-7685 to the requests, which is held by the `requests_hash` of
+    /// the header of the execution block: the SHA-256 hash of the concatenated SHA-256 hashes of
+    /// the requests of `to_requests_list`.
+    pub fn requests_hash(&self) -> Hash256 {
+        let hashes = self
+            .to_requests_list()
+            .iter()
+            .flat_map(|request| ethereum_hashing::hash_fixed(request))
+            .collect::<Vec<_>>();
+        Hash256::from(ethereum_hashing::hash_fixed(&hashes))
+    }
+
+    /// Decodes requests in the format of EIP-7685.
+    ///
+    /// Returns `Err` if a request is empty, has an unknown type or fails to decode, or if the types
+    /// of the requests are not strictly increasing.
+    pub fn from_requests_list<B: AsRef<[u8]>>(requests: &[B]) -> Result<Self, DecodeError> {
+        let mut execution_requests = Self::default();
+        let mut previous_type = None;
+
+        for request in requests {
+            let (&request_type, data) = match request.as_ref().split_first() {
+                Some((request_type, data)) if !data.is_empty() => (request_type, data),
+                _ => {
+                    return Err(DecodeError::BytesInvalid(
+                        "requests must have a type and data".into(),
+                    ))
+                }
+            };
+            if previous_type >= Some(request_type) {
+                return Err(DecodeError::BytesInvalid(format!(
+                    "request type {} is not greater than the type of the previous request",
+                    request_type
+                )));
+            }
+            previous_type = Some(request_type);
+
+            match request_type {
+                DEPOSIT_REQUEST_TYPE => {
+                    execution_requests.deposits = VariableList::from_ssz_bytes(data)?
+                }
+                WITHDRAWAL_REQUEST_TYPE => {
+                    execution_requests.withdrawals = VariableList::from_ssz_bytes(data)?
+                }
+                CONSOLIDATION_REQUEST_TYPE => {
+                    execution_requests.consolidations = VariableList::from_ssz_bytes(data)?
+                }
+                _ => {
+                    return Err(DecodeError::BytesInvalid(format!(
+                        "unknown request type {}",
+                        request_type
+                    )))
+                }
+            }
+        }
+
+        Ok(execution_requests)
+    }
+}
+
+#[derive(Clone, Default, Encode, Decode, TreeHash, PartialEq, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[ssz(struct_behaviour = "container")]
+pub struct DepositRequest {
+    pub pubkey: PublicKeyBytes,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::hex_fixed_vec"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::json_schema::Hex<H256>"))]
+    pub withdrawal_credentials: H256,
+    pub amount: Gwei,
+    pub signature: SignatureBytes,
+    #[cfg_attr(feature = "serde", serde(with = "serde_utils::quoted_u64"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "crate::json_schema::Quoted<u64>")
+    )]
+    pub index: u64,
+}
+
+#[derive(Clone, Default, Encode, Decode, TreeHash, PartialEq, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[ssz(struct_behaviour = "container")]
+pub struct WithdrawalRequest {
+    pub source_address: ExecutionAddress,
+    pub validator_pubkey: PublicKeyBytes,
+    pub amount: Gwei,
+}
+
+#[derive(Clone, Default, Encode, Decode, TreeHash, PartialEq, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[ssz(struct_behaviour = "container")]
+pub struct ConsolidationRequest {
+    pub source_address: ExecutionAddress,
+    pub source_pubkey: PublicKeyBytes,
+    pub target_pubkey: PublicKeyBytes,
+}
+
+#[derive(Clone, Default, Encode, Decode, TreeHash, PartialEq, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(bound = "P: Preset"))]
+#[cfg_attr(
+    feature = "schemars",
+    schemars(bound = "P: Preset + schemars::JsonSchema")
+)]
+#[ssz(struct_behaviour = "container")]
+pub struct BlobSidecar<P: Preset = Mainnet> {
+    #[cfg_attr(feature = "serde", serde(with = "serde_utils::quoted_u64"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "crate::json_schema::Quoted<u64>")
+    )]
+    pub index: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::hex_fixed_vec"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "crate::json_schema::Hex<Blob<P>>")
+    )]
+    pub blob: Blob<P>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::hex_fixed_vec"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "crate::json_schema::Hex<KzgCommitment>")
+    )]
+    pub kzg_commitment: KzgCommitment,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::hex_fixed_vec"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "crate::json_schema::Hex<KzgProof>")
+    )]
+    pub kzg_proof: KzgProof,
+    pub signed_block_header: SignedBeaconBlockHeader,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_utils::fixed_vec_of_hex_fixed_vec")
+    )]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(
+            with = "crate::json_schema::HexItems<FixedVector<H256, P::KzgCommitmentInclusionProofDepth>>"
+        )
+    )]
+    pub kzg_commitment_inclusion_proof: FixedVector<H256, P::KzgCommitmentInclusionProofDepth>,
+}
+
+/// A `BlobSidecar` signed by the proposer, as gossiped in the drafts of Deneb. The final
+/// specification gossips `BlobSidecar`s, which are authenticated by their signed block header and
+/// inclusion proof instead.
+#[derive(Clone, Default, Encode, Decode, TreeHash, PartialEq, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(bound = "P: Preset"))]
+#[cfg_attr(
+    feature = "schemars",
+    schemars(bound = "P: Preset + schemars::JsonSchema")
+)]
+#[ssz(struct_behaviour = "container")]
+pub struct SignedBlobSidecar<P: Preset = Mainnet> {
+    pub message: BlobSidecar<P>,
+    pub signature: SignatureBytes,
+}
+
+impl<P: Preset> BlobSidecar<P> {
+    /// Returns `true` if `kzg_commitment_inclusion_proof` proves `kzg_commitment` as the
+    /// commitment at `index` in the body of the block of `signed_block_header`.
+    ///
+    /// The proof is not checked against the signature of the header, nor the blob against the
+    /// commitment.
+    pub fn verify_inclusion_proof(&self) -> bool {
+        let Ok(gindex) = kzg_commitment_gindex::<P>(self.index) else {
+            return false;
+        };
+        let depth = P::KzgCommitmentInclusionProofDepth::to_usize();
+        let branch = self
+            .kzg_commitment_inclusion_proof
+            .iter()
+            .map(|node| Hash256::from_slice(node))
+            .collect::<Vec<_>>();
+
+        verify_merkle_branch(
+            self.kzg_commitment.tree_hash_root(),
+            &branch,
+            depth,
+            gindex % (1 << depth),
+            Hash256::from_slice(&self.signed_block_header.message.body_root),
+        )
+    }
+}
+
+/// Returns the generalized index of the blob KZG commitment at `index` in the body of a block.
+///
+/// The index is the same in the bodies of Deneb and Electra.
+fn kzg_commitment_gindex<P: Preset>(index: u64) -> Result<u64, Error> {
+    BeaconBlockBodyDeneb::<P>::gindex_of(&["blob_kzg_commitments", &index.to_string()])
+}
+
+impl_memory_size_for_container!(SignedBeaconBlockPhase0<P: Preset>, message, signature);
+impl_memory_size_for_container!(
+    BeaconBlockPhase0<P: Preset>,
+    slot,
+    proposer_index,
+    parent_root,
+    state_root,
+    body,
+);
+impl_memory_size_for_container!(
+    BeaconBlockBodyPhase0<P: Preset>,
+    randao_reveal,
+    eth1_data,
+    graffiti,
+    proposer_slashings,
+    attester_slashings,
+    attestations,
+    deposits,
+    voluntary_exits,
+);
+impl_memory_size_for_container!(SignedBeaconBlockAltair<P: Preset>, message, signature);
+impl_memory_size_for_container!(
+    BeaconBlockAltair<P: Preset>,
+    slot,
+    proposer_index,
+    parent_root,
+    state_root,
+    body,
+);
+impl_memory_size_for_container!(
+    BeaconBlockBodyAltair<P: Preset>,
+    randao_reveal,
+    eth1_data,
+    graffiti,
+    proposer_slashings,
+    attester_slashings,
+    attestations,
+    deposits,
+    voluntary_exits,
+    sync_aggregate,
+);
+impl_memory_size_for_container!(SignedBeaconBlockBellatrix<P: Preset>, message, signature);
+impl_memory_size_for_container!(
+    BeaconBlockBellatrix<P: Preset>,
+    slot,
+    proposer_index,
+    parent_root,
+    state_root,
+    body,
+);
+impl_memory_size_for_container!(
+    BeaconBlockBodyBellatrix<P: Preset>,
+    randao_reveal,
+    eth1_data,
+    graffiti,
+    proposer_slashings,
+    attester_slashings,
+    attestations,
+    deposits,
+    voluntary_exits,
+    sync_aggregate,
+    execution_payload,
+);
+impl_memory_size_for_container!(SignedBeaconBlockCapella<P: Preset>, message, signature);
+impl_memory_size_for_container!(
+    BeaconBlockCapella<P: Preset>,
+    slot,
+    proposer_index,
+    parent_root,
+    state_root,
+    body,
+);
+impl_memory_size_for_container!(
+    BeaconBlockBodyCapella<P: Preset>,
+    randao_reveal,
+    eth1_data,
+    graffiti,
+    proposer_slashings,
+    attester_slashings,
+    attestations,
+    deposits,
+    voluntary_exits,
+    sync_aggregate,
+    execution_payload,
+    bls_to_execution_changes,
+);
+impl_memory_size_for_container!(SignedBeaconBlockDeneb<P: Preset>, message, signature);
+impl_memory_size_for_container!(
+    BeaconBlockDeneb<P: Preset>,
+    slot,
+    proposer_index,
+    parent_root,
+    state_root,
+    body,
+);
+impl_memory_size_for_container!(
+    BeaconBlockBodyDeneb<P: Preset>,
+    randao_reveal,
+    eth1_data,
+    graffiti,
+    proposer_slashings,
+    attester_slashings,
+    attestations,
+    deposits,
+    voluntary_exits,
+    sync_aggregate,
+    execution_payload,
+    bls_to_execution_changes,
+    blob_kzg_commitments,
+);
+impl_memory_size_for_container!(SignedBeaconBlockElectra<P: Preset>, message, signature);
+impl_memory_size_for_container!(
+    BeaconBlockElectra<P: Preset>,
+    slot,
+    proposer_index,
+    parent_root,
+    state_root,
+    body,
+);
+impl_memory_size_for_container!(
+    BeaconBlockBodyElectra<P: Preset>,
+    randao_reveal,
+    eth1_data,
+    graffiti,
+    proposer_slashings,
+    attester_slashings,
+    attestations,
+    deposits,
+    voluntary_exits,
+    sync_aggregate,
+    execution_payload,
+    bls_to_execution_changes,
+    blob_kzg_commitments,
+    execution_requests,
+);
+impl_memory_size_for_container!(SignedBlindedBeaconBlockBellatrix<P: Preset>, message, signature);
+impl_memory_size_for_container!(
+    BlindedBeaconBlockBellatrix<P: Preset>,
+    slot,
+    proposer_index,
+    parent_root,
+    state_root,
+    body,
+);
+impl_memory_size_for_container!(
+    BlindedBeaconBlockBodyBellatrix<P: Preset>,
+    randao_reveal,
+    eth1_data,
+    graffiti,
+    proposer_slashings,
+    attester_slashings,
+    attestations,
+    deposits,
+    voluntary_exits,
+    sync_aggregate,
+    execution_payload_header,
+);
+impl_memory_size_for_container!(SignedBlindedBeaconBlockCapella<P: Preset>, message, signature);
+impl_memory_size_for_container!(
+    BlindedBeaconBlockCapella<P: Preset>,
+    slot,
+    proposer_index,
+    parent_root,
+    state_root,
+    body,
+);
+impl_memory_size_for_container!(
+    BlindedBeaconBlockBodyCapella<P: Preset>,
+    randao_reveal,
+    eth1_data,
+    graffiti,
+    proposer_slashings,
+    attester_slashings,
+    attestations,
+    deposits,
+    voluntary_exits,
+    sync_aggregate,
+    execution_payload_header,
+    bls_to_execution_changes,
+);
+impl_memory_size_for_container!(SignedBlindedBeaconBlockDeneb<P: Preset>, message, signature);
+impl_memory_size_for_container!(
+    BlindedBeaconBlockDeneb<P: Preset>,
+    slot,
+    proposer_index,
+    parent_root,
+    state_root,
+    body,
+);
+impl_memory_size_for_container!(
+    BlindedBeaconBlockBodyDeneb<P: Preset>,
+    randao_reveal,
+    eth1_data,
+    graffiti,
+    proposer_slashings,
+    attester_slashings,
+    attestations,
+    deposits,
+    voluntary_exits,
+    sync_aggregate,
+    execution_payload_header,
+    bls_to_execution_changes,
+    blob_kzg_commitments,
+);
+impl_memory_size_for_container!(SignedBlindedBeaconBlockElectra<P: Preset>, message, signature);
+impl_memory_size_for_container!(
+    BlindedBeaconBlockElectra<P: Preset>,
+    slot,
+    proposer_index,
+    parent_root,
+    state_root,
+    body,
+);
+impl_memory_size_for_container!(
+    BlindedBeaconBlockBodyElectra<P: Preset>,
+    randao_reveal,
+    eth1_data,
+    graffiti,
+    proposer_slashings,
+    attester_slashings,
+    attestations,
+    deposits,
+    voluntary_exits,
+    sync_aggregate,
+    execution_payload_header,
+    bls_to_execution_changes,
+    blob_kzg_commitments,
+    execution_requests,
+);
+impl_memory_size_for_container!(SignedBeaconBlockHeader, message, signature);
+impl_memory_size_for_container!(
+    BeaconBlockHeader,
+    slot,
+    proposer_index,
+    parent_root,
+    state_root,
+    body_root,
+);
+impl_memory_size_for_container!(Eth1Data, deposit_root, deposit_count, block_hash);
+impl_memory_size_for_container!(ProposerSlashing, signed_header_1, signed_header_2);
+impl_memory_size_for_container!(Checkpoint, epoch, root);
+impl_memory_size_for_container!(
+    AttestationData,
+    slot,
+    index,
+    beacon_block_root,
+    source,
+    target
+);
+impl_memory_size_for_container!(IndexedAttestation<P: Preset>, attesting_indices, data, signature);
+impl_memory_size_for_container!(AttesterSlashing<P: Preset>, attestation_1, attestation_2);
+impl_memory_size_for_container!(Attestation<P: Preset>, aggregation_bits, data, signature);
+impl_memory_size_for_container!(
+    AttestationElectra<P: Preset>,
+    aggregation_bits,
+    data,
+    signature,
+    committee_bits,
+);
+impl_memory_size_for_container!(
+    IndexedAttestationElectra<P: Preset>,
+    attesting_indices,
+    data,
+    signature
+);
+impl_memory_size_for_container!(AttesterSlashingElectra<P: Preset>, attestation_1, attestation_2);
+impl_memory_size_for_container!(
+    DepositData,
+    pubkey,
+    withdrawal_credentials,
+    amount,
+    signature,
+);
+impl_memory_size_for_container!(DepositMessage, pubkey, withdrawal_credentials, amount);
+impl_memory_size_for_container!(Deposit, proof, data);
+impl_memory_size_for_container!(VoluntaryExit, epoch, validator_index);
+impl_memory_size_for_container!(SignedVoluntaryExit, message, signature);
+impl_memory_size_for_container!(
+    SyncAggregate<P: Preset>,
+    sync_committee_bits,
+    sync_committee_signature,
+);
+impl_memory_size_for_container!(Withdrawal, index, validator_index, address, amount);
+impl_memory_size_for_container!(
+    ExecutionPayloadBellatrix<P: Preset>,
+    parent_hash,
+    fee_recipient,
+    state_root,
+    receipts_root,
+    logs_bloom,
+    prev_randao,
+    block_number,
+    gas_limit,
+    gas_used,
+    timestamp,
+    extra_data,
+    base_fee_per_gas,
+    block_hash,
+    transactions,
+);
+impl_memory_size_for_container!(
+    ExecutionPayloadCapella<P: Preset>,
+    parent_hash,
+    fee_recipient,
+    state_root,
+    receipts_root,
+    logs_bloom,
+    prev_randao,
+    block_number,
+    gas_limit,
+    gas_used,
+    timestamp,
+    extra_data,
+    base_fee_per_gas,
+    block_hash,
+    transactions,
+    withdrawals,
+);
+impl_memory_size_for_container!(
+    ExecutionPayloadDeneb<P: Preset>,
+    parent_hash,
+    fee_recipient,
+    state_root,
+    receipts_root,
+    logs_bloom,
+    prev_randao,
+    block_number,
+    gas_limit,
+    gas_used,
+    timestamp,
+    extra_data,
+    base_fee_per_gas,
+    block_hash,
+    transactions,
+    withdrawals,
+    blob_gas_used,
+    excess_blob_gas,
+);
+impl_memory_size_for_container!(
+    ExecutionPayloadHeaderBellatrix<P: Preset>,
+    parent_hash,
+    fee_recipient,
+    state_root,
+    receipts_root,
+    logs_bloom,
+    prev_randao,
+    block_number,
+    gas_limit,
+    gas_used,
+    timestamp,
+    extra_data,
+    base_fee_per_gas,
+    block_hash,
+    transactions_root,
+);
+impl_memory_size_for_container!(
+    ExecutionPayloadHeaderCapella<P: Preset>,
+    parent_hash,
+    fee_recipient,
+    state_root,
+    receipts_root,
+    logs_bloom,
+    prev_randao,
+    block_number,
+    gas_limit,
+    gas_used,
+    timestamp,
+    extra_data,
+    base_fee_per_gas,
+    block_hash,
+    transactions_root,
+    withdrawals_root,
+);
+impl_memory_size_for_container!(
+    ExecutionPayloadHeaderDeneb<P: Preset>,
+    parent_hash,
+    fee_recipient,
+    state_root,
+    receipts_root,
+    logs_bloom,
+    prev_randao,
+    block_number,
+    gas_limit,
+    gas_used,
+    timestamp,
+    extra_data,
+    base_fee_per_gas,
+    block_hash,
+    transactions_root,
+    withdrawals_root,
+    blob_gas_used,
+    excess_blob_gas,
+);
+impl_memory_size_for_container!(SignedBlsToExecutionChange, message, signature);
+impl_memory_size_for_container!(
+    BlsToExecutionChange,
+    validator_index,
+    from_bls_pubkey,
+    to_execution_address,
+);
+impl_memory_size_for_container!(
+    ExecutionRequests<P: Preset>,
+    deposits,
+    withdrawals,
+    consolidations,
+);
+impl_memory_size_for_container!(
+    DepositRequest,
+    pubkey,
+    withdrawal_credentials,
+    amount,
+    signature,
+    index,
+);
+impl_memory_size_for_container!(WithdrawalRequest, source_address, validator_pubkey, amount);
+impl_memory_size_for_container!(
+    ConsolidationRequest,
+    source_address,
+    source_pubkey,
+    target_pubkey
+);
+impl_memory_size_for_container!(
+    BlobSidecar<P: Preset>,
+    index,
+    blob,
+    kzg_commitment,
+    kzg_proof,
+    signed_block_header,
+    kzg_commitment_inclusion_proof,
+);
+impl_memory_size_for_container!(SignedBlobSidecar<P: Preset>, message, signature);
+
+impl_merkle_proof_for_container!(SignedBeaconBlockPhase0<P: Preset>, message, signature);
+impl_merkle_proof_for_container!(
+    BeaconBlockPhase0<P: Preset>,
+    slot,
+    proposer_index,
+    parent_root,
+    state_root,
+    body,
+);
+impl_merkle_proof_for_container!(
+    BeaconBlockBodyPhase0<P: Preset>,
+    randao_reveal,
+    eth1_data,
+    graffiti,
+    proposer_slashings,
+    attester_slashings,
+    attestations,
+    deposits,
+    voluntary_exits,
+);
+impl_merkle_proof_for_container!(SignedBeaconBlockAltair<P: Preset>, message, signature);
+impl_merkle_proof_for_container!(
+    BeaconBlockAltair<P: Preset>,
+    slot,
+    proposer_index,
+    parent_root,
+    state_root,
+    body,
+);
+impl_merkle_proof_for_container!(
+    BeaconBlockBodyAltair<P: Preset>,
+    randao_reveal,
+    eth1_data,
+    graffiti,
+    proposer_slashings,
+    attester_slashings,
+    attestations,
+    deposits,
+    voluntary_exits,
+    sync_aggregate,
+);
+impl_merkle_proof_for_container!(SignedBeaconBlockBellatrix<P: Preset>, message, signature);
+impl_merkle_proof_for_container!(
+    BeaconBlockBellatrix<P: Preset>,
+    slot,
+    proposer_index,
+    parent_root,
+    state_root,
+    body,
+);
+impl_merkle_proof_for_container!(
+    BeaconBlockBodyBellatrix<P: Preset>,
+    randao_reveal,
+    eth1_data,
+    graffiti,
+    proposer_slashings,
+    attester_slashings,
+    attestations,
+    deposits,
+    voluntary_exits,
+    sync_aggregate,
+    execution_payload,
+);
+impl_merkle_proof_for_container!(SignedBeaconBlockCapella<P: Preset>, message, signature);
+impl_merkle_proof_for_container!(
+    BeaconBlockCapella<P: Preset>,
+    slot,
+    proposer_index,
+    parent_root,
+    state_root,
+    body,
+);
+impl_merkle_proof_for_container!(
+    BeaconBlockBodyCapella<P: Preset>,
+    randao_reveal,
+    eth1_data,
+    graffiti,
+    proposer_slashings,
+    attester_slashings,
+    attestations,
+    deposits,
+    voluntary_exits,
+    sync_aggregate,
+    execution_payload,
+    bls_to_execution_changes,
+);
+impl_merkle_proof_for_container!(SignedBeaconBlockDeneb<P: Preset>, message, signature);
+impl_merkle_proof_for_container!(
+    BeaconBlockDeneb<P: Preset>,
+    slot,
+    proposer_index,
+    parent_root,
+    state_root,
+    body,
+);
+impl_merkle_proof_for_container!(
+    BeaconBlockBodyDeneb<P: Preset>,
+    randao_reveal,
+    eth1_data,
+    graffiti,
+    proposer_slashings,
+    attester_slashings,
+    attestations,
+    deposits,
+    voluntary_exits,
+    sync_aggregate,
+    execution_payload,
+    bls_to_execution_changes,
+    blob_kzg_commitments,
+);
+impl_merkle_proof_for_container!(SignedBeaconBlockElectra<P: Preset>, message, signature);
+impl_merkle_proof_for_container!(
+    BeaconBlockElectra<P: Preset>,
+    slot,
+    proposer_index,
+    parent_root,
+    state_root,
+    body,
+);
+impl_merkle_proof_for_container!(
+    BeaconBlockBodyElectra<P: Preset>,
+    randao_reveal,
+    eth1_data,
+    graffiti,
+    proposer_slashings,
+    attester_slashings,
+    attestations,
+    deposits,
+    voluntary_exits,
+    sync_aggregate,
+    execution_payload,
+    bls_to_execution_changes,
+    blob_kzg_commitments,
+    execution_requests,
+);
+impl_merkle_proof_for_container!(SignedBlindedBeaconBlockBellatrix<P: Preset>, message, signature);
+impl_merkle_proof_for_container!(
+    BlindedBeaconBlockBellatrix<P: Preset>,
+    slot,
+    proposer_index,
+    parent_root,
+    state_root,
+    body,
+);
+impl_merkle_proof_for_container!(
+    BlindedBeaconBlockBodyBellatrix<P: Preset>,
+    randao_reveal,
+    eth1_data,
+    graffiti,
+    proposer_slashings,
+    attester_slashings,
+    attestations,
+    deposits,
+    voluntary_exits,
+    sync_aggregate,
+    execution_payload_header,
+);
+impl_merkle_proof_for_container!(SignedBlindedBeaconBlockCapella<P: Preset>, message, signature);
+impl_merkle_proof_for_container!(
+    BlindedBeaconBlockCapella<P: Preset>,
+    slot,
+    proposer_index,
+    parent_root,
+    state_root,
+    body,
+);
+impl_merkle_proof_for_container!(
+    BlindedBeaconBlockBodyCapella<P: Preset>,
+    randao_reveal,
+    eth1_data,
+    graffiti,
+    proposer_slashings,
+    attester_slashings,
+    attestations,
+    deposits,
+    voluntary_exits,
+    sync_aggregate,
+    execution_payload_header,
+    bls_to_execution_changes,
+);
+impl_merkle_proof_for_container!(SignedBlindedBeaconBlockDeneb<P: Preset>, message, signature);
+impl_merkle_proof_for_container!(
+    BlindedBeaconBlockDeneb<P: Preset>,
+    slot,
+    proposer_index,
+    parent_root,
+    state_root,
+    body,
+);
+impl_merkle_proof_for_container!(
+    BlindedBeaconBlockBodyDeneb<P: Preset>,
+    randao_reveal,
+    eth1_data,
+    graffiti,
+    proposer_slashings,
+    attester_slashings,
+    attestations,
+    deposits,
+    voluntary_exits,
+    sync_aggregate,
+    execution_payload_header,
+    bls_to_execution_changes,
+    blob_kzg_commitments,
+);
+impl_merkle_proof_for_container!(SignedBlindedBeaconBlockElectra<P: Preset>, message, signature);
+impl_merkle_proof_for_container!(
+    BlindedBeaconBlockElectra<P: Preset>,
+    slot,
+    proposer_index,
+    parent_root,
+    state_root,
+    body,
+);
+impl_merkle_proof_for_container!(
+    BlindedBeaconBlockBodyElectra<P: Preset>,
+    randao_reveal,
+    eth1_data,
+    graffiti,
+    proposer_slashings,
+    attester_slashings,
+    attestations,
+    deposits,
+    voluntary_exits,
+    sync_aggregate,
+    execution_payload_header,
+    bls_to_execution_changes,
+    blob_kzg_commitments,
+    execution_requests,
+);
+impl_merkle_proof_for_container!(SignedBeaconBlockHeader, message, signature);
+impl_merkle_proof_for_container!(
+    BeaconBlockHeader,
+    slot,
+    proposer_index,
+    parent_root,
+    state_root,
+    body_root,
+);
+impl_merkle_proof_for_container!(Eth1Data, deposit_root, deposit_count, block_hash);
+impl_merkle_proof_for_container!(ProposerSlashing, signed_header_1, signed_header_2);
+impl_merkle_proof_for_container!(Checkpoint, epoch, root);
+impl_merkle_proof_for_container!(
+    AttestationData,
+    slot,
+    index,
+    beacon_block_root,
+    source,
+    target
+);
+impl_merkle_proof_for_container!(IndexedAttestation<P: Preset>, attesting_indices, data, signature);
+impl_merkle_proof_for_container!(AttesterSlashing<P: Preset>, attestation_1, attestation_2);
+impl_merkle_proof_for_container!(Attestation<P: Preset>, aggregation_bits, data, signature);
+impl_merkle_proof_for_container!(
+    AttestationElectra<P: Preset>,
+    aggregation_bits,
+    data,
+    signature,
+    committee_bits,
+);
+impl_merkle_proof_for_container!(
+    IndexedAttestationElectra<P: Preset>,
+    attesting_indices,
+    data,
+    signature
+);
+impl_merkle_proof_for_container!(AttesterSlashingElectra<P: Preset>, attestation_1, attestation_2);
+impl_merkle_proof_for_container!(
+    DepositData,
+    pubkey,
+    withdrawal_credentials,
+    amount,
+    signature,
+);
+impl_merkle_proof_for_container!(DepositMessage, pubkey, withdrawal_credentials, amount);
+impl_merkle_proof_for_container!(Deposit, proof, data);
+impl_merkle_proof_for_container!(VoluntaryExit, epoch, validator_index);
+impl_merkle_proof_for_container!(SignedVoluntaryExit, message, signature);
+impl_merkle_proof_for_container!(
+    SyncAggregate<P: Preset>,
+    sync_committee_bits,
+    sync_committee_signature,
+);
+impl_merkle_proof_for_container!(Withdrawal, index, validator_index, address, amount);
+impl_merkle_proof_for_container!(
+    ExecutionPayloadBellatrix<P: Preset>,
+    parent_hash,
+    fee_recipient,
+    state_root,
+    receipts_root,
+    logs_bloom,
+    prev_randao,
+    block_number,
+    gas_limit,
+    gas_used,
+    timestamp,
+    extra_data,
+    base_fee_per_gas,
+    block_hash,
+    transactions,
+);
+impl_merkle_proof_for_container!(
+    ExecutionPayloadCapella<P: Preset>,
+    parent_hash,
+    fee_recipient,
+    state_root,
+    receipts_root,
+    logs_bloom,
+    prev_randao,
+    block_number,
+    gas_limit,
+    gas_used,
+    timestamp,
+    extra_data,
+    base_fee_per_gas,
+    block_hash,
+    transactions,
+    withdrawals,
+);
+impl_merkle_proof_for_container!(
+    ExecutionPayloadDeneb<P: Preset>,
+    parent_hash,
+    fee_recipient,
+    state_root,
+    receipts_root,
+    logs_bloom,
+    prev_randao,
+    block_number,
+    gas_limit,
+    gas_used,
+    timestamp,
+    extra_data,
+    base_fee_per_gas,
+    block_hash,
+    transactions,
+    withdrawals,
+    blob_gas_used,
+    excess_blob_gas,
+);
+impl_merkle_proof_for_container!(
+    ExecutionPayloadHeaderBellatrix<P: Preset>,
+    parent_hash,
+    fee_recipient,
+    state_root,
+    receipts_root,
+    logs_bloom,
+    prev_randao,
+    block_number,
+    gas_limit,
+    gas_used,
+    timestamp,
+    extra_data,
+    base_fee_per_gas,
+    block_hash,
+    transactions_root,
+);
+impl_merkle_proof_for_container!(
+    ExecutionPayloadHeaderCapella<P: Preset>,
+    parent_hash,
+    fee_recipient,
+    state_root,
+    receipts_root,
+    logs_bloom,
+    prev_randao,
+    block_number,
+    gas_limit,
+    gas_used,
+    timestamp,
+    extra_data,
+    base_fee_per_gas,
+    block_hash,
+    transactions_root,
+    withdrawals_root,
+);
+impl_merkle_proof_for_container!(
+    ExecutionPayloadHeaderDeneb<P: Preset>,
+    parent_hash,
+    fee_recipient,
+    state_root,
+    receipts_root,
+    logs_bloom,
+    prev_randao,
+    block_number,
+    gas_limit,
+    gas_used,
+    timestamp,
+    extra_data,
+    base_fee_per_gas,
+    block_hash,
+    transactions_root,
+    withdrawals_root,
+    blob_gas_used,
+    excess_blob_gas,
+);
+impl_merkle_proof_for_container!(SignedBlsToExecutionChange, message, signature);
+impl_merkle_proof_for_container!(
+    BlsToExecutionChange,
+    validator_index,
+    from_bls_pubkey,
+    to_execution_address,
+);
+impl_merkle_proof_for_container!(
+    ExecutionRequests<P: Preset>,
+    deposits,
+    withdrawals,
+    consolidations,
+);
+impl_merkle_proof_for_container!(
+    DepositRequest,
+    pubkey,
+    withdrawal_credentials,
+    amount,
+    signature,
+    index,
+);
+impl_merkle_proof_for_container!(WithdrawalRequest, source_address, validator_pubkey, amount);
+impl_merkle_proof_for_container!(
+    ConsolidationRequest,
+    source_address,
+    source_pubkey,
+    target_pubkey,
+);
+impl_merkle_proof_for_container!(
+    BlobSidecar<P: Preset>,
+    index,
+    blob,
+    kzg_commitment,
+    kzg_proof,
+    signed_block_header,
+    kzg_commitment_inclusion_proof,
+);
+impl_merkle_proof_for_container!(SignedBlobSidecar<P: Preset>, message, signature);
+
+impl_beacon_block!(SignedBeaconBlockPhase0, BeaconBlockPhase0);
+impl_beacon_block!(SignedBeaconBlockAltair, BeaconBlockAltair);
+impl_beacon_block!(SignedBeaconBlockBellatrix, BeaconBlockBellatrix);
+impl_beacon_block!(SignedBeaconBlockCapella, BeaconBlockCapella);
+impl_beacon_block!(SignedBeaconBlockDeneb, BeaconBlockDeneb);
+impl_beacon_block!(SignedBeaconBlockElectra, BeaconBlockElectra);
+impl_beacon_block!(
+    SignedBlindedBeaconBlockBellatrix,
+    BlindedBeaconBlockBellatrix
+);
+impl_beacon_block!(SignedBlindedBeaconBlockCapella, BlindedBeaconBlockCapella);
+impl_beacon_block!(SignedBlindedBeaconBlockDeneb, BlindedBeaconBlockDeneb);
+impl_beacon_block!(SignedBlindedBeaconBlockElectra, BlindedBeaconBlockElectra);
+
+impl_blob_sidecar!(SignedBeaconBlockDeneb);
+impl_blob_sidecar!(SignedBeaconBlockElectra);
+
+impl_execution_payload!(
+    ExecutionPayloadBellatrix,
+    ExecutionPayloadHeaderBellatrix,
+    [
+        parent_hash,
+        fee_recipient,
+        state_root,
+        receipts_root,
+        logs_bloom,
+        prev_randao,
+        block_number,
+        gas_limit,
+        gas_used,
+        timestamp,
+        extra_data,
+        base_fee_per_gas,
+        block_hash
+    ],
+    [transactions => transactions_root]
+);
+impl_execution_payload!(
+    ExecutionPayloadCapella,
+    ExecutionPayloadHeaderCapella,
+    [
+        parent_hash,
+        fee_recipient,
+        state_root,
+        receipts_root,
+        logs_bloom,
+        prev_randao,
+        block_number,
+        gas_limit,
+        gas_used,
+        timestamp,
+        extra_data,
+        base_fee_per_gas,
+        block_hash
+    ],
+    [transactions => transactions_root, withdrawals => withdrawals_root]
+);
+impl_execution_payload!(
+    ExecutionPayloadDeneb,
+    ExecutionPayloadHeaderDeneb,
+    [
+        parent_hash,
+        fee_recipient,
+        state_root,
+        receipts_root,
+        logs_bloom,
+        prev_randao,
+        block_number,
+        gas_limit,
+        gas_used,
+        timestamp,
+        extra_data,
+        base_fee_per_gas,
+        block_hash,
+        blob_gas_used,
+        excess_blob_gas
+    ],
+    [transactions => transactions_root, withdrawals => withdrawals_root]
+);
+impl_blinded_block!(
+    SignedBeaconBlockBellatrix,
+    BeaconBlockBellatrix,
+    BeaconBlockBodyBellatrix,
+    SignedBlindedBeaconBlockBellatrix,
+    BlindedBeaconBlockBellatrix,
+    BlindedBeaconBlockBodyBellatrix,
+    ExecutionPayloadBellatrix,
+    [
+        randao_reveal,
+        eth1_data,
+        graffiti,
+        proposer_slashings,
+        attester_slashings,
+        attestations,
+        deposits,
+        voluntary_exits,
+        sync_aggregate
+    ]
+);
+impl_blinded_block!(
+    SignedBeaconBlockCapella,
+    BeaconBlockCapella,
+    BeaconBlockBodyCapella,
+    SignedBlindedBeaconBlockCapella,
+    BlindedBeaconBlockCapella,
+    BlindedBeaconBlockBodyCapella,
+    ExecutionPayloadCapella,
+    [
+        randao_reveal,
+        eth1_data,
+        graffiti,
+        proposer_slashings,
+        attester_slashings,
+        attestations,
+        deposits,
+        voluntary_exits,
+        sync_aggregate,
+        bls_to_execution_changes
+    ]
+);
+impl_blinded_block!(
+    SignedBeaconBlockDeneb,
+    BeaconBlockDeneb,
+    BeaconBlockBodyDeneb,
+    SignedBlindedBeaconBlockDeneb,
+    BlindedBeaconBlockDeneb,
+    BlindedBeaconBlockBodyDeneb,
+    ExecutionPayloadDeneb,
+    [
+        randao_reveal,
+        eth1_data,
+        graffiti,
+        proposer_slashings,
+        attester_slashings,
+        attestations,
+        deposits,
+        voluntary_exits,
+        sync_aggregate,
+        bls_to_execution_changes,
+        blob_kzg_commitments
+    ]
+);
+impl_blinded_block!(
+    SignedBeaconBlockElectra,
+    BeaconBlockElectra,
+    BeaconBlockBodyElectra,
+    SignedBlindedBeaconBlockElectra,
+    BlindedBeaconBlockElectra,
+    BlindedBeaconBlockBodyElectra,
+    ExecutionPayloadDeneb,
+    [
+        randao_reveal,
+        eth1_data,
+        graffiti,
+        proposer_slashings,
+        attester_slashings,
+        attestations,
+        deposits,
+        voluntary_exits,
+        sync_aggregate,
+        bls_to_execution_changes,
+        blob_kzg_commitments,
+        execution_requests
+    ]
+);
+
+impl SignedRoot for BeaconBlockHeader {}
+impl SignedRoot for AttestationData {}
+impl SignedRoot for VoluntaryExit {}
+impl SignedRoot for BlsToExecutionChange {}
+impl SignedRoot for DepositMessage {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{compute_domain, compute_signing_root, gindex_of, verify_merkle_branch, Minimal};
+    use tree_hash::{merkle_root, TreeHash};
+
+    #[test]
+    fn checkpoint_root() {
+        let checkpoint = Checkpoint {
+            epoch: Epoch::new(3),
+            root: H256::from_elem(0xaa),
+        };
+
+        let mut leaves = vec![0; 64];
+        leaves[0] = 3;
+        leaves[32..].fill(0xaa);
+
+        assert_eq!(checkpoint.tree_hash_root(), merkle_root(&leaves, 2));
+        assert_eq!(
+            format!("{:?}", checkpoint),
+            format!(
+                "Checkpoint {{ epoch: Epoch(3), root: 0x{} }}",
+                "aa".repeat(32)
+            )
+        );
+    }
+
+    #[test]
+    fn custom_bit_list_root() {
+        let mut bits = BitList::<typenum::U2048>::with_capacity(100).unwrap();
+        bits.set(42, true).unwrap();
+
+        assert_eq!(
+            CustomBitList(bits.clone()).tree_hash_root(),
+            bits.tree_hash_root()
+        );
+    }
+
+    #[test]
+    fn execution_block_hash_proof() {
+        let bytes = std::fs::read("state.ssz").unwrap();
+        let block = SignedBeaconBlockCapella::<Mainnet>::from_ssz_bytes(&bytes).unwrap();
+        let body = &block.message.body;
+
+        // `execution_payload` is field 9 of 11 in the body, `block_hash` is field 12 of 15 in
+        // the payload.
+        let gindex = (16 + 9) * 16 + 12;
+        assert_eq!(
+            gindex_of::<BeaconBlockBodyCapella>(&["execution_payload", "block_hash"]),
+            Ok(gindex)
+        );
+        assert_eq!(
+            body.tree_hash_node(gindex),
+            Ok(body.execution_payload.block_hash.tree_hash_root())
+        );
+        assert_eq!(body.tree_hash_node(1), Ok(body.tree_hash_root()));
+
+        let branch = body.merkle_proof(gindex).unwrap();
+        assert_eq!(branch.len(), 8);
+
+        assert!(verify_merkle_branch(
+            body.execution_payload.block_hash.tree_hash_root(),
+            &branch,
+            8,
+            gindex - (1 << 8),
+            body.tree_hash_root()
+        ));
+
+        // The same node through the signed block: `message` is field 0 of 2, `body` is field 4
+        // of 5 in the block.
+        let gindex = ((2 * 8 + 4) * 16 + 9) * 16 + 12;
+        assert_eq!(
+            gindex_of::<SignedBeaconBlockCapella>(&[
+                "message",
+                "body",
+                "execution_payload",
+                "block_hash"
+            ]),
+            Ok(gindex)
+        );
+        assert_eq!(
+            block.tree_hash_node(gindex),
+            Ok(body.execution_payload.block_hash.tree_hash_root())
+        );
+        assert_eq!(block.merkle_proof(gindex).unwrap()[..8], branch[..]);
+    }
+
+    fn assert_round_trip<T, U>()
+    where
+        T: Encode + Decode + Default + PartialEq + Debug,
+        U: Decode,
+    {
+        let bytes = T::default().as_ssz_bytes();
+
+        assert_eq!(T::from_ssz_bytes(&bytes), Ok(T::default()));
+        // The blocks of the next fork have more fields.
+        assert!(U::from_ssz_bytes(&bytes).is_err());
+    }
+
+    #[test]
+    fn fork_blocks() {
+        assert_round_trip::<SignedBeaconBlockPhase0, SignedBeaconBlockAltair>();
+        assert_round_trip::<SignedBeaconBlockAltair, SignedBeaconBlockBellatrix>();
+        assert_round_trip::<SignedBeaconBlockBellatrix, SignedBeaconBlockCapella>();
+        assert_round_trip::<SignedBeaconBlockCapella, SignedBeaconBlockDeneb>();
+        assert_round_trip::<SignedBeaconBlockDeneb, SignedBeaconBlockElectra>();
+        assert_round_trip::<SignedBeaconBlockElectra, SignedBeaconBlockPhase0>();
+
+        let bytes = std::fs::read("state.ssz").unwrap();
+        assert!(SignedBeaconBlockCapella::<Mainnet>::from_ssz_bytes(&bytes).is_ok());
+        assert!(SignedBeaconBlockBellatrix::<Mainnet>::from_ssz_bytes(&bytes).is_err());
+        assert!(SignedBeaconBlockDeneb::<Mainnet>::from_ssz_bytes(&bytes).is_err());
+    }
+
+    #[test]
+    fn fork_enum() {
+        let mut phase0 = SignedBeaconBlockPhase0::<Mainnet>::default();
+        phase0.message.slot = Slot::new(7);
+        phase0.message.proposer_index = ValidatorIndex::new(3);
+        phase0.message.body.graffiti = Graffiti::new([0x42; 32]);
+        let block = SignedBeaconBlock::from(phase0.clone());
+
+        assert_eq!(block.slot(), Slot::new(7));
+        assert_eq!(block.proposer_index(), ValidatorIndex::new(3));
+        assert_eq!(block.body().graffiti(), &phase0.message.body.graffiti);
+        assert_eq!(block.body().sync_aggregate(), None);
+        assert_eq!(
+            block.body().body_root(),
+            phase0.message.body.tree_hash_root()
+        );
+        assert_eq!(block.as_ssz_bytes(), phase0.as_ssz_bytes());
+        assert_eq!(block.ssz_bytes_len(), phase0.ssz_bytes_len());
+        assert_eq!(block.tree_hash_root(), phase0.tree_hash_root());
+        assert_eq!(block.tree_hash_node(2), phase0.tree_hash_node(2));
+        assert_eq!(block.signed_block_header(), phase0.signed_block_header());
+
+        let block = SignedBeaconBlock::Electra(SignedBeaconBlockElectra::<Mainnet>::default());
+        assert!(block.body().sync_aggregate().is_some());
+        assert_eq!(
+            block.message_root(),
+            block.signed_block_header().message.tree_hash_root()
+        );
+    }
+
+    #[test]
+    fn fork_decoding() {
+        let schedule = ForkSchedule {
+            slots_per_epoch: 32,
+            altair_fork_epoch: Some(Epoch::new(1)),
+            bellatrix_fork_epoch: Some(Epoch::new(2)),
+            capella_fork_epoch: Some(Epoch::new(3)),
+            deneb_fork_epoch: Some(Epoch::new(4)),
+            electra_fork_epoch: Some(Epoch::new(5)),
+        };
+        let blocks: [SignedBeaconBlock; 6] = [
+            SignedBeaconBlockPhase0::default().into(),
+            SignedBeaconBlockAltair::default().into(),
+            SignedBeaconBlockBellatrix::default().into(),
+            SignedBeaconBlockCapella::default().into(),
+            SignedBeaconBlockDeneb::default().into(),
+            SignedBeaconBlockElectra::default().into(),
+        ];
+
+        for (fork, block) in ForkName::ALL.into_iter().zip(blocks) {
+            assert_eq!(block.fork_name(), fork);
+
+            // The last slot of the first epoch of the fork.
+            let slot = schedule.fork_epoch(fork).unwrap().end_slot(32);
+            let mut bytes = block.as_ssz_bytes();
+            bytes[100..108].copy_from_slice(&slot.as_ssz_bytes());
+
+            let block =
+                SignedBeaconBlock::<Mainnet>::from_ssz_bytes_for_fork(&bytes, fork).unwrap();
+            assert_eq!(block.slot(), slot);
+            assert_eq!(block.fork_name(), fork);
+            assert_eq!(
+                SignedBeaconBlock::from_ssz_bytes_for_schedule(&bytes, &schedule),
+                Ok(block)
+            );
+        }
+
+        let bytes = std::fs::read("state.ssz").unwrap();
+        assert!(
+            SignedBeaconBlock::<Mainnet>::from_ssz_bytes_for_fork(&bytes, ForkName::Capella)
+                .is_ok()
+        );
+        assert!(
+            SignedBeaconBlock::<Mainnet>::from_ssz_bytes_for_fork(&bytes, ForkName::Deneb).is_err()
+        );
+        assert_eq!(
+            SignedBeaconBlock::<Mainnet>::from_ssz_bytes_for_schedule(&[0; 107], &schedule),
+            Err(DecodeError::InvalidByteLength {
+                len: 107,
+                expected: 108
+            })
+        );
+    }
+
+    #[test]
+    fn blinded_blocks() {
+        assert_round_trip::<SignedBlindedBeaconBlockBellatrix, SignedBlindedBeaconBlockCapella>();
+        assert_round_trip::<SignedBlindedBeaconBlockCapella, SignedBlindedBeaconBlockDeneb>();
+        assert_round_trip::<SignedBlindedBeaconBlockDeneb, SignedBlindedBeaconBlockElectra>();
+
+        let mut full = SignedBeaconBlockCapella::<Mainnet>::default();
+        full.message.slot = Slot::new(9);
+        let payload = &mut full.message.body.execution_payload;
+        payload.block_hash = H256::from_elem(0x11);
+        payload.transactions = CowList::from(
+            VariableList::new(vec![VariableList::new(vec![1, 2, 3]).unwrap()]).unwrap(),
+        );
+        payload.withdrawals = VariableList::new(vec![Withdrawal::default()]).unwrap();
+
+        let payload = &full.message.body.execution_payload;
+        let mut blinded = SignedBlindedBeaconBlockCapella::<Mainnet>::default();
+        blinded.message.slot = Slot::new(9);
+        blinded.message.body.execution_payload_header = ExecutionPayloadHeaderCapella {
+            block_hash: payload.block_hash.clone(),
+            transactions_root: h256(payload.transactions.tree_hash_root()),
+            withdrawals_root: h256(payload.withdrawals.tree_hash_root()),
+            ..ExecutionPayloadHeaderCapella::default()
+        };
+
+        // The header has the same root as the payload, and hence the block as the full block.
+        assert_eq!(
+            blinded
+                .message
+                .body
+                .execution_payload_header
+                .tree_hash_root(),
+            payload.tree_hash_root()
+        );
+        assert_eq!(blinded.tree_hash_root(), full.tree_hash_root());
+        assert_eq!(blinded.message_root(), full.message_root());
+        assert_eq!(blinded.signed_block_header(), full.signed_block_header());
+        assert_eq!(
+            gindex_of::<BlindedBeaconBlockBodyCapella>(&["execution_payload_header", "block_hash"]),
+            gindex_of::<BeaconBlockBodyCapella>(&["execution_payload", "block_hash"])
+        );
+
+        let block = SignedBlindedBeaconBlock::from(blinded.clone());
+        assert_eq!(block.fork_name(), ForkName::Capella);
+        assert_eq!(block.slot(), Slot::new(9));
+        assert_eq!(block.execution_block_hash(), &H256::from_elem(0x11));
+        assert_eq!(block.tree_hash_root(), full.tree_hash_root());
+
+        let bytes = block.as_ssz_bytes();
+        assert_eq!(bytes, blinded.as_ssz_bytes());
+        assert_eq!(
+            SignedBlindedBeaconBlock::<Mainnet>::from_ssz_bytes_for_fork(&bytes, ForkName::Capella),
+            Ok(block)
+        );
+        assert!(
+            SignedBlindedBeaconBlock::<Mainnet>::from_ssz_bytes_for_fork(&bytes, ForkName::Deneb)
+                .is_err()
+        );
+        assert!(
+            SignedBlindedBeaconBlock::<Mainnet>::from_ssz_bytes_for_fork(&bytes, ForkName::Altair)
+                .is_err()
+        );
+        assert!(
+            SignedBlindedBeaconBlock::<Mainnet>::from_ssz_bytes_for_schedule(
+                &bytes,
+                &ForkSchedule::mainnet()
+            )
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn payload_list_roots() {
+        let transaction = vec![0xab; 100];
+        let payload = ExecutionPayloadCapella::<Mainnet> {
+            transactions: CowList::from(
+                VariableList::new(vec![VariableList::new(transaction.clone()).unwrap()]).unwrap(),
+            ),
+            withdrawals: VariableList::new(vec![Withdrawal::default()]).unwrap(),
+            ..ExecutionPayloadCapella::default()
+        };
+
+        // A transaction is a list of up to 2^30 bytes, in a list of up to 2^20 transactions.
+        let transaction_root =
+            tree_hash::mix_in_length(&merkle_root(&transaction, 1 << 25), transaction.len());
+        let transactions_root =
+            tree_hash::mix_in_length(&merkle_root(transaction_root.as_bytes(), 1 << 20), 1);
+        assert_eq!(payload.transactions_root(), transactions_root);
+        let withdrawals_root = payload.withdrawals.tree_hash_root();
+        assert_eq!(payload.withdrawals_root(), withdrawals_root);
+
+        let header = payload.to_header();
+        assert_eq!(header.transactions_root, h256(transactions_root));
+        assert_eq!(header.withdrawals_root, h256(withdrawals_root));
+
+        let payload = ExecutionPayload::from(payload);
+        assert_eq!(payload.transactions_root(), transactions_root);
+        assert_eq!(payload.withdrawals_root(), Some(withdrawals_root));
+        let bellatrix = ExecutionPayload::from(ExecutionPayloadBellatrix::<Mainnet>::default());
+        assert_eq!(bellatrix.withdrawals_root(), None);
+        assert_eq!(
+            bellatrix.transactions_root(),
+            VariableList::<Transaction, typenum::U1048576>::empty().tree_hash_root()
+        );
+    }
+
+    #[test]
+    fn blinded_conversions() {
+        let mut deneb = SignedBeaconBlockDeneb::default();
+        let payload = &mut deneb.message.body.execution_payload;
+        payload.block_number = 5;
+        payload.blob_gas_used = 1 << 17;
+        payload.transactions = CowList::from(
+            VariableList::new(vec![VariableList::new(vec![0xab; 100]).unwrap()]).unwrap(),
+        );
+        let mut electra = SignedBeaconBlockElectra::default();
+        electra.message.body.execution_payload = deneb.message.body.execution_payload.clone();
+
+        let blocks: [SignedBeaconBlock; 4] = [
+            SignedBeaconBlockBellatrix::default().into(),
+            SignedBeaconBlockCapella::default().into(),
+            deneb.into(),
+            electra.into(),
+        ];
+
+        for block in blocks {
+            let payload = match &block {
+                SignedBeaconBlock::Bellatrix(block) => {
+                    ExecutionPayload::from(block.message.body.execution_payload.clone())
+                }
+                SignedBeaconBlock::Capella(block) => {
+                    block.message.body.execution_payload.clone().into()
+                }
+                SignedBeaconBlock::Deneb(block) => {
+                    block.message.body.execution_payload.clone().into()
+                }
+                SignedBeaconBlock::Electra(block) => {
+                    block.message.body.execution_payload.clone().into()
+                }
+                _ => unreachable!(),
+            };
+
+            let blinded = block.clone().into_blinded().unwrap();
+            assert_eq!(blinded.fork_name(), block.fork_name());
+            assert_eq!(blinded.tree_hash_root(), block.tree_hash_root());
+            assert_eq!(blinded.signed_block_header(), block.signed_block_header());
+            assert_eq!(blinded.execution_block_hash(), payload.block_hash());
+
+            // A payload of another fork.
+            let other = match payload {
+                ExecutionPayload::Bellatrix(_) => ExecutionPayloadCapella::default().into(),
+                _ => ExecutionPayload::from(ExecutionPayloadBellatrix::default()),
+            };
+            assert_eq!(
+                blinded.clone().try_into_full(other.clone()),
+                Err(Error::ExecutionPayloadMismatch {
+                    expected: payload.tree_hash_root(),
+                    found: other.tree_hash_root(),
+                })
+            );
+            assert_eq!(blinded.try_into_full(payload), Ok(block));
+        }
+
+        // A payload of the same fork with a different gas limit.
+        let mut capella = SignedBeaconBlockCapella::<Mainnet>::default();
+        let payload = capella.message.body.execution_payload.clone();
+        capella.message.body.execution_payload.gas_limit = 30_000_000;
+        assert!(matches!(
+            capella.into_blinded().try_into_full(payload),
+            Err(Error::ExecutionPayloadMismatch { .. })
+        ));
+
+        assert_eq!(
+            SignedBeaconBlock::from(SignedBeaconBlockAltair::<Mainnet>::default()).into_blinded(),
+            None
+        );
+    }
+
+    #[test]
+    fn blob_sidecars() {
+        let commitments = (0..3)
+            .map(|i| KzgCommitment::from_elem(i + 1))
+            .collect::<Vec<_>>();
+        let mut deneb = SignedBeaconBlockDeneb::<Mainnet>::default();
+        deneb.message.slot = Slot::new(12);
+        deneb.message.body.blob_kzg_commitments = VariableList::new(commitments.clone()).unwrap();
+        let mut electra = SignedBeaconBlockElectra::<Mainnet>::default();
+        electra.message.body.blob_kzg_commitments = VariableList::new(commitments).unwrap();
+
+        let sidecars = [
+            deneb.blob_sidecar(1, Blob::<Mainnet>::from_elem(7), KzgProof::from_elem(8)),
+            electra.blob_sidecar(2, Blob::<Mainnet>::default(), KzgProof::default()),
+        ];
+        for sidecar in sidecars {
+            let mut sidecar = sidecar.unwrap();
+            assert_eq!(
+                sidecar.kzg_commitment,
+                KzgCommitment::from_elem(sidecar.index as u8 + 1)
+            );
+            assert!(sidecar.verify_inclusion_proof());
+            assert_eq!(
+                BlobSidecar::from_ssz_bytes(&sidecar.as_ssz_bytes()),
+                Ok(sidecar.clone())
+            );
+
+            sidecar.index -= 1;
+            assert!(!sidecar.verify_inclusion_proof());
+            sidecar.index += 1;
+            let header = &mut sidecar.signed_block_header.message;
+            header.slot = header.slot.saturating_add(1);
+            assert!(sidecar.verify_inclusion_proof());
+            sidecar.signed_block_header.message.body_root = H256::default();
+            assert!(!sidecar.verify_inclusion_proof());
+        }
+
+        let sidecar = deneb
+            .blob_sidecar(0, Blob::<Mainnet>::default(), KzgProof::default())
+            .unwrap();
+        assert_eq!(sidecar.signed_block_header, deneb.signed_block_header());
+        assert_eq!(
+            sidecar.signed_block_header.message.body_root,
+            h256(deneb.message.body.tree_hash_root())
+        );
+        assert_eq!(
+            deneb.blob_sidecar(3, Blob::<Mainnet>::default(), KzgProof::default()),
+            Err(Error::OutOfBounds { i: 3, len: 3 })
+        );
+        assert!(!BlobSidecar {
+            index: 4096,
+            ..sidecar
+        }
+        .verify_inclusion_proof());
+    }
+
+    #[test]
+    fn execution_requests_list() {
+        let requests = ExecutionRequests::<Mainnet> {
+            deposits: VariableList::new(vec![
+                DepositRequest {
+                    amount: Gwei::new(32_000_000_000),
+                    index: 7,
+                    ..DepositRequest::default()
+                };
+                2
+            ])
+            .unwrap(),
+            withdrawals: VariableList::default(),
+            consolidations: VariableList::new(vec![ConsolidationRequest::default()]).unwrap(),
+        };
+
+        let list = requests.to_requests_list();
+        assert_eq!(list.len(), 2);
+        assert_eq!(list[0][0], DEPOSIT_REQUEST_TYPE);
+        assert_eq!(list[0][1..], requests.deposits.as_ssz_bytes()[..]);
+        assert_eq!(list[1][0], CONSOLIDATION_REQUEST_TYPE);
+        assert_eq!(
+            ExecutionRequests::from_requests_list(&list),
+            Ok(requests.clone())
+        );
+
+        assert!(ExecutionRequests::<Mainnet>::default()
+            .to_requests_list()
+            .is_empty());
+        assert_eq!(
+            ExecutionRequests::<Mainnet>::from_requests_list::<Vec<u8>>(&[]),
+            Ok(ExecutionRequests::default())
+        );
+
+        // Out of order, repeated, unknown or malformed requests.
+        let reversed = [list[1].clone(), list[0].clone()];
+        assert!(ExecutionRequests::<Mainnet>::from_requests_list(&reversed).is_err());
+        let repeated = [list[0].clone(), list[0].clone()];
+        assert!(ExecutionRequests::<Mainnet>::from_requests_list(&repeated).is_err());
+        let mut unknown = list[1].clone();
+        unknown[0] = 3;
+        assert!(ExecutionRequests::<Mainnet>::from_requests_list(&[unknown]).is_err());
+        let truncated = &list[0][..list[0].len() - 1];
+        assert!(ExecutionRequests::<Mainnet>::from_requests_list(&[truncated]).is_err());
+        assert!(ExecutionRequests::<Mainnet>::from_requests_list(&[[]]).is_err());
+    }
+
+    #[test]
+    fn electra_attestations() {
+        let mut aggregation_bits = BitList::with_capacity(5).unwrap();
+        aggregation_bits.set(1, true).unwrap();
+        aggregation_bits.set(4, true).unwrap();
+        let attestation = Attestation::<Mainnet> {
+            aggregation_bits: CustomBitList(aggregation_bits),
+            data: AttestationData {
+                slot: Slot::new(3),
+                index: CommitteeIndex::new(17),
+                ..AttestationData::default()
+            },
+            signature: SignatureBytes::new([9; 96]),
+        };
+
+        let electra = AttestationElectra::try_from(attestation.clone()).unwrap();
+        assert_eq!(electra.committee_indices(), vec![CommitteeIndex::new(17)]);
+        assert_eq!(electra.data.index, CommitteeIndex::new(0));
+        assert_eq!(electra.data.slot, Slot::new(3));
+        assert_eq!(electra.signature, attestation.signature);
+        // The bits of the members of the committee are unchanged.
+        assert_eq!(
+            electra.aggregation_bits.as_ssz_bytes(),
+            attestation.aggregation_bits.as_ssz_bytes()
+        );
+
+        let mut attestation = attestation;
+        attestation.data.index = CommitteeIndex::new(64);
+        assert_eq!(
+            AttestationElectra::try_from(attestation),
+            Err(Error::OutOfBounds { i: 64, len: 64 })
+        );
+
+        let indexed = IndexedAttestation::<Mainnet> {
+            attesting_indices: VariableList::new(vec![4, 8, 15]).unwrap(),
+            ..IndexedAttestation::default()
+        };
+        let slashing = AttesterSlashingElectra::from(AttesterSlashing {
+            attestation_1: indexed.clone(),
+            attestation_2: IndexedAttestation::default(),
+        });
+        // Only the limit of the attesting indices changes, which affects the root but not the
+        // encoding.
+        assert_eq!(slashing.attestation_1.attesting_indices[..], [4, 8, 15]);
+        assert_eq!(
+            slashing.attestation_1.as_ssz_bytes(),
+            indexed.as_ssz_bytes()
+        );
+        assert_ne!(
+            slashing.attestation_1.tree_hash_root(),
+            indexed.tree_hash_root()
+        );
+    }
+
+    #[test]
+    fn indexed_attestations() {
+        let committee = [9, 3, 7, 1].map(ValidatorIndex::new);
+        let mut bits = BitList::with_capacity(4).unwrap();
+        bits.set(0, true).unwrap();
+        bits.set(1, true).unwrap();
+        bits.set(3, true).unwrap();
+        let attestation = Attestation::<Minimal> {
+            aggregation_bits: CustomBitList(bits),
+            data: AttestationData {
+                slot: Slot::new(3),
+                index: CommitteeIndex::new(1),
+                ..AttestationData::default()
+            },
+            signature: SignatureBytes::new([9; 96]),
+        };
+
+        let indexed = attestation.to_indexed(&committee).unwrap();
+        assert_eq!(indexed.attesting_indices[..], [1, 3, 9]);
+        assert_eq!(indexed.data, attestation.data);
+        assert_eq!(indexed.signature, attestation.signature);
+        assert_eq!(
+            attestation.to_indexed(&committee[..3]),
+            Err(Error::OutOfBounds { i: 4, len: 3 })
+        );
+
+        // The bits of the second committee follow those of the first.
+        let mut electra = AttestationElectra::try_from(attestation).unwrap();
+        electra.committee_bits.set(0, true).unwrap();
+        let first_committee = [ValidatorIndex::new(4)];
+        let second_committee = [9, 3, 7].map(ValidatorIndex::new);
+        let indexed = electra
+            .to_indexed(&[&first_committee, &second_committee])
+            .unwrap();
+        assert_eq!(indexed.attesting_indices[..], [4, 7, 9]);
+        assert_eq!(
+            electra.to_indexed(&[&first_committee]),
+            Err(Error::OutOfBounds { i: 1, len: 2 })
+        );
+        assert_eq!(
+            electra.to_indexed(&[&first_committee, &second_committee[..2]]),
+            Err(Error::OutOfBounds { i: 4, len: 3 })
+        );
+    }
+
+    #[test]
+    fn slashings() {
+        let header = BeaconBlockHeader {
+            slot: Slot::new(10),
+            proposer_index: ValidatorIndex::new(7),
+            ..BeaconBlockHeader::default()
+        };
+        let slashing = |header_2: BeaconBlockHeader| ProposerSlashing {
+            signed_header_1: SignedBeaconBlockHeader {
+                message: header.clone(),
+                signature: SignatureBytes::default(),
+            },
+            signed_header_2: SignedBeaconBlockHeader {
+                message: header_2,
+                signature: SignatureBytes::default(),
+            },
+        };
+        assert!(slashing(BeaconBlockHeader {
+            body_root: H256::from_elem(1),
+            ..header.clone()
+        })
+        .is_valid_structure());
+        assert!(!slashing(header.clone()).is_valid_structure());
+        assert!(!slashing(BeaconBlockHeader {
+            slot: Slot::new(11),
+            body_root: H256::from_elem(1),
+            ..header.clone()
+        })
+        .is_valid_structure());
+        assert!(!slashing(BeaconBlockHeader {
+            proposer_index: ValidatorIndex::new(8),
+            body_root: H256::from_elem(1),
+            ..header.clone()
+        })
+        .is_valid_structure());
+
+        let vote = |source: u64, target: u64, root: u8| AttestationData {
+            beacon_block_root: H256::from_elem(root),
+            source: Checkpoint {
+                epoch: Epoch::new(source),
+                root: H256::default(),
+            },
+            target: Checkpoint {
+                epoch: Epoch::new(target),
+                root: H256::default(),
+            },
+            ..AttestationData::default()
+        };
+        assert!(vote(1, 3, 0).is_double_vote(&vote(1, 3, 1)));
+        assert!(vote(1, 3, 0).is_double_vote(&vote(2, 3, 0)));
+        assert!(!vote(1, 3, 0).is_double_vote(&vote(1, 3, 0)));
+        assert!(!vote(1, 3, 0).is_double_vote(&vote(1, 4, 0)));
+
+        assert!(vote(1, 5, 0).is_surround_vote(&vote(2, 4, 0)));
+        assert!(!vote(2, 4, 0).is_surround_vote(&vote(1, 5, 0)));
+        assert!(!vote(1, 5, 0).is_surround_vote(&vote(1, 4, 0)));
+        assert!(!vote(1, 5, 0).is_surround_vote(&vote(2, 5, 0)));
+
+        let attester_slashing =
+            |data_1: AttestationData, data_2: AttestationData| AttesterSlashing::<Minimal> {
+                attestation_1: IndexedAttestation {
+                    data: data_1,
+                    ..IndexedAttestation::default()
+                },
+                attestation_2: IndexedAttestation {
+                    data: data_2,
+                    ..IndexedAttestation::default()
+                },
+            };
+        assert!(attester_slashing(vote(1, 3, 0), vote(1, 3, 1)).is_slashable());
+        assert!(attester_slashing(vote(1, 5, 0), vote(2, 4, 0)).is_slashable());
+        assert!(!attester_slashing(vote(2, 4, 0), vote(1, 5, 0)).is_slashable());
+        assert!(!attester_slashing(vote(1, 3, 0), vote(3, 4, 0)).is_slashable());
+        assert!(
+            AttesterSlashingElectra::from(attester_slashing(vote(1, 3, 0), vote(1, 3, 1)))
+                .is_slashable()
+        );
+    }
+
+    #[test]
+    fn attestation_bits_union() {
+        let attestation = |attesters: &[usize]| {
+            let mut bits = BitList::with_capacity(4).unwrap();
+            for &attester in attesters {
+                bits.set(attester, true).unwrap();
+            }
+            // The point at infinity, whose aggregate with itself is itself.
+            let mut signature = [0; 96];
+            signature[0] = 0xc0;
+            Attestation::<Minimal> {
+                aggregation_bits: CustomBitList(bits),
+                data: AttestationData::default(),
+                signature: SignatureBytes::new(signature),
+            }
+        };
+
+        // The signature is not aggregated.
+        let mut union = attestation(&[0, 3]);
+        let mut other = attestation(&[1, 3]);
+        other.signature = SignatureBytes::new([0xaa; 96]);
+        union.union_bits(&other).unwrap();
+        assert_eq!(union, attestation(&[0, 1, 3]));
+
+        let mut other_data = attestation(&[2]);
+        other_data.data.slot = Slot::new(1);
+        assert_eq!(
+            union.union_bits(&other_data),
+            Err(Error::AttestationMismatch)
+        );
+        let mut other_committee = attestation(&[2]);
+        other_committee.aggregation_bits = CustomBitList(BitList::with_capacity(5).unwrap());
+        assert_eq!(
+            union.union_bits(&other_committee),
+            Err(Error::OutOfBounds { i: 5, len: 4 })
+        );
+        assert_eq!(union, attestation(&[0, 1, 3]));
+
+        let mut electra = AttestationElectra::try_from(attestation(&[0, 1])).unwrap();
+        electra
+            .union_bits(&AttestationElectra::try_from(attestation(&[1, 2])).unwrap())
+            .unwrap();
+        assert_eq!(
+            electra,
+            AttestationElectra::try_from(attestation(&[0, 1, 2])).unwrap()
+        );
+
+        #[cfg(feature = "bls")]
+        {
+            electra
+                .aggregate_overlapping(&AttestationElectra::try_from(attestation(&[2])).unwrap())
+                .unwrap();
+            assert_eq!(
+                electra.aggregate(&AttestationElectra::try_from(attestation(&[2])).unwrap()),
+                Err(Error::OverlappingAggregationBits)
+            );
+            electra
+                .aggregate(&AttestationElectra::try_from(attestation(&[3])).unwrap())
+                .unwrap();
+            assert_eq!(
+                electra,
+                AttestationElectra::try_from(attestation(&[0, 1, 2, 3])).unwrap()
+            );
+        }
+    }
+
+    #[cfg(feature = "bls")]
+    #[test]
+    fn attestation_aggregation() {
+        use blst::min_pk::{AggregateSignature, SecretKey};
+
+        let signatures: Vec<_> = (1..=3)
+            .map(|seed| {
+                let secret_key = SecretKey::key_gen(&[seed; 32], &[]).unwrap();
+                secret_key.sign(b"attestation", b"", &[])
+            })
+            .collect();
+        let attestation = |signers: &[usize]| {
+            let mut bits = BitList::with_capacity(4).unwrap();
+            for &signer in signers {
+                bits.set(signer, true).unwrap();
+            }
+            let signatures: Vec<_> = signers.iter().map(|&signer| &signatures[signer]).collect();
+            Attestation::<Minimal> {
+                aggregation_bits: CustomBitList(bits),
+                data: AttestationData {
+                    slot: Slot::new(3),
+                    ..AttestationData::default()
+                },
+                signature: SignatureBytes::new(
+                    AggregateSignature::aggregate(&signatures, true)
+                        .unwrap()
+                        .to_signature()
+                        .compress(),
+                ),
+            }
+        };
+
+        let mut aggregate = attestation(&[0]);
+        aggregate.aggregate(&attestation(&[2])).unwrap();
+        aggregate.aggregate(&attestation(&[1])).unwrap();
+        assert_eq!(aggregate, attestation(&[0, 1, 2]));
+
+        assert_eq!(
+            aggregate.aggregate(&attestation(&[2])),
+            Err(Error::OverlappingAggregationBits)
+        );
+        let mut other_data = attestation(&[0]);
+        other_data.data.slot = Slot::new(4);
+        assert_eq!(
+            attestation(&[1]).aggregate(&other_data),
+            Err(Error::AttestationMismatch)
+        );
+        let mut other_committee = attestation(&[0]);
+        other_committee.aggregation_bits = CustomBitList(BitList::with_capacity(5).unwrap());
+        assert_eq!(
+            attestation(&[1]).aggregate(&other_committee),
+            Err(Error::OutOfBounds { i: 5, len: 4 })
+        );
+        assert_eq!(aggregate, attestation(&[0, 1, 2]));
+
+        // The signature of the attester in common is aggregated twice.
+        let mut overlapping = attestation(&[0, 1]);
+        overlapping
+            .aggregate_overlapping(&attestation(&[1]))
+            .unwrap();
+        assert_eq!(
+            overlapping.aggregation_bits,
+            attestation(&[0, 1]).aggregation_bits
+        );
+        let twice = [&signatures[0], &signatures[1], &signatures[1]];
+        assert_eq!(
+            overlapping.signature,
+            SignatureBytes::new(
+                AggregateSignature::aggregate(&twice, true)
+                    .unwrap()
+                    .to_signature()
+                    .compress()
+            )
+        );
+
+        let mut electra = AttestationElectra::try_from(attestation(&[0])).unwrap();
+        electra
+            .aggregate(&AttestationElectra::try_from(attestation(&[1])).unwrap())
+            .unwrap();
+        assert_eq!(
+            electra,
+            AttestationElectra::try_from(attestation(&[0, 1])).unwrap()
+        );
+        let mut other_committee = attestation(&[2]);
+        other_committee.data.index = CommitteeIndex::new(1);
+        assert_eq!(
+            electra.aggregate(&AttestationElectra::try_from(other_committee).unwrap()),
+            Err(Error::AttestationMismatch)
+        );
+    }
+
+    #[test]
+    fn minimal_preset() {
+        let mainnet = SignedBeaconBlockElectra::<Mainnet>::default();
+        let mut minimal = SignedBeaconBlockElectra::<Minimal>::default();
+        // The sync committee has 32 members instead of 512.
+        assert_eq!(
+            mainnet.as_ssz_bytes().len() - minimal.as_ssz_bytes().len(),
+            (512 - 32) / 8
+        );
+        assert_ne!(minimal.tree_hash_root(), mainnet.tree_hash_root());
+
+        minimal.message.slot = Slot::new(8);
+        minimal.message.body.blob_kzg_commitments =
+            VariableList::new(vec![KzgCommitment::from_elem(1); 32]).unwrap();
+        assert!(minimal
+            .message
+            .body
+            .blob_kzg_commitments
+            .push(KzgCommitment::default())
+            .is_err());
+
+        let bytes = minimal.as_ssz_bytes();
+        assert_eq!(
+            SignedBeaconBlockElectra::from_ssz_bytes(&bytes),
+            Ok(minimal.clone())
+        );
+        assert!(SignedBeaconBlockElectra::<Mainnet>::from_ssz_bytes(&bytes).is_err());
+
+        // The commitments of the minimal preset have shorter inclusion proofs.
+        let sidecar = minimal
+            .blob_sidecar(31, Blob::<Minimal>::default(), KzgProof::default())
+            .unwrap();
+        assert_eq!(sidecar.kzg_commitment_inclusion_proof.len(), 10);
+        assert!(sidecar.verify_inclusion_proof());
+        assert_eq!(
+            BlobSidecar::from_ssz_bytes(&sidecar.as_ssz_bytes()),
+            Ok(sidecar)
+        );
+
+        // There are 4 committees per slot instead of 64.
+        let attestation = Attestation::<Minimal> {
+            data: AttestationData {
+                index: CommitteeIndex::new(4),
+                ..AttestationData::default()
+            },
+            ..Attestation::default()
+        };
+        assert_eq!(
+            AttestationElectra::try_from(attestation),
+            Err(Error::OutOfBounds { i: 4, len: 4 })
+        );
+    }
+
+    #[test]
+    fn fork_body_paths() {
+        assert_eq!(
+            gindex_of::<BeaconBlockBodyPhase0>(&["voluntary_exits"]),
+            Ok(15)
+        );
+        assert!(gindex_of::<BeaconBlockBodyPhase0>(&["sync_aggregate"]).is_err());
+        assert_eq!(
+            gindex_of::<BeaconBlockBodyAltair>(&["sync_aggregate"]),
+            Ok(24)
+        );
+        assert_eq!(
+            gindex_of::<BeaconBlockBodyBellatrix>(&["execution_payload"]),
+            Ok(25)
+        );
+        // The `execution_payload` and `blob_kzg_commitments` indices of the light client and blob
+        // sidecar specifications.
+        assert_eq!(
+            gindex_of::<BeaconBlockBodyDeneb>(&["execution_payload"]),
+            Ok(25)
+        );
+        assert_eq!(
+            gindex_of::<BeaconBlockBodyDeneb>(&["blob_kzg_commitments"]),
+            Ok(27)
+        );
+        assert_eq!(
+            gindex_of::<BeaconBlockBodyElectra>(&["blob_kzg_commitments"]),
+            Ok(27)
+        );
+        assert_eq!(
+            gindex_of::<BeaconBlockBodyElectra>(&["execution_requests", "consolidations"]),
+            Ok(28 * 4 + 2)
+        );
+        assert_eq!(
+            gindex_of::<BeaconBlockBodyDeneb>(&["execution_payload", "excess_blob_gas"]),
+            Ok(25 * 32 + 16)
+        );
+    }
+
+    #[test]
+    fn withdrawal_amount_path() {
+        let mut body = BeaconBlockBodyCapella::<Mainnet>::default();
+        let withdrawals = (0..4)
+            .map(|i| Withdrawal {
+                amount: Gwei::new(32 + i),
+                ..Withdrawal::default()
+            })
+            .collect::<Vec<_>>();
+        body.execution_payload.withdrawals = VariableList::new(withdrawals).unwrap();
+
+        let gindex = gindex_of::<BeaconBlockBodyCapella>(&[
+            "execution_payload",
+            "withdrawals",
+            "3",
+            "amount",
+        ])
+        .unwrap();
+        assert_eq!(body.tree_hash_node(gindex), Ok(35_u64.tree_hash_root()));
+
+        let gindex =
+            gindex_of::<BeaconBlockBodyCapella>(&["execution_payload", "withdrawals", "__len__"])
+                .unwrap();
+        assert_eq!(body.tree_hash_node(gindex), Ok(4_u64.tree_hash_root()));
+        assert!(
+            gindex_of::<BeaconBlockBodyCapella>(&["execution_payload", "withdrawals", "16"])
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn block_header() {
+        let bytes = std::fs::read("state.ssz").unwrap();
+        let block = SignedBeaconBlockCapella::<Mainnet>::from_ssz_bytes(&bytes).unwrap();
+        let header = block.message.block_header();
+
+        assert_eq!(header.slot, block.message.slot);
+        assert_eq!(
+            header.body_root[..],
+            block.message.body.tree_hash_root()[..]
+        );
+        assert_eq!(header.tree_hash_root(), block.message.tree_hash_root());
+        assert_eq!(block.message_root(), block.message.canonical_root());
+        assert_eq!(
+            block.signed_block_header().message.tree_hash_root(),
+            block.message_root()
+        );
+        assert_eq!(block.signed_block_header().signature, block.signature);
+    }
+
+    #[test]
+    fn signing_roots() {
+        let domain = compute_domain([0, 0, 0, 0], [1, 0, 0, 0], Hash256::repeat_byte(1));
+        let block = BeaconBlockCapella::<Mainnet> {
+            slot: Slot::new(42),
+            ..BeaconBlockCapella::default()
+        };
+
+        // A header is signed in place of its block by the same signature.
+        assert_eq!(
+            block.signing_root(domain),
+            block.block_header().signing_root(domain)
+        );
+        assert_eq!(
+            block.signing_root(domain),
+            compute_signing_root(&block, domain)
+        );
+        assert_ne!(
+            block.signing_root(domain),
+            block.signing_root(compute_domain([1, 0, 0, 0], [1, 0, 0, 0], Hash256::zero()))
+        );
+        assert_ne!(
+            VoluntaryExit::default().signing_root(domain),
+            AttestationData::default().signing_root(domain)
+        );
+    }
+
+    #[test]
+    fn deposit_proof() {
+        let deposit_data = DepositData {
+            amount: Gwei::new(32_000_000_000),
+            ..DepositData::default()
+        };
+
+        // The contract holds `deposit_data` at index 2, after two empty deposits.
+        let leaves = [
+            Hash256::zero(),
+            Hash256::zero(),
+            deposit_data.tree_hash_root(),
+        ];
+        let contract_root = merkle_root(
+            &leaves.iter().flat_map(|leaf| leaf.0).collect::<Vec<_>>(),
+            1 << 32,
+        );
+        let deposit_root = h256(tree_hash::mix_in_length(&contract_root, leaves.len()));
+
+        let mut branch = vec![Hash256::zero()];
+        branch.push(merkle_root(&[0; 64], 2));
+        for height in 2..32 {
+            branch.push(merkle_root(&[], 1 << height));
+        }
+        // The deposit count mixed in by the contract.
+        branch.push(3_u64.tree_hash_root());
+
+        let deposit = Deposit {
+            proof: FixedVector::new(branch.iter().copied().map(h256).collect()).unwrap(),
+            data: deposit_data,
+        };
+        assert!(deposit.verify_proof(&deposit_root, 2));
+        assert!(!deposit.verify_proof(&deposit_root, 3));
+        assert!(!deposit.verify_proof(&h256(Hash256::zero()), 2));
+    }
+
+    #[test]
+    fn signed_beacon_block_root() {
+        let block = SignedBeaconBlockCapella::<Mainnet>::default();
+
+        assert_eq!(
+            block.tree_hash_root(),
+            merkle_root(
+                &[
+                    block.message.tree_hash_root().as_bytes(),
+                    block.signature.tree_hash_root().as_bytes(),
+                ]
+                .concat(),
+                2
+            )
+        );
+        assert_ne!(block.tree_hash_root(), Hash256::zero());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn beacon_api_json() {
+        let checkpoint = Checkpoint {
+            epoch: Epoch::new(3),
+            root: H256::from_elem(0xaa),
+        };
+        let json = serde_json::to_value(&checkpoint).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({ "epoch": "3", "root": format!("0x{}", "aa".repeat(32)) })
+        );
+        assert_eq!(
+            serde_json::from_value::<Checkpoint>(json).unwrap(),
+            checkpoint
+        );
+        assert!(serde_json::from_str::<Checkpoint>(r#"{"epoch":3,"root":"0x"}"#).is_err());
+
+        let bytes = std::fs::read("state.ssz").unwrap();
+        let block = SignedBeaconBlockCapella::<Mainnet>::from_ssz_bytes(&bytes).unwrap();
+        let json = serde_json::to_value(&block).unwrap();
+
+        let payload = &json["message"]["body"]["execution_payload"];
+        assert_eq!(
+            payload["block_number"],
+            block
+                .message
+                .body
+                .execution_payload
+                .block_number
+                .to_string()
+        );
+        assert!(payload["base_fee_per_gas"]
+            .as_str()
+            .unwrap()
+            .parse::<u128>()
+            .is_ok());
+        assert!(payload["transactions"][0]
+            .as_str()
+            .unwrap()
+            .starts_with("0x"));
+        assert_eq!(
+            json["message"]["body"]["sync_aggregate"]["sync_committee_bits"]
+                .as_str()
+                .unwrap()
+                .len(),
+            2 + 2 * 64
+        );
+
+        let decoded = serde_json::from_value::<SignedBeaconBlockCapella<Mainnet>>(json).unwrap();
+        assert_eq!(decoded, block);
+    }
 }
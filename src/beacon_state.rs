@@ -0,0 +1,1893 @@
+use crate::beacon_block::{
+    h256, Attestation, AttestationData, BeaconBlockHeader, Checkpoint, CustomBitList, Eth1Data,
+    ExecutionPayloadHeaderBellatrix, ExecutionPayloadHeaderCapella, ExecutionPayloadHeaderDeneb,
+    H256,
+};
+use crate::{
+    BitVector, Epoch, Error, FixedVector, ForkName, ForkSchedule, Gwei, Mainnet, MemorySize,
+    MerkleProof, Preset, PublicKeyBytes, SignatureBytes, Slot, ValidatorIndex, VariableList,
+    Version,
+};
+use ssz::{Decode, DecodeError, Encode};
+use ssz_derive::{Decode, Encode};
+use tree_hash::Hash256;
+use tree_hash_derive::TreeHash;
+use typenum::Unsigned;
+
+#[derive(Clone, Default, Encode, Decode, TreeHash, PartialEq, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(bound = "P: Preset"))]
+#[cfg_attr(
+    feature = "schemars",
+    schemars(bound = "P: Preset + schemars::JsonSchema")
+)]
+#[ssz(struct_behaviour = "container")]
+pub struct BeaconStatePhase0<P: Preset = Mainnet> {
+    #[cfg_attr(feature = "serde", serde(with = "serde_utils::quoted_u64"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "crate::json_schema::Quoted<u64>")
+    )]
+    pub genesis_time: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::hex_fixed_vec"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::json_schema::Hex<H256>"))]
+    pub genesis_validators_root: H256,
+    pub slot: Slot,
+    pub fork: Fork,
+    pub latest_block_header: BeaconBlockHeader,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_utils::fixed_vec_of_hex_fixed_vec")
+    )]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(
+            with = "crate::json_schema::HexItems<FixedVector<H256, P::SlotsPerHistoricalRoot>>"
+        )
+    )]
+    pub block_roots: FixedVector<H256, P::SlotsPerHistoricalRoot>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_utils::fixed_vec_of_hex_fixed_vec")
+    )]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(
+            with = "crate::json_schema::HexItems<FixedVector<H256, P::SlotsPerHistoricalRoot>>"
+        )
+    )]
+    pub state_roots: FixedVector<H256, P::SlotsPerHistoricalRoot>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_utils::list_of_hex_fixed_vec")
+    )]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(
+            with = "crate::json_schema::HexItems<VariableList<H256, P::HistoricalRootsLimit>>"
+        )
+    )]
+    pub historical_roots: VariableList<H256, P::HistoricalRootsLimit>,
+    pub eth1_data: Eth1Data,
+    pub eth1_data_votes: VariableList<Eth1Data, P::Eth1DataVotesLimit>,
+    #[cfg_attr(feature = "serde", serde(with = "serde_utils::quoted_u64"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "crate::json_schema::Quoted<u64>")
+    )]
+    pub eth1_deposit_index: u64,
+    pub validators: VariableList<Validator, P::ValidatorRegistryLimit>,
+    pub balances: VariableList<Gwei, P::ValidatorRegistryLimit>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_utils::fixed_vec_of_hex_fixed_vec")
+    )]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(
+            with = "crate::json_schema::HexItems<FixedVector<H256, P::EpochsPerHistoricalVector>>"
+        )
+    )]
+    pub randao_mixes: FixedVector<H256, P::EpochsPerHistoricalVector>,
+    pub slashings: FixedVector<Gwei, P::EpochsPerSlashingsVector>,
+    pub previous_epoch_attestations: VariableList<PendingAttestation<P>, P::EpochAttestationsLimit>,
+    pub current_epoch_attestations: VariableList<PendingAttestation<P>, P::EpochAttestationsLimit>,
+    pub justification_bits: BitVector<typenum::U4>,
+    pub previous_justified_checkpoint: Checkpoint,
+    pub current_justified_checkpoint: Checkpoint,
+    pub finalized_checkpoint: Checkpoint,
+}
+
+#[derive(Clone, Default, Encode, Decode, TreeHash, PartialEq, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(bound = "P: Preset"))]
+#[cfg_attr(
+    feature = "schemars",
+    schemars(bound = "P: Preset + schemars::JsonSchema")
+)]
+#[ssz(struct_behaviour = "container")]
+pub struct BeaconStateAltair<P: Preset = Mainnet> {
+    #[cfg_attr(feature = "serde", serde(with = "serde_utils::quoted_u64"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "crate::json_schema::Quoted<u64>")
+    )]
+    pub genesis_time: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::hex_fixed_vec"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::json_schema::Hex<H256>"))]
+    pub genesis_validators_root: H256,
+    pub slot: Slot,
+    pub fork: Fork,
+    pub latest_block_header: BeaconBlockHeader,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_utils::fixed_vec_of_hex_fixed_vec")
+    )]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(
+            with = "crate::json_schema::HexItems<FixedVector<H256, P::SlotsPerHistoricalRoot>>"
+        )
+    )]
+    pub block_roots: FixedVector<H256, P::SlotsPerHistoricalRoot>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_utils::fixed_vec_of_hex_fixed_vec")
+    )]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(
+            with = "crate::json_schema::HexItems<FixedVector<H256, P::SlotsPerHistoricalRoot>>"
+        )
+    )]
+    pub state_roots: FixedVector<H256, P::SlotsPerHistoricalRoot>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_utils::list_of_hex_fixed_vec")
+    )]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(
+            with = "crate::json_schema::HexItems<VariableList<H256, P::HistoricalRootsLimit>>"
+        )
+    )]
+    pub historical_roots: VariableList<H256, P::HistoricalRootsLimit>,
+    pub eth1_data: Eth1Data,
+    pub eth1_data_votes: VariableList<Eth1Data, P::Eth1DataVotesLimit>,
+    #[cfg_attr(feature = "serde", serde(with = "serde_utils::quoted_u64"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "crate::json_schema::Quoted<u64>")
+    )]
+    pub eth1_deposit_index: u64,
+    pub validators: VariableList<Validator, P::ValidatorRegistryLimit>,
+    pub balances: VariableList<Gwei, P::ValidatorRegistryLimit>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_utils::fixed_vec_of_hex_fixed_vec")
+    )]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(
+            with = "crate::json_schema::HexItems<FixedVector<H256, P::EpochsPerHistoricalVector>>"
+        )
+    )]
+    pub randao_mixes: FixedVector<H256, P::EpochsPerHistoricalVector>,
+    pub slashings: FixedVector<Gwei, P::EpochsPerSlashingsVector>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_utils::quoted_u8_var_list")
+    )]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(
+            with = "crate::json_schema::QuotedItems<VariableList<u8, P::ValidatorRegistryLimit>>"
+        )
+    )]
+    pub previous_epoch_participation: VariableList<u8, P::ValidatorRegistryLimit>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_utils::quoted_u8_var_list")
+    )]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(
+            with = "crate::json_schema::QuotedItems<VariableList<u8, P::ValidatorRegistryLimit>>"
+        )
+    )]
+    pub current_epoch_participation: VariableList<u8, P::ValidatorRegistryLimit>,
+    pub justification_bits: BitVector<typenum::U4>,
+    pub previous_justified_checkpoint: Checkpoint,
+    pub current_justified_checkpoint: Checkpoint,
+    pub finalized_checkpoint: Checkpoint,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_utils::quoted_u64_var_list")
+    )]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(
+            with = "crate::json_schema::QuotedItems<VariableList<u64, P::ValidatorRegistryLimit>>"
+        )
+    )]
+    pub inactivity_scores: VariableList<u64, P::ValidatorRegistryLimit>,
+    pub current_sync_committee: SyncCommittee<P>,
+    pub next_sync_committee: SyncCommittee<P>,
+}
+
+#[derive(Clone, Default, Encode, Decode, TreeHash, PartialEq, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(bound = "P: Preset"))]
+#[cfg_attr(
+    feature = "schemars",
+    schemars(bound = "P: Preset + schemars::JsonSchema")
+)]
+#[ssz(struct_behaviour = "container")]
+pub struct BeaconStateBellatrix<P: Preset = Mainnet> {
+    #[cfg_attr(feature = "serde", serde(with = "serde_utils::quoted_u64"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "crate::json_schema::Quoted<u64>")
+    )]
+    pub genesis_time: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::hex_fixed_vec"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::json_schema::Hex<H256>"))]
+    pub genesis_validators_root: H256,
+    pub slot: Slot,
+    pub fork: Fork,
+    pub latest_block_header: BeaconBlockHeader,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_utils::fixed_vec_of_hex_fixed_vec")
+    )]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(
+            with = "crate::json_schema::HexItems<FixedVector<H256, P::SlotsPerHistoricalRoot>>"
+        )
+    )]
+    pub block_roots: FixedVector<H256, P::SlotsPerHistoricalRoot>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_utils::fixed_vec_of_hex_fixed_vec")
+    )]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(
+            with = "crate::json_schema::HexItems<FixedVector<H256, P::SlotsPerHistoricalRoot>>"
+        )
+    )]
+    pub state_roots: FixedVector<H256, P::SlotsPerHistoricalRoot>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_utils::list_of_hex_fixed_vec")
+    )]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(
+            with = "crate::json_schema::HexItems<VariableList<H256, P::HistoricalRootsLimit>>"
+        )
+    )]
+    pub historical_roots: VariableList<H256, P::HistoricalRootsLimit>,
+    pub eth1_data: Eth1Data,
+    pub eth1_data_votes: VariableList<Eth1Data, P::Eth1DataVotesLimit>,
+    #[cfg_attr(feature = "serde", serde(with = "serde_utils::quoted_u64"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "crate::json_schema::Quoted<u64>")
+    )]
+    pub eth1_deposit_index: u64,
+    pub validators: VariableList<Validator, P::ValidatorRegistryLimit>,
+    pub balances: VariableList<Gwei, P::ValidatorRegistryLimit>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_utils::fixed_vec_of_hex_fixed_vec")
+    )]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(
+            with = "crate::json_schema::HexItems<FixedVector<H256, P::EpochsPerHistoricalVector>>"
+        )
+    )]
+    pub randao_mixes: FixedVector<H256, P::EpochsPerHistoricalVector>,
+    pub slashings: FixedVector<Gwei, P::EpochsPerSlashingsVector>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_utils::quoted_u8_var_list")
+    )]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(
+            with = "crate::json_schema::QuotedItems<VariableList<u8, P::ValidatorRegistryLimit>>"
+        )
+    )]
+    pub previous_epoch_participation: VariableList<u8, P::ValidatorRegistryLimit>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_utils::quoted_u8_var_list")
+    )]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(
+            with = "crate::json_schema::QuotedItems<VariableList<u8, P::ValidatorRegistryLimit>>"
+        )
+    )]
+    pub current_epoch_participation: VariableList<u8, P::ValidatorRegistryLimit>,
+    pub justification_bits: BitVector<typenum::U4>,
+    pub previous_justified_checkpoint: Checkpoint,
+    pub current_justified_checkpoint: Checkpoint,
+    pub finalized_checkpoint: Checkpoint,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_utils::quoted_u64_var_list")
+    )]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(
+            with = "crate::json_schema::QuotedItems<VariableList<u64, P::ValidatorRegistryLimit>>"
+        )
+    )]
+    pub inactivity_scores: VariableList<u64, P::ValidatorRegistryLimit>,
+    pub current_sync_committee: SyncCommittee<P>,
+    pub next_sync_committee: SyncCommittee<P>,
+    pub latest_execution_payload_header: ExecutionPayloadHeaderBellatrix<P>,
+}
+
+#[derive(Clone, Default, Encode, Decode, TreeHash, PartialEq, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(bound = "P: Preset"))]
+#[cfg_attr(
+    feature = "schemars",
+    schemars(bound = "P: Preset + schemars::JsonSchema")
+)]
+#[ssz(struct_behaviour = "container")]
+pub struct BeaconStateCapella<P: Preset = Mainnet> {
+    #[cfg_attr(feature = "serde", serde(with = "serde_utils::quoted_u64"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "crate::json_schema::Quoted<u64>")
+    )]
+    pub genesis_time: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::hex_fixed_vec"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::json_schema::Hex<H256>"))]
+    pub genesis_validators_root: H256,
+    pub slot: Slot,
+    pub fork: Fork,
+    pub latest_block_header: BeaconBlockHeader,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_utils::fixed_vec_of_hex_fixed_vec")
+    )]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(
+            with = "crate::json_schema::HexItems<FixedVector<H256, P::SlotsPerHistoricalRoot>>"
+        )
+    )]
+    pub block_roots: FixedVector<H256, P::SlotsPerHistoricalRoot>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_utils::fixed_vec_of_hex_fixed_vec")
+    )]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(
+            with = "crate::json_schema::HexItems<FixedVector<H256, P::SlotsPerHistoricalRoot>>"
+        )
+    )]
+    pub state_roots: FixedVector<H256, P::SlotsPerHistoricalRoot>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_utils::list_of_hex_fixed_vec")
+    )]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(
+            with = "crate::json_schema::HexItems<VariableList<H256, P::HistoricalRootsLimit>>"
+        )
+    )]
+    pub historical_roots: VariableList<H256, P::HistoricalRootsLimit>,
+    pub eth1_data: Eth1Data,
+    pub eth1_data_votes: VariableList<Eth1Data, P::Eth1DataVotesLimit>,
+    #[cfg_attr(feature = "serde", serde(with = "serde_utils::quoted_u64"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "crate::json_schema::Quoted<u64>")
+    )]
+    pub eth1_deposit_index: u64,
+    pub validators: VariableList<Validator, P::ValidatorRegistryLimit>,
+    pub balances: VariableList<Gwei, P::ValidatorRegistryLimit>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_utils::fixed_vec_of_hex_fixed_vec")
+    )]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(
+            with = "crate::json_schema::HexItems<FixedVector<H256, P::EpochsPerHistoricalVector>>"
+        )
+    )]
+    pub randao_mixes: FixedVector<H256, P::EpochsPerHistoricalVector>,
+    pub slashings: FixedVector<Gwei, P::EpochsPerSlashingsVector>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_utils::quoted_u8_var_list")
+    )]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(
+            with = "crate::json_schema::QuotedItems<VariableList<u8, P::ValidatorRegistryLimit>>"
+        )
+    )]
+    pub previous_epoch_participation: VariableList<u8, P::ValidatorRegistryLimit>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_utils::quoted_u8_var_list")
+    )]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(
+            with = "crate::json_schema::QuotedItems<VariableList<u8, P::ValidatorRegistryLimit>>"
+        )
+    )]
+    pub current_epoch_participation: VariableList<u8, P::ValidatorRegistryLimit>,
+    pub justification_bits: BitVector<typenum::U4>,
+    pub previous_justified_checkpoint: Checkpoint,
+    pub current_justified_checkpoint: Checkpoint,
+    pub finalized_checkpoint: Checkpoint,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_utils::quoted_u64_var_list")
+    )]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(
+            with = "crate::json_schema::QuotedItems<VariableList<u64, P::ValidatorRegistryLimit>>"
+        )
+    )]
+    pub inactivity_scores: VariableList<u64, P::ValidatorRegistryLimit>,
+    pub current_sync_committee: SyncCommittee<P>,
+    pub next_sync_committee: SyncCommittee<P>,
+    pub latest_execution_payload_header: ExecutionPayloadHeaderCapella<P>,
+    #[cfg_attr(feature = "serde", serde(with = "serde_utils::quoted_u64"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "crate::json_schema::Quoted<u64>")
+    )]
+    pub next_withdrawal_index: u64,
+    pub next_withdrawal_validator_index: ValidatorIndex,
+    pub historical_summaries: VariableList<HistoricalSummary, P::HistoricalRootsLimit>,
+}
+
+#[derive(Clone, Default, Encode, Decode, TreeHash, PartialEq, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(bound = "P: Preset"))]
+#[cfg_attr(
+    feature = "schemars",
+    schemars(bound = "P: Preset + schemars::JsonSchema")
+)]
+#[ssz(struct_behaviour = "container")]
+pub struct BeaconStateDeneb<P: Preset = Mainnet> {
+    #[cfg_attr(feature = "serde", serde(with = "serde_utils::quoted_u64"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "crate::json_schema::Quoted<u64>")
+    )]
+    pub genesis_time: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::hex_fixed_vec"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::json_schema::Hex<H256>"))]
+    pub genesis_validators_root: H256,
+    pub slot: Slot,
+    pub fork: Fork,
+    pub latest_block_header: BeaconBlockHeader,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_utils::fixed_vec_of_hex_fixed_vec")
+    )]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(
+            with = "crate::json_schema::HexItems<FixedVector<H256, P::SlotsPerHistoricalRoot>>"
+        )
+    )]
+    pub block_roots: FixedVector<H256, P::SlotsPerHistoricalRoot>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_utils::fixed_vec_of_hex_fixed_vec")
+    )]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(
+            with = "crate::json_schema::HexItems<FixedVector<H256, P::SlotsPerHistoricalRoot>>"
+        )
+    )]
+    pub state_roots: FixedVector<H256, P::SlotsPerHistoricalRoot>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_utils::list_of_hex_fixed_vec")
+    )]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(
+            with = "crate::json_schema::HexItems<VariableList<H256, P::HistoricalRootsLimit>>"
+        )
+    )]
+    pub historical_roots: VariableList<H256, P::HistoricalRootsLimit>,
+    pub eth1_data: Eth1Data,
+    pub eth1_data_votes: VariableList<Eth1Data, P::Eth1DataVotesLimit>,
+    #[cfg_attr(feature = "serde", serde(with = "serde_utils::quoted_u64"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "crate::json_schema::Quoted<u64>")
+    )]
+    pub eth1_deposit_index: u64,
+    pub validators: VariableList<Validator, P::ValidatorRegistryLimit>,
+    pub balances: VariableList<Gwei, P::ValidatorRegistryLimit>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_utils::fixed_vec_of_hex_fixed_vec")
+    )]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(
+            with = "crate::json_schema::HexItems<FixedVector<H256, P::EpochsPerHistoricalVector>>"
+        )
+    )]
+    pub randao_mixes: FixedVector<H256, P::EpochsPerHistoricalVector>,
+    pub slashings: FixedVector<Gwei, P::EpochsPerSlashingsVector>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_utils::quoted_u8_var_list")
+    )]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(
+            with = "crate::json_schema::QuotedItems<VariableList<u8, P::ValidatorRegistryLimit>>"
+        )
+    )]
+    pub previous_epoch_participation: VariableList<u8, P::ValidatorRegistryLimit>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_utils::quoted_u8_var_list")
+    )]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(
+            with = "crate::json_schema::QuotedItems<VariableList<u8, P::ValidatorRegistryLimit>>"
+        )
+    )]
+    pub current_epoch_participation: VariableList<u8, P::ValidatorRegistryLimit>,
+    pub justification_bits: BitVector<typenum::U4>,
+    pub previous_justified_checkpoint: Checkpoint,
+    pub current_justified_checkpoint: Checkpoint,
+    pub finalized_checkpoint: Checkpoint,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_utils::quoted_u64_var_list")
+    )]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(
+            with = "crate::json_schema::QuotedItems<VariableList<u64, P::ValidatorRegistryLimit>>"
+        )
+    )]
+    pub inactivity_scores: VariableList<u64, P::ValidatorRegistryLimit>,
+    pub current_sync_committee: SyncCommittee<P>,
+    pub next_sync_committee: SyncCommittee<P>,
+    pub latest_execution_payload_header: ExecutionPayloadHeaderDeneb<P>,
+    #[cfg_attr(feature = "serde", serde(with = "serde_utils::quoted_u64"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "crate::json_schema::Quoted<u64>")
+    )]
+    pub next_withdrawal_index: u64,
+    pub next_withdrawal_validator_index: ValidatorIndex,
+    pub historical_summaries: VariableList<HistoricalSummary, P::HistoricalRootsLimit>,
+}
+
+#[derive(Clone, Default, Encode, Decode, TreeHash, PartialEq, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(bound = "P: Preset"))]
+#[cfg_attr(
+    feature = "schemars",
+    schemars(bound = "P: Preset + schemars::JsonSchema")
+)]
+#[ssz(struct_behaviour = "container")]
+pub struct BeaconStateElectra<P: Preset = Mainnet> {
+    #[cfg_attr(feature = "serde", serde(with = "serde_utils::quoted_u64"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "crate::json_schema::Quoted<u64>")
+    )]
+    pub genesis_time: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::hex_fixed_vec"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::json_schema::Hex<H256>"))]
+    pub genesis_validators_root: H256,
+    pub slot: Slot,
+    pub fork: Fork,
+    pub latest_block_header: BeaconBlockHeader,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_utils::fixed_vec_of_hex_fixed_vec")
+    )]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(
+            with = "crate::json_schema::HexItems<FixedVector<H256, P::SlotsPerHistoricalRoot>>"
+        )
+    )]
+    pub block_roots: FixedVector<H256, P::SlotsPerHistoricalRoot>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_utils::fixed_vec_of_hex_fixed_vec")
+    )]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(
+            with = "crate::json_schema::HexItems<FixedVector<H256, P::SlotsPerHistoricalRoot>>"
+        )
+    )]
+    pub state_roots: FixedVector<H256, P::SlotsPerHistoricalRoot>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_utils::list_of_hex_fixed_vec")
+    )]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(
+            with = "crate::json_schema::HexItems<VariableList<H256, P::HistoricalRootsLimit>>"
+        )
+    )]
+    pub historical_roots: VariableList<H256, P::HistoricalRootsLimit>,
+    pub eth1_data: Eth1Data,
+    pub eth1_data_votes: VariableList<Eth1Data, P::Eth1DataVotesLimit>,
+    #[cfg_attr(feature = "serde", serde(with = "serde_utils::quoted_u64"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "crate::json_schema::Quoted<u64>")
+    )]
+    pub eth1_deposit_index: u64,
+    pub validators: VariableList<Validator, P::ValidatorRegistryLimit>,
+    pub balances: VariableList<Gwei, P::ValidatorRegistryLimit>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_utils::fixed_vec_of_hex_fixed_vec")
+    )]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(
+            with = "crate::json_schema::HexItems<FixedVector<H256, P::EpochsPerHistoricalVector>>"
+        )
+    )]
+    pub randao_mixes: FixedVector<H256, P::EpochsPerHistoricalVector>,
+    pub slashings: FixedVector<Gwei, P::EpochsPerSlashingsVector>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_utils::quoted_u8_var_list")
+    )]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(
+            with = "crate::json_schema::QuotedItems<VariableList<u8, P::ValidatorRegistryLimit>>"
+        )
+    )]
+    pub previous_epoch_participation: VariableList<u8, P::ValidatorRegistryLimit>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_utils::quoted_u8_var_list")
+    )]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(
+            with = "crate::json_schema::QuotedItems<VariableList<u8, P::ValidatorRegistryLimit>>"
+        )
+    )]
+    pub current_epoch_participation: VariableList<u8, P::ValidatorRegistryLimit>,
+    pub justification_bits: BitVector<typenum::U4>,
+    pub previous_justified_checkpoint: Checkpoint,
+    pub current_justified_checkpoint: Checkpoint,
+    pub finalized_checkpoint: Checkpoint,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_utils::quoted_u64_var_list")
+    )]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(
+            with = "crate::json_schema::QuotedItems<VariableList<u64, P::ValidatorRegistryLimit>>"
+        )
+    )]
+    pub inactivity_scores: VariableList<u64, P::ValidatorRegistryLimit>,
+    pub current_sync_committee: SyncCommittee<P>,
+    pub next_sync_committee: SyncCommittee<P>,
+    pub latest_execution_payload_header: ExecutionPayloadHeaderDeneb<P>,
+    #[cfg_attr(feature = "serde", serde(with = "serde_utils::quoted_u64"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "crate::json_schema::Quoted<u64>")
+    )]
+    pub next_withdrawal_index: u64,
+    pub next_withdrawal_validator_index: ValidatorIndex,
+    pub historical_summaries: VariableList<HistoricalSummary, P::HistoricalRootsLimit>,
+    #[cfg_attr(feature = "serde", serde(with = "serde_utils::quoted_u64"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "crate::json_schema::Quoted<u64>")
+    )]
+    pub deposit_requests_start_index: u64,
+    pub deposit_balance_to_consume: Gwei,
+    pub exit_balance_to_consume: Gwei,
+    pub earliest_exit_epoch: Epoch,
+    pub consolidation_balance_to_consume: Gwei,
+    pub earliest_consolidation_epoch: Epoch,
+    pub pending_deposits: VariableList<PendingDeposit, P::PendingDepositsLimit>,
+    pub pending_partial_withdrawals:
+        VariableList<PendingPartialWithdrawal, P::PendingPartialWithdrawalsLimit>,
+    pub pending_consolidations: VariableList<PendingConsolidation, P::PendingConsolidationsLimit>,
+}
+
+#[derive(Clone, Default, Encode, Decode, TreeHash, PartialEq, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[ssz(struct_behaviour = "container")]
+pub struct Fork {
+    #[cfg_attr(feature = "serde", serde(with = "serde_utils::bytes_4_hex"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "crate::json_schema::Hex<Version>")
+    )]
+    pub previous_version: Version,
+    #[cfg_attr(feature = "serde", serde(with = "serde_utils::bytes_4_hex"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "crate::json_schema::Hex<Version>")
+    )]
+    pub current_version: Version,
+    pub epoch: Epoch,
+}
+
+#[derive(Clone, Default, Encode, Decode, TreeHash, PartialEq, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[ssz(struct_behaviour = "container")]
+pub struct Validator {
+    pub pubkey: PublicKeyBytes,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::hex_fixed_vec"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::json_schema::Hex<H256>"))]
+    pub withdrawal_credentials: H256,
+    pub effective_balance: Gwei,
+    pub slashed: bool,
+    pub activation_eligibility_epoch: Epoch,
+    pub activation_epoch: Epoch,
+    pub exit_epoch: Epoch,
+    pub withdrawable_epoch: Epoch,
+}
+
+impl Fork {
+    /// Returns the version that is active at `epoch`, as used to compute the domain of messages
+    /// of that epoch.
+    pub fn version_at(&self, epoch: Epoch) -> Version {
+        if epoch < self.epoch {
+            self.previous_version
+        } else {
+            self.current_version
+        }
+    }
+}
+
+impl Validator {
+    /// True if the validator is active at `epoch`, as per `is_active_validator` in the consensus
+    /// specs.
+    pub fn is_active_at(&self, epoch: Epoch) -> bool {
+        self.activation_epoch <= epoch && epoch < self.exit_epoch
+    }
+
+    /// True if the validator can be slashed at `epoch`, as per `is_slashable_validator` in the
+    /// consensus specs.
+    pub fn is_slashable_at(&self, epoch: Epoch) -> bool {
+        !self.slashed && self.activation_epoch <= epoch && epoch < self.withdrawable_epoch
+    }
+}
+
+/// An attestation included in a block, as recorded in the states of Phase0 until the end of the
+/// next epoch.
+#[derive(Clone, Default, Encode, Decode, TreeHash, PartialEq, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(bound = "P: Preset"))]
+#[cfg_attr(
+    feature = "schemars",
+    schemars(bound = "P: Preset + schemars::JsonSchema")
+)]
+#[ssz(struct_behaviour = "container")]
+pub struct PendingAttestation<P: Preset = Mainnet> {
+    pub aggregation_bits: CustomBitList<P::MaxValidatorsPerCommittee>,
+    pub data: AttestationData,
+    #[cfg_attr(feature = "serde", serde(with = "serde_utils::quoted_u64"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "crate::json_schema::Quoted<u64>")
+    )]
+    pub inclusion_delay: u64,
+    pub proposer_index: ValidatorIndex,
+}
+
+impl<P: Preset> PendingAttestation<P> {
+    /// Returns the record of `attestation` included at `slot` by `proposer_index`, as per
+    /// `process_attestation` in the Phase0 specs.
+    pub fn new(attestation: Attestation<P>, slot: Slot, proposer_index: ValidatorIndex) -> Self {
+        Self {
+            inclusion_delay: slot.as_u64().saturating_sub(attestation.data.slot.as_u64()),
+            aggregation_bits: attestation.aggregation_bits,
+            data: attestation.data,
+            proposer_index,
+        }
+    }
+
+    /// Returns the members of `committee` whose bits are set, as per `get_attesting_indices` in
+    /// the Phase0 specs.
+    ///
+    /// Returns `Err` if the number of bits differs from the size of the committee.
+    pub fn attesting_indices(
+        &self,
+        committee: &[ValidatorIndex],
+    ) -> Result<Vec<ValidatorIndex>, Error> {
+        let bits = self.aggregation_bits.bits();
+        if bits.len() != committee.len() {
+            return Err(Error::OutOfBounds {
+                i: bits.len(),
+                len: committee.len(),
+            });
+        }
+
+        Ok(committee
+            .iter()
+            .zip(bits.iter())
+            .filter(|(_, bit)| *bit)
+            .map(|(index, _)| *index)
+            .collect())
+    }
+}
+
+#[derive(Clone, Default, Encode, Decode, TreeHash, PartialEq, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(bound = "P: Preset"))]
+#[cfg_attr(
+    feature = "schemars",
+    schemars(bound = "P: Preset + schemars::JsonSchema")
+)]
+#[ssz(struct_behaviour = "container")]
+pub struct SyncCommittee<P: Preset = Mainnet> {
+    pub pubkeys: FixedVector<PublicKeyBytes, P::SyncCommitteeSize>,
+    pub aggregate_pubkey: PublicKeyBytes,
+}
+
+#[derive(Clone, Default, Encode, Decode, TreeHash, PartialEq, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[ssz(struct_behaviour = "container")]
+pub struct HistoricalSummary {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::hex_fixed_vec"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::json_schema::Hex<H256>"))]
+    pub block_summary_root: H256,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::hex_fixed_vec"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::json_schema::Hex<H256>"))]
+    pub state_summary_root: H256,
+}
+
+/// The block and state roots of a period of `SLOTS_PER_HISTORICAL_ROOT` slots, whose root was
+/// appended to `historical_roots` before Capella.
+#[derive(Clone, Default, Encode, Decode, TreeHash, PartialEq, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(bound = "P: Preset"))]
+#[cfg_attr(
+    feature = "schemars",
+    schemars(bound = "P: Preset + schemars::JsonSchema")
+)]
+#[ssz(struct_behaviour = "container")]
+pub struct HistoricalBatch<P: Preset = Mainnet> {
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_utils::fixed_vec_of_hex_fixed_vec")
+    )]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(
+            with = "crate::json_schema::HexItems<FixedVector<H256, P::SlotsPerHistoricalRoot>>"
+        )
+    )]
+    pub block_roots: FixedVector<H256, P::SlotsPerHistoricalRoot>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_utils::fixed_vec_of_hex_fixed_vec")
+    )]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(
+            with = "crate::json_schema::HexItems<FixedVector<H256, P::SlotsPerHistoricalRoot>>"
+        )
+    )]
+    pub state_roots: FixedVector<H256, P::SlotsPerHistoricalRoot>,
+}
+
+impl HistoricalSummary {
+    /// Returns the summary of the `block_roots` and `state_roots` of a state, as appended to
+    /// `historical_summaries` at the end of each period from Capella.
+    pub fn new<N: Unsigned>(
+        block_roots: &FixedVector<H256, N>,
+        state_roots: &FixedVector<H256, N>,
+    ) -> Self {
+        Self {
+            block_summary_root: h256(tree_hash::TreeHash::tree_hash_root(block_roots)),
+            state_summary_root: h256(tree_hash::TreeHash::tree_hash_root(state_roots)),
+        }
+    }
+}
+
+impl<P: Preset> HistoricalBatch<P> {
+    /// Returns the summary of the batch. Since both are containers of two roots, the summary has
+    /// the same tree hash root as the batch, so that `historical_roots` and
+    /// `historical_summaries` can be checked against each other.
+    pub fn historical_summary(&self) -> HistoricalSummary {
+        HistoricalSummary::new(&self.block_roots, &self.state_roots)
+    }
+}
+
+#[derive(Clone, Default, Encode, Decode, TreeHash, PartialEq, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[ssz(struct_behaviour = "container")]
+pub struct PendingDeposit {
+    pub pubkey: PublicKeyBytes,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::hex_fixed_vec"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::json_schema::Hex<H256>"))]
+    pub withdrawal_credentials: H256,
+    pub amount: Gwei,
+    pub signature: SignatureBytes,
+    pub slot: Slot,
+}
+
+#[derive(Clone, Default, Encode, Decode, TreeHash, PartialEq, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[ssz(struct_behaviour = "container")]
+pub struct PendingPartialWithdrawal {
+    pub validator_index: ValidatorIndex,
+    pub amount: Gwei,
+    pub withdrawable_epoch: Epoch,
+}
+
+#[derive(Clone, Default, Encode, Decode, TreeHash, PartialEq, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[ssz(struct_behaviour = "container")]
+pub struct PendingConsolidation {
+    pub source_index: ValidatorIndex,
+    pub target_index: ValidatorIndex,
+}
+
+impl_memory_size_for_container!(
+    BeaconStatePhase0<P: Preset>,
+    genesis_time,
+    genesis_validators_root,
+    slot,
+    fork,
+    latest_block_header,
+    block_roots,
+    state_roots,
+    historical_roots,
+    eth1_data,
+    eth1_data_votes,
+    eth1_deposit_index,
+    validators,
+    balances,
+    randao_mixes,
+    slashings,
+    previous_epoch_attestations,
+    current_epoch_attestations,
+    justification_bits,
+    previous_justified_checkpoint,
+    current_justified_checkpoint,
+    finalized_checkpoint,
+);
+impl_memory_size_for_container!(
+    BeaconStateAltair<P: Preset>,
+    genesis_time,
+    genesis_validators_root,
+    slot,
+    fork,
+    latest_block_header,
+    block_roots,
+    state_roots,
+    historical_roots,
+    eth1_data,
+    eth1_data_votes,
+    eth1_deposit_index,
+    validators,
+    balances,
+    randao_mixes,
+    slashings,
+    previous_epoch_participation,
+    current_epoch_participation,
+    justification_bits,
+    previous_justified_checkpoint,
+    current_justified_checkpoint,
+    finalized_checkpoint,
+    inactivity_scores,
+    current_sync_committee,
+    next_sync_committee,
+);
+impl_memory_size_for_container!(
+    BeaconStateBellatrix<P: Preset>,
+    genesis_time,
+    genesis_validators_root,
+    slot,
+    fork,
+    latest_block_header,
+    block_roots,
+    state_roots,
+    historical_roots,
+    eth1_data,
+    eth1_data_votes,
+    eth1_deposit_index,
+    validators,
+    balances,
+    randao_mixes,
+    slashings,
+    previous_epoch_participation,
+    current_epoch_participation,
+    justification_bits,
+    previous_justified_checkpoint,
+    current_justified_checkpoint,
+    finalized_checkpoint,
+    inactivity_scores,
+    current_sync_committee,
+    next_sync_committee,
+    latest_execution_payload_header,
+);
+impl_memory_size_for_container!(
+    BeaconStateCapella<P: Preset>,
+    genesis_time,
+    genesis_validators_root,
+    slot,
+    fork,
+    latest_block_header,
+    block_roots,
+    state_roots,
+    historical_roots,
+    eth1_data,
+    eth1_data_votes,
+    eth1_deposit_index,
+    validators,
+    balances,
+    randao_mixes,
+    slashings,
+    previous_epoch_participation,
+    current_epoch_participation,
+    justification_bits,
+    previous_justified_checkpoint,
+    current_justified_checkpoint,
+    finalized_checkpoint,
+    inactivity_scores,
+    current_sync_committee,
+    next_sync_committee,
+    latest_execution_payload_header,
+    next_withdrawal_index,
+    next_withdrawal_validator_index,
+    historical_summaries,
+);
+impl_memory_size_for_container!(
+    BeaconStateDeneb<P: Preset>,
+    genesis_time,
+    genesis_validators_root,
+    slot,
+    fork,
+    latest_block_header,
+    block_roots,
+    state_roots,
+    historical_roots,
+    eth1_data,
+    eth1_data_votes,
+    eth1_deposit_index,
+    validators,
+    balances,
+    randao_mixes,
+    slashings,
+    previous_epoch_participation,
+    current_epoch_participation,
+    justification_bits,
+    previous_justified_checkpoint,
+    current_justified_checkpoint,
+    finalized_checkpoint,
+    inactivity_scores,
+    current_sync_committee,
+    next_sync_committee,
+    latest_execution_payload_header,
+    next_withdrawal_index,
+    next_withdrawal_validator_index,
+    historical_summaries,
+);
+impl_memory_size_for_container!(
+    BeaconStateElectra<P: Preset>,
+    genesis_time,
+    genesis_validators_root,
+    slot,
+    fork,
+    latest_block_header,
+    block_roots,
+    state_roots,
+    historical_roots,
+    eth1_data,
+    eth1_data_votes,
+    eth1_deposit_index,
+    validators,
+    balances,
+    randao_mixes,
+    slashings,
+    previous_epoch_participation,
+    current_epoch_participation,
+    justification_bits,
+    previous_justified_checkpoint,
+    current_justified_checkpoint,
+    finalized_checkpoint,
+    inactivity_scores,
+    current_sync_committee,
+    next_sync_committee,
+    latest_execution_payload_header,
+    next_withdrawal_index,
+    next_withdrawal_validator_index,
+    historical_summaries,
+    deposit_requests_start_index,
+    deposit_balance_to_consume,
+    exit_balance_to_consume,
+    earliest_exit_epoch,
+    consolidation_balance_to_consume,
+    earliest_consolidation_epoch,
+    pending_deposits,
+    pending_partial_withdrawals,
+    pending_consolidations,
+);
+impl_memory_size_for_container!(Fork, previous_version, current_version, epoch);
+impl_memory_size_for_container!(
+    Validator,
+    pubkey,
+    withdrawal_credentials,
+    effective_balance,
+    slashed,
+    activation_eligibility_epoch,
+    activation_epoch,
+    exit_epoch,
+    withdrawable_epoch,
+);
+impl_memory_size_for_container!(
+    PendingAttestation<P: Preset>,
+    aggregation_bits,
+    data,
+    inclusion_delay,
+    proposer_index,
+);
+impl_memory_size_for_container!(SyncCommittee<P: Preset>, pubkeys, aggregate_pubkey);
+impl_memory_size_for_container!(HistoricalSummary, block_summary_root, state_summary_root);
+impl_memory_size_for_container!(HistoricalBatch<P: Preset>, block_roots, state_roots);
+impl_memory_size_for_container!(
+    PendingDeposit,
+    pubkey,
+    withdrawal_credentials,
+    amount,
+    signature,
+    slot,
+);
+impl_memory_size_for_container!(
+    PendingPartialWithdrawal,
+    validator_index,
+    amount,
+    withdrawable_epoch,
+);
+impl_memory_size_for_container!(PendingConsolidation, source_index, target_index);
+
+impl_merkle_proof_for_container!(
+    BeaconStatePhase0<P: Preset>,
+    genesis_time,
+    genesis_validators_root,
+    slot,
+    fork,
+    latest_block_header,
+    block_roots,
+    state_roots,
+    historical_roots,
+    eth1_data,
+    eth1_data_votes,
+    eth1_deposit_index,
+    validators,
+    balances,
+    randao_mixes,
+    slashings,
+    previous_epoch_attestations,
+    current_epoch_attestations,
+    justification_bits,
+    previous_justified_checkpoint,
+    current_justified_checkpoint,
+    finalized_checkpoint,
+);
+impl_merkle_proof_for_container!(
+    BeaconStateAltair<P: Preset>,
+    genesis_time,
+    genesis_validators_root,
+    slot,
+    fork,
+    latest_block_header,
+    block_roots,
+    state_roots,
+    historical_roots,
+    eth1_data,
+    eth1_data_votes,
+    eth1_deposit_index,
+    validators,
+    balances,
+    randao_mixes,
+    slashings,
+    previous_epoch_participation,
+    current_epoch_participation,
+    justification_bits,
+    previous_justified_checkpoint,
+    current_justified_checkpoint,
+    finalized_checkpoint,
+    inactivity_scores,
+    current_sync_committee,
+    next_sync_committee,
+);
+impl_merkle_proof_for_container!(
+    BeaconStateBellatrix<P: Preset>,
+    genesis_time,
+    genesis_validators_root,
+    slot,
+    fork,
+    latest_block_header,
+    block_roots,
+    state_roots,
+    historical_roots,
+    eth1_data,
+    eth1_data_votes,
+    eth1_deposit_index,
+    validators,
+    balances,
+    randao_mixes,
+    slashings,
+    previous_epoch_participation,
+    current_epoch_participation,
+    justification_bits,
+    previous_justified_checkpoint,
+    current_justified_checkpoint,
+    finalized_checkpoint,
+    inactivity_scores,
+    current_sync_committee,
+    next_sync_committee,
+    latest_execution_payload_header,
+);
+impl_merkle_proof_for_container!(
+    BeaconStateCapella<P: Preset>,
+    genesis_time,
+    genesis_validators_root,
+    slot,
+    fork,
+    latest_block_header,
+    block_roots,
+    state_roots,
+    historical_roots,
+    eth1_data,
+    eth1_data_votes,
+    eth1_deposit_index,
+    validators,
+    balances,
+    randao_mixes,
+    slashings,
+    previous_epoch_participation,
+    current_epoch_participation,
+    justification_bits,
+    previous_justified_checkpoint,
+    current_justified_checkpoint,
+    finalized_checkpoint,
+    inactivity_scores,
+    current_sync_committee,
+    next_sync_committee,
+    latest_execution_payload_header,
+    next_withdrawal_index,
+    next_withdrawal_validator_index,
+    historical_summaries,
+);
+impl_merkle_proof_for_container!(
+    BeaconStateDeneb<P: Preset>,
+    genesis_time,
+    genesis_validators_root,
+    slot,
+    fork,
+    latest_block_header,
+    block_roots,
+    state_roots,
+    historical_roots,
+    eth1_data,
+    eth1_data_votes,
+    eth1_deposit_index,
+    validators,
+    balances,
+    randao_mixes,
+    slashings,
+    previous_epoch_participation,
+    current_epoch_participation,
+    justification_bits,
+    previous_justified_checkpoint,
+    current_justified_checkpoint,
+    finalized_checkpoint,
+    inactivity_scores,
+    current_sync_committee,
+    next_sync_committee,
+    latest_execution_payload_header,
+    next_withdrawal_index,
+    next_withdrawal_validator_index,
+    historical_summaries,
+);
+impl_merkle_proof_for_container!(
+    BeaconStateElectra<P: Preset>,
+    genesis_time,
+    genesis_validators_root,
+    slot,
+    fork,
+    latest_block_header,
+    block_roots,
+    state_roots,
+    historical_roots,
+    eth1_data,
+    eth1_data_votes,
+    eth1_deposit_index,
+    validators,
+    balances,
+    randao_mixes,
+    slashings,
+    previous_epoch_participation,
+    current_epoch_participation,
+    justification_bits,
+    previous_justified_checkpoint,
+    current_justified_checkpoint,
+    finalized_checkpoint,
+    inactivity_scores,
+    current_sync_committee,
+    next_sync_committee,
+    latest_execution_payload_header,
+    next_withdrawal_index,
+    next_withdrawal_validator_index,
+    historical_summaries,
+    deposit_requests_start_index,
+    deposit_balance_to_consume,
+    exit_balance_to_consume,
+    earliest_exit_epoch,
+    consolidation_balance_to_consume,
+    earliest_consolidation_epoch,
+    pending_deposits,
+    pending_partial_withdrawals,
+    pending_consolidations,
+);
+impl_merkle_proof_for_container!(Fork, previous_version, current_version, epoch);
+impl_merkle_proof_for_container!(
+    Validator,
+    pubkey,
+    withdrawal_credentials,
+    effective_balance,
+    slashed,
+    activation_eligibility_epoch,
+    activation_epoch,
+    exit_epoch,
+    withdrawable_epoch,
+);
+impl_merkle_proof_for_container!(
+    PendingAttestation<P: Preset>,
+    aggregation_bits,
+    data,
+    inclusion_delay,
+    proposer_index,
+);
+impl_merkle_proof_for_container!(SyncCommittee<P: Preset>, pubkeys, aggregate_pubkey);
+impl_merkle_proof_for_container!(HistoricalSummary, block_summary_root, state_summary_root);
+impl_merkle_proof_for_container!(HistoricalBatch<P: Preset>, block_roots, state_roots);
+impl_merkle_proof_for_container!(
+    PendingDeposit,
+    pubkey,
+    withdrawal_credentials,
+    amount,
+    signature,
+    slot,
+);
+impl_merkle_proof_for_container!(
+    PendingPartialWithdrawal,
+    validator_index,
+    amount,
+    withdrawable_epoch,
+);
+impl_merkle_proof_for_container!(PendingConsolidation, source_index, target_index);
+
+/// A state of any fork.
+///
+/// Like `SignedBeaconBlock`, the state is encoded and merkleized as the state of its fork, and
+/// decoding requires knowing the fork.
+///
+/// ## Example
+///
+/// ```
+/// use ssz::Encode;
+/// use ssz_types::{BeaconState, BeaconStateDeneb, ForkName, ForkSchedule, Mainnet, Slot};
+/// use tree_hash::TreeHash;
+///
+/// let mut deneb = BeaconStateDeneb::<Mainnet>::default();
+/// deneb.slot = Slot::new(300_000 * 32);
+/// let bytes = deneb.as_ssz_bytes();
+///
+/// let schedule = ForkSchedule::mainnet();
+/// let state = BeaconState::<Mainnet>::from_ssz_bytes_for_schedule(&bytes, &schedule).unwrap();
+/// assert_eq!(state.fork_name(), ForkName::Deneb);
+/// assert_eq!(state.slot(), deneb.slot);
+/// assert_eq!(state.tree_hash_root(), deneb.tree_hash_root());
+/// ```
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum BeaconState<P: Preset = Mainnet> {
+    Phase0(BeaconStatePhase0<P>),
+    Altair(BeaconStateAltair<P>),
+    Bellatrix(BeaconStateBellatrix<P>),
+    Capella(BeaconStateCapella<P>),
+    Deneb(BeaconStateDeneb<P>),
+    Electra(BeaconStateElectra<P>),
+}
+
+impl<P: Preset> BeaconState<P> {
+    /// Decodes the state of `fork` from `bytes`.
+    pub fn from_ssz_bytes_for_fork(bytes: &[u8], fork: ForkName) -> Result<Self, DecodeError> {
+        match fork {
+            ForkName::Phase0 => BeaconStatePhase0::from_ssz_bytes(bytes).map(Self::Phase0),
+            ForkName::Altair => BeaconStateAltair::from_ssz_bytes(bytes).map(Self::Altair),
+            ForkName::Bellatrix => BeaconStateBellatrix::from_ssz_bytes(bytes).map(Self::Bellatrix),
+            ForkName::Capella => BeaconStateCapella::from_ssz_bytes(bytes).map(Self::Capella),
+            ForkName::Deneb => BeaconStateDeneb::from_ssz_bytes(bytes).map(Self::Deneb),
+            ForkName::Electra => BeaconStateElectra::from_ssz_bytes(bytes).map(Self::Electra),
+        }
+    }
+
+    /// Decodes a state from `bytes`, as the state of the fork that is active at its slot in
+    /// `schedule`.
+    pub fn from_ssz_bytes_for_schedule(
+        bytes: &[u8],
+        schedule: &ForkSchedule,
+    ) -> Result<Self, DecodeError> {
+        // `slot` follows `genesis_time` and `genesis_validators_root` in the states of all forks.
+        let slot_start = <u64 as Decode>::ssz_fixed_len() + <H256 as Decode>::ssz_fixed_len();
+        let slot_bytes =
+            bytes
+                .get(slot_start..slot_start + 8)
+                .ok_or(DecodeError::InvalidByteLength {
+                    len: bytes.len(),
+                    expected: slot_start + 8,
+                })?;
+        let slot = Slot::from_ssz_bytes(slot_bytes)?;
+
+        Self::from_ssz_bytes_for_fork(bytes, schedule.fork_at_slot(slot))
+    }
+
+    /// Returns the fork of the state.
+    pub fn fork_name(&self) -> ForkName {
+        match self {
+            Self::Phase0(_) => ForkName::Phase0,
+            Self::Altair(_) => ForkName::Altair,
+            Self::Bellatrix(_) => ForkName::Bellatrix,
+            Self::Capella(_) => ForkName::Capella,
+            Self::Deneb(_) => ForkName::Deneb,
+            Self::Electra(_) => ForkName::Electra,
+        }
+    }
+
+    /// Returns the slot of the state.
+    pub fn slot(&self) -> Slot {
+        map_fork!(self, Self, state => state.slot)
+    }
+
+    /// Returns the root of the validators at genesis, which identifies the chain.
+    pub fn genesis_validators_root(&self) -> Hash256 {
+        map_fork!(self, Self, state => Hash256::from_slice(&state.genesis_validators_root))
+    }
+
+    /// Returns the versions of the fork of the state.
+    pub fn fork(&self) -> &Fork {
+        map_fork!(self, Self, state => &state.fork)
+    }
+
+    /// Returns the header of the latest block applied to the state.
+    pub fn latest_block_header(&self) -> &BeaconBlockHeader {
+        map_fork!(self, Self, state => &state.latest_block_header)
+    }
+
+    /// Returns the validator registry.
+    pub fn validators(&self) -> &[Validator] {
+        map_fork!(self, Self, state => &state.validators)
+    }
+
+    /// Returns the balances of the validators, in Gwei.
+    pub fn balances(&self) -> &[Gwei] {
+        map_fork!(self, Self, state => &state.balances)
+    }
+
+    /// Returns the latest finalized checkpoint.
+    pub fn finalized_checkpoint(&self) -> &Checkpoint {
+        map_fork!(self, Self, state => &state.finalized_checkpoint)
+    }
+}
+
+impl<P: Preset> From<BeaconStatePhase0<P>> for BeaconState<P> {
+    fn from(state: BeaconStatePhase0<P>) -> Self {
+        Self::Phase0(state)
+    }
+}
+
+impl<P: Preset> From<BeaconStateAltair<P>> for BeaconState<P> {
+    fn from(state: BeaconStateAltair<P>) -> Self {
+        Self::Altair(state)
+    }
+}
+
+impl<P: Preset> From<BeaconStateBellatrix<P>> for BeaconState<P> {
+    fn from(state: BeaconStateBellatrix<P>) -> Self {
+        Self::Bellatrix(state)
+    }
+}
+
+impl<P: Preset> From<BeaconStateCapella<P>> for BeaconState<P> {
+    fn from(state: BeaconStateCapella<P>) -> Self {
+        Self::Capella(state)
+    }
+}
+
+impl<P: Preset> From<BeaconStateDeneb<P>> for BeaconState<P> {
+    fn from(state: BeaconStateDeneb<P>) -> Self {
+        Self::Deneb(state)
+    }
+}
+
+impl<P: Preset> From<BeaconStateElectra<P>> for BeaconState<P> {
+    fn from(state: BeaconStateElectra<P>) -> Self {
+        Self::Electra(state)
+    }
+}
+
+impl<P: Preset> Encode for BeaconState<P> {
+    fn is_ssz_fixed_len() -> bool {
+        false
+    }
+
+    fn ssz_append(&self, buf: &mut Vec<u8>) {
+        map_fork!(self, Self, state => state.ssz_append(buf))
+    }
+
+    fn ssz_bytes_len(&self) -> usize {
+        map_fork!(self, Self, state => state.ssz_bytes_len())
+    }
+}
+
+impl<P: Preset> tree_hash::TreeHash for BeaconState<P> {
+    fn tree_hash_type() -> tree_hash::TreeHashType {
+        tree_hash::TreeHashType::Container
+    }
+
+    fn tree_hash_packed_encoding(&self) -> tree_hash::PackedEncoding {
+        unreachable!("Container should never be packed.")
+    }
+
+    fn tree_hash_packing_factor() -> usize {
+        unreachable!("Container should never be packed.")
+    }
+
+    fn tree_hash_root(&self) -> Hash256 {
+        map_fork!(self, Self, state => state.tree_hash_root())
+    }
+}
+
+impl<P: Preset> MerkleProof for BeaconState<P> {
+    fn tree_hash_node(&self, gindex: u64) -> Result<Hash256, Error> {
+        map_fork!(self, Self, state => state.tree_hash_node(gindex))
+    }
+}
+
+impl<P: Preset> MemorySize for BeaconState<P> {
+    fn heap_size(&self) -> usize {
+        map_fork!(self, Self, state => state.heap_size())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        gindex_of, MerklePath, Minimal, FINALIZED_ROOT_GINDEX, FINALIZED_ROOT_GINDEX_ELECTRA,
+    };
+    use tree_hash::TreeHash;
+
+    #[test]
+    fn light_client_gindices() {
+        // The generalized indices of the light client specifications, which move in Electra as
+        // the number of fields of the state exceeds 32.
+        assert_eq!(
+            gindex_of::<BeaconStateAltair>(&["finalized_checkpoint", "root"]),
+            Ok(FINALIZED_ROOT_GINDEX)
+        );
+        assert_eq!(
+            gindex_of::<BeaconStateDeneb>(&["current_sync_committee"]),
+            Ok(54)
+        );
+        assert_eq!(
+            gindex_of::<BeaconStateDeneb>(&["next_sync_committee"]),
+            Ok(55)
+        );
+        assert_eq!(
+            gindex_of::<BeaconStateElectra>(&["finalized_checkpoint", "root"]),
+            Ok(FINALIZED_ROOT_GINDEX_ELECTRA)
+        );
+        assert_eq!(
+            gindex_of::<BeaconStateElectra>(&["current_sync_committee"]),
+            Ok(86)
+        );
+        assert_eq!(
+            gindex_of::<BeaconStateElectra>(&["next_sync_committee"]),
+            Ok(87)
+        );
+        assert!(BeaconStatePhase0::<Mainnet>::gindex_of(&["current_sync_committee"]).is_err());
+    }
+
+    #[test]
+    fn validator_epochs() {
+        let mut validator = Validator {
+            activation_epoch: Epoch::new(10),
+            exit_epoch: Epoch::new(20),
+            withdrawable_epoch: Epoch::new(276),
+            ..Validator::default()
+        };
+
+        assert!(!validator.is_active_at(Epoch::new(9)));
+        assert!(validator.is_active_at(Epoch::new(10)));
+        assert!(!validator.is_active_at(Epoch::new(20)));
+        assert!(validator.is_slashable_at(Epoch::new(20)));
+        assert!(!validator.is_slashable_at(Epoch::new(276)));
+        validator.slashed = true;
+        assert!(!validator.is_slashable_at(Epoch::new(15)));
+
+        let fork = Fork {
+            previous_version: [1, 0, 0, 0],
+            current_version: [2, 0, 0, 0],
+            epoch: Epoch::new(5),
+        };
+        assert_eq!(fork.version_at(Epoch::new(4)), [1, 0, 0, 0]);
+        assert_eq!(fork.version_at(Epoch::new(5)), [2, 0, 0, 0]);
+    }
+
+    #[test]
+    fn pending_attestations() {
+        let mut bits = crate::BitList::<typenum::U2048>::with_capacity(4).unwrap();
+        bits.set(1, true).unwrap();
+        bits.set(3, true).unwrap();
+        let attestation = Attestation::<Mainnet> {
+            aggregation_bits: CustomBitList::from_ssz_bytes(&bits.as_ssz_bytes()).unwrap(),
+            data: AttestationData {
+                slot: Slot::new(100),
+                ..AttestationData::default()
+            },
+            ..Attestation::default()
+        };
+
+        let pending = PendingAttestation::new(attestation, Slot::new(103), ValidatorIndex::new(7));
+        assert_eq!(pending.inclusion_delay, 3);
+        assert_eq!(pending.proposer_index, ValidatorIndex::new(7));
+        assert_eq!(pending.data.slot, Slot::new(100));
+        let committee = [10, 11, 12, 13].map(ValidatorIndex::new);
+        assert_eq!(
+            pending.attesting_indices(&committee),
+            Ok(vec![ValidatorIndex::new(11), ValidatorIndex::new(13)])
+        );
+        assert_eq!(
+            pending.attesting_indices(&committee[..3]),
+            Err(Error::OutOfBounds { i: 4, len: 3 })
+        );
+
+        let mut state = BeaconStatePhase0::default();
+        state
+            .previous_epoch_attestations
+            .push(pending.clone())
+            .unwrap();
+        let decoded = BeaconStatePhase0::from_ssz_bytes(&state.as_ssz_bytes()).unwrap();
+        assert_eq!(decoded.previous_epoch_attestations[0], pending);
+        assert_eq!(decoded.tree_hash_root(), state.tree_hash_root());
+    }
+
+    #[test]
+    fn minimal_preset() {
+        let mut state = BeaconStateAltair::<Minimal> {
+            slot: Slot::new(64),
+            ..BeaconStateAltair::default()
+        };
+        state.block_roots[63] = h256(Hash256::repeat_byte(1));
+        state.validators.push(Validator::default()).unwrap();
+        state.inactivity_scores.push(7).unwrap();
+
+        let bytes = state.as_ssz_bytes();
+        let mainnet = BeaconStateAltair::<Mainnet>::default();
+        assert!(bytes.len() < mainnet.as_ssz_bytes().len());
+        assert_eq!(BeaconStateAltair::from_ssz_bytes(&bytes), Ok(state.clone()));
+        assert!(BeaconStateAltair::<Mainnet>::from_ssz_bytes(&bytes).is_err());
+
+        let decoded =
+            BeaconState::<Minimal>::from_ssz_bytes_for_fork(&bytes, ForkName::Altair).unwrap();
+        assert_eq!(decoded.slot(), Slot::new(64));
+        assert_eq!(decoded.tree_hash_root(), state.tree_hash_root());
+
+        // The fields of the state are in the same positions in all presets.
+        assert_eq!(
+            gindex_of::<BeaconStateAltair<Minimal>>(&["finalized_checkpoint", "root"]),
+            Ok(FINALIZED_ROOT_GINDEX)
+        );
+
+        let batch = HistoricalBatch::<Minimal> {
+            block_roots: state.block_roots.clone(),
+            state_roots: state.state_roots.clone(),
+        };
+        assert_eq!(
+            batch.historical_summary().tree_hash_root(),
+            batch.tree_hash_root()
+        );
+    }
+
+    #[test]
+    fn historical_batch() {
+        let mut batch = HistoricalBatch::<Mainnet>::default();
+        batch.block_roots[0] = h256(Hash256::repeat_byte(1));
+        batch.state_roots[8191] = h256(Hash256::repeat_byte(2));
+
+        let summary = batch.historical_summary();
+        assert_eq!(
+            summary.block_summary_root,
+            h256(batch.block_roots.tree_hash_root())
+        );
+        assert_eq!(
+            summary.state_summary_root,
+            h256(batch.state_roots.tree_hash_root())
+        );
+        assert_ne!(summary.block_summary_root, summary.state_summary_root);
+        assert_eq!(summary.tree_hash_root(), batch.tree_hash_root());
+
+        let bytes = batch.as_ssz_bytes();
+        assert_eq!(bytes.len(), 2 * 8192 * 32);
+        assert_eq!(HistoricalBatch::from_ssz_bytes(&bytes), Ok(batch));
+    }
+
+    #[test]
+    fn fork_states() {
+        let schedule = ForkSchedule {
+            slots_per_epoch: 32,
+            altair_fork_epoch: Some(Epoch::new(1)),
+            bellatrix_fork_epoch: Some(Epoch::new(2)),
+            capella_fork_epoch: Some(Epoch::new(3)),
+            deneb_fork_epoch: Some(Epoch::new(4)),
+            electra_fork_epoch: Some(Epoch::new(5)),
+        };
+        let validator = Validator {
+            effective_balance: Gwei::new(32_000_000_000),
+            exit_epoch: Epoch::new(u64::MAX),
+            ..Validator::default()
+        };
+
+        let mut phase0 = BeaconStatePhase0::<Mainnet>::default();
+        phase0.validators.push(validator.clone()).unwrap();
+        phase0.balances.push(Gwei::new(32_000_000_007)).unwrap();
+        let mut electra = BeaconStateElectra {
+            slot: Slot::new(5 * 32),
+            ..BeaconStateElectra::default()
+        };
+        electra.fork.current_version = [5, 0, 0, 0];
+        electra
+            .pending_consolidations
+            .push(PendingConsolidation {
+                source_index: ValidatorIndex::new(1),
+                target_index: ValidatorIndex::new(2),
+            })
+            .unwrap();
+
+        for state in [BeaconState::from(phase0), BeaconState::from(electra)] {
+            let bytes = state.as_ssz_bytes();
+            assert_eq!(bytes.len(), state.ssz_bytes_len());
+
+            let decoded = BeaconState::from_ssz_bytes_for_schedule(&bytes, &schedule).unwrap();
+            assert_eq!(decoded.fork_name(), state.fork_name());
+            assert_eq!(decoded.tree_hash_root(), state.tree_hash_root());
+            assert_eq!(decoded, state);
+
+            // The states of other forks have different fields.
+            for fork in ForkName::ALL {
+                if fork != state.fork_name() {
+                    assert!(BeaconState::<Mainnet>::from_ssz_bytes_for_fork(&bytes, fork).is_err());
+                }
+            }
+        }
+
+        let state = BeaconState::Phase0(BeaconStatePhase0::<Mainnet> {
+            validators: VariableList::new(vec![validator]).unwrap(),
+            ..BeaconStatePhase0::default()
+        });
+        assert_eq!(state.validators()[0].exit_epoch, Epoch::new(u64::MAX));
+        assert!(state.balances().is_empty());
+        assert_eq!(state.genesis_validators_root(), Hash256::zero());
+        assert_eq!(
+            state.tree_hash_node(gindex_of::<BeaconStatePhase0>(&["fork"]).unwrap()),
+            Ok(state.fork().tree_hash_root())
+        );
+    }
+}
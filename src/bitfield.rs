@@ -1,5 +1,8 @@
-use crate::tree_hash::bitfield_bytes_tree_hash_root;
-use crate::Error;
+use crate::proof::{
+    bitfield_bytes_tree_hash_node, bitfield_gindex_of, list_gindex_of, list_tree_hash_node,
+};
+use crate::tree_hash::{bitfield_bytes_tree_hash_root, mix_in_length};
+use crate::{Error, MemorySize, MerklePath, MerkleProof, SszReflect, SszSchema};
 use core::marker::PhantomData;
 use derivative::Derivative;
 use serde::de::{Deserialize, Deserializer};
@@ -7,6 +10,7 @@ use serde::ser::{Serialize, Serializer};
 use serde_utils::hex::{encode as hex_encode, PrefixedHexVisitor};
 use smallvec::{smallvec, SmallVec, ToSmallVec};
 use ssz::{Decode, Encode};
+use std::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Range};
 use tree_hash::Hash256;
 use typenum::Unsigned;
 
@@ -35,8 +39,16 @@ pub struct Fixed<N> {
     _phantom: PhantomData<N>,
 }
 
+/// A marker struct used to declare a `Bitfield` with a fixed length that is set at runtime,
+/// rather than at the type-level.
+///
+/// See the [`DynamicBitVector`](type.DynamicBitVector.html) docs for usage.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Dynamic;
+
 impl<N: Unsigned + Clone> BitfieldBehaviour for Variable<N> {}
 impl<N: Unsigned + Clone> BitfieldBehaviour for Fixed<N> {}
+impl BitfieldBehaviour for Dynamic {}
 
 /// A heap-allocated, ordered, variable-length collection of `bool` values, limited to `N` bits.
 pub type BitList<N> = Bitfield<Variable<N>>;
@@ -46,6 +58,27 @@ pub type BitList<N> = Bitfield<Variable<N>>;
 /// See [Bitfield](struct.Bitfield.html) documentation.
 pub type BitVector<N> = Bitfield<Fixed<N>>;
 
+/// A heap-allocated, ordered, fixed-length collection of `bool` values, where the length is
+/// chosen at instantiation rather than at the type-level.
+///
+/// Useful when the length of a `BitVector` depends on runtime configuration (e.g., the preset in
+/// use). Since its SSZ length is not known at compile-time it does not implement `Encode` or
+/// `Decode`; instead use `into_bytes` and `from_bytes` (which take the length), or convert
+/// to/from a `BitVector`.
+///
+/// ```
+/// use ssz_types::{BitVector, DynamicBitVector, typenum};
+///
+/// let mut dynamic = DynamicBitVector::with_len(8);
+/// dynamic.set(3, true).unwrap();
+///
+/// let typed = BitVector::<typenum::U8>::try_from(dynamic.clone()).unwrap();
+/// assert_eq!(DynamicBitVector::from(typed), dynamic);
+///
+/// assert!(BitVector::<typenum::U16>::try_from(dynamic).is_err());
+/// ```
+pub type DynamicBitVector = Bitfield<Dynamic>;
+
 /// A heap-allocated, ordered, fixed-length, collection of `bool` values. Use of
 /// [`BitList`](type.BitList.html) or [`BitVector`](type.BitVector.html) type aliases is preferred
 /// over direct use of this struct.
@@ -170,6 +203,9 @@ impl<N: Unsigned + Clone> Bitfield<Variable<N>> {
     /// Instantiates a new instance from `bytes`. Consumes the same format that `self.into_bytes()`
     /// produces (SSZ).
     ///
+    /// Decoding is strict: only the canonical encoding is accepted, so `bytes` must end with the
+    /// byte containing the length bit and there can be no bits set above it.
+    ///
     /// Returns `None` if `bytes` are not a valid encoding.
     pub fn from_bytes(bytes: SmallVec<[u8; SMALLVEC_LEN]>) -> Result<Self, Error> {
         let bytes_len = bytes.len();
@@ -214,12 +250,12 @@ impl<N: Unsigned + Clone> Bitfield<Variable<N>> {
     pub fn intersection(&self, other: &Self) -> Self {
         let min_len = std::cmp::min(self.len(), other.len());
         let mut result = Self::with_capacity(min_len).expect("min len always less than N");
-        // Bitwise-and the bytes together, starting from the left of each vector. This takes care
+        // Bitwise-and the words together, starting from the left of each vector. This takes care
         // of masking out any entries beyond `min_len` as well, assuming the bitfield doesn't
         // contain any set bits beyond its length.
-        for i in 0..result.bytes.len() {
-            result.bytes[i] = self.bytes[i] & other.bytes[i];
-        }
+        let byte_len = result.bytes.len();
+        result.bytes.copy_from_slice(&self.bytes[..byte_len]);
+        zip_words_mut(&mut result.bytes, &other.bytes, |a, b| a & b);
         result
     }
 
@@ -227,18 +263,158 @@ impl<N: Unsigned + Clone> Bitfield<Variable<N>> {
     ///
     /// Return a new BitList with length equal to the longer of the two inputs.
     pub fn union(&self, other: &Self) -> Self {
-        let max_len = std::cmp::max(self.len(), other.len());
-        let mut result = Self::with_capacity(max_len).expect("max len always less than N");
-        for i in 0..result.bytes.len() {
-            result.bytes[i] =
-                self.bytes.get(i).copied().unwrap_or(0) | other.bytes.get(i).copied().unwrap_or(0);
-        }
+        let (mut result, shorter) = self.clone_longer(other);
+        zip_words_mut(&mut result.bytes, &shorter.bytes, |a, b| a | b);
         result
     }
 
-    /// Returns `true` if `self` is a subset of `other` and `false` otherwise.
-    pub fn is_subset(&self, other: &Self) -> bool {
-        self.difference(other).is_zero()
+    /// Compute the symmetric difference of two BitLists of potentially different lengths.
+    ///
+    /// Return a new BitList with length equal to the longer of the two inputs, where the bits
+    /// beyond the end of the shorter input are treated as unset.
+    pub fn symmetric_difference(&self, other: &Self) -> Self {
+        let (mut result, shorter) = self.clone_longer(other);
+        zip_words_mut(&mut result.bytes, &shorter.bytes, |a, b| a ^ b);
+        result
+    }
+
+    /// Returns a clone of the longer of `self` and `other`, along with a reference to the
+    /// shorter.
+    fn clone_longer<'a>(&'a self, other: &'a Self) -> (Self, &'a Self) {
+        if self.len() >= other.len() {
+            (self.clone(), other)
+        } else {
+            (other.clone(), self)
+        }
+    }
+
+    /// Instantiate a `BitList` of length `len` where each bit is independently set with
+    /// probability `density`.
+    ///
+    /// Returns `Err` if `len > N`.
+    ///
+    /// ## Panics
+    ///
+    /// If `density` is not within `0.0..=1.0`.
+    #[cfg(feature = "rand")]
+    pub fn random<R: rand::Rng + ?Sized>(
+        len: usize,
+        density: f64,
+        rng: &mut R,
+    ) -> Result<Self, Error> {
+        let mut bitfield = Self::with_capacity(len)?;
+        bitfield.fill_random(density, rng);
+        Ok(bitfield)
+    }
+
+    /// Instantiate a `BitList` of length `len` with the bits at each of `indices` set.
+    ///
+    /// Returns `Err` if `len > N` or any of `indices` is not less than `len`.
+    pub fn from_indices(len: usize, indices: &[usize]) -> Result<Self, Error> {
+        let mut bitfield = Self::with_capacity(len)?;
+        for &i in indices {
+            bitfield.set(i, true)?;
+        }
+        Ok(bitfield)
+    }
+
+    /// Returns a view of the SSZ-encoded `BitList` in `bytes`, without copying them.
+    ///
+    /// Applies the same validation as `Self::from_bytes`.
+    pub fn view_ssz_bytes(bytes: &[u8]) -> Result<BitSliceRef<'_>, Error> {
+        let last = *bytes.last().ok_or(Error::MissingLengthInformation)?;
+        if last == 0 {
+            return Err(Error::MissingLengthInformation);
+        }
+
+        // The length bit is the highest set bit of the last byte.
+        let len = (bytes.len() - 1) * 8 + 7 - last.leading_zeros() as usize;
+
+        if len <= Self::max_len() {
+            Ok(BitSliceRef { bytes, len })
+        } else {
+            Err(Error::OutOfBounds {
+                i: len,
+                len: Self::max_len(),
+            })
+        }
+    }
+
+    /// Consumes `self`, returning a `BitVector` with the same bits set.
+    ///
+    /// Returns `Err` if `self.len()` is not equal to `N`.
+    pub fn try_into_bitvector(self) -> Result<Bitfield<Fixed<N>>, Error> {
+        if self.len() == N::to_usize() {
+            Ok(Bitfield {
+                bytes: self.bytes,
+                len: self.len,
+                _phantom: PhantomData,
+            })
+        } else {
+            Err(Error::OutOfBounds {
+                i: self.len(),
+                len: N::to_usize(),
+            })
+        }
+    }
+
+    /// Changes the length of `self` to `new_len`, in the manner of `Vec::resize`.
+    ///
+    /// If `new_len` is greater than `self.len()` the new bits are set to `value`, otherwise the
+    /// bits at and above `new_len` are discarded.
+    ///
+    /// Returns `Err` if `new_len > N`, leaving `self` unchanged.
+    pub fn resize_in_place(&mut self, new_len: usize, value: bool) -> Result<(), Error> {
+        if new_len > Self::max_len() {
+            return Err(Error::OutOfBounds {
+                i: new_len,
+                len: Self::max_len(),
+            });
+        }
+
+        let old_len = self.len;
+        self.bytes.resize(bytes_for_bit_len(new_len), 0);
+        self.len = new_len;
+
+        if new_len < old_len {
+            // Clear the discarded bits that share the final byte, so that there are no set bits
+            // beyond the length. An empty list keeps a single byte, which is cleared entirely.
+            if !new_len.is_multiple_of(8) || new_len == 0 {
+                let last = self.bytes.last_mut().expect("bitfield always has a byte");
+                *last &= (1 << (new_len % 8)) - 1;
+            }
+        } else {
+            self.set_range(old_len..new_len, value)?;
+        }
+
+        Ok(())
+    }
+
+    /// Appends the bits of `other` to the end of `self`.
+    ///
+    /// Returns `Err` if the combined length would exceed `N`, leaving `self` unchanged.
+    pub fn extend_from_bitlist<M: Unsigned + Clone>(
+        &mut self,
+        other: &Bitfield<Variable<M>>,
+    ) -> Result<(), Error> {
+        let old_len = self.len();
+        self.resize_in_place(old_len + other.len(), false)?;
+
+        if old_len.is_multiple_of(8) {
+            // The bits of `other` are byte-aligned with the end of `self`, so they can be copied
+            // over directly.
+            let start = old_len / 8;
+            let count = self.bytes.len() - start;
+            self.bytes[start..].copy_from_slice(&other.bytes[..count]);
+        } else {
+            for (i, bit) in other.iter().enumerate() {
+                if bit {
+                    self.set(old_len + i, true)?;
+                }
+            }
+        }
+
+        Ok(())
     }
 
     /// Returns a new BitList of length M, with the same bits set as `self`.
@@ -297,22 +473,74 @@ impl<N: Unsigned + Clone> Bitfield<Fixed<N>> {
     /// Instantiates a new instance from `bytes`. Consumes the same format that `self.into_bytes()`
     /// produces (SSZ).
     ///
+    /// Decoding is strict: only the canonical encoding is accepted, so any padding bits above `N`
+    /// in the final byte must be zero.
+    ///
     /// Returns `None` if `bytes` are not a valid encoding.
     pub fn from_bytes(bytes: SmallVec<[u8; SMALLVEC_LEN]>) -> Result<Self, Error> {
         Self::from_raw_bytes(bytes, Self::capacity())
     }
 
+    /// Instantiate a `BitVector` where each bit is independently set with probability `density`.
+    ///
+    /// ## Panics
+    ///
+    /// If `density` is not within `0.0..=1.0`.
+    #[cfg(feature = "rand")]
+    pub fn random<R: rand::Rng + ?Sized>(density: f64, rng: &mut R) -> Self {
+        let mut bitfield = Self::new();
+        bitfield.fill_random(density, rng);
+        bitfield
+    }
+
+    /// Instantiate a `BitVector` with the bits at each of `indices` set.
+    ///
+    /// Returns `Err` if any of `indices` is not less than `N`.
+    pub fn from_indices(indices: &[usize]) -> Result<Self, Error> {
+        let mut bitfield = Self::new();
+        for &i in indices {
+            bitfield.set(i, true)?;
+        }
+        Ok(bitfield)
+    }
+
+    /// Returns a view of the SSZ-encoded `BitVector` in `bytes`, without copying them.
+    ///
+    /// Applies the same validation as `Self::from_bytes`.
+    pub fn view_ssz_bytes(bytes: &[u8]) -> Result<BitSliceRef<'_>, Error> {
+        let len = Self::capacity();
+        let expected = bytes_for_bit_len(len);
+
+        if bytes.len() != expected {
+            return Err(Error::InvalidByteCount {
+                given: bytes.len(),
+                expected,
+            });
+        }
+
+        let slice = BitSliceRef { bytes, len };
+        if slice.trailing_byte() == bytes.get(len / 8).copied().unwrap_or(0) {
+            Ok(slice)
+        } else {
+            Err(Error::ExcessBits)
+        }
+    }
+
+    /// Consumes `self`, returning a `BitList` of length `N` with the same bits set.
+    pub fn into_bitlist(self) -> Bitfield<Variable<N>> {
+        Bitfield {
+            bytes: self.bytes,
+            len: self.len,
+            _phantom: PhantomData,
+        }
+    }
+
     /// Compute the intersection of two fixed-length `Bitfield`s.
     ///
     /// Return a new fixed-length `Bitfield`.
     pub fn intersection(&self, other: &Self) -> Self {
-        let mut result = Self::new();
-        // Bitwise-and the bytes together, starting from the left of each vector. This takes care
-        // of masking out any entries beyond `min_len` as well, assuming the bitfield doesn't
-        // contain any set bits beyond its length.
-        for i in 0..result.bytes.len() {
-            result.bytes[i] = self.bytes[i] & other.bytes[i];
-        }
+        let mut result = self.clone();
+        zip_words_mut(&mut result.bytes, &other.bytes, |a, b| a & b);
         result
     }
 
@@ -320,17 +548,18 @@ impl<N: Unsigned + Clone> Bitfield<Fixed<N>> {
     ///
     /// Return a new fixed-length `Bitfield`.
     pub fn union(&self, other: &Self) -> Self {
-        let mut result = Self::new();
-        for i in 0..result.bytes.len() {
-            result.bytes[i] =
-                self.bytes.get(i).copied().unwrap_or(0) | other.bytes.get(i).copied().unwrap_or(0);
-        }
+        let mut result = self.clone();
+        zip_words_mut(&mut result.bytes, &other.bytes, |a, b| a | b);
         result
     }
 
-    /// Returns `true` if `self` is a subset of `other` and `false` otherwise.
-    pub fn is_subset(&self, other: &Self) -> bool {
-        self.difference(other).is_zero()
+    /// Compute the symmetric difference of two fixed-length `Bitfield`s.
+    ///
+    /// Return a new fixed-length `Bitfield`.
+    pub fn symmetric_difference(&self, other: &Self) -> Self {
+        let mut result = self.clone();
+        zip_words_mut(&mut result.bytes, &other.bytes, |a, b| a ^ b);
+        result
     }
 }
 
@@ -340,6 +569,148 @@ impl<N: Unsigned + Clone> Default for Bitfield<Fixed<N>> {
     }
 }
 
+impl Bitfield<Dynamic> {
+    /// Instantiate a new `Bitfield` with a fixed-length of `len` bits.
+    ///
+    /// All bits are initialized to `false`.
+    pub fn with_len(len: usize) -> Self {
+        Self {
+            bytes: smallvec![0; bytes_for_bit_len(len)],
+            len,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Consumes `self`, returning a serialized representation.
+    ///
+    /// The output is faithful to the SSZ encoding of a `BitVector` of the same length.
+    pub fn into_bytes(self) -> SmallVec<[u8; SMALLVEC_LEN]> {
+        self.into_raw_bytes()
+    }
+
+    /// Instantiates a new instance of length `len` from `bytes`. Consumes the same format that
+    /// `self.into_bytes()` produces (SSZ).
+    ///
+    /// Returns `Err` if `bytes` are not a valid encoding of `len` bits.
+    pub fn from_bytes(bytes: SmallVec<[u8; SMALLVEC_LEN]>, len: usize) -> Result<Self, Error> {
+        Self::from_raw_bytes(bytes, len)
+    }
+}
+
+impl<N: Unsigned + Clone> From<Bitfield<Fixed<N>>> for Bitfield<Dynamic> {
+    fn from(bitfield: Bitfield<Fixed<N>>) -> Self {
+        Self {
+            bytes: bitfield.bytes,
+            len: bitfield.len,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<N: Unsigned + Clone> TryFrom<Bitfield<Dynamic>> for Bitfield<Fixed<N>> {
+    type Error = Error;
+
+    /// Returns `Err` if the length of `bitfield` is not equal to `N`.
+    fn try_from(bitfield: Bitfield<Dynamic>) -> Result<Self, Error> {
+        if bitfield.len() == N::to_usize() {
+            Ok(Self {
+                bytes: bitfield.bytes,
+                len: bitfield.len,
+                _phantom: PhantomData,
+            })
+        } else {
+            Err(Error::OutOfBounds {
+                i: bitfield.len(),
+                len: N::to_usize(),
+            })
+        }
+    }
+}
+
+impl<N: Unsigned + Clone> ssz::TryFromIter<bool> for Bitfield<Fixed<N>> {
+    type Error = Error;
+
+    /// Collects all values of `iter` into a new `BitVector`.
+    ///
+    /// Returns `Err` as soon as `iter` yields more than `N` values, or if it yields fewer.
+    fn try_from_iter<I>(iter: I) -> Result<Self, Self::Error>
+    where
+        I: IntoIterator<Item = bool>,
+    {
+        let capacity = Self::capacity();
+        let mut bitfield = Self::new();
+        let mut len = 0;
+
+        for bit in iter {
+            if len >= capacity {
+                return Err(Error::OutOfBounds {
+                    i: len,
+                    len: capacity,
+                });
+            }
+            if bit {
+                bitfield.bytes[len / 8] |= 1 << (len % 8);
+            }
+            len += 1;
+        }
+
+        if len == capacity {
+            Ok(bitfield)
+        } else {
+            Err(Error::OutOfBounds {
+                i: len,
+                len: capacity,
+            })
+        }
+    }
+}
+
+impl<N: Unsigned + Clone> ssz::TryFromIter<bool> for Bitfield<Variable<N>> {
+    type Error = Error;
+
+    /// Collects all values of `iter` into a new `BitList` with a length equal to the number of
+    /// values.
+    ///
+    /// Returns `Err` as soon as `iter` yields more than `N` values.
+    fn try_from_iter<I>(iter: I) -> Result<Self, Self::Error>
+    where
+        I: IntoIterator<Item = bool>,
+    {
+        let max_len = Self::max_len();
+        let mut bytes = SmallVec::new();
+        let mut len = 0;
+
+        for bit in iter {
+            // Bail out as soon as the length tries to exceed the limit. This guards against
+            // memory denial-of-service attacks.
+            if len >= max_len {
+                return Err(Error::OutOfBounds {
+                    i: len,
+                    len: max_len,
+                });
+            }
+            if len % 8 == 0 {
+                bytes.push(0);
+            }
+            if bit {
+                *bytes.last_mut().expect("byte was pushed above") |= 1 << (len % 8);
+            }
+            len += 1;
+        }
+
+        if bytes.is_empty() {
+            // A bitfield with a length of 0 is represented by a single zero byte.
+            bytes.push(0);
+        }
+
+        Ok(Self {
+            bytes,
+            len,
+            _phantom: PhantomData,
+        })
+    }
+}
+
 impl<T: BitfieldBehaviour> Bitfield<T> {
     /// Sets the `i`'th bit to `value`.
     ///
@@ -381,6 +752,61 @@ impl<T: BitfieldBehaviour> Bitfield<T> {
         }
     }
 
+    /// Sets all bits in `range` to `value`, operating a whole word at a time.
+    ///
+    /// Returns `Err` if `range` is out-of-bounds of `self`, or if `range.start > range.end`.
+    pub fn set_range(&mut self, range: Range<usize>, value: bool) -> Result<(), Error> {
+        let Range { start, end } = range;
+
+        if start > end || end > self.len {
+            return Err(Error::OutOfBounds {
+                i: std::cmp::max(start, end),
+                len: self.len,
+            });
+        }
+
+        if start == end {
+            return Ok(());
+        }
+
+        let first_word = start / BITS_PER_WORD;
+        for (i, chunk) in self
+            .bytes
+            .chunks_mut(BYTES_PER_WORD)
+            .enumerate()
+            .skip(first_word)
+            .take_while(|(i, _)| i * BITS_PER_WORD < end)
+        {
+            // The portion of `range` that falls within this word.
+            let word_start = i * BITS_PER_WORD;
+            let low = start.saturating_sub(word_start);
+            let high = std::cmp::min(end - word_start, BITS_PER_WORD);
+            let mask = (u64::MAX >> (BITS_PER_WORD - (high - low))) << low;
+
+            let word = read_word(chunk);
+            let word = if value { word | mask } else { word & !mask };
+            let len = chunk.len();
+            chunk.copy_from_slice(&word.to_le_bytes()[..len]);
+        }
+
+        Ok(())
+    }
+
+    /// Independently sets each bit to `true` with probability `density`, or `false` otherwise.
+    #[cfg(feature = "rand")]
+    fn fill_random<R: rand::Rng + ?Sized>(&mut self, density: f64, rng: &mut R) {
+        for i in 0..self.len {
+            if rng.gen_bool(density) {
+                self.bytes[i / 8] |= 1 << (i % 8);
+            }
+        }
+    }
+
+    /// Sets all bits to `false`, without changing the length of `self`.
+    pub fn clear(&mut self) {
+        self.bytes.iter_mut().for_each(|byte| *byte = 0);
+    }
+
     /// Returns the number of bits stored in `self`.
     pub fn len(&self) -> usize {
         self.len
@@ -401,6 +827,30 @@ impl<T: BitfieldBehaviour> Bitfield<T> {
         &self.bytes
     }
 
+    /// Returns an iterator over the underlying bytes of the bitfield as `u64` words.
+    ///
+    /// Bit `j` of the `i`'th word is the bit at index `i * 64 + j`. The final word is padded with
+    /// zeros, so there are no set bits at or above `self.len()`.
+    pub fn iter_words(&self) -> impl Iterator<Item = u64> + '_ {
+        words(&self.bytes)
+    }
+
+    /// Returns the underlying bytes of the bitfield as `u64` words, in the layout described by
+    /// `Self::iter_words`.
+    ///
+    /// The bytes of a bitfield are not guaranteed to be aligned for `u64`, so this copies them.
+    pub fn to_u64_words(&self) -> Vec<u64> {
+        self.iter_words().collect()
+    }
+
+    /// Returns a borrowed, read-only view of the bits of `self`.
+    pub fn as_bit_slice(&self) -> BitSliceRef<'_> {
+        BitSliceRef {
+            bytes: &self.bytes,
+            len: self.len,
+        }
+    }
+
     /// Instantiates from the given `bytes`, which are the same format as output from
     /// `self.into_raw_bytes()`.
     ///
@@ -447,11 +897,21 @@ impl<T: BitfieldBehaviour> Bitfield<T> {
     /// there are no set bits.
     pub fn highest_set_bit(&self) -> Option<usize> {
         self.bytes
-            .iter()
+            .chunks(BYTES_PER_WORD)
+            .map(read_word)
             .enumerate()
             .rev()
-            .find(|(_, byte)| **byte > 0)
-            .map(|(i, byte)| i * 8 + 7 - byte.leading_zeros() as usize)
+            .find(|(_, word)| *word != 0)
+            .map(|(i, word)| i * BITS_PER_WORD + BITS_PER_WORD - 1 - word.leading_zeros() as usize)
+    }
+
+    /// Returns the `Some(i)` where `i` is the lowest index with a set bit. Returns `None` if
+    /// there are no set bits.
+    pub fn lowest_set_bit(&self) -> Option<usize> {
+        words(&self.bytes)
+            .enumerate()
+            .find(|(_, word)| *word != 0)
+            .map(|(i, word)| i * BITS_PER_WORD + word.trailing_zeros() as usize)
     }
 
     /// Returns an iterator across bitfield `bool` values, starting at the lowest index.
@@ -464,14 +924,62 @@ impl<T: BitfieldBehaviour> Bitfield<T> {
 
     /// Returns true if no bits are set.
     pub fn is_zero(&self) -> bool {
-        self.bytes.iter().all(|byte| *byte == 0)
+        words(&self.bytes).all(|word| word == 0)
+    }
+
+    /// Returns the indices of all bits that are set to `true`, in ascending order.
+    pub fn to_indices(&self) -> Vec<usize> {
+        let mut indices = Vec::with_capacity(self.num_set_bits());
+        for (i, mut word) in words(&self.bytes).enumerate() {
+            while word != 0 {
+                indices.push(i * BITS_PER_WORD + word.trailing_zeros() as usize);
+                // Clear the lowest set bit.
+                word &= word - 1;
+            }
+        }
+        indices
     }
 
     /// Returns the number of bits that are set to `true`.
     pub fn num_set_bits(&self) -> usize {
-        self.bytes
-            .iter()
-            .map(|byte| byte.count_ones() as usize)
+        words(&self.bytes)
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    /// Returns `true` if `self` is a subset of `other` and `false` otherwise.
+    ///
+    /// Bits beyond the length of the shorter bitfield are treated as unset.
+    pub fn is_subset(&self, other: &Self) -> bool {
+        zip_words(&self.bytes, &other.bytes).all(|(a, b)| a & !b == 0)
+    }
+
+    /// Returns `true` if `self` and `other` have no set bits in common.
+    pub fn is_disjoint(&self, other: &Self) -> bool {
+        zip_words(&self.bytes, &other.bytes).all(|(a, b)| a & b == 0)
+    }
+
+    /// Returns `true` if `self` and `other` have at least one set bit in common.
+    pub fn intersects(&self, other: &Self) -> bool {
+        !self.is_disjoint(other)
+    }
+
+    /// Returns the number of bits that are set in both `self` and `other`.
+    ///
+    /// Equivalent to `self.intersection(other).num_set_bits()`, without allocating.
+    pub fn overlap_count(&self, other: &Self) -> usize {
+        zip_words(&self.bytes, &other.bytes)
+            .map(|(a, b)| (a & b).count_ones() as usize)
+            .sum()
+    }
+
+    /// Returns the number of bits that are set in exactly one of `self` and `other`.
+    ///
+    /// Bits beyond the length of the shorter bitfield are treated as unset. Equivalent to
+    /// `self.symmetric_difference(other).num_set_bits()`, without allocating.
+    pub fn hamming_distance(&self, other: &Self) -> usize {
+        zip_words(&self.bytes, &other.bytes)
+            .map(|(a, b)| (a ^ b).count_ones() as usize)
             .sum()
     }
 
@@ -484,11 +992,7 @@ impl<T: BitfieldBehaviour> Bitfield<T> {
 
     /// Compute the difference of this Bitfield and another of potentially different length.
     pub fn difference_inplace(&mut self, other: &Self) {
-        let min_byte_len = std::cmp::min(self.bytes.len(), other.bytes.len());
-
-        for i in 0..min_byte_len {
-            self.bytes[i] &= !other.bytes[i];
-        }
+        zip_words_mut(&mut self.bytes, &other.bytes, |a, b| a & !b);
     }
 
     /// Shift the bits to higher indices, filling the lower indices with zeroes.
@@ -521,6 +1025,80 @@ fn bytes_for_bit_len(bit_len: usize) -> usize {
     std::cmp::max(1, (bit_len + 7) / 8)
 }
 
+/// Number of bytes in the `u64` words used for bulk bitwise operations.
+pub(crate) const BYTES_PER_WORD: usize = 8;
+
+/// Number of bits in the `u64` words used for bulk bitwise operations.
+pub(crate) const BITS_PER_WORD: usize = BYTES_PER_WORD * 8;
+
+/// Reads up to `BYTES_PER_WORD` little-endian bytes into a `u64`, zero-padding any missing high
+/// bytes.
+fn read_word(bytes: &[u8]) -> u64 {
+    let mut buf = [0; BYTES_PER_WORD];
+    buf[..bytes.len()].copy_from_slice(bytes);
+    u64::from_le_bytes(buf)
+}
+
+/// Reads exactly `BYTES_PER_WORD` little-endian bytes into a `u64`.
+#[inline]
+fn read_full_word(bytes: &[u8]) -> u64 {
+    u64::from_le_bytes(bytes.try_into().expect("chunk must be exactly one word"))
+}
+
+/// Returns an iterator over `bytes` as little-endian `u64` words, with the final word
+/// zero-padded.
+pub(crate) fn words(bytes: &[u8]) -> impl Iterator<Item = u64> + '_ {
+    let chunks = bytes.chunks_exact(BYTES_PER_WORD);
+    let remainder = chunks.remainder();
+    chunks
+        .map(read_full_word)
+        .chain((!remainder.is_empty()).then(|| read_word(remainder)))
+}
+
+/// Returns an iterator over the pairs of `u64` words of `a` and `b`, zero-padding whichever is
+/// shorter.
+fn zip_words<'a>(a: &'a [u8], b: &'a [u8]) -> impl Iterator<Item = (u64, u64)> + 'a {
+    let (mut a, mut b) = (words(a), words(b));
+    std::iter::from_fn(move || match (a.next(), b.next()) {
+        (None, None) => None,
+        (a, b) => Some((a.unwrap_or(0), b.unwrap_or(0))),
+    })
+}
+
+/// Replaces each `u64` word of `dst` with `op(dst_word, src_word)`.
+///
+/// `src` is treated as if it were zero-padded to the length of `dst`, and any bytes of `src`
+/// beyond the length of `dst` are ignored.
+fn zip_words_mut<F>(dst: &mut [u8], src: &[u8], op: F)
+where
+    F: Fn(u64, u64) -> u64,
+{
+    // Fast path over the words that are whole in both `dst` and `src`. Each iteration is
+    // branch-free, which allows the compiler to vectorise the loop (e.g. a 2048-bit
+    // `aggregation_bits` is processed as 32 words without any per-bit or per-byte work).
+    let whole = std::cmp::min(dst.len(), src.len()) / BYTES_PER_WORD * BYTES_PER_WORD;
+    let (dst_head, dst_tail) = dst.split_at_mut(whole);
+    let (src_head, src_tail) = src.split_at(whole);
+
+    for (dst_chunk, src_chunk) in dst_head
+        .chunks_exact_mut(BYTES_PER_WORD)
+        .zip(src_head.chunks_exact(BYTES_PER_WORD))
+    {
+        let word = op(read_full_word(dst_chunk), read_full_word(src_chunk));
+        dst_chunk.copy_from_slice(&word.to_le_bytes());
+    }
+
+    // Slow path over any trailing partial word of either input.
+    for (i, dst_chunk) in dst_tail.chunks_mut(BYTES_PER_WORD).enumerate() {
+        let src_chunk = src_tail.get(i * BYTES_PER_WORD..).map_or(&[][..], |rest| {
+            &rest[..std::cmp::min(rest.len(), BYTES_PER_WORD)]
+        });
+        let word = op(read_word(dst_chunk), read_word(src_chunk));
+        let len = dst_chunk.len();
+        dst_chunk.copy_from_slice(&word.to_le_bytes()[..len]);
+    }
+}
+
 /// An iterator over the bits in a `Bitfield`.
 pub struct BitIter<'a, T> {
     bitfield: &'a Bitfield<T>,
@@ -537,6 +1115,211 @@ impl<'a, T: BitfieldBehaviour> Iterator for BitIter<'a, T> {
     }
 }
 
+/// Implements a `std::ops` bitwise operator and its `Assign` variant for a `Bitfield` kind, in
+/// terms of the named set operation.
+///
+/// The assigning variant operates in-place whenever the result has the same length as `self`,
+/// which is always the case for `BitVector`.
+macro_rules! impl_bitwise_op {
+    (
+        $kind: ident,
+        $op_trait: ident,
+        $op_fn: ident,
+        $assign_trait: ident,
+        $assign_fn: ident,
+        $method: ident,
+        $in_place: expr,
+        $word_op: expr
+    ) => {
+        impl<N: Unsigned + Clone> $op_trait for &Bitfield<$kind<N>> {
+            type Output = Bitfield<$kind<N>>;
+
+            fn $op_fn(self, rhs: Self) -> Self::Output {
+                self.$method(rhs)
+            }
+        }
+
+        impl<N: Unsigned + Clone> $op_trait for Bitfield<$kind<N>> {
+            type Output = Self;
+
+            fn $op_fn(mut self, rhs: Self) -> Self::Output {
+                self.$assign_fn(&rhs);
+                self
+            }
+        }
+
+        impl<N: Unsigned + Clone> $assign_trait<&Self> for Bitfield<$kind<N>> {
+            fn $assign_fn(&mut self, rhs: &Self) {
+                let in_place: fn(usize, usize) -> bool = $in_place;
+                if in_place(self.len(), rhs.len()) {
+                    zip_words_mut(&mut self.bytes, &rhs.bytes, $word_op);
+                } else {
+                    *self = self.$method(rhs);
+                }
+            }
+        }
+
+        impl<N: Unsigned + Clone> $assign_trait for Bitfield<$kind<N>> {
+            fn $assign_fn(&mut self, rhs: Self) {
+                self.$assign_fn(&rhs)
+            }
+        }
+    };
+}
+
+/// Implements `|`, `&` and `^` (and `|=`, `&=` and `^=`) as `union`, `intersection` and
+/// `symmetric_difference` respectively.
+///
+/// For `BitList`s of mismatched lengths, `|` and `^` produce a list as long as the longer operand
+/// and `&` produces a list as long as the shorter operand.
+macro_rules! impl_bitwise_ops {
+    ($kind: ident) => {
+        impl_bitwise_op!(
+            $kind,
+            BitOr,
+            bitor,
+            BitOrAssign,
+            bitor_assign,
+            union,
+            |len, rhs_len| len >= rhs_len,
+            |a, b| a | b
+        );
+        impl_bitwise_op!(
+            $kind,
+            BitAnd,
+            bitand,
+            BitAndAssign,
+            bitand_assign,
+            intersection,
+            |len, rhs_len| len <= rhs_len,
+            |a, b| a & b
+        );
+        impl_bitwise_op!(
+            $kind,
+            BitXor,
+            bitxor,
+            BitXorAssign,
+            bitxor_assign,
+            symmetric_difference,
+            |len, rhs_len| len >= rhs_len,
+            |a, b| a ^ b
+        );
+    };
+}
+
+impl_bitwise_ops!(Variable);
+impl_bitwise_ops!(Fixed);
+
+/// A borrowed, read-only view of the bits of a `BitList` or `BitVector`.
+///
+/// Can be obtained from an existing bitfield with `Bitfield::as_bit_slice`, or directly from SSZ
+/// bytes with `BitList::view_ssz_bytes` and `BitVector::view_ssz_bytes`, avoiding the copy
+/// required to decode them.
+#[derive(Clone, Copy, Debug)]
+pub struct BitSliceRef<'a> {
+    /// At least the bytes required to represent `len` bits. Any bits at or above `len` are
+    /// ignored (e.g., the length bit of an SSZ-encoded `BitList`).
+    bytes: &'a [u8],
+    len: usize,
+}
+
+impl<'a> BitSliceRef<'a> {
+    /// Returns the number of bits in `self`.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if `self.len() == 0`.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the value of the `i`'th bit.
+    ///
+    /// Returns `Error` if `i` is out-of-bounds of `self`.
+    pub fn get(&self, i: usize) -> Result<bool, Error> {
+        if i < self.len {
+            Ok(self.bytes[i / 8] & 1 << (i % 8) > 0)
+        } else {
+            Err(Error::OutOfBounds { i, len: self.len })
+        }
+    }
+
+    /// Returns an iterator across the `bool` values of `self`, starting at the lowest index.
+    pub fn iter(&self) -> BitSliceIter<'a> {
+        BitSliceIter { slice: *self, i: 0 }
+    }
+
+    /// Returns the number of bits that are set to `true`.
+    pub fn num_set_bits(&self) -> usize {
+        words(self.whole_bytes())
+            .map(|word| word.count_ones() as usize)
+            .sum::<usize>()
+            + self.trailing_byte().count_ones() as usize
+    }
+
+    /// Returns true if no bits are set.
+    pub fn is_zero(&self) -> bool {
+        words(self.whole_bytes()).all(|word| word == 0) && self.trailing_byte() == 0
+    }
+
+    /// The bytes of `self` in which all eight bits are within the length.
+    pub(crate) fn whole_bytes(&self) -> &'a [u8] {
+        &self.bytes[..self.len / 8]
+    }
+
+    /// The byte containing the bits above the last whole byte, with any bits at or above the
+    /// length masked out.
+    pub(crate) fn trailing_byte(&self) -> u8 {
+        let mask = (1u8 << (self.len % 8)).wrapping_sub(1);
+        self.bytes.get(self.len / 8).map_or(0, |byte| byte & mask)
+    }
+}
+
+/// Compares the bits within the length only, e.g. a view of SSZ bytes is equal to the bitfield
+/// they encode.
+impl PartialEq for BitSliceRef<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len
+            && self.whole_bytes() == other.whole_bytes()
+            && self.trailing_byte() == other.trailing_byte()
+    }
+}
+
+impl Eq for BitSliceRef<'_> {}
+
+impl<'a> IntoIterator for BitSliceRef<'a> {
+    type Item = bool;
+    type IntoIter = BitSliceIter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over the bits in a `BitSliceRef`.
+pub struct BitSliceIter<'a> {
+    slice: BitSliceRef<'a>,
+    i: usize,
+}
+
+impl Iterator for BitSliceIter<'_> {
+    type Item = bool;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let res = self.slice.get(self.i).ok()?;
+        self.i += 1;
+        Some(res)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.slice.len().saturating_sub(self.i);
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for BitSliceIter<'_> {}
+
 impl<N: Unsigned + Clone> Encode for Bitfield<Variable<N>> {
     fn is_ssz_fixed_len() -> bool {
         false
@@ -599,6 +1382,16 @@ impl<N: Unsigned + Clone> Decode for Bitfield<Fixed<N>> {
     }
 }
 
+impl<T: BitfieldBehaviour> MemorySize for Bitfield<T> {
+    fn heap_size(&self) -> usize {
+        if self.bytes.spilled() {
+            self.bytes.capacity()
+        } else {
+            0
+        }
+    }
+}
+
 impl<N: Unsigned + Clone> Serialize for Bitfield<Variable<N>> {
     /// Serde serialization is compliant with the Ethereum YAML test format.
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
@@ -643,6 +1436,38 @@ impl<'de, N: Unsigned + Clone> Deserialize<'de> for Bitfield<Fixed<N>> {
     }
 }
 
+/// The schema of the hex of the SSZ, including the length bit.
+#[cfg(feature = "schemars")]
+impl<N: Unsigned + Clone> schemars::JsonSchema for Bitfield<Variable<N>> {
+    fn is_referenceable() -> bool {
+        false
+    }
+
+    fn schema_name() -> String {
+        format!("BitList{}", N::to_usize())
+    }
+
+    fn json_schema(_: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        crate::json_schema::hex_schema(1, N::to_usize() / 8 + 1)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl<N: Unsigned + Clone> schemars::JsonSchema for Bitfield<Fixed<N>> {
+    fn is_referenceable() -> bool {
+        false
+    }
+
+    fn schema_name() -> String {
+        format!("BitVector{}", N::to_usize())
+    }
+
+    fn json_schema(_: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        let len = N::to_usize().div_ceil(8);
+        crate::json_schema::hex_schema(len, len)
+    }
+}
+
 impl<N: Unsigned + Clone> tree_hash::TreeHash for Bitfield<Variable<N>> {
     fn tree_hash_type() -> tree_hash::TreeHashType {
         tree_hash::TreeHashType::List
@@ -660,7 +1485,7 @@ impl<N: Unsigned + Clone> tree_hash::TreeHash for Bitfield<Variable<N>> {
         // Note: we use `as_slice` because it does _not_ have the length-delimiting bit set (or
         // present).
         let root = bitfield_bytes_tree_hash_root::<N>(self.as_slice());
-        tree_hash::mix_in_length(&root, self.len())
+        mix_in_length(&root, self.len())
     }
 }
 
@@ -682,25 +1507,75 @@ impl<N: Unsigned + Clone> tree_hash::TreeHash for Bitfield<Fixed<N>> {
     }
 }
 
+impl<N: Unsigned + Clone> MerkleProof for Bitfield<Variable<N>> {
+    fn tree_hash_node(&self, gindex: u64) -> Result<Hash256, Error> {
+        list_tree_hash_node(gindex, self.len(), |gindex| {
+            bitfield_bytes_tree_hash_node::<N>(self.as_slice(), gindex)
+        })
+    }
+}
+
+impl<N: Unsigned + Clone> MerkleProof for Bitfield<Fixed<N>> {
+    fn tree_hash_node(&self, gindex: u64) -> Result<Hash256, Error> {
+        bitfield_bytes_tree_hash_node::<N>(self.as_slice(), gindex)
+    }
+}
+
+impl<N: Unsigned + Clone> MerklePath for Bitfield<Variable<N>> {
+    fn gindex_of(path: &[&str]) -> Result<u64, Error> {
+        list_gindex_of(path, |path| bitfield_gindex_of(N::to_usize(), path))
+    }
+}
+
+impl<N: Unsigned + Clone> MerklePath for Bitfield<Fixed<N>> {
+    fn gindex_of(path: &[&str]) -> Result<u64, Error> {
+        bitfield_gindex_of(N::to_usize(), path)
+    }
+}
+
+impl<N: Unsigned + Clone> SszReflect for Bitfield<Variable<N>> {
+    fn ssz_schema() -> SszSchema {
+        SszSchema::Bitfield {
+            len: N::to_usize(),
+            is_list: true,
+        }
+    }
+}
+
+impl<N: Unsigned + Clone> SszReflect for Bitfield<Fixed<N>> {
+    fn ssz_schema() -> SszSchema {
+        SszSchema::Bitfield {
+            len: N::to_usize(),
+            is_list: false,
+        }
+    }
+}
+
 #[cfg(feature = "arbitrary")]
-impl<N: 'static + Unsigned> arbitrary::Arbitrary<'_> for Bitfield<Fixed<N>> {
+impl<N: 'static + Unsigned + Clone> arbitrary::Arbitrary<'_> for Bitfield<Fixed<N>> {
     fn arbitrary(u: &mut arbitrary::Unstructured<'_>) -> arbitrary::Result<Self> {
-        let size = N::to_usize();
-        let mut vec = smallvec![0u8; size];
-        u.fill_buffer(&mut vec)?;
-        Ok(Self::from_bytes(vec).map_err(|_| arbitrary::Error::IncorrectFormat)?)
+        let mut bitfield = Self::new();
+        for i in 0..N::to_usize() {
+            bitfield
+                .set(i, u.arbitrary()?)
+                .map_err(|_| arbitrary::Error::IncorrectFormat)?;
+        }
+        Ok(bitfield)
     }
 }
 
 #[cfg(feature = "arbitrary")]
-impl<N: 'static + Unsigned> arbitrary::Arbitrary<'_> for Bitfield<Variable<N>> {
+impl<N: 'static + Unsigned + Clone> arbitrary::Arbitrary<'_> for Bitfield<Variable<N>> {
     fn arbitrary(u: &mut arbitrary::Unstructured<'_>) -> arbitrary::Result<Self> {
-        let max_size = N::to_usize();
-        let rand = usize::arbitrary(u)?;
-        let size = std::cmp::min(rand, max_size);
-        let mut vec = smallvec![0u8; size];
-        u.fill_buffer(&mut vec)?;
-        Ok(Self::from_bytes(vec).map_err(|_| arbitrary::Error::IncorrectFormat)?)
+        let len = std::cmp::min(u.arbitrary_len::<bool>()?, N::to_usize());
+        let mut bitfield =
+            Self::with_capacity(len).map_err(|_| arbitrary::Error::IncorrectFormat)?;
+        for i in 0..len {
+            bitfield
+                .set(i, u.arbitrary()?)
+                .map_err(|_| arbitrary::Error::IncorrectFormat)?;
+        }
+        Ok(bitfield)
     }
 }
 
@@ -708,13 +1583,16 @@ impl<N: 'static + Unsigned> arbitrary::Arbitrary<'_> for Bitfield<Variable<N>> {
 mod bitvector {
     use super::*;
     use crate::BitVector;
+    use ssz::TryFromIter;
 
     pub type BitVector0 = BitVector<typenum::U0>;
     pub type BitVector1 = BitVector<typenum::U1>;
     pub type BitVector4 = BitVector<typenum::U4>;
     pub type BitVector8 = BitVector<typenum::U8>;
+    pub type BitVector12 = BitVector<typenum::U12>;
     pub type BitVector16 = BitVector<typenum::U16>;
     pub type BitVector64 = BitVector<typenum::U64>;
+    pub type BitVector100 = BitVector<typenum::U100>;
 
     #[test]
     fn ssz_encode() {
@@ -794,51 +1672,275 @@ mod bitvector {
     }
 
     #[test]
-    fn subset() {
-        let a = BitVector16::from_raw_bytes(smallvec![0b1000, 0b0001], 16).unwrap();
-        let b = BitVector16::from_raw_bytes(smallvec![0b1100, 0b0001], 16).unwrap();
-        let c = BitVector16::from_raw_bytes(smallvec![0b1100, 0b1001], 16).unwrap();
+    fn subset() {
+        let a = BitVector16::from_raw_bytes(smallvec![0b1000, 0b0001], 16).unwrap();
+        let b = BitVector16::from_raw_bytes(smallvec![0b1100, 0b0001], 16).unwrap();
+        let c = BitVector16::from_raw_bytes(smallvec![0b1100, 0b1001], 16).unwrap();
+
+        assert_eq!(a.len(), 16);
+        assert_eq!(b.len(), 16);
+        assert_eq!(c.len(), 16);
+
+        // a vector is always a subset of itself
+        assert!(a.is_subset(&a));
+        assert!(b.is_subset(&b));
+        assert!(c.is_subset(&c));
+
+        assert!(a.is_subset(&b));
+        assert!(a.is_subset(&c));
+        assert!(b.is_subset(&c));
+
+        assert!(!b.is_subset(&a));
+        assert!(!c.is_subset(&a));
+        assert!(!c.is_subset(&b));
+    }
+
+    #[test]
+    fn disjoint_and_intersects() {
+        let a = BitVector16::from_raw_bytes(smallvec![0b1100, 0b0001], 16).unwrap();
+        let b = BitVector16::from_raw_bytes(smallvec![0b0011, 0b1000], 16).unwrap();
+        let c = BitVector16::from_raw_bytes(smallvec![0b1000, 0b0000], 16).unwrap();
+        let empty = BitVector16::new();
+
+        assert!(a.is_disjoint(&b));
+        assert!(b.is_disjoint(&a));
+        assert!(!a.intersects(&b));
+
+        assert!(!a.is_disjoint(&c));
+        assert!(a.intersects(&c));
+        assert!(c.intersects(&a));
+
+        assert!(a.is_disjoint(&empty));
+        assert!(empty.is_disjoint(&empty));
+        assert!(!empty.intersects(&empty));
+    }
+
+    #[test]
+    fn union() {
+        let a = BitVector16::from_raw_bytes(smallvec![0b1100, 0b0001], 16).unwrap();
+        let b = BitVector16::from_raw_bytes(smallvec![0b1011, 0b1001], 16).unwrap();
+        let c = BitVector16::from_raw_bytes(smallvec![0b1111, 0b1001], 16).unwrap();
+
+        assert_eq!(a.union(&b), c);
+        assert_eq!(b.union(&a), c);
+        assert_eq!(a.union(&a), a);
+        assert_eq!(b.union(&b), b);
+        assert_eq!(c.union(&c), c);
+    }
+
+    #[test]
+    fn union_diff_length() {
+        let a = BitVector16::from_bytes(smallvec![0b0010_1011, 0b0010_1110]).unwrap();
+        let b = BitVector16::from_bytes(smallvec![0b0000_0001, 0b0010_1101]).unwrap();
+        let c = BitVector16::from_bytes(smallvec![0b0010_1011, 0b0010_1111]).unwrap();
+
+        assert_eq!(a.len(), c.len());
+        assert_eq!(a.union(&b), c);
+        assert_eq!(b.union(&a), c);
+    }
+
+    #[test]
+    fn symmetric_difference() {
+        let a = BitVector16::from_raw_bytes(smallvec![0b1100, 0b0001], 16).unwrap();
+        let b = BitVector16::from_raw_bytes(smallvec![0b1011, 0b1001], 16).unwrap();
+        let c = BitVector16::from_raw_bytes(smallvec![0b0111, 0b1000], 16).unwrap();
+
+        assert_eq!(a.symmetric_difference(&b), c);
+        assert_eq!(b.symmetric_difference(&a), c);
+        assert!(a.symmetric_difference(&a).is_zero());
+        assert_eq!(a.symmetric_difference(&c), b);
+    }
+
+    #[test]
+    fn multi_word_operations() {
+        let mut a = BitVector100::new();
+        let mut b = BitVector100::new();
+        for i in (0..100).step_by(3) {
+            a.set(i, true).unwrap();
+        }
+        for i in (0..100).step_by(5) {
+            b.set(i, true).unwrap();
+        }
+
+        for i in 0..100 {
+            let (x, y) = (a.get(i).unwrap(), b.get(i).unwrap());
+            assert_eq!(a.union(&b).get(i), Ok(x | y), "i = {}", i);
+            assert_eq!(a.intersection(&b).get(i), Ok(x & y), "i = {}", i);
+            assert_eq!(a.difference(&b).get(i), Ok(x & !y), "i = {}", i);
+            assert_eq!(a.symmetric_difference(&b).get(i), Ok(x ^ y), "i = {}", i);
+        }
+    }
+
+    #[test]
+    fn committee_sized_operations() {
+        type BitVector512 = BitVector<typenum::U512>;
+
+        let a = BitVector512::try_from_iter((0..512).map(|i| i % 3 == 0)).unwrap();
+        let b = BitVector512::try_from_iter((0..512).map(|i| i % 7 == 0)).unwrap();
+
+        assert_eq!(a.num_set_bits(), (0..512).filter(|i| i % 3 == 0).count());
+        assert_eq!(
+            a.intersection(&b).num_set_bits(),
+            (0..512).filter(|i| i % 21 == 0).count()
+        );
+        assert_eq!(
+            a.union(&b).num_set_bits(),
+            (0..512).filter(|i| i % 3 == 0 || i % 7 == 0).count()
+        );
+        assert!(!a.is_zero());
+        assert!(a.difference(&a).is_zero());
+    }
+
+    #[test]
+    fn overlap_and_hamming_distance() {
+        let a = BitVector16::from_raw_bytes(smallvec![0b1100, 0b0001], 16).unwrap();
+        let b = BitVector16::from_raw_bytes(smallvec![0b1011, 0b1001], 16).unwrap();
+
+        assert_eq!(a.overlap_count(&b), 2);
+        assert_eq!(a.hamming_distance(&b), 4);
+        assert_eq!(a.overlap_count(&a), a.num_set_bits());
+        assert_eq!(a.hamming_distance(&a), 0);
+    }
+
+    #[test]
+    fn bitwise_ops() {
+        let a = BitVector16::from_raw_bytes(smallvec![0b1100, 0b0001], 16).unwrap();
+        let b = BitVector16::from_raw_bytes(smallvec![0b1011, 0b1001], 16).unwrap();
+
+        assert_eq!(&a | &b, a.union(&b));
+        assert_eq!(&a & &b, a.intersection(&b));
+        assert_eq!(&a ^ &b, a.symmetric_difference(&b));
+        assert_eq!(a.clone() | b.clone(), a.union(&b));
+
+        let mut c = a.clone();
+        c |= &b;
+        assert_eq!(c, a.union(&b));
+        let mut c = a.clone();
+        c &= &b;
+        assert_eq!(c, a.intersection(&b));
+        let mut c = a.clone();
+        c ^= b.clone();
+        assert_eq!(c, a.symmetric_difference(&b));
+    }
+
+    #[test]
+    fn try_from_iter() {
+        let bits = [
+            true, false, true, true, false, false, false, false, false, true,
+        ];
+
+        let a = BitVector16::try_from_iter(bits.iter().copied().chain([false; 6])).unwrap();
+        for (i, bit) in bits.iter().enumerate() {
+            assert_eq!(a.get(i), Ok(*bit));
+        }
+        for i in bits.len()..16 {
+            assert_eq!(a.get(i), Ok(false));
+        }
+        assert_eq!(a.as_slice(), &[0b0000_1101, 0b0000_0010]);
+
+        // The number of values must be the fixed length.
+        assert_eq!(
+            BitVector8::try_from_iter(bits.iter().copied()),
+            Err(Error::OutOfBounds { i: 8, len: 8 })
+        );
+        assert_eq!(
+            BitVector16::try_from_iter(bits.iter().copied()),
+            Err(Error::OutOfBounds { i: 10, len: 16 })
+        );
+        assert_eq!(
+            BitVector0::try_from_iter(std::iter::repeat(true)),
+            Err(Error::OutOfBounds { i: 0, len: 0 })
+        );
+        assert_eq!(
+            BitVector0::try_from_iter(std::iter::empty()),
+            Ok(BitVector0::new())
+        );
+    }
+
+    #[test]
+    fn serde_hex() {
+        let a = BitVector16::from_raw_bytes(smallvec![0b1100, 0b0001], 16).unwrap();
+        let json = serde_json::to_string(&a).unwrap();
+        assert_eq!(json, r#""0x0c01""#);
+        assert_eq!(serde_json::from_str::<BitVector16>(&json).unwrap(), a);
+
+        // Wrong byte count.
+        assert!(serde_json::from_str::<BitVector16>(r#""0x0c""#).is_err());
+        assert!(serde_json::from_str::<BitVector16>(r#""0x0c0100""#).is_err());
+        // Non-zero padding bits.
+        assert!(serde_json::from_str::<BitVector4>(r#""0x1f""#).is_err());
+        // Missing prefix.
+        assert!(serde_json::from_str::<BitVector16>(r#""0c01""#).is_err());
+    }
+
+    #[test]
+    fn indices() {
+        let a = BitVector100::from_indices(&[0, 5, 63, 64, 99]).unwrap();
+        assert_eq!(a.num_set_bits(), 5);
+        assert_eq!(a.to_indices(), vec![0, 5, 63, 64, 99]);
 
-        assert_eq!(a.len(), 16);
-        assert_eq!(b.len(), 16);
-        assert_eq!(c.len(), 16);
+        assert!(BitVector16::new().to_indices().is_empty());
+        assert_eq!(
+            BitVector16::from_indices(&[3, 16]),
+            Err(Error::OutOfBounds { i: 16, len: 16 })
+        );
+    }
 
-        // a vector is always a subset of itself
-        assert!(a.is_subset(&a));
-        assert!(b.is_subset(&b));
-        assert!(c.is_subset(&c));
+    #[test]
+    fn view_ssz_bytes() {
+        let a = BitVector16::from_raw_bytes(smallvec![0b1100, 0b0001], 16).unwrap();
+        let bytes = a.as_ssz_bytes();
+        let view = BitVector16::view_ssz_bytes(&bytes).unwrap();
 
-        assert!(a.is_subset(&b));
-        assert!(a.is_subset(&c));
-        assert!(b.is_subset(&c));
+        assert_eq!(view, a.as_bit_slice());
+        assert_eq!(view.len(), 16);
+        assert_eq!(view.num_set_bits(), 3);
+        assert!(view.iter().eq(a.iter()));
 
-        assert!(!b.is_subset(&a));
-        assert!(!c.is_subset(&a));
-        assert!(!c.is_subset(&b));
+        assert_eq!(
+            BitVector16::view_ssz_bytes(&[0]),
+            Err(Error::InvalidByteCount {
+                given: 1,
+                expected: 2
+            })
+        );
+        assert_eq!(
+            BitVector4::view_ssz_bytes(&[0b0001_1111]),
+            Err(Error::ExcessBits)
+        );
+        assert!(BitVector4::view_ssz_bytes(&[0b0000_1111]).is_ok());
+        assert!(BitVector0::view_ssz_bytes(&[0b0000_0000])
+            .unwrap()
+            .is_zero());
+        assert!(BitVector0::view_ssz_bytes(&[0b0000_0001]).is_err());
     }
 
+    #[cfg(feature = "rand")]
     #[test]
-    fn union() {
-        let a = BitVector16::from_raw_bytes(smallvec![0b1100, 0b0001], 16).unwrap();
-        let b = BitVector16::from_raw_bytes(smallvec![0b1011, 0b1001], 16).unwrap();
-        let c = BitVector16::from_raw_bytes(smallvec![0b1111, 0b1001], 16).unwrap();
+    fn random() {
+        use rand::{rngs::StdRng, SeedableRng};
 
-        assert_eq!(a.union(&b), c);
-        assert_eq!(b.union(&a), c);
-        assert_eq!(a.union(&a), a);
-        assert_eq!(b.union(&b), b);
-        assert_eq!(c.union(&c), c);
+        let mut rng = StdRng::seed_from_u64(42);
+        assert!(BitVector100::random(0.0, &mut rng).is_zero());
+        assert_eq!(BitVector100::random(1.0, &mut rng).num_set_bits(), 100);
+
+        let b = BitVector100::random(0.5, &mut rng);
+        assert!(b.num_set_bits() > 20 && b.num_set_bits() < 80);
+        assert_round_trip(b);
     }
 
     #[test]
-    fn union_diff_length() {
-        let a = BitVector16::from_bytes(smallvec![0b0010_1011, 0b0010_1110]).unwrap();
-        let b = BitVector16::from_bytes(smallvec![0b0000_0001, 0b0010_1101]).unwrap();
-        let c = BitVector16::from_bytes(smallvec![0b0010_1011, 0b0010_1111]).unwrap();
+    fn into_bitlist() {
+        let a = BitVector16::from_raw_bytes(smallvec![0b1100, 0b0001], 16).unwrap();
+        let list = a.clone().into_bitlist();
 
-        assert_eq!(a.len(), c.len());
-        assert_eq!(a.union(&b), c);
-        assert_eq!(b.union(&a), c);
+        assert_eq!(list.len(), 16);
+        assert_eq!(list.as_slice(), a.as_slice());
+        assert_eq!(list.try_into_bitvector(), Ok(a));
+
+        let list = BitVector0::new().into_bitlist();
+        assert!(list.is_empty());
+        assert_eq!(list.as_ssz_bytes(), vec![0b0000_0001]);
     }
 
     #[test]
@@ -894,6 +1996,28 @@ mod bitvector {
         }
     }
 
+    #[test]
+    fn ssz_decode_is_canonical() {
+        // Any accepted encoding must re-encode to exactly the same bytes.
+        for byte in 0..=u8::MAX {
+            for bytes in [vec![byte], vec![byte, 0], vec![0, byte]] {
+                if let Ok(b) = BitVector4::from_ssz_bytes(&bytes) {
+                    assert_eq!(b.as_ssz_bytes(), bytes);
+                }
+                if let Ok(b) = BitVector8::from_ssz_bytes(&bytes) {
+                    assert_eq!(b.as_ssz_bytes(), bytes);
+                }
+                if let Ok(b) = BitVector12::from_ssz_bytes(&bytes) {
+                    assert_eq!(b.as_ssz_bytes(), bytes);
+                }
+            }
+        }
+
+        // Padding bits above `N` are rejected.
+        assert!(BitVector12::from_ssz_bytes(&[0, 0b0001_0000]).is_err());
+        assert!(BitVector12::from_ssz_bytes(&[0, 0b0000_1000]).is_ok());
+    }
+
     #[test]
     fn excess_bits_nimbus() {
         let bad = vec![0b0001_1111];
@@ -1002,6 +2126,21 @@ mod bitlist {
         assert!(BitList1024::from_ssz_bytes(&[0b1000_0000, 0, 0, 0, 0]).is_err());
     }
 
+    #[test]
+    fn ssz_decode_is_canonical() {
+        // Any accepted encoding must re-encode to exactly the same bytes.
+        for byte in 0..=u8::MAX {
+            for bytes in [vec![byte], vec![byte, 0], vec![0, byte], vec![byte, 1]] {
+                if let Ok(b) = BitList8::from_ssz_bytes(&bytes) {
+                    assert_eq!(b.as_ssz_bytes(), bytes);
+                }
+                if let Ok(b) = BitList16::from_ssz_bytes(&bytes) {
+                    assert_eq!(b.as_ssz_bytes(), bytes);
+                }
+            }
+        }
+    }
+
     #[test]
     fn ssz_round_trip() {
         assert_round_trip(BitList0::with_capacity(0).unwrap());
@@ -1246,6 +2385,45 @@ mod bitlist {
         );
     }
 
+    #[test]
+    fn lowest_set_bit() {
+        assert_eq!(
+            BitList1024::with_capacity(16).unwrap().lowest_set_bit(),
+            None
+        );
+        assert_eq!(
+            BitList1024::from_raw_bytes(smallvec![0b0000_0110, 0b0000_0001], 16)
+                .unwrap()
+                .lowest_set_bit(),
+            Some(1)
+        );
+        assert_eq!(
+            BitList1024::from_raw_bytes(smallvec![0b0000_0000, 0b1000_0000], 16)
+                .unwrap()
+                .lowest_set_bit(),
+            Some(15)
+        );
+    }
+
+    #[test]
+    fn highest_and_lowest_set_bit_multi_word() {
+        for len in [1, 63, 64, 65, 200, 1024] {
+            for i in [0, len / 2, len - 1] {
+                let a = BitList1024::from_indices(len, &[i]).unwrap();
+                assert_eq!(a.highest_set_bit(), Some(i), "len = {len}");
+                assert_eq!(a.lowest_set_bit(), Some(i), "len = {len}");
+            }
+
+            let a = BitList1024::from_indices(len, &[0, len - 1]).unwrap();
+            assert_eq!(a.lowest_set_bit(), Some(0));
+            assert_eq!(a.highest_set_bit(), Some(len - 1));
+        }
+
+        let a = crate::BitVector::<typenum::U100>::from_indices(&[37, 70]).unwrap();
+        assert_eq!(a.lowest_set_bit(), Some(37));
+        assert_eq!(a.highest_set_bit(), Some(70));
+    }
+
     #[test]
     fn intersection() {
         let a = BitList1024::from_raw_bytes(smallvec![0b1100, 0b0001], 16).unwrap();
@@ -1301,6 +2479,29 @@ mod bitlist {
         assert!(c.is_subset(&e));
     }
 
+    #[test]
+    fn disjoint_and_intersects_diff_length() {
+        let a = BitList1024::from_raw_bytes(smallvec![0b1100, 0b0001], 16).unwrap();
+        let b = BitList1024::from_raw_bytes(smallvec![0b0011, 0b1000, 0b0001], 24).unwrap();
+        let c = BitList1024::from_raw_bytes(smallvec![0b0000, 0b0000, 0b0001], 24).unwrap();
+
+        assert!(a.is_disjoint(&b));
+        assert!(b.is_disjoint(&a));
+        assert!(!a.intersects(&b));
+
+        // Bits beyond the end of `a` never intersect it.
+        assert!(a.is_disjoint(&c));
+        assert!(b.intersects(&c));
+        assert!(c.intersects(&b));
+
+        let mut long = BitList1024::with_capacity(1024).unwrap();
+        long.set(1000, true).unwrap();
+        assert!(a.is_disjoint(&long));
+        assert!(long.intersects(&long));
+        assert!(!long.is_subset(&a));
+        assert!(a.is_subset(&a.union(&long)));
+    }
+
     #[test]
     fn intersection_diff_length() {
         let a = BitList1024::from_bytes(smallvec![0b0010_1110, 0b0010_1011]).unwrap();
@@ -1345,6 +2546,113 @@ mod bitlist {
         assert_eq!(d.union(&a), d);
     }
 
+    #[test]
+    fn symmetric_difference() {
+        let a = BitList1024::from_raw_bytes(smallvec![0b1100, 0b0001], 16).unwrap();
+        let b = BitList1024::from_raw_bytes(smallvec![0b1011, 0b1001], 16).unwrap();
+        let c = BitList1024::from_raw_bytes(smallvec![0b0111, 0b1000], 16).unwrap();
+
+        assert_eq!(a.symmetric_difference(&b), c);
+        assert_eq!(b.symmetric_difference(&a), c);
+        assert!(a.symmetric_difference(&a).is_zero());
+    }
+
+    #[test]
+    fn symmetric_difference_diff_length() {
+        let a = BitList1024::from_raw_bytes(smallvec![0b0110, 0b1100, 0b0011], 24).unwrap();
+        let b = BitList1024::from_raw_bytes(smallvec![0b1011, 0b1001], 16).unwrap();
+        let c = BitList1024::from_raw_bytes(smallvec![0b1101, 0b0101, 0b0011], 24).unwrap();
+
+        assert_eq!(a.symmetric_difference(&b), c);
+        assert_eq!(b.symmetric_difference(&a), c);
+        assert_eq!(c.len(), 24);
+    }
+
+    #[test]
+    fn multi_word_diff_length() {
+        let mut a = BitList1024::with_capacity(150).unwrap();
+        let mut b = BitList1024::with_capacity(77).unwrap();
+        for i in (0..150).step_by(3) {
+            a.set(i, true).unwrap();
+        }
+        for i in (0..77).step_by(2) {
+            b.set(i, true).unwrap();
+        }
+
+        assert_eq!(a.union(&b).len(), 150);
+        assert_eq!(a.intersection(&b).len(), 77);
+        assert_eq!(a.difference(&b).len(), 150);
+        assert_eq!(a.symmetric_difference(&b).len(), 150);
+
+        for i in 0..150 {
+            let x = a.get(i).unwrap();
+            let y = b.get(i).unwrap_or(false);
+            assert_eq!(a.union(&b).get(i), Ok(x | y), "i = {}", i);
+            assert_eq!(a.difference(&b).get(i), Ok(x & !y), "i = {}", i);
+            assert_eq!(a.symmetric_difference(&b).get(i), Ok(x ^ y), "i = {}", i);
+            if i < 77 {
+                assert_eq!(a.intersection(&b).get(i), Ok(x & y), "i = {}", i);
+            }
+        }
+    }
+
+    #[test]
+    fn u64_words() {
+        let a = BitList1024::from_indices(130, &[0, 63, 64, 129]).unwrap();
+        assert_eq!(a.to_u64_words(), vec![1 | 1 << 63, 1, 1 << 1]);
+        assert!(a.iter_words().eq(a.to_u64_words()));
+
+        let b = BitList1024::with_capacity(0).unwrap();
+        assert_eq!(b.to_u64_words(), vec![0]);
+
+        let c = BitList1024::from_indices(8, &[7]).unwrap();
+        assert_eq!(c.to_u64_words(), vec![1 << 7]);
+    }
+
+    #[test]
+    fn overlap_and_hamming_distance_diff_length() {
+        let a = BitList1024::from_indices(200, &[0, 3, 64, 150, 199]).unwrap();
+        let b = BitList1024::from_indices(70, &[3, 4, 64, 69]).unwrap();
+
+        for (x, y) in [(&a, &b), (&b, &a)] {
+            assert_eq!(x.overlap_count(y), 2);
+            assert_eq!(x.overlap_count(y), x.intersection(y).num_set_bits());
+            assert_eq!(x.hamming_distance(y), 5);
+            assert_eq!(
+                x.hamming_distance(y),
+                x.symmetric_difference(y).num_set_bits()
+            );
+        }
+    }
+
+    #[test]
+    fn bitwise_ops_diff_length() {
+        let a = BitList1024::from_raw_bytes(smallvec![0b0110, 0b1100, 0b0011], 24).unwrap();
+        let b = BitList1024::from_raw_bytes(smallvec![0b1011, 0b1001], 16).unwrap();
+
+        for (x, y) in [(&a, &b), (&b, &a)] {
+            assert_eq!(x | y, x.union(y));
+            assert_eq!(x & y, x.intersection(y));
+            assert_eq!(x ^ y, x.symmetric_difference(y));
+            assert_eq!(x.clone() & y.clone(), x.intersection(y));
+
+            let mut z = x.clone();
+            z |= y;
+            assert_eq!(z, x.union(y));
+            assert_eq!(z.len(), 24);
+
+            let mut z = x.clone();
+            z &= y;
+            assert_eq!(z, x.intersection(y));
+            assert_eq!(z.len(), 16);
+
+            let mut z = x.clone();
+            z ^= y.clone();
+            assert_eq!(z, x.symmetric_difference(y));
+            assert_eq!(z.len(), 24);
+        }
+    }
+
     #[test]
     fn difference() {
         let a = BitList1024::from_raw_bytes(smallvec![0b1100, 0b0001], 16).unwrap();
@@ -1368,6 +2676,58 @@ mod bitlist {
         assert_eq!(b.difference(&a), b_a);
     }
 
+    #[test]
+    fn set_range() {
+        for len in [0, 1, 7, 8, 9, 63, 64, 65, 130] {
+            for start in 0..=len {
+                for end in [start, (start + len) / 2, len] {
+                    if end < start {
+                        continue;
+                    }
+                    let mut a = BitList1024::with_capacity(len).unwrap();
+                    a.set_range(start..end, true).unwrap();
+                    for i in 0..len {
+                        assert_eq!(
+                            a.get(i),
+                            Ok((start..end).contains(&i)),
+                            "{len} {start} {end}"
+                        );
+                    }
+                    assert_round_trip(a.clone());
+
+                    let mut b = BitList1024::with_capacity(len).unwrap();
+                    b.set_range(0..len, true).unwrap();
+                    b.set_range(start..end, false).unwrap();
+                    for i in 0..len {
+                        assert_eq!(
+                            b.get(i),
+                            Ok(!(start..end).contains(&i)),
+                            "{len} {start} {end}"
+                        );
+                    }
+                }
+            }
+        }
+
+        let mut a = BitList1024::with_capacity(16).unwrap();
+        assert_eq!(
+            a.set_range(4..17, true),
+            Err(Error::OutOfBounds { i: 17, len: 16 })
+        );
+        #[allow(clippy::reversed_empty_ranges)]
+        let reversed = 5..4;
+        assert!(a.set_range(reversed, true).is_err());
+        assert!(a.is_zero());
+    }
+
+    #[test]
+    fn clear() {
+        let mut a = BitList1024::from_raw_bytes(smallvec![0b1100_1111, 0b0101_0110], 16).unwrap();
+        a.clear();
+        assert!(a.is_zero());
+        assert_eq!(a.len(), 16);
+    }
+
     #[test]
     fn shift_up() {
         let mut a = BitList1024::from_raw_bytes(smallvec![0b1100_1111, 0b1101_0110], 16).unwrap();
@@ -1390,6 +2750,14 @@ mod bitlist {
 
         assert_eq!(a.num_set_bits(), 3);
         assert_eq!(b.num_set_bits(), 5);
+
+        for len in [0, 1, 63, 64, 65, 130, 1024] {
+            let mut c = BitList1024::with_capacity(len).unwrap();
+            for i in (0..len).step_by(3) {
+                c.set(i, true).unwrap();
+            }
+            assert_eq!(c.num_set_bits(), c.iter().filter(|bit| *bit).count());
+        }
     }
 
     #[test]
@@ -1404,6 +2772,31 @@ mod bitlist {
         );
     }
 
+    #[test]
+    fn try_from_iter() {
+        use ssz::TryFromIter;
+
+        let empty = BitList8::try_from_iter(std::iter::empty()).unwrap();
+        assert_eq!(empty, BitList8::with_capacity(0).unwrap());
+
+        for len in 0..=16 {
+            let bits = (0..len).map(|i| i % 3 == 0);
+            let bitfield = BitList16::try_from_iter(bits.clone()).unwrap();
+
+            let mut expected = BitList16::with_capacity(len).unwrap();
+            for (i, bit) in bits.enumerate() {
+                expected.set(i, bit).unwrap();
+            }
+            assert_eq!(bitfield, expected);
+            assert_round_trip(bitfield);
+        }
+
+        assert_eq!(
+            BitList8::try_from_iter(std::iter::repeat(true)),
+            Err(Error::OutOfBounds { i: 8, len: 8 })
+        );
+    }
+
     #[test]
     fn ssz_bytes_len() {
         for i in 1..64 {
@@ -1422,6 +2815,193 @@ mod bitlist {
         assert_eq!(std::mem::size_of::<BitList1024>(), SMALLVEC_LEN + 24);
     }
 
+    #[test]
+    fn serde_hex() {
+        let mut a = BitList16::with_capacity(10).unwrap();
+        a.set(0, true).unwrap();
+        a.set(9, true).unwrap();
+        let json = serde_json::to_string(&a).unwrap();
+        assert_eq!(json, r#""0x0106""#);
+        assert_eq!(serde_json::from_str::<BitList16>(&json).unwrap(), a);
+
+        // Missing length bit.
+        assert!(serde_json::from_str::<BitList16>(r#""0x0100""#).is_err());
+        // Trailing zero byte after the length bit.
+        assert!(serde_json::from_str::<BitList16>(r#""0x010600""#).is_err());
+        // Longer than `N`.
+        assert!(serde_json::from_str::<BitList8>(r#""0x0106""#).is_err());
+    }
+
+    #[test]
+    fn resize_in_place() {
+        let mut a = BitList16::with_capacity(3).unwrap();
+        a.set(1, true).unwrap();
+
+        a.resize_in_place(12, true).unwrap();
+        assert_eq!(a.len(), 12);
+        assert_eq!(
+            a.iter().collect::<Vec<_>>(),
+            [vec![false, true, false], vec![true; 9]].concat()
+        );
+
+        a.resize_in_place(16, false).unwrap();
+        assert_eq!(a.len(), 16);
+        assert_eq!(a.num_set_bits(), 10);
+
+        a.resize_in_place(5, false).unwrap();
+        assert_eq!(a, BitList16::from_raw_bytes(smallvec![0b11010], 5).unwrap());
+        assert_round_trip(a.clone());
+
+        a.resize_in_place(8, false).unwrap();
+        assert_eq!(a, BitList16::from_raw_bytes(smallvec![0b11010], 8).unwrap());
+
+        a.resize_in_place(0, true).unwrap();
+        assert_eq!(a, BitList16::with_capacity(0).unwrap());
+
+        assert_eq!(
+            a.resize_in_place(17, true),
+            Err(Error::OutOfBounds { i: 17, len: 16 })
+        );
+        assert!(a.is_empty());
+    }
+
+    #[test]
+    fn resize_in_place_to_byte_boundary() {
+        let mut a = BitList16::from_raw_bytes(smallvec![0xff, 0xff], 16).unwrap();
+        a.resize_in_place(8, false).unwrap();
+        assert_eq!(a, BitList16::from_raw_bytes(smallvec![0xff], 8).unwrap());
+        assert_eq!(a.num_set_bits(), 8);
+
+        let bytes = [0b1010_0101, 0b0011_1100, 0b1111_0000];
+        let mut a = BitList1024::from_raw_bytes(bytes.into_iter().collect(), 24).unwrap();
+        a.resize_in_place(16, true).unwrap();
+        assert_eq!(
+            a,
+            BitList1024::from_raw_bytes(bytes[..2].iter().copied().collect(), 16).unwrap()
+        );
+        assert_round_trip(a);
+    }
+
+    #[test]
+    fn extend_from_bitlist() {
+        let mut a = BitList1024::from_raw_bytes(smallvec![0b0000_0101], 3).unwrap();
+        let b = BitList16::from_raw_bytes(smallvec![0b1000_0001, 0b0000_0001], 9).unwrap();
+
+        a.extend_from_bitlist(&b).unwrap();
+        assert_eq!(a.len(), 12);
+        assert_eq!(
+            a.iter().collect::<Vec<_>>(),
+            a.iter().take(3).chain(b.iter()).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            a,
+            BitList1024::from_raw_bytes(smallvec![0b0000_1101, 0b0000_1100], 12).unwrap()
+        );
+
+        // Byte-aligned.
+        let mut c = BitList1024::from_raw_bytes(smallvec![0b1111_0000], 8).unwrap();
+        c.extend_from_bitlist(&b).unwrap();
+        assert_eq!(
+            c,
+            BitList1024::from_raw_bytes(smallvec![0b1111_0000, 0b1000_0001, 0b0000_0001], 17)
+                .unwrap()
+        );
+
+        // Extending with an empty list is a no-op.
+        c.extend_from_bitlist(&BitList0::with_capacity(0).unwrap())
+            .unwrap();
+        assert_eq!(c.len(), 17);
+
+        let mut d = BitList8::with_capacity(0).unwrap();
+        assert!(d.extend_from_bitlist(&b).is_err());
+        assert!(d.is_empty());
+    }
+
+    #[test]
+    fn indices() {
+        let a = BitList1024::from_indices(200, &[199, 1, 64, 1]).unwrap();
+        assert_eq!(a.len(), 200);
+        assert_eq!(a.to_indices(), vec![1, 64, 199]);
+
+        let b = BitList1024::from_raw_bytes(smallvec![0b1011, 0b1001], 16).unwrap();
+        assert_eq!(b.to_indices(), vec![0, 1, 3, 8, 11]);
+        assert_eq!(BitList1024::from_indices(16, &b.to_indices()), Ok(b));
+
+        assert!(BitList16::from_indices(17, &[]).is_err());
+        assert_eq!(
+            BitList16::from_indices(4, &[4]),
+            Err(Error::OutOfBounds { i: 4, len: 4 })
+        );
+    }
+
+    #[test]
+    fn view_ssz_bytes() {
+        for len in 0..=16 {
+            let mut a = BitList16::with_capacity(len).unwrap();
+            for i in (0..len).step_by(3) {
+                a.set(i, true).unwrap();
+            }
+            let bytes = a.as_ssz_bytes();
+            let view = BitList16::view_ssz_bytes(&bytes).unwrap();
+
+            // The length bit is not included in the view.
+            assert_eq!(view, a.as_bit_slice());
+            assert_eq!(view.len(), len);
+            assert_eq!(view.num_set_bits(), a.num_set_bits());
+            assert_eq!(view.is_zero(), a.is_zero());
+            assert_eq!(view.iter().len(), len);
+            assert!(view.iter().eq(a.iter()));
+            assert!(view.into_iter().eq(a.as_bit_slice().iter()));
+        }
+
+        assert_eq!(
+            BitList16::view_ssz_bytes(&[]),
+            Err(Error::MissingLengthInformation)
+        );
+        assert_eq!(
+            BitList16::view_ssz_bytes(&[0b0000_0001, 0b0000_0000]),
+            Err(Error::MissingLengthInformation)
+        );
+        assert_eq!(
+            BitList8::view_ssz_bytes(&[0b0000_0000, 0b0000_0010]),
+            Err(Error::OutOfBounds { i: 9, len: 8 })
+        );
+        assert!(BitList0::view_ssz_bytes(&[0b0000_0001]).unwrap().is_empty());
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn random() {
+        use rand::{rngs::StdRng, SeedableRng};
+
+        let mut rng = StdRng::seed_from_u64(42);
+        for len in [0, 1, 9, 100, 1024] {
+            let b = BitList1024::random(len, 1.0, &mut rng).unwrap();
+            assert_eq!(b.len(), len);
+            assert_eq!(b.num_set_bits(), len);
+            assert_round_trip(b);
+
+            let b = BitList1024::random(len, 0.3, &mut rng).unwrap();
+            assert_eq!(b.len(), len);
+            assert_round_trip(b);
+        }
+
+        assert!(BitList16::random(17, 0.5, &mut rng).is_err());
+    }
+
+    #[test]
+    fn try_into_bitvector() {
+        let a = BitList16::from_raw_bytes(smallvec![0b1100, 0b0001], 16).unwrap();
+        let vector = a.clone().try_into_bitvector().unwrap();
+        assert_eq!(vector.as_slice(), a.as_slice());
+        assert_eq!(vector.into_bitlist(), a);
+
+        assert_eq!(
+            BitList16::with_capacity(15).unwrap().try_into_bitvector(),
+            Err(Error::OutOfBounds { i: 15, len: 16 })
+        );
+    }
+
     #[test]
     fn resize() {
         let mut bit_list = BitList1::with_capacity(1).unwrap();
@@ -1439,3 +3019,56 @@ mod bitlist {
         resized_bit_list.resize::<typenum::U16>().unwrap_err();
     }
 }
+
+#[cfg(test)]
+mod dynamic_bitvector {
+    use super::*;
+    use crate::{BitVector, DynamicBitVector};
+
+    #[test]
+    fn with_len() {
+        for len in [0, 1, 8, 9, 100] {
+            let b = DynamicBitVector::with_len(len);
+            assert_eq!(b.len(), len);
+            assert!(b.is_zero());
+            assert!(b.get(len).is_err());
+        }
+    }
+
+    #[test]
+    fn bytes_round_trip() {
+        let mut b = DynamicBitVector::with_len(12);
+        b.set(0, true).unwrap();
+        b.set(11, true).unwrap();
+
+        let bytes = b.clone().into_bytes();
+        assert_eq!(&bytes[..], &[0b0000_0001, 0b0000_1000]);
+        assert_eq!(DynamicBitVector::from_bytes(bytes.clone(), 12), Ok(b));
+
+        // The length must match the number of bytes and padding bits must be zero.
+        assert!(DynamicBitVector::from_bytes(bytes.clone(), 16).is_ok());
+        assert!(DynamicBitVector::from_bytes(bytes.clone(), 8).is_err());
+        assert!(DynamicBitVector::from_bytes(bytes, 11).is_err());
+    }
+
+    #[test]
+    fn bitvector_conversion() {
+        let typed = BitVector::<typenum::U16>::from_indices(&[1, 15]).unwrap();
+        let dynamic = DynamicBitVector::from(typed.clone());
+
+        assert_eq!(dynamic.len(), 16);
+        assert_eq!(dynamic.to_indices(), vec![1, 15]);
+        assert_eq!(
+            BitVector::<typenum::U16>::try_from(dynamic.clone()),
+            Ok(typed.clone())
+        );
+        assert_eq!(
+            BitVector::<typenum::U8>::try_from(dynamic),
+            Err(Error::OutOfBounds { i: 16, len: 8 })
+        );
+        assert_eq!(
+            typed.as_ssz_bytes(),
+            DynamicBitVector::from(typed).into_bytes().to_vec()
+        );
+    }
+}
@@ -0,0 +1,412 @@
+use crate::beacon_block::ByteVector;
+use crate::{Error, MemorySize, MerklePath, MerkleProof, SszReflect, SszSchema};
+use ssz_derive::{Decode, Encode};
+use std::fmt;
+use std::str::FromStr;
+use tree_hash::{Hash256, TreeHash};
+
+#[cfg(feature = "bls")]
+use blst::min_pk::AggregateSignature;
+#[cfg(feature = "bls")]
+pub use blst::min_pk::{PublicKey, Signature};
+
+/// The modulus of the base field of BLS12-381, in big-endian bytes.
+const FIELD_MODULUS: [u8; 48] = [
+    0x1a, 0x01, 0x11, 0xea, 0x39, 0x7f, 0xe6, 0x9a, 0x4b, 0x1b, 0xa7, 0xb6, 0x43, 0x4b, 0xac, 0xd7,
+    0x64, 0x77, 0x4b, 0x84, 0xf3, 0x85, 0x12, 0xbf, 0x67, 0x30, 0xd2, 0xa0, 0xf6, 0xb0, 0xf6, 0x24,
+    0x1e, 0xab, 0xff, 0xfe, 0xb1, 0x53, 0xff, 0xff, 0xb9, 0xfe, 0xff, 0xff, 0xff, 0xff, 0xaa, 0xab,
+];
+
+/// The flags in the 3 most significant bits of a compressed point.
+const COMPRESSION_FLAG: u8 = 0x80;
+const INFINITY_FLAG: u8 = 0x40;
+const SIGN_FLAG: u8 = 0x20;
+
+/// True if `bytes` is the compressed encoding of the point at infinity.
+fn is_infinity(bytes: &[u8]) -> bool {
+    bytes[0] == COMPRESSION_FLAG | INFINITY_FLAG && bytes[1..].iter().all(|&byte| byte == 0)
+}
+
+/// True if `bytes` is well formed as a compressed point, whose coordinates are one (G1) or two
+/// (G2) field elements: the compression flag is set, the point at infinity has no other bits set,
+/// and every coordinate is below the field modulus.
+///
+/// This does not check that the point is on the curve or in the subgroup, which requires the
+/// point to be decompressed.
+fn is_well_formed(bytes: &[u8]) -> bool {
+    if bytes[0] & COMPRESSION_FLAG == 0 {
+        return false;
+    }
+    if bytes[0] & INFINITY_FLAG != 0 {
+        return is_infinity(bytes);
+    }
+
+    bytes
+        .chunks(FIELD_MODULUS.len())
+        .enumerate()
+        .all(|(i, coordinate)| {
+            let mut coordinate = coordinate.to_vec();
+            if i == 0 {
+                coordinate[0] &= !(COMPRESSION_FLAG | INFINITY_FLAG | SIGN_FLAG);
+            }
+            coordinate.as_slice() < FIELD_MODULUS.as_slice()
+        })
+}
+
+/// Implements the methods and traits shared by the compressed points, which encode, hash and
+/// prove like the `ByteVector` they wrap.
+macro_rules! impl_compressed_point {
+    ($type: ident, $inner: ty, $len: expr) => {
+        impl $type {
+            /// Returns the point with the given compressed bytes.
+            pub fn new(bytes: [u8; $len]) -> Self {
+                Self(<$inner>::new(bytes.to_vec()).expect("the length is that of the point"))
+            }
+
+            /// Returns the point with the compressed bytes in `bytes`.
+            ///
+            /// Returns `Err` if `bytes` does not have the length of a compressed point.
+            pub fn from_slice(bytes: &[u8]) -> Result<Self, Error> {
+                <$inner>::new(bytes.to_vec())
+                    .map(Self)
+                    .map_err(|_| Error::InvalidByteCount {
+                        given: bytes.len(),
+                        expected: $len,
+                    })
+            }
+
+            /// Returns the compressed bytes of the point.
+            pub fn as_bytes(&self) -> &[u8] {
+                &self.0
+            }
+
+            /// True if the bytes are the compressed encoding of the point at infinity.
+            pub fn is_infinity(&self) -> bool {
+                is_infinity(&self.0)
+            }
+
+            /// True if the bytes are well formed as a compressed point: the compression flag
+            /// is set, the point at infinity has no other bits set, and the coordinates are
+            /// below the field modulus.
+            ///
+            /// This is a cheap check of the format only. Whether the point is on the curve and
+            /// in the subgroup is only known once the point is decompressed.
+            pub fn is_well_formed(&self) -> bool {
+                is_well_formed(&self.0)
+            }
+        }
+
+        impl TreeHash for $type {
+            fn tree_hash_type() -> tree_hash::TreeHashType {
+                <$inner>::tree_hash_type()
+            }
+
+            fn tree_hash_packed_encoding(&self) -> tree_hash::PackedEncoding {
+                self.0.tree_hash_packed_encoding()
+            }
+
+            fn tree_hash_packing_factor() -> usize {
+                <$inner>::tree_hash_packing_factor()
+            }
+
+            fn tree_hash_root(&self) -> Hash256 {
+                self.0.tree_hash_root()
+            }
+        }
+
+        impl MerkleProof for $type {
+            fn tree_hash_node(&self, gindex: u64) -> Result<Hash256, Error> {
+                self.0.tree_hash_node(gindex)
+            }
+        }
+
+        impl MerklePath for $type {
+            fn gindex_of(path: &[&str]) -> Result<u64, Error> {
+                <$inner>::gindex_of(path)
+            }
+        }
+
+        impl SszReflect for $type {
+            fn ssz_schema() -> SszSchema {
+                <$inner>::ssz_schema()
+            }
+        }
+
+        impl MemorySize for $type {
+            fn heap_size(&self) -> usize {
+                self.0.heap_size()
+            }
+        }
+
+        /// Formats the compressed bytes as `0x`-prefixed hex.
+        impl fmt::Display for $type {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Display::fmt(&self.0, f)
+            }
+        }
+
+        impl fmt::LowerHex for $type {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::LowerHex::fmt(&self.0, f)
+            }
+        }
+
+        impl FromStr for $type {
+            type Err = Error;
+
+            /// Parses `0x`-prefixed hex of exactly the length of a compressed point. The bytes
+            /// are not checked to be well formed.
+            fn from_str(hex: &str) -> Result<Self, Error> {
+                hex.parse().map(Self)
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl serde::Serialize for $type {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                crate::serde_utils::hex_fixed_vec::serialize(&self.0, serializer)
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<'de> serde::Deserialize<'de> for $type {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                crate::serde_utils::hex_fixed_vec::deserialize(deserializer).map(Self)
+            }
+        }
+
+        #[cfg(feature = "schemars")]
+        impl schemars::JsonSchema for $type {
+            fn schema_name() -> String {
+                stringify!($type).to_string()
+            }
+
+            fn json_schema(_: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+                crate::json_schema::hex_schema($len, $len)
+            }
+        }
+    };
+}
+
+/// The compressed bytes of a BLS public key, i.e. a point of G1.
+///
+/// The bytes are not checked to be a valid point when they are decoded, so that containers with
+/// invalid keys can still be decoded and hashed. `is_well_formed` checks the format of the
+/// bytes, and with the `bls` feature `decompress` checks that they are a valid key.
+///
+/// ## Example
+///
+/// ```
+/// use ssz_types::PublicKeyBytes;
+///
+/// let mut bytes = [0; 48];
+/// bytes[0] = 0xc0;
+/// let infinity = PublicKeyBytes::new(bytes);
+/// assert!(infinity.is_infinity());
+/// assert!(infinity.is_well_formed());
+///
+/// // Without the compression flag, the bytes are not a compressed point.
+/// assert!(!PublicKeyBytes::default().is_well_formed());
+/// ```
+#[derive(Clone, Default, PartialEq, Eq, Hash, Debug, Encode, Decode)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[ssz(struct_behaviour = "transparent")]
+pub struct PublicKeyBytes(ByteVector<typenum::U48>);
+
+/// The compressed bytes of a BLS signature, i.e. a point of G2.
+///
+/// As with `PublicKeyBytes`, the bytes are not checked when they are decoded.
+#[derive(Clone, Default, PartialEq, Eq, Hash, Debug, Encode, Decode)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[ssz(struct_behaviour = "transparent")]
+pub struct SignatureBytes(ByteVector<typenum::U96>);
+
+impl_compressed_point!(PublicKeyBytes, ByteVector<typenum::U48>, 48);
+impl_compressed_point!(SignatureBytes, ByteVector<typenum::U96>, 96);
+
+#[cfg(feature = "bls")]
+impl PublicKeyBytes {
+    /// Returns the decompressed public key, as per `KeyValidate` in the BLS signature draft: the
+    /// point must be in the G1 subgroup, and not the point at infinity.
+    pub fn decompress(&self) -> Result<PublicKey, Error> {
+        PublicKey::key_validate(self.as_bytes()).map_err(|_| Error::InvalidBlsPoint)
+    }
+}
+
+#[cfg(feature = "bls")]
+impl SignatureBytes {
+    /// Returns the decompressed signature, whose point must be in the G2 subgroup. The point at
+    /// infinity is allowed, since it is e.g. the signature of an empty sync aggregate.
+    pub fn decompress(&self) -> Result<Signature, Error> {
+        Signature::sig_validate(self.as_bytes(), false).map_err(|_| Error::InvalidBlsPoint)
+    }
+
+    /// Aggregates `other` into the signature, so that it is the signature of the signers of both
+    /// over the same message.
+    pub fn aggregate(&mut self, other: &Self) -> Result<(), Error> {
+        let mut aggregate = AggregateSignature::from_signature(&self.decompress()?);
+        aggregate
+            .add_signature(&other.decompress()?, false)
+            .map_err(|_| Error::InvalidBlsPoint)?;
+        *self = Self::new(aggregate.to_signature().compress());
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// The public key of the first validator of the interop keys used by local testnets.
+    const PUBLIC_KEY: &str = concat!(
+        "0xa99a76ed7796f7be22d5b7e85deeb7c5677e88e511e0b337",
+        "618f8c4eb61349b4bf2d153f649f7b53359fe8b94a38e44c"
+    );
+
+    fn public_key() -> PublicKeyBytes {
+        PublicKeyBytes::from_slice(&serde_utils::hex::decode(PUBLIC_KEY).unwrap()).unwrap()
+    }
+
+    fn infinity<T, const N: usize>(new: fn([u8; N]) -> T) -> T {
+        let mut bytes = [0; N];
+        bytes[0] = 0xc0;
+        new(bytes)
+    }
+
+    #[test]
+    fn hex() {
+        let public_key = public_key();
+        assert_eq!(public_key.to_string(), PUBLIC_KEY);
+        assert_eq!(format!("{:x}", public_key), PUBLIC_KEY[2..]);
+        assert_eq!(
+            format!("{:?}", public_key),
+            format!("PublicKeyBytes({PUBLIC_KEY})")
+        );
+        assert_eq!(PUBLIC_KEY.parse(), Ok(public_key));
+
+        // A public key is too short for a signature.
+        assert_eq!(
+            PUBLIC_KEY.parse::<SignatureBytes>(),
+            Err(Error::InvalidHex {
+                hex: PUBLIC_KEY.into()
+            })
+        );
+        let signature = infinity(SignatureBytes::new);
+        assert_eq!(signature.to_string().parse(), Ok(signature));
+    }
+
+    #[test]
+    fn formats() {
+        assert!(public_key().is_well_formed());
+        assert!(!public_key().is_infinity());
+
+        assert!(infinity(PublicKeyBytes::new).is_infinity());
+        assert!(infinity(PublicKeyBytes::new).is_well_formed());
+        assert!(infinity(SignatureBytes::new).is_infinity());
+        assert!(infinity(SignatureBytes::new).is_well_formed());
+
+        // The point at infinity with the sign flag or a coordinate set.
+        let mut bytes = [0; 96];
+        bytes[0] = 0xe0;
+        assert!(!SignatureBytes::new(bytes).is_well_formed());
+        bytes[0] = 0xc0;
+        bytes[95] = 1;
+        assert!(!SignatureBytes::new(bytes).is_well_formed());
+        assert!(!SignatureBytes::new(bytes).is_infinity());
+
+        // Coordinates at the field modulus.
+        let mut bytes = [0; 48];
+        bytes.copy_from_slice(&FIELD_MODULUS);
+        bytes[0] |= COMPRESSION_FLAG;
+        assert!(!PublicKeyBytes::new(bytes).is_well_formed());
+        bytes[47] -= 1;
+        assert!(PublicKeyBytes::new(bytes).is_well_formed());
+
+        let mut bytes = [0; 96];
+        bytes[0] = COMPRESSION_FLAG;
+        bytes[48..].copy_from_slice(&FIELD_MODULUS);
+        assert!(!SignatureBytes::new(bytes).is_well_formed());
+
+        assert!(!PublicKeyBytes::default().is_well_formed());
+        assert_eq!(
+            PublicKeyBytes::from_slice(&[0; 47]),
+            Err(Error::InvalidByteCount {
+                given: 47,
+                expected: 48
+            })
+        );
+    }
+
+    #[test]
+    fn transparent_encoding() {
+        use ssz::Encode;
+
+        let public_key = public_key();
+        let vector = ByteVector::<typenum::U48>::new(public_key.as_bytes().to_vec()).unwrap();
+        assert_eq!(public_key.as_ssz_bytes(), vector.as_ssz_bytes());
+        assert_eq!(public_key.tree_hash_root(), vector.tree_hash_root());
+        assert_eq!(public_key.tree_hash_node(2), vector.tree_hash_node(2));
+
+        let signature = SignatureBytes::new([7; 96]);
+        let vector = ByteVector::<typenum::U96>::new(vec![7; 96]).unwrap();
+        assert_eq!(signature.tree_hash_root(), vector.tree_hash_root());
+        assert_eq!(signature.tree_hash_node(4), vector.tree_hash_node(4));
+    }
+
+    #[cfg(feature = "bls")]
+    #[test]
+    fn decompress() {
+        use blst::min_pk::SecretKey;
+
+        assert!(public_key().decompress().is_ok());
+        assert_eq!(
+            infinity(PublicKeyBytes::new).decompress(),
+            Err(Error::InvalidBlsPoint)
+        );
+        assert!(infinity(SignatureBytes::new).decompress().is_ok());
+
+        let secret_key = SecretKey::key_gen(&[1; 32], &[]).unwrap();
+        let signature = SignatureBytes::new(secret_key.sign(b"message", b"", &[]).compress());
+        assert!(signature.is_well_formed());
+        assert!(signature.decompress().is_ok());
+
+        // A well formed point which is not on the curve.
+        let mut bytes = [0; 48];
+        bytes[0] = COMPRESSION_FLAG;
+        bytes[47] = 1;
+        let invalid = PublicKeyBytes::new(bytes);
+        assert!(invalid.is_well_formed());
+        assert_eq!(invalid.decompress(), Err(Error::InvalidBlsPoint));
+    }
+
+    #[cfg(feature = "bls")]
+    #[test]
+    fn aggregate() {
+        use blst::min_pk::SecretKey;
+
+        let signatures: Vec<_> = (1..=2)
+            .map(|seed| {
+                let secret_key = SecretKey::key_gen(&[seed; 32], &[]).unwrap();
+                secret_key.sign(b"message", b"", &[])
+            })
+            .collect();
+        let expected = AggregateSignature::aggregate(&[&signatures[0], &signatures[1]], true)
+            .unwrap()
+            .to_signature();
+
+        let mut signature = SignatureBytes::new(signatures[0].compress());
+        signature
+            .aggregate(&SignatureBytes::new(signatures[1].compress()))
+            .unwrap();
+        assert_eq!(signature.as_bytes(), expected.compress());
+
+        // The point at infinity is the identity of aggregation.
+        signature.aggregate(&infinity(SignatureBytes::new)).unwrap();
+        assert_eq!(signature.as_bytes(), expected.compress());
+
+        assert_eq!(
+            signature.aggregate(&SignatureBytes::default()),
+            Err(Error::InvalidBlsPoint)
+        );
+    }
+}
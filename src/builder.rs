@@ -0,0 +1,641 @@
+use crate::beacon_block::{H256, U256};
+use crate::{
+    Blob, ExecutionAddress, ExecutionPayloadDeneb, ExecutionPayloadHeaderBellatrix,
+    ExecutionPayloadHeaderCapella, ExecutionPayloadHeaderDeneb, ExecutionRequests, ForkName,
+    KzgCommitment, KzgProof, Mainnet, Preset, PublicKeyBytes, SignatureBytes, SignedRoot,
+    VariableList,
+};
+use ssz::{Decode, DecodeError, Encode};
+use ssz_derive::{Decode, Encode};
+use tree_hash::Hash256;
+use tree_hash_derive::TreeHash;
+
+/// The registration of a validator with builders, with the fee recipient and gas limit of the
+/// payloads they should build for it.
+#[derive(Clone, Default, Encode, Decode, TreeHash, PartialEq, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[ssz(struct_behaviour = "container")]
+pub struct ValidatorRegistration {
+    pub fee_recipient: ExecutionAddress,
+    #[cfg_attr(feature = "serde", serde(with = "serde_utils::quoted_u64"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "crate::json_schema::Quoted<u64>")
+    )]
+    pub gas_limit: u64,
+    #[cfg_attr(feature = "serde", serde(with = "serde_utils::quoted_u64"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "crate::json_schema::Quoted<u64>")
+    )]
+    pub timestamp: u64,
+    pub pubkey: PublicKeyBytes,
+}
+
+/// A `ValidatorRegistration` signed by the validator in the domain of `compute_builder_domain`.
+#[derive(Clone, Default, Encode, Decode, TreeHash, PartialEq, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[ssz(struct_behaviour = "container")]
+pub struct SignedValidatorRegistration {
+    pub message: ValidatorRegistration,
+    pub signature: SignatureBytes,
+}
+
+/// The bid of a builder for the payload of a block of Bellatrix, whose `value` is paid to the
+/// fee recipient of the proposer in wei.
+#[derive(Clone, Default, Encode, Decode, TreeHash, PartialEq, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(bound = "P: Preset"))]
+#[cfg_attr(
+    feature = "schemars",
+    schemars(bound = "P: Preset + schemars::JsonSchema")
+)]
+#[ssz(struct_behaviour = "container")]
+pub struct BuilderBidBellatrix<P: Preset = Mainnet> {
+    pub header: ExecutionPayloadHeaderBellatrix<P>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::quoted_u256"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "crate::json_schema::Quoted<U256>")
+    )]
+    pub value: U256,
+    pub pubkey: PublicKeyBytes,
+}
+
+#[derive(Clone, Default, Encode, Decode, TreeHash, PartialEq, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(bound = "P: Preset"))]
+#[cfg_attr(
+    feature = "schemars",
+    schemars(bound = "P: Preset + schemars::JsonSchema")
+)]
+#[ssz(struct_behaviour = "container")]
+pub struct BuilderBidCapella<P: Preset = Mainnet> {
+    pub header: ExecutionPayloadHeaderCapella<P>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::quoted_u256"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "crate::json_schema::Quoted<U256>")
+    )]
+    pub value: U256,
+    pub pubkey: PublicKeyBytes,
+}
+
+/// The bid of a builder for the payload of a block of Deneb, which commits to the blobs of the
+/// payload.
+#[derive(Clone, Default, Encode, Decode, TreeHash, PartialEq, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(bound = "P: Preset"))]
+#[cfg_attr(
+    feature = "schemars",
+    schemars(bound = "P: Preset + schemars::JsonSchema")
+)]
+#[ssz(struct_behaviour = "container")]
+pub struct BuilderBidDeneb<P: Preset = Mainnet> {
+    pub header: ExecutionPayloadHeaderDeneb<P>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_utils::list_of_hex_fixed_vec")
+    )]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(
+            with = "crate::json_schema::HexItems<VariableList<KzgCommitment, P::MaxBlobCommitmentsPerBlock>>"
+        )
+    )]
+    pub blob_kzg_commitments: VariableList<KzgCommitment, P::MaxBlobCommitmentsPerBlock>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::quoted_u256"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "crate::json_schema::Quoted<U256>")
+    )]
+    pub value: U256,
+    pub pubkey: PublicKeyBytes,
+}
+
+/// The bid of a builder for the payload of a block of Electra, which also holds the execution
+/// requests of the payload.
+#[derive(Clone, Default, Encode, Decode, TreeHash, PartialEq, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(bound = "P: Preset"))]
+#[cfg_attr(
+    feature = "schemars",
+    schemars(bound = "P: Preset + schemars::JsonSchema")
+)]
+#[ssz(struct_behaviour = "container")]
+pub struct BuilderBidElectra<P: Preset = Mainnet> {
+    pub header: ExecutionPayloadHeaderDeneb<P>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_utils::list_of_hex_fixed_vec")
+    )]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(
+            with = "crate::json_schema::HexItems<VariableList<KzgCommitment, P::MaxBlobCommitmentsPerBlock>>"
+        )
+    )]
+    pub blob_kzg_commitments: VariableList<KzgCommitment, P::MaxBlobCommitmentsPerBlock>,
+    pub execution_requests: ExecutionRequests<P>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::quoted_u256"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "crate::json_schema::Quoted<U256>")
+    )]
+    pub value: U256,
+    pub pubkey: PublicKeyBytes,
+}
+
+#[derive(Clone, Default, Encode, Decode, TreeHash, PartialEq, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(bound = "P: Preset"))]
+#[cfg_attr(
+    feature = "schemars",
+    schemars(bound = "P: Preset + schemars::JsonSchema")
+)]
+#[ssz(struct_behaviour = "container")]
+pub struct SignedBuilderBidBellatrix<P: Preset = Mainnet> {
+    pub message: BuilderBidBellatrix<P>,
+    pub signature: SignatureBytes,
+}
+
+#[derive(Clone, Default, Encode, Decode, TreeHash, PartialEq, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(bound = "P: Preset"))]
+#[cfg_attr(
+    feature = "schemars",
+    schemars(bound = "P: Preset + schemars::JsonSchema")
+)]
+#[ssz(struct_behaviour = "container")]
+pub struct SignedBuilderBidCapella<P: Preset = Mainnet> {
+    pub message: BuilderBidCapella<P>,
+    pub signature: SignatureBytes,
+}
+
+#[derive(Clone, Default, Encode, Decode, TreeHash, PartialEq, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(bound = "P: Preset"))]
+#[cfg_attr(
+    feature = "schemars",
+    schemars(bound = "P: Preset + schemars::JsonSchema")
+)]
+#[ssz(struct_behaviour = "container")]
+pub struct SignedBuilderBidDeneb<P: Preset = Mainnet> {
+    pub message: BuilderBidDeneb<P>,
+    pub signature: SignatureBytes,
+}
+
+#[derive(Clone, Default, Encode, Decode, TreeHash, PartialEq, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(bound = "P: Preset"))]
+#[cfg_attr(
+    feature = "schemars",
+    schemars(bound = "P: Preset + schemars::JsonSchema")
+)]
+#[ssz(struct_behaviour = "container")]
+pub struct SignedBuilderBidElectra<P: Preset = Mainnet> {
+    pub message: BuilderBidElectra<P>,
+    pub signature: SignatureBytes,
+}
+
+/// The blobs of a payload of Deneb, with their commitments and proofs.
+#[derive(Clone, Default, Encode, Decode, TreeHash, PartialEq, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(bound = "P: Preset"))]
+#[cfg_attr(
+    feature = "schemars",
+    schemars(bound = "P: Preset + schemars::JsonSchema")
+)]
+#[ssz(struct_behaviour = "container")]
+pub struct BlobsBundle<P: Preset = Mainnet> {
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_utils::list_of_hex_fixed_vec")
+    )]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(
+            with = "crate::json_schema::HexItems<VariableList<KzgCommitment, P::MaxBlobCommitmentsPerBlock>>"
+        )
+    )]
+    pub commitments: VariableList<KzgCommitment, P::MaxBlobCommitmentsPerBlock>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_utils::list_of_hex_fixed_vec")
+    )]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(
+            with = "crate::json_schema::HexItems<VariableList<KzgProof, P::MaxBlobCommitmentsPerBlock>>"
+        )
+    )]
+    pub proofs: VariableList<KzgProof, P::MaxBlobCommitmentsPerBlock>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_utils::list_of_hex_fixed_vec")
+    )]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(
+            with = "crate::json_schema::HexItems<VariableList<Blob<P>, P::MaxBlobCommitmentsPerBlock>>"
+        )
+    )]
+    pub blobs: VariableList<Blob<P>, P::MaxBlobCommitmentsPerBlock>,
+}
+
+/// The payload and blobs that a builder reveals for a signed blinded block of Deneb or Electra.
+#[derive(Clone, Default, Encode, Decode, TreeHash, PartialEq, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(bound = "P: Preset"))]
+#[cfg_attr(
+    feature = "schemars",
+    schemars(bound = "P: Preset + schemars::JsonSchema")
+)]
+#[ssz(struct_behaviour = "container")]
+pub struct ExecutionPayloadAndBlobsBundle<P: Preset = Mainnet> {
+    pub execution_payload: ExecutionPayloadDeneb<P>,
+    pub blobs_bundle: BlobsBundle<P>,
+}
+
+impl<P: Preset> BlobsBundle<P> {
+    /// True if the bundle has a proof and blob for each of its commitments, and its commitments
+    /// are `blob_kzg_commitments`, the commitments of the bid it reveals the blobs of.
+    pub fn matches_commitments(
+        &self,
+        blob_kzg_commitments: &VariableList<KzgCommitment, P::MaxBlobCommitmentsPerBlock>,
+    ) -> bool {
+        self.commitments == *blob_kzg_commitments
+            && self.proofs.len() == self.commitments.len()
+            && self.blobs.len() == self.commitments.len()
+    }
+}
+
+/// Implements the methods and traits shared by the enums over the bids of several forks.
+macro_rules! impl_bid_enum {
+    ($enum: ident, [$($variant: ident($bid: ident)),*], $bid_of: ident => $message: expr) => {
+        impl<P: Preset> $enum<P> {
+            /// Decodes the bid of `fork` from `bytes`.
+            pub fn from_ssz_bytes_for_fork(
+                bytes: &[u8],
+                fork: ForkName,
+            ) -> Result<Self, DecodeError> {
+                match fork {
+                    $(ForkName::$variant => $bid::from_ssz_bytes(bytes).map(Self::$variant),)*
+                    _ => Err(DecodeError::BytesInvalid(format!(
+                        "{} has no {} variant",
+                        stringify!($enum),
+                        fork
+                    ))),
+                }
+            }
+
+            /// Returns the fork of the bid.
+            pub fn fork_name(&self) -> ForkName {
+                match self {
+                    $(Self::$variant(_) => ForkName::$variant,)*
+                }
+            }
+
+            /// Returns the hash of the execution block that is bid for.
+            pub fn block_hash(&self) -> &H256 {
+                map_fork!(self, Self, [$($variant),*], $bid_of => &$message.header.block_hash)
+            }
+
+            /// Returns the value of the bid, in wei.
+            pub fn value(&self) -> &U256 {
+                map_fork!(self, Self, [$($variant),*], $bid_of => &$message.value)
+            }
+
+            /// Returns the public key of the builder.
+            pub fn pubkey(&self) -> &PublicKeyBytes {
+                map_fork!(self, Self, [$($variant),*], $bid_of => &$message.pubkey)
+            }
+        }
+
+        $(
+            impl<P: Preset> From<$bid<P>> for $enum<P> {
+                fn from(bid: $bid<P>) -> Self {
+                    Self::$variant(bid)
+                }
+            }
+        )*
+
+        impl<P: Preset> Encode for $enum<P> {
+            fn is_ssz_fixed_len() -> bool {
+                false
+            }
+
+            fn ssz_append(&self, buf: &mut Vec<u8>) {
+                map_fork!(self, Self, [$($variant),*], bid => bid.ssz_append(buf))
+            }
+
+            fn ssz_bytes_len(&self) -> usize {
+                map_fork!(self, Self, [$($variant),*], bid => bid.ssz_bytes_len())
+            }
+        }
+
+        impl<P: Preset> tree_hash::TreeHash for $enum<P> {
+            fn tree_hash_type() -> tree_hash::TreeHashType {
+                tree_hash::TreeHashType::Container
+            }
+
+            fn tree_hash_packed_encoding(&self) -> tree_hash::PackedEncoding {
+                unreachable!("Container should never be packed.")
+            }
+
+            fn tree_hash_packing_factor() -> usize {
+                unreachable!("Container should never be packed.")
+            }
+
+            fn tree_hash_root(&self) -> Hash256 {
+                map_fork!(self, Self, [$($variant),*], bid => bid.tree_hash_root())
+            }
+        }
+    };
+}
+
+/// The bid of a builder for the payload of a block of any fork since Bellatrix.
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum BuilderBid<P: Preset = Mainnet> {
+    Bellatrix(BuilderBidBellatrix<P>),
+    Capella(BuilderBidCapella<P>),
+    Deneb(BuilderBidDeneb<P>),
+    Electra(BuilderBidElectra<P>),
+}
+
+impl_bid_enum!(
+    BuilderBid,
+    [
+        Bellatrix(BuilderBidBellatrix),
+        Capella(BuilderBidCapella),
+        Deneb(BuilderBidDeneb),
+        Electra(BuilderBidElectra)
+    ],
+    bid => bid
+);
+
+/// A `BuilderBid` signed by the builder in the domain of `compute_builder_domain`.
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum SignedBuilderBid<P: Preset = Mainnet> {
+    Bellatrix(SignedBuilderBidBellatrix<P>),
+    Capella(SignedBuilderBidCapella<P>),
+    Deneb(SignedBuilderBidDeneb<P>),
+    Electra(SignedBuilderBidElectra<P>),
+}
+
+impl_bid_enum!(
+    SignedBuilderBid,
+    [
+        Bellatrix(SignedBuilderBidBellatrix),
+        Capella(SignedBuilderBidCapella),
+        Deneb(SignedBuilderBidDeneb),
+        Electra(SignedBuilderBidElectra)
+    ],
+    bid => bid.message
+);
+
+impl<P: Preset> SignedBuilderBid<P> {
+    /// Returns the signature of the builder over the bid.
+    pub fn signature(&self) -> &SignatureBytes {
+        map_fork!(self, Self, [Bellatrix, Capella, Deneb, Electra], bid => &bid.signature)
+    }
+
+    /// Returns the bid, without its signature.
+    pub fn into_message(self) -> BuilderBid<P> {
+        match self {
+            Self::Bellatrix(bid) => bid.message.into(),
+            Self::Capella(bid) => bid.message.into(),
+            Self::Deneb(bid) => bid.message.into(),
+            Self::Electra(bid) => bid.message.into(),
+        }
+    }
+}
+
+impl_memory_size_for_container!(
+    ValidatorRegistration,
+    fee_recipient,
+    gas_limit,
+    timestamp,
+    pubkey
+);
+impl_memory_size_for_container!(SignedValidatorRegistration, message, signature);
+impl_memory_size_for_container!(BuilderBidBellatrix<P: Preset>, header, value, pubkey);
+impl_memory_size_for_container!(BuilderBidCapella<P: Preset>, header, value, pubkey);
+impl_memory_size_for_container!(
+    BuilderBidDeneb<P: Preset>,
+    header,
+    blob_kzg_commitments,
+    value,
+    pubkey
+);
+impl_memory_size_for_container!(
+    BuilderBidElectra<P: Preset>,
+    header,
+    blob_kzg_commitments,
+    execution_requests,
+    value,
+    pubkey
+);
+impl_memory_size_for_container!(SignedBuilderBidBellatrix<P: Preset>, message, signature);
+impl_memory_size_for_container!(SignedBuilderBidCapella<P: Preset>, message, signature);
+impl_memory_size_for_container!(SignedBuilderBidDeneb<P: Preset>, message, signature);
+impl_memory_size_for_container!(SignedBuilderBidElectra<P: Preset>, message, signature);
+impl_memory_size_for_container!(BlobsBundle<P: Preset>, commitments, proofs, blobs);
+impl_memory_size_for_container!(
+    ExecutionPayloadAndBlobsBundle<P: Preset>,
+    execution_payload,
+    blobs_bundle
+);
+
+impl_merkle_proof_for_container!(
+    ValidatorRegistration,
+    fee_recipient,
+    gas_limit,
+    timestamp,
+    pubkey
+);
+impl_merkle_proof_for_container!(SignedValidatorRegistration, message, signature);
+impl_merkle_proof_for_container!(BuilderBidBellatrix<P: Preset>, header, value, pubkey);
+impl_merkle_proof_for_container!(BuilderBidCapella<P: Preset>, header, value, pubkey);
+impl_merkle_proof_for_container!(
+    BuilderBidDeneb<P: Preset>,
+    header,
+    blob_kzg_commitments,
+    value,
+    pubkey
+);
+impl_merkle_proof_for_container!(
+    BuilderBidElectra<P: Preset>,
+    header,
+    blob_kzg_commitments,
+    execution_requests,
+    value,
+    pubkey
+);
+impl_merkle_proof_for_container!(SignedBuilderBidBellatrix<P: Preset>, message, signature);
+impl_merkle_proof_for_container!(SignedBuilderBidCapella<P: Preset>, message, signature);
+impl_merkle_proof_for_container!(SignedBuilderBidDeneb<P: Preset>, message, signature);
+impl_merkle_proof_for_container!(SignedBuilderBidElectra<P: Preset>, message, signature);
+impl_merkle_proof_for_container!(BlobsBundle<P: Preset>, commitments, proofs, blobs);
+impl_merkle_proof_for_container!(
+    ExecutionPayloadAndBlobsBundle<P: Preset>,
+    execution_payload,
+    blobs_bundle
+);
+
+impl SignedRoot for ValidatorRegistration {}
+impl<P: Preset> SignedRoot for BuilderBidBellatrix<P> {}
+impl<P: Preset> SignedRoot for BuilderBidCapella<P> {}
+impl<P: Preset> SignedRoot for BuilderBidDeneb<P> {}
+impl<P: Preset> SignedRoot for BuilderBidElectra<P> {}
+impl<P: Preset> SignedRoot for BuilderBid<P> {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{compute_builder_domain, Minimal};
+    use tree_hash::TreeHash;
+
+    #[test]
+    fn registration_round_trip() {
+        let signed = SignedValidatorRegistration {
+            message: ValidatorRegistration {
+                fee_recipient: ExecutionAddress::new([1; 20]),
+                gas_limit: 30_000_000,
+                timestamp: 1_700_000_000,
+                pubkey: PublicKeyBytes::new([2; 48]),
+            },
+            signature: SignatureBytes::new([3; 96]),
+        };
+        let bytes = signed.as_ssz_bytes();
+        assert_eq!(bytes.len(), 20 + 8 + 8 + 48 + 96);
+        assert_eq!(
+            SignedValidatorRegistration::from_ssz_bytes(&bytes),
+            Ok(signed)
+        );
+    }
+
+    #[test]
+    fn bids() {
+        let mut deneb = SignedBuilderBidDeneb::<Minimal>::default();
+        deneb.message.header.block_hash = H256::from_elem(7);
+        deneb.message.value = U256::new(vec![1, 0, 0, 0]).unwrap();
+        let bytes = deneb.as_ssz_bytes();
+
+        let bid =
+            SignedBuilderBid::<Minimal>::from_ssz_bytes_for_fork(&bytes, ForkName::Deneb).unwrap();
+        assert_eq!(bid.fork_name(), ForkName::Deneb);
+        assert_eq!(bid.block_hash(), &H256::from_elem(7));
+        assert_eq!(bid.value()[0], 1);
+        assert_eq!(bid.as_ssz_bytes(), bytes);
+        assert_eq!(bid.tree_hash_root(), deneb.tree_hash_root());
+
+        // The bids of Electra have additional fields, and there are no bids before Bellatrix.
+        assert!(
+            SignedBuilderBid::<Minimal>::from_ssz_bytes_for_fork(&bytes, ForkName::Electra)
+                .is_err()
+        );
+        assert!(
+            SignedBuilderBid::<Minimal>::from_ssz_bytes_for_fork(&bytes, ForkName::Altair).is_err()
+        );
+
+        let message = bid.into_message();
+        assert_eq!(message.tree_hash_root(), deneb.message.tree_hash_root());
+        let domain = compute_builder_domain([0; 4]);
+        assert_eq!(
+            message.signing_root(domain),
+            deneb.message.signing_root(domain)
+        );
+
+        let electra = BuilderBid::from(BuilderBidElectra::<Minimal>::default());
+        assert_eq!(electra.fork_name(), ForkName::Electra);
+        assert_eq!(electra.pubkey(), &PublicKeyBytes::default());
+    }
+
+    #[test]
+    fn blobs_bundle() {
+        let commitments = VariableList::new(vec![KzgCommitment::from_elem(1); 2]).unwrap();
+        let mut bundle = BlobsBundle::<Minimal> {
+            commitments: commitments.clone(),
+            proofs: VariableList::new(vec![KzgProof::default(); 2]).unwrap(),
+            blobs: VariableList::new(vec![Blob::<Minimal>::default(); 2]).unwrap(),
+        };
+        assert!(bundle.matches_commitments(&commitments));
+        assert!(!bundle.matches_commitments(&VariableList::default()));
+
+        bundle.blobs.pop();
+        assert!(!bundle.matches_commitments(&commitments));
+
+        let revealed = ExecutionPayloadAndBlobsBundle::<Minimal> {
+            blobs_bundle: bundle,
+            ..ExecutionPayloadAndBlobsBundle::default()
+        };
+        let bytes = revealed.as_ssz_bytes();
+        assert_eq!(
+            ExecutionPayloadAndBlobsBundle::from_ssz_bytes(&bytes),
+            Ok(revealed)
+        );
+    }
+}
@@ -0,0 +1,420 @@
+use crate::hex::fmt_hex;
+use crate::proof::{list_gindex_of, list_tree_hash_node, vec_gindex_of, vec_tree_hash_node};
+use crate::tree_hash::{mix_in_length, vec_tree_hash_root};
+use crate::{Error, MemorySize, MerklePath, MerkleProof, SszReflect, SszSchema, VariableList};
+use bytes::Bytes;
+use serde::{Deserializer, Serializer};
+use serde_utils::hex::{self, PrefixedHexVisitor};
+use ssz::BYTES_PER_LENGTH_OFFSET;
+use std::fmt;
+use std::marker::PhantomData;
+use std::ops::Deref;
+use tree_hash::Hash256;
+use typenum::Unsigned;
+
+/// Emulates a SSZ `List[uint8, N]`, backed by a reference-counted `bytes::Bytes` buffer.
+///
+/// Has the same SSZ encoding, tree hash root and JSON representation as
+/// `VariableList<u8, N>`, however cloning a `ByteList` or creating one with
+/// `from_shared_ssz_bytes` does not copy the underlying bytes. This allows large byte lists (such
+/// as execution payload transactions) to reference slices of the buffer they were received in.
+///
+/// The `ssz::Decode` implementation cannot borrow from its input and so copies it. Use
+/// `ByteList::from_shared_ssz_bytes` or `VariableList::from_shared_ssz_bytes` to avoid the copy.
+///
+/// ## Example
+///
+/// ```
+/// use bytes::Bytes;
+/// use ssz_types::{ByteList, VariableList, typenum::{U4, U16}};
+///
+/// // Two transactions, `0x0102` and `0x03`, as they might arrive over the network.
+/// let buf = Bytes::from(vec![8, 0, 0, 0, 10, 0, 0, 0, 1, 2, 3]);
+///
+/// let txs = VariableList::<ByteList<U16>, U4>::from_shared_ssz_bytes(&buf).unwrap();
+/// assert_eq!(&txs[0][..], &[1, 2]);
+/// assert_eq!(&txs[1][..], &[3]);
+///
+/// // Both transactions point into `buf`.
+/// assert_eq!(txs[0].as_bytes().as_ptr(), buf[8..].as_ptr());
+/// ```
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct ByteList<N> {
+    bytes: Bytes,
+    _phantom: PhantomData<N>,
+}
+
+impl<N> fmt::Debug for ByteList<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_hex(&self.bytes, f)
+    }
+}
+
+impl<N: Unsigned> ByteList<N> {
+    /// Returns `Ok` if the given `bytes` do not exceed the maximum length of `Self`. Otherwise
+    /// returns `Err`.
+    pub fn new(bytes: Bytes) -> Result<Self, Error> {
+        if bytes.len() <= N::to_usize() {
+            Ok(Self {
+                bytes,
+                _phantom: PhantomData,
+            })
+        } else {
+            Err(Error::OutOfBounds {
+                i: bytes.len(),
+                len: Self::max_len(),
+            })
+        }
+    }
+
+    /// Create an empty list.
+    pub fn empty() -> Self {
+        Self {
+            bytes: Bytes::new(),
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Returns the number of bytes presently in `self`.
+    pub fn len(&self) -> usize {
+        self.bytes.len()
+    }
+
+    /// True if `self` does not contain any bytes.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the type-level maximum length.
+    pub fn max_len() -> usize {
+        N::to_usize()
+    }
+
+    /// Returns the underlying buffer.
+    pub fn as_bytes(&self) -> &Bytes {
+        &self.bytes
+    }
+
+    /// Returns the underlying buffer, consuming `self`.
+    pub fn into_bytes(self) -> Bytes {
+        self.bytes
+    }
+
+    /// Decodes `bytes` as SSZ without copying them.
+    pub fn from_shared_ssz_bytes(bytes: &Bytes) -> Result<Self, ssz::DecodeError> {
+        Self::new(bytes.clone())
+            .map_err(|e| ssz::DecodeError::BytesInvalid(format!("Invalid ByteList bytes: {:?}", e)))
+    }
+}
+
+impl<N: Unsigned, M: Unsigned> VariableList<ByteList<N>, M> {
+    /// Decodes `bytes` as a SSZ list of byte lists, where each item references a slice of `bytes`
+    /// rather than a copy.
+    ///
+    /// Applies the same validation as `ssz::Decode::from_ssz_bytes`.
+    pub fn from_shared_ssz_bytes(bytes: &Bytes) -> Result<Self, ssz::DecodeError> {
+        if bytes.is_empty() {
+            return Ok(Self::empty());
+        }
+
+        let first_offset = ssz::read_offset(bytes)?;
+        if first_offset > bytes.len() {
+            return Err(ssz::DecodeError::OffsetOutOfBounds(first_offset));
+        }
+        if first_offset % BYTES_PER_LENGTH_OFFSET != 0 || first_offset < BYTES_PER_LENGTH_OFFSET {
+            return Err(ssz::DecodeError::InvalidListFixedBytesLen(first_offset));
+        }
+
+        let num_items = first_offset / BYTES_PER_LENGTH_OFFSET;
+        if num_items > M::to_usize() {
+            return Err(ssz::DecodeError::BytesInvalid(format!(
+                "VariableList of {} items exceeds maximum of {}",
+                num_items,
+                M::to_usize()
+            )));
+        }
+
+        let mut items = Vec::with_capacity(num_items);
+        let mut start = first_offset;
+        for i in 1..=num_items {
+            let end = if i == num_items {
+                bytes.len()
+            } else {
+                let offset = ssz::read_offset(&bytes[i * BYTES_PER_LENGTH_OFFSET..])?;
+                if offset > bytes.len() {
+                    return Err(ssz::DecodeError::OffsetOutOfBounds(offset));
+                } else if offset < start {
+                    return Err(ssz::DecodeError::OffsetsAreDecreasing(offset));
+                }
+                offset
+            };
+
+            items.push(ByteList::from_shared_ssz_bytes(&bytes.slice(start..end))?);
+            start = end;
+        }
+
+        Ok(VariableList::new_truncated(items))
+    }
+}
+
+impl<N: Unsigned> TryFrom<Vec<u8>> for ByteList<N> {
+    type Error = Error;
+
+    fn try_from(vec: Vec<u8>) -> Result<Self, Error> {
+        Self::new(Bytes::from(vec))
+    }
+}
+
+impl<N: Unsigned> From<ByteList<N>> for Vec<u8> {
+    fn from(list: ByteList<N>) -> Vec<u8> {
+        list.bytes.to_vec()
+    }
+}
+
+impl<N: Unsigned> From<VariableList<u8, N>> for ByteList<N> {
+    fn from(list: VariableList<u8, N>) -> Self {
+        Self {
+            bytes: Bytes::from(Vec::from(list)),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<N: Unsigned> From<ByteList<N>> for VariableList<u8, N> {
+    fn from(list: ByteList<N>) -> Self {
+        VariableList::new_truncated(list.into())
+    }
+}
+
+impl<N: Unsigned> Default for ByteList<N> {
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+impl<N: Unsigned> Deref for ByteList<N> {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        &self.bytes[..]
+    }
+}
+
+impl<N: Unsigned> AsRef<[u8]> for ByteList<N> {
+    fn as_ref(&self) -> &[u8] {
+        &self.bytes[..]
+    }
+}
+
+impl<'a, N: Unsigned> IntoIterator for &'a ByteList<N> {
+    type Item = &'a u8;
+    type IntoIter = std::slice::Iter<'a, u8>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<N: Unsigned> MemorySize for ByteList<N> {
+    /// Counts the bytes referenced by `self`, which may be a slice of a larger shared buffer.
+    fn heap_size(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<N: Unsigned> MerkleProof for ByteList<N> {
+    fn tree_hash_node(&self, gindex: u64) -> Result<Hash256, Error> {
+        list_tree_hash_node(gindex, self.len(), |gindex| {
+            vec_tree_hash_node(self.bytes.iter(), N::to_usize(), gindex)
+        })
+    }
+}
+
+impl<N: Unsigned> MerklePath for ByteList<N> {
+    fn gindex_of(path: &[&str]) -> Result<u64, Error> {
+        list_gindex_of(path, |path| vec_gindex_of::<u8>(N::to_usize(), path))
+    }
+}
+
+impl<N: Unsigned> SszReflect for ByteList<N> {
+    fn ssz_schema() -> SszSchema {
+        SszSchema::list(u8::ssz_schema(), N::to_usize())
+    }
+}
+
+impl<N: Unsigned> serde::Serialize for ByteList<N> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&hex::encode(&self.bytes))
+    }
+}
+
+impl<'de, N: Unsigned> serde::Deserialize<'de> for ByteList<N> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let bytes = deserializer.deserialize_str(PrefixedHexVisitor)?;
+        Self::try_from(bytes)
+            .map_err(|e| serde::de::Error::custom(format!("invalid byte list: {:?}", e)))
+    }
+}
+
+impl<N: Unsigned> tree_hash::TreeHash for ByteList<N> {
+    fn tree_hash_type() -> tree_hash::TreeHashType {
+        tree_hash::TreeHashType::List
+    }
+
+    fn tree_hash_packed_encoding(&self) -> tree_hash::PackedEncoding {
+        unreachable!("List should never be packed.")
+    }
+
+    fn tree_hash_packing_factor() -> usize {
+        unreachable!("List should never be packed.")
+    }
+
+    fn tree_hash_root(&self) -> Hash256 {
+        let root = vec_tree_hash_root::<u8, N>(&self.bytes);
+
+        mix_in_length(&root, self.len())
+    }
+}
+
+impl<N: Unsigned> ssz::Encode for ByteList<N> {
+    fn is_ssz_fixed_len() -> bool {
+        false
+    }
+
+    fn ssz_bytes_len(&self) -> usize {
+        self.len()
+    }
+
+    fn ssz_append(&self, buf: &mut Vec<u8>) {
+        buf.extend_from_slice(&self.bytes)
+    }
+}
+
+impl<N: Unsigned> ssz::Decode for ByteList<N> {
+    fn is_ssz_fixed_len() -> bool {
+        false
+    }
+
+    fn from_ssz_bytes(bytes: &[u8]) -> Result<Self, ssz::DecodeError> {
+        Self::from_shared_ssz_bytes(&Bytes::copy_from_slice(bytes))
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a, N: 'static + Unsigned> arbitrary::Arbitrary<'a> for ByteList<N> {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        VariableList::arbitrary(u).map(Self::from)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ssz::*;
+    use tree_hash::TreeHash;
+    use typenum::*;
+
+    #[test]
+    fn new() {
+        assert!(ByteList::<U4>::try_from(vec![42; 5]).is_err());
+        assert!(ByteList::<U4>::try_from(vec![42; 4]).is_ok());
+        assert!(ByteList::<U4>::try_from(vec![]).is_ok());
+    }
+
+    #[test]
+    fn matches_variable_list() {
+        for len in 0..=32 {
+            let vec: Vec<u8> = (0..len).collect();
+            let list = VariableList::<u8, U32>::try_from(vec.clone()).unwrap();
+            let bytes = ByteList::<U32>::try_from(vec).unwrap();
+
+            assert_eq!(bytes.as_ssz_bytes(), list.as_ssz_bytes());
+            assert_eq!(bytes.ssz_bytes_len(), list.ssz_bytes_len());
+            assert_eq!(bytes.tree_hash_root(), list.tree_hash_root());
+            assert_eq!(
+                ByteList::<U32>::from_ssz_bytes(&list.as_ssz_bytes()),
+                Ok(bytes.clone())
+            );
+            assert_eq!(
+                serde_json::to_string(&bytes).unwrap(),
+                serde_json::to_string(&VariableListHex(list)).unwrap()
+            );
+        }
+    }
+
+    #[derive(serde_derive::Serialize)]
+    #[serde(transparent)]
+    struct VariableListHex(
+        #[serde(with = "crate::serde_utils::hex_var_list")] VariableList<u8, U32>,
+    );
+
+    #[test]
+    fn serde() {
+        let bytes = ByteList::<U4>::try_from(vec![1, 2, 3]).unwrap();
+
+        let json = serde_json::to_string(&bytes).unwrap();
+        assert_eq!(json, "\"0x010203\"");
+        assert_eq!(serde_json::from_str::<ByteList<U4>>(&json).unwrap(), bytes);
+        assert!(serde_json::from_str::<ByteList<U4>>("\"0x0102030405\"").is_err());
+    }
+
+    type Transactions = VariableList<ByteList<U8>, U4>;
+
+    fn transactions(items: Vec<Vec<u8>>) -> Transactions {
+        items
+            .into_iter()
+            .map(|tx| ByteList::try_from(tx).unwrap())
+            .collect::<Vec<_>>()
+            .try_into()
+            .unwrap()
+    }
+
+    #[test]
+    fn shared_list_round_trip() {
+        for items in [
+            vec![],
+            vec![vec![]],
+            vec![vec![1, 2, 3], vec![], vec![4]],
+            vec![vec![5; 8]; 4],
+        ] {
+            let list = transactions(items);
+            let buf = Bytes::from(list.as_ssz_bytes());
+
+            let decoded = Transactions::from_shared_ssz_bytes(&buf).unwrap();
+            assert_eq!(decoded, list);
+            assert_eq!(Transactions::from_ssz_bytes(&buf), Ok(list));
+
+            for tx in decoded.iter().filter(|tx| !tx.is_empty()) {
+                let start = tx.as_ptr() as usize - buf.as_ptr() as usize;
+                assert!(start + tx.len() <= buf.len());
+            }
+        }
+    }
+
+    #[test]
+    fn shared_list_invalid() {
+        let decode = |bytes: Vec<u8>| Transactions::from_shared_ssz_bytes(&Bytes::from(bytes));
+
+        // Too many items.
+        let list = vec![vec![1u8]; 5];
+        assert!(decode(list.as_ssz_bytes()).is_err());
+
+        // An item that is too long.
+        let list = vec![vec![1u8; 9]];
+        assert!(decode(list.as_ssz_bytes()).is_err());
+
+        // Offsets that are out of bounds, decreasing or misaligned.
+        assert!(decode(vec![9, 0, 0, 0]).is_err());
+        assert!(decode(vec![8, 0, 0, 0, 4, 0, 0, 0]).is_err());
+        assert!(decode(vec![8, 0, 0, 0, 9, 0, 0, 0]).is_err());
+        assert!(decode(vec![5, 0, 0, 0, 0]).is_err());
+        assert!(decode(vec![0, 0, 0, 0]).is_err());
+        assert!(decode(vec![4, 0, 0]).is_err());
+    }
+
+    #[test]
+    fn debug() {
+        let list = ByteList::<U16>::try_from(vec![0xde, 0xad]).unwrap();
+        assert_eq!(format!("{:?}", list), "0xdead");
+    }
+}
@@ -0,0 +1,229 @@
+use crate::hasher::hash32_concat;
+use crate::tree_hash::{mix_in_length, zero_hash};
+use crate::Error;
+use std::io;
+use std::marker::PhantomData;
+use tree_hash::{Hash256, BYTES_PER_CHUNK};
+use typenum::Unsigned;
+
+/// Computes the tree hash root of a `List[uint8, N]` (e.g. a `Transaction`) from its bytes,
+/// without holding all of them in memory.
+///
+/// The bytes can be written in pieces of any size, including through `io::Write`. Only one
+/// partial chunk and one node per level of the tree are kept, so memory use does not depend on
+/// the length of the list.
+///
+/// ## Example
+///
+/// ```
+/// use ssz_types::{ByteListHasher, VariableList, typenum::U1073741824};
+/// use tree_hash::TreeHash;
+///
+/// let bytes = vec![42; 100_000];
+/// let list = VariableList::<u8, U1073741824>::new(bytes.clone()).unwrap();
+///
+/// let root = ByteListHasher::<U1073741824>::from_reader(&bytes[..]).unwrap();
+/// assert_eq!(root, list.tree_hash_root());
+/// ```
+#[derive(Clone, Debug)]
+pub struct ByteListHasher<N> {
+    /// The root of the last full subtree at each height, for the heights at which the number of
+    /// full chunks has a set bit. The last entry is the root of a full list.
+    branch: Vec<Hash256>,
+    /// The bytes of the chunk that is being filled.
+    chunk: [u8; BYTES_PER_CHUNK],
+    len: usize,
+    _phantom: PhantomData<N>,
+}
+
+impl<N: Unsigned> Default for ByteListHasher<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<N: Unsigned> ByteListHasher<N> {
+    /// Returns a hasher for an empty list.
+    pub fn new() -> Self {
+        Self {
+            branch: vec![Hash256::zero(); Self::depth() + 1],
+            chunk: [0; BYTES_PER_CHUNK],
+            len: 0,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Returns the root of the list holding the concatenation of `chunks`.
+    ///
+    /// Returns `Err` if the list would be longer than `N`.
+    pub fn from_chunks<I, B>(chunks: I) -> Result<Hash256, Error>
+    where
+        I: IntoIterator<Item = B>,
+        B: AsRef<[u8]>,
+    {
+        let mut hasher = Self::new();
+        for chunk in chunks {
+            hasher.update(chunk.as_ref())?;
+        }
+
+        Ok(hasher.finish())
+    }
+
+    /// Returns the root of the list holding the bytes read from `reader`.
+    ///
+    /// Returns `Err` if `reader` fails or if the list would be longer than `N`.
+    pub fn from_reader<R: io::Read>(mut reader: R) -> io::Result<Hash256> {
+        let mut hasher = Self::new();
+        io::copy(&mut reader, &mut hasher)?;
+
+        Ok(hasher.finish())
+    }
+
+    /// Returns the number of bytes written so far.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// True if no bytes have been written.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Appends `bytes` to the list.
+    ///
+    /// Returns `Err` without appending anything if the list would be longer than `N`.
+    pub fn update(&mut self, mut bytes: &[u8]) -> Result<(), Error> {
+        if self.len + bytes.len() > N::to_usize() {
+            return Err(Error::OutOfBounds {
+                i: self.len + bytes.len(),
+                len: N::to_usize(),
+            });
+        }
+
+        while !bytes.is_empty() {
+            let offset = self.len % BYTES_PER_CHUNK;
+            let n = std::cmp::min(BYTES_PER_CHUNK - offset, bytes.len());
+
+            self.chunk[offset..offset + n].copy_from_slice(&bytes[..n]);
+            self.len += n;
+            bytes = &bytes[n..];
+
+            if self.len.is_multiple_of(BYTES_PER_CHUNK) {
+                self.push_chunk();
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns the root of the list, with its length mixed in.
+    pub fn finish(self) -> Hash256 {
+        let full_chunks = self.len / BYTES_PER_CHUNK;
+        let depth = self.branch.len() - 1;
+        if full_chunks == 1 << depth {
+            return mix_in_length(&self.branch[depth], self.len);
+        }
+
+        let mut node = Hash256::from(self.chunk);
+        // Whether `node` is a subtree holding the partial chunk, rather than only zeros.
+        let mut partial = !self.len.is_multiple_of(BYTES_PER_CHUNK);
+
+        for (height, left) in self.branch[..depth].iter().enumerate() {
+            if (full_chunks >> height) & 1 == 1 {
+                node = hash32_concat(left.as_bytes(), node.as_bytes());
+                partial = true;
+            } else if partial {
+                node = hash32_concat(node.as_bytes(), zero_hash(height).as_bytes());
+            } else {
+                node = zero_hash(height + 1);
+            }
+        }
+
+        mix_in_length(&node, self.len)
+    }
+
+    /// Adds the full chunk to the tree, merging the subtrees it completes.
+    fn push_chunk(&mut self) {
+        let index = self.len / BYTES_PER_CHUNK - 1;
+        let mut node = Hash256::from(self.chunk);
+
+        let mut height = 0;
+        while (index >> height) & 1 == 1 {
+            node = hash32_concat(self.branch[height].as_bytes(), node.as_bytes());
+            height += 1;
+        }
+        self.branch[height] = node;
+
+        self.chunk = [0; BYTES_PER_CHUNK];
+    }
+
+    fn depth() -> usize {
+        N::to_usize()
+            .div_ceil(BYTES_PER_CHUNK)
+            .max(1)
+            .next_power_of_two()
+            .trailing_zeros() as usize
+    }
+}
+
+impl<N: Unsigned> io::Write for ByteListHasher<N> {
+    fn write(&mut self, bytes: &[u8]) -> io::Result<usize> {
+        self.update(bytes)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, format!("{:?}", e)))?;
+
+        Ok(bytes.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::VariableList;
+    use tree_hash::TreeHash;
+    use typenum::*;
+
+    fn assert_matches_list<N: Unsigned>(bytes: &[u8]) {
+        let expected = VariableList::<u8, N>::new(bytes.to_vec())
+            .unwrap()
+            .tree_hash_root();
+
+        assert_eq!(ByteListHasher::<N>::from_reader(bytes).unwrap(), expected);
+        for piece in [1, 7, 32, 33, 1000] {
+            assert_eq!(
+                ByteListHasher::<N>::from_chunks(bytes.chunks(piece)),
+                Ok(expected),
+                "{} bytes in pieces of {}",
+                bytes.len(),
+                piece
+            );
+        }
+    }
+
+    #[test]
+    fn matches_variable_list() {
+        for len in [0, 1, 31, 32, 33, 64, 95, 96, 128, 1000, 1024] {
+            let bytes = (0..len).map(|i| i as u8).collect::<Vec<_>>();
+            assert_matches_list::<U1024>(&bytes);
+            assert_matches_list::<U1073741824>(&bytes);
+        }
+        assert_matches_list::<U1>(&[]);
+        assert_matches_list::<U1>(&[1]);
+        assert_matches_list::<U32>(&[1; 32]);
+    }
+
+    #[test]
+    fn too_long() {
+        let mut hasher = ByteListHasher::<U64>::new();
+        assert_eq!(hasher.update(&[1; 40]), Ok(()));
+        assert_eq!(
+            hasher.update(&[2; 25]),
+            Err(Error::OutOfBounds { i: 65, len: 64 })
+        );
+        assert_eq!(hasher.len(), 40);
+        assert!(ByteListHasher::<U64>::from_reader(&[0; 65][..]).is_err());
+    }
+}
@@ -0,0 +1,360 @@
+use crate::{
+    compute_fork_digest, Epoch, Error, ForkDigest, ForkName, ForkSchedule, Mainnet, Preset, Version,
+};
+use std::collections::HashMap;
+use tree_hash::Hash256;
+use typenum::Unsigned;
+
+/// The epoch of the forks which are not scheduled, as written in configs.
+pub const FAR_FUTURE_EPOCH: Epoch = Epoch::new(u64::MAX);
+
+/// The runtime configuration of a chain, as in the `config.yaml` of the consensus specs and of
+/// testnets. Only the values which identify the forks of the chain and bound its blobs are kept.
+///
+/// Forks which are not scheduled, i.e. whose epoch is `FAR_FUTURE_EPOCH`, have no epoch.
+///
+/// ## Example
+///
+/// ```
+/// use ssz_types::{ChainConfig, Epoch, ForkName, Minimal, Slot};
+///
+/// let config = ChainConfig::from_yaml(
+///     r#"
+/// PRESET_BASE: 'minimal'
+/// CONFIG_NAME: 'devnet'
+/// MIN_GENESIS_TIME: 1578009600
+/// GENESIS_DELAY: 300
+/// SECONDS_PER_SLOT: 6
+///
+/// GENESIS_FORK_VERSION: 0x00000001
+/// ALTAIR_FORK_VERSION: 0x01000001
+/// ALTAIR_FORK_EPOCH: 0
+/// BELLATRIX_FORK_VERSION: 0x02000001
+/// BELLATRIX_FORK_EPOCH: 0
+/// CAPELLA_FORK_VERSION: 0x03000001
+/// CAPELLA_FORK_EPOCH: 0
+/// DENEB_FORK_VERSION: 0x04000001
+/// DENEB_FORK_EPOCH: 2
+/// ELECTRA_FORK_VERSION: 0x05000001
+/// ELECTRA_FORK_EPOCH: 18446744073709551615
+///
+/// MAX_BLOBS_PER_BLOCK: 6
+/// MAX_BLOBS_PER_BLOCK_ELECTRA: 9
+/// "#,
+/// )
+/// .unwrap();
+///
+/// assert_eq!(config.fork_at_epoch(Epoch::new(1)), ForkName::Capella);
+/// assert_eq!(
+///     config.fork_schedule::<Minimal>().fork_at_slot(Slot::new(16)),
+///     ForkName::Deneb
+/// );
+/// assert_eq!(config.fork_epoch(ForkName::Electra), None);
+/// assert_eq!(config.max_blobs_per_block(ForkName::Deneb), 6);
+/// ```
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct ChainConfig {
+    /// The name of the preset of the chain, e.g. `mainnet` or `minimal`.
+    pub preset_base: String,
+    pub config_name: Option<String>,
+    pub min_genesis_time: u64,
+    pub genesis_delay: u64,
+    pub seconds_per_slot: u64,
+    pub genesis_fork_version: Version,
+    pub altair_fork_version: Version,
+    pub altair_fork_epoch: Option<Epoch>,
+    pub bellatrix_fork_version: Version,
+    pub bellatrix_fork_epoch: Option<Epoch>,
+    pub capella_fork_version: Version,
+    pub capella_fork_epoch: Option<Epoch>,
+    pub deneb_fork_version: Version,
+    pub deneb_fork_epoch: Option<Epoch>,
+    pub electra_fork_version: Version,
+    pub electra_fork_epoch: Option<Epoch>,
+    pub max_blobs_per_block: u64,
+    pub max_blobs_per_block_electra: u64,
+}
+
+impl ChainConfig {
+    /// Parses a config in the format of the `config.yaml` files of the consensus specs: one
+    /// `KEY: value` pair per line, with optional comments and quotes.
+    ///
+    /// Keys that are not fields of `ChainConfig`, and nested values such as `BLOB_SCHEDULE`, are
+    /// ignored.
+    ///
+    /// Returns `Err` if a key is missing or has a malformed value.
+    pub fn from_yaml(yaml: &str) -> Result<Self, Error> {
+        let values = parse_yaml(yaml);
+        let value = |key: &str| {
+            values
+                .get(key)
+                .copied()
+                .ok_or_else(|| Error::InvalidChainConfig {
+                    key: key.to_string(),
+                })
+        };
+        let u64_value = |key: &str| {
+            value(key)?
+                .parse::<u64>()
+                .map_err(|_| Error::InvalidChainConfig {
+                    key: key.to_string(),
+                })
+        };
+        let epoch_value = |key: &str| {
+            u64_value(key).map(|epoch| Some(Epoch::new(epoch)).filter(|&e| e != FAR_FUTURE_EPOCH))
+        };
+        let version_value = |key: &str| {
+            let invalid = || Error::InvalidChainConfig {
+                key: key.to_string(),
+            };
+            let bytes = serde_utils::hex::decode(value(key)?).map_err(|_| invalid())?;
+            Version::try_from(bytes.as_slice()).map_err(|_| invalid())
+        };
+
+        Ok(Self {
+            preset_base: value("PRESET_BASE")?.to_string(),
+            config_name: values.get("CONFIG_NAME").map(|name| name.to_string()),
+            min_genesis_time: u64_value("MIN_GENESIS_TIME")?,
+            genesis_delay: u64_value("GENESIS_DELAY")?,
+            seconds_per_slot: u64_value("SECONDS_PER_SLOT")?,
+            genesis_fork_version: version_value("GENESIS_FORK_VERSION")?,
+            altair_fork_version: version_value("ALTAIR_FORK_VERSION")?,
+            altair_fork_epoch: epoch_value("ALTAIR_FORK_EPOCH")?,
+            bellatrix_fork_version: version_value("BELLATRIX_FORK_VERSION")?,
+            bellatrix_fork_epoch: epoch_value("BELLATRIX_FORK_EPOCH")?,
+            capella_fork_version: version_value("CAPELLA_FORK_VERSION")?,
+            capella_fork_epoch: epoch_value("CAPELLA_FORK_EPOCH")?,
+            deneb_fork_version: version_value("DENEB_FORK_VERSION")?,
+            deneb_fork_epoch: epoch_value("DENEB_FORK_EPOCH")?,
+            electra_fork_version: version_value("ELECTRA_FORK_VERSION")?,
+            electra_fork_epoch: epoch_value("ELECTRA_FORK_EPOCH")?,
+            max_blobs_per_block: u64_value("MAX_BLOBS_PER_BLOCK")?,
+            max_blobs_per_block_electra: u64_value("MAX_BLOBS_PER_BLOCK_ELECTRA")?,
+        })
+    }
+
+    /// Returns the version of `fork`.
+    pub fn fork_version(&self, fork: ForkName) -> Version {
+        match fork {
+            ForkName::Phase0 => self.genesis_fork_version,
+            ForkName::Altair => self.altair_fork_version,
+            ForkName::Bellatrix => self.bellatrix_fork_version,
+            ForkName::Capella => self.capella_fork_version,
+            ForkName::Deneb => self.deneb_fork_version,
+            ForkName::Electra => self.electra_fork_version,
+        }
+    }
+
+    /// Returns the epoch at which `fork` activates, if it is scheduled.
+    pub fn fork_epoch(&self, fork: ForkName) -> Option<Epoch> {
+        // The epochs of the forks do not depend on the preset.
+        self.fork_schedule::<Mainnet>().fork_epoch(fork)
+    }
+
+    /// Returns the schedule of the forks of the chain, with the slots per epoch of preset `P`.
+    pub fn fork_schedule<P: Preset>(&self) -> ForkSchedule {
+        ForkSchedule {
+            slots_per_epoch: P::SlotsPerEpoch::to_u64(),
+            altair_fork_epoch: self.altair_fork_epoch,
+            bellatrix_fork_epoch: self.bellatrix_fork_epoch,
+            capella_fork_epoch: self.capella_fork_epoch,
+            deneb_fork_epoch: self.deneb_fork_epoch,
+            electra_fork_epoch: self.electra_fork_epoch,
+        }
+    }
+
+    /// Returns the fork that is active at `epoch`.
+    pub fn fork_at_epoch(&self, epoch: Epoch) -> ForkName {
+        self.fork_schedule::<Mainnet>().fork_at_epoch(epoch)
+    }
+
+    /// Returns the digest of `fork` on the chain with `genesis_validators_root`.
+    pub fn fork_digest(&self, fork: ForkName, genesis_validators_root: Hash256) -> ForkDigest {
+        compute_fork_digest(self.fork_version(fork), genesis_validators_root)
+    }
+
+    /// Returns the digests of the scheduled forks on the chain with `genesis_validators_root`, in
+    /// order of activation, as needed to subscribe to the gossip topics of each fork.
+    pub fn fork_digests(&self, genesis_validators_root: Hash256) -> Vec<(ForkName, ForkDigest)> {
+        ForkName::ALL
+            .into_iter()
+            .filter(|&fork| self.fork_epoch(fork).is_some())
+            .map(|fork| (fork, self.fork_digest(fork, genesis_validators_root)))
+            .collect()
+    }
+
+    /// Returns the maximum number of blobs of a block of `fork`, which is zero before Deneb.
+    pub fn max_blobs_per_block(&self, fork: ForkName) -> u64 {
+        match fork {
+            ForkName::Phase0 | ForkName::Altair | ForkName::Bellatrix | ForkName::Capella => 0,
+            ForkName::Deneb => self.max_blobs_per_block,
+            ForkName::Electra => self.max_blobs_per_block_electra,
+        }
+    }
+}
+
+/// Returns the top-level `KEY: value` pairs of `yaml`, without comments and quotes.
+fn parse_yaml(yaml: &str) -> HashMap<&str, &str> {
+    yaml.lines()
+        // Nested values are indented or list items.
+        .filter(|line| !line.starts_with(|c: char| c.is_whitespace() || c == '-' || c == '#'))
+        .filter_map(|line| {
+            let line = match line.find(" #") {
+                Some(comment) => &line[..comment],
+                None => line,
+            };
+            let (key, value) = line.split_once(':')?;
+            let value = value.trim();
+            let value = [('\'', '\''), ('"', '"')]
+                .into_iter()
+                .find_map(|(open, close)| value.strip_prefix(open)?.strip_suffix(close))
+                .unwrap_or(value);
+            Some((key.trim(), value)).filter(|(_, value)| !value.is_empty())
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Mainnet, Minimal};
+
+    const MAINNET: &str = r#"
+# Mainnet config
+
+# Extends the mainnet preset
+PRESET_BASE: 'mainnet'
+
+# Free-form short name of the network that this configuration applies to - known
+# canonical network names include:
+# * 'mainnet' - there can be only one
+CONFIG_NAME: 'mainnet'
+
+# Genesis
+# ---------------------------------------------------------------
+# `2**14` (= 16,384)
+MIN_GENESIS_ACTIVE_VALIDATOR_COUNT: 16384
+# Dec 1, 2020, 12pm UTC
+MIN_GENESIS_TIME: 1606824000
+# Mainnet initial fork version, recommend altering for testnets
+GENESIS_FORK_VERSION: 0x00000000
+# 604800 seconds (7 days)
+GENESIS_DELAY: 604800
+
+# Forking
+# ---------------------------------------------------------------
+# Altair
+ALTAIR_FORK_VERSION: 0x01000000
+ALTAIR_FORK_EPOCH: 74240  # Oct 27, 2021, 10:56:23am UTC
+# Bellatrix
+BELLATRIX_FORK_VERSION: 0x02000000
+BELLATRIX_FORK_EPOCH: 144896  # Sept 6, 2022, 11:34:47am UTC
+# Capella
+CAPELLA_FORK_VERSION: 0x03000000
+CAPELLA_FORK_EPOCH: 194048  # April 12, 2023, 10:27:35pm UTC
+# Deneb
+DENEB_FORK_VERSION: 0x04000000
+DENEB_FORK_EPOCH: 269568  # March 13, 2024, 01:55:35pm UTC
+# Electra
+ELECTRA_FORK_VERSION: 0x05000000
+ELECTRA_FORK_EPOCH: 364032  # May 7, 2025, 10:05:11am UTC
+
+# Time parameters
+# ---------------------------------------------------------------
+# 12 seconds
+SECONDS_PER_SLOT: 12
+
+# Deposit contract
+# ---------------------------------------------------------------
+DEPOSIT_CHAIN_ID: 1
+DEPOSIT_CONTRACT_ADDRESS: 0x00000000219ab540356cBB839Cbe05303d7705Fa
+
+# Deneb
+# `uint64(6)`
+MAX_BLOBS_PER_BLOCK: 6
+
+# Electra
+# `uint64(9)`
+MAX_BLOBS_PER_BLOCK_ELECTRA: 9
+
+# Fulu
+BLOB_SCHEDULE:
+  - EPOCH: 412672
+    MAX_BLOBS_PER_BLOCK: 15
+"#;
+
+    fn genesis_validators_root() -> Hash256 {
+        Hash256::from_slice(
+            &serde_utils::hex::decode(
+                "0x4b363db94e286120d76eb905340fdd4e54bfe9f06bf33ff6cf5ad27f511bfe95",
+            )
+            .unwrap(),
+        )
+    }
+
+    #[test]
+    fn mainnet() {
+        let config = ChainConfig::from_yaml(MAINNET).unwrap();
+
+        assert_eq!(config.preset_base, "mainnet");
+        assert_eq!(config.config_name.as_deref(), Some("mainnet"));
+        assert_eq!(config.genesis_delay, 604_800);
+        assert_eq!(config.electra_fork_version, [5, 0, 0, 0]);
+        // The nested blob schedule does not override the limit of Deneb.
+        assert_eq!(config.max_blobs_per_block(ForkName::Deneb), 6);
+        assert_eq!(config.max_blobs_per_block(ForkName::Electra), 9);
+        assert_eq!(config.max_blobs_per_block(ForkName::Capella), 0);
+
+        assert_eq!(config.fork_schedule::<Mainnet>(), ForkSchedule::mainnet());
+        assert_eq!(config.fork_schedule::<Minimal>().slots_per_epoch, 8);
+        assert_eq!(config.fork_at_epoch(Epoch::new(269_567)), ForkName::Capella);
+        assert_eq!(config.fork_at_epoch(Epoch::new(269_568)), ForkName::Deneb);
+
+        let digests = config.fork_digests(genesis_validators_root());
+        assert_eq!(digests.len(), ForkName::ALL.len());
+        assert_eq!(digests[0], (ForkName::Phase0, [0xb5, 0x30, 0x3f, 0x2a]));
+        assert_eq!(digests[4], (ForkName::Deneb, [0x6a, 0x95, 0xa1, 0xa9]));
+    }
+
+    #[test]
+    fn unscheduled_forks() {
+        let yaml = MAINNET.replace(
+            "ELECTRA_FORK_EPOCH: 364032",
+            "ELECTRA_FORK_EPOCH: 18446744073709551615",
+        );
+        let config = ChainConfig::from_yaml(&yaml).unwrap();
+
+        assert_eq!(config.electra_fork_epoch, None);
+        assert_eq!(config.fork_at_epoch(Epoch::new(u64::MAX)), ForkName::Deneb);
+        assert_eq!(
+            config.fork_digests(genesis_validators_root()).last(),
+            Some(&(ForkName::Deneb, [0x6a, 0x95, 0xa1, 0xa9]))
+        );
+    }
+
+    #[test]
+    fn invalid_configs() {
+        let invalid = |key: &str| {
+            Err(Error::InvalidChainConfig {
+                key: key.to_string(),
+            })
+        };
+
+        assert_eq!(
+            ChainConfig::from_yaml(&MAINNET.replace("SECONDS_PER_SLOT: 12", "")),
+            invalid("SECONDS_PER_SLOT")
+        );
+        assert_eq!(
+            ChainConfig::from_yaml(&MAINNET.replace("0x04000000", "0x040000")),
+            invalid("DENEB_FORK_VERSION")
+        );
+        assert_eq!(
+            ChainConfig::from_yaml(&MAINNET.replace("EPOCH: 74240", "EPOCH: soon")),
+            invalid("ALTAIR_FORK_EPOCH")
+        );
+        assert_eq!(
+            ChainConfig::from_yaml(&MAINNET.replace("MAX_BLOBS_PER_BLOCK: 6", "")),
+            invalid("MAX_BLOBS_PER_BLOCK")
+        );
+    }
+}
@@ -0,0 +1,91 @@
+//! Aliases of the collection types with lengths given as `const` generics rather than `typenum`
+//! types, e.g. `FixedVector<u64, 32>` rather than `FixedVector<u64, typenum::U32>`.
+//!
+//! Each alias resolves to the corresponding `typenum` type, so values convert freely between the
+//! two spellings without any conversion function. Lengths are supported for every value from `0`
+//! to `1024` and for the powers of two and ten beyond that, which covers the limits used in the
+//! consensus specs.
+//!
+//! Generic code can accept any supported length with `typenum::Const<N>: typenum::ToUInt` and
+//! `typenum::U<N>: typenum::Unsigned` bounds. The compiler cannot infer `N` from an alias, so such
+//! functions must be called with `N` given explicitly.
+//!
+//! Requires the `const-generics` feature.
+//!
+//! ## Example
+//!
+//! ```
+//! use ssz_types::{const_generic, typenum, FixedVector};
+//!
+//! let vector: const_generic::FixedVector<u64, 4> = FixedVector::new(vec![1, 2, 3, 4]).unwrap();
+//!
+//! // The aliases are the `typenum` types.
+//! let same: FixedVector<u64, typenum::U4> = vector;
+//! assert_eq!(&same[..], &[1, 2, 3, 4]);
+//! ```
+use typenum::U;
+
+/// `FixedVector` with a `const` length. See the module documentation.
+pub type FixedVector<T, const N: usize> = crate::FixedVector<T, U<N>>;
+
+/// `VariableList` with a `const` maximum length. See the module documentation.
+pub type VariableList<T, const N: usize> = crate::VariableList<T, U<N>>;
+
+/// `SmallVariableList` with a `const` maximum length. See the module documentation.
+pub type SmallVariableList<A, const N: usize> = crate::SmallVariableList<A, U<N>>;
+
+/// `PersistentList` with a `const` maximum length. See the module documentation.
+pub type PersistentList<T, const N: usize> = crate::PersistentList<T, U<N>>;
+
+/// `PersistentVector` with a `const` length. See the module documentation.
+pub type PersistentVector<T, const N: usize> = crate::PersistentVector<T, U<N>>;
+
+/// `BitList` with a `const` maximum length. See the module documentation.
+pub type BitList<const N: usize> = crate::BitList<U<N>>;
+
+/// `BitVector` with a `const` length. See the module documentation.
+pub type BitVector<const N: usize> = crate::BitVector<U<N>>;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ssz::{Decode, Encode};
+    use tree_hash::TreeHash;
+    use typenum::{Const, ToUInt, Unsigned};
+
+    fn capacity<const N: usize>(_: &FixedVector<u8, N>) -> usize
+    where
+        Const<N>: ToUInt,
+        U<N>: Unsigned,
+    {
+        N
+    }
+
+    #[test]
+    fn aliases_are_typenum_types() {
+        let vector: FixedVector<u64, 4> = crate::FixedVector::new(vec![1, 2, 3, 4]).unwrap();
+        let typenum: crate::FixedVector<u64, typenum::U4> = vector.clone();
+        assert_eq!(vector, typenum);
+
+        let list: VariableList<u64, 1024> = crate::VariableList::new(vec![1, 2]).unwrap();
+        let typenum: crate::VariableList<u64, typenum::U1024> = list.clone();
+        assert_eq!(list.tree_hash_root(), typenum.tree_hash_root());
+
+        let bits: BitVector<8> = crate::BitVector::<typenum::U8>::new();
+        assert_eq!(bits.len(), 8);
+
+        let bits: BitList<2048> = crate::BitList::with_capacity(2048).unwrap();
+        assert_eq!(
+            BitList::<2048>::from_ssz_bytes(&bits.as_ssz_bytes()),
+            Ok(bits)
+        );
+    }
+
+    #[test]
+    fn spec_limits() {
+        assert_eq!(<U<{ 1 << 30 }> as Unsigned>::to_usize(), 1 << 30);
+        assert_eq!(<U<{ 1 << 40 }> as Unsigned>::to_usize(), 1 << 40);
+        assert_eq!(VariableList::<u8, { 1 << 30 }>::max_len(), 1 << 30);
+        assert_eq!(capacity::<48>(&FixedVector::<u8, 48>::default()), 48);
+    }
+}
@@ -0,0 +1,289 @@
+use crate::tree_hash::MaybeSync;
+use crate::{Error, MemorySize, MerklePath, MerkleProof, SszReflect, SszSchema, VariableList};
+use derivative::Derivative;
+use std::ops::Deref;
+use std::sync::Arc;
+use tree_hash::Hash256;
+use typenum::Unsigned;
+
+/// A `VariableList` behind an `Arc`, which is copied only when it is modified.
+///
+/// Cloning is `O(1)` regardless of the length of the list. All clones share the same allocation
+/// until one of them calls `CowList::make_mut`, which copies the list if it is still shared.
+///
+/// Has identical SSZ encoding and tree hash root to `VariableList<T, N>`, so it can be used in
+/// place of a large list in a container that is cloned frequently.
+///
+/// ## Example
+///
+/// ```
+/// use ssz_types::{CowList, VariableList, typenum};
+///
+/// let list: CowList<u64, typenum::U4> = VariableList::new(vec![1, 2]).unwrap().into();
+/// let mut copy = list.clone();
+/// assert!(CowList::ptr_eq(&list, &copy));
+///
+/// copy.make_mut().push(3).unwrap();
+/// assert!(!CowList::ptr_eq(&list, &copy));
+/// assert_eq!(&list[..], &[1, 2]);
+/// assert_eq!(&copy[..], &[1, 2, 3]);
+/// ```
+#[derive(Derivative)]
+#[derivative(
+    Debug(bound = "VariableList<T, N>: std::fmt::Debug"),
+    Clone(bound = ""),
+    PartialEq(bound = "VariableList<T, N>: PartialEq"),
+    Eq(bound = "VariableList<T, N>: Eq"),
+    Hash(bound = "VariableList<T, N>: std::hash::Hash")
+)]
+pub struct CowList<T, N> {
+    list: Arc<VariableList<T, N>>,
+}
+
+impl<T, N: Unsigned> CowList<T, N> {
+    /// Returns `Ok` if the given `vec` does not exceed the maximum length of `Self`. Otherwise
+    /// returns `Err`.
+    pub fn new(vec: Vec<T>) -> Result<Self, Error> {
+        VariableList::new(vec).map(Self::from)
+    }
+
+    /// Returns `true` if `this` and `other` share the same allocation.
+    pub fn ptr_eq(this: &Self, other: &Self) -> bool {
+        Arc::ptr_eq(&this.list, &other.list)
+    }
+}
+
+impl<T: Clone, N: Unsigned> CowList<T, N> {
+    /// Returns a mutable reference to the list, first copying it if it is shared with another
+    /// `CowList`.
+    pub fn make_mut(&mut self) -> &mut VariableList<T, N> {
+        Arc::make_mut(&mut self.list)
+    }
+
+    /// Returns the list, copying it if it is shared with another `CowList`.
+    pub fn into_inner(self) -> VariableList<T, N> {
+        Arc::try_unwrap(self.list).unwrap_or_else(|list| (*list).clone())
+    }
+}
+
+impl<T, N: Unsigned> From<VariableList<T, N>> for CowList<T, N> {
+    fn from(list: VariableList<T, N>) -> Self {
+        Self {
+            list: Arc::new(list),
+        }
+    }
+}
+
+impl<T, N: Unsigned> From<Arc<VariableList<T, N>>> for CowList<T, N> {
+    fn from(list: Arc<VariableList<T, N>>) -> Self {
+        Self { list }
+    }
+}
+
+impl<T, N: Unsigned> TryFrom<Vec<T>> for CowList<T, N> {
+    type Error = Error;
+
+    /// Equivalent to `Self::new`.
+    fn try_from(vec: Vec<T>) -> Result<Self, Error> {
+        Self::new(vec)
+    }
+}
+
+impl<T, N: Unsigned> Default for CowList<T, N> {
+    fn default() -> Self {
+        VariableList::empty().into()
+    }
+}
+
+impl<T, N: Unsigned> Deref for CowList<T, N> {
+    type Target = VariableList<T, N>;
+
+    fn deref(&self) -> &VariableList<T, N> {
+        &self.list
+    }
+}
+
+impl<T, N: Unsigned> AsRef<VariableList<T, N>> for CowList<T, N> {
+    fn as_ref(&self) -> &VariableList<T, N> {
+        &self.list
+    }
+}
+
+impl<'a, T, N: Unsigned> IntoIterator for &'a CowList<T, N> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.list.iter()
+    }
+}
+
+impl<T: MemorySize, N: Unsigned> MemorySize for CowList<T, N> {
+    fn heap_size(&self) -> usize {
+        self.list.heap_size()
+    }
+}
+
+impl<T, N: Unsigned> MerkleProof for CowList<T, N>
+where
+    T: tree_hash::TreeHash + MerkleProof,
+{
+    fn tree_hash_node(&self, gindex: u64) -> Result<Hash256, Error> {
+        self.list.tree_hash_node(gindex)
+    }
+}
+
+impl<T, N: Unsigned> MerklePath for CowList<T, N>
+where
+    T: tree_hash::TreeHash + MerklePath,
+{
+    fn gindex_of(path: &[&str]) -> Result<u64, Error> {
+        VariableList::<T, N>::gindex_of(path)
+    }
+}
+
+impl<T: SszReflect, N: Unsigned> SszReflect for CowList<T, N> {
+    fn ssz_schema() -> SszSchema {
+        VariableList::<T, N>::ssz_schema()
+    }
+}
+
+impl<T: serde::Serialize, N: Unsigned> serde::Serialize for CowList<T, N> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.list.serialize(serializer)
+    }
+}
+
+impl<'de, T: serde::Deserialize<'de>, N: Unsigned> serde::Deserialize<'de> for CowList<T, N> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        VariableList::deserialize(deserializer).map(Self::from)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl<T: schemars::JsonSchema, N: Unsigned> schemars::JsonSchema for CowList<T, N> {
+    fn is_referenceable() -> bool {
+        false
+    }
+
+    fn schema_name() -> String {
+        VariableList::<T, N>::schema_name()
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        VariableList::<T, N>::json_schema(gen)
+    }
+}
+
+impl<T: tree_hash::TreeHash + MaybeSync, N: Unsigned> tree_hash::TreeHash for CowList<T, N> {
+    fn tree_hash_type() -> tree_hash::TreeHashType {
+        tree_hash::TreeHashType::List
+    }
+
+    fn tree_hash_packed_encoding(&self) -> tree_hash::PackedEncoding {
+        unreachable!("List should never be packed.")
+    }
+
+    fn tree_hash_packing_factor() -> usize {
+        unreachable!("List should never be packed.")
+    }
+
+    fn tree_hash_root(&self) -> Hash256 {
+        self.list.tree_hash_root()
+    }
+}
+
+impl<T: ssz::Encode, N: Unsigned> ssz::Encode for CowList<T, N> {
+    fn is_ssz_fixed_len() -> bool {
+        <VariableList<T, N>>::is_ssz_fixed_len()
+    }
+
+    fn ssz_fixed_len() -> usize {
+        <VariableList<T, N>>::ssz_fixed_len()
+    }
+
+    fn ssz_bytes_len(&self) -> usize {
+        self.list.ssz_bytes_len()
+    }
+
+    fn ssz_append(&self, buf: &mut Vec<u8>) {
+        self.list.ssz_append(buf)
+    }
+}
+
+impl<T: ssz::Decode, N: Unsigned> ssz::Decode for CowList<T, N> {
+    fn is_ssz_fixed_len() -> bool {
+        <VariableList<T, N>>::is_ssz_fixed_len()
+    }
+
+    fn ssz_fixed_len() -> usize {
+        <VariableList<T, N>>::ssz_fixed_len()
+    }
+
+    fn from_ssz_bytes(bytes: &[u8]) -> Result<Self, ssz::DecodeError> {
+        VariableList::from_ssz_bytes(bytes).map(Self::from)
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a, T: arbitrary::Arbitrary<'a>, N: 'static + Unsigned> arbitrary::Arbitrary<'a>
+    for CowList<T, N>
+{
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        VariableList::arbitrary(u).map(Self::from)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ssz::{Decode, Encode};
+    use tree_hash::TreeHash;
+    use typenum::*;
+
+    #[test]
+    fn copy_on_write() {
+        let list: CowList<u64, U4> = CowList::new(vec![1, 2]).unwrap();
+        let mut copy = list.clone();
+        assert!(CowList::ptr_eq(&list, &copy));
+        assert_eq!(list, copy);
+
+        copy.make_mut().push(3).unwrap();
+        assert!(!CowList::ptr_eq(&list, &copy));
+        assert_eq!(&list[..], &[1, 2]);
+        assert_eq!(&copy[..], &[1, 2, 3]);
+
+        // A list that is no longer shared is modified in place.
+        let before = &copy[0] as *const u64;
+        copy.make_mut()[0] = 42;
+        assert_eq!(&copy[0] as *const u64, before);
+
+        assert_eq!(&copy.into_inner()[..], &[42, 2, 3]);
+        assert!(CowList::<u64, U4>::new(vec![0; 5]).is_err());
+    }
+
+    #[test]
+    fn matches_variable_list() {
+        let list: VariableList<VariableList<u8, U4>, U8> = VariableList::new(vec![
+            VariableList::new(vec![1, 2]).unwrap(),
+            VariableList::empty(),
+        ])
+        .unwrap();
+        let cow = CowList::from(list.clone());
+
+        assert_eq!(cow.as_ssz_bytes(), list.as_ssz_bytes());
+        assert_eq!(cow.tree_hash_root(), list.tree_hash_root());
+        assert_eq!(
+            CowList::from_ssz_bytes(&list.as_ssz_bytes()),
+            Ok(cow.clone())
+        );
+        assert_eq!(
+            serde_json::to_string(&cow).unwrap(),
+            serde_json::to_string(&list).unwrap()
+        );
+        assert_eq!(
+            serde_json::from_str::<CowList<VariableList<u8, U4>, U8>>(r#"[[1,2],[]]"#).unwrap(),
+            cow
+        );
+    }
+}
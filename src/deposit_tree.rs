@@ -0,0 +1,581 @@
+use crate::hasher::hash32_concat;
+use crate::tree_hash::mix_in_length;
+use crate::{DepositData, Error, VariableList, DEPOSIT_CONTRACT_TREE_DEPTH};
+use ssz_derive::{Decode, Encode};
+use tree_hash::{Hash256, TreeHash};
+use tree_hash_derive::TreeHash;
+
+/// A node of a `DepositTree`, as per the `MerkleTree` of EIP-4881.
+#[derive(Clone, Debug, PartialEq)]
+enum Node {
+    /// A subtree of the given height which only holds empty leaves.
+    Zero(usize),
+    Leaf(Hash256),
+    Branch {
+        left: Box<Node>,
+        right: Box<Node>,
+        root: Hash256,
+    },
+    /// A full subtree of finalized deposits, of which only the root is kept.
+    Finalized {
+        count: u64,
+        root: Hash256,
+    },
+}
+
+impl Node {
+    fn root(&self) -> Hash256 {
+        match self {
+            Node::Zero(height) => zero_hash(*height),
+            Node::Leaf(root) | Node::Branch { root, .. } | Node::Finalized { root, .. } => *root,
+        }
+    }
+
+    fn branch(left: Node, right: Node) -> Self {
+        let root = hash32_concat(left.root().as_bytes(), right.root().as_bytes());
+
+        Node::Branch {
+            left: Box::new(left),
+            right: Box::new(right),
+            root,
+        }
+    }
+
+    /// Appends `leaf` to a subtree of the given `height` holding `count` leaves.
+    fn push(&mut self, leaf: Hash256, count: u64, height: usize) {
+        match self {
+            Node::Zero(_) if height == 0 => *self = Node::Leaf(leaf),
+            Node::Zero(_) => {
+                let mut left = Node::Zero(height - 1);
+                left.push(leaf, count, height - 1);
+                *self = Node::branch(left, Node::Zero(height - 1));
+            }
+            Node::Branch { left, right, root } => {
+                let half = 1 << (height - 1);
+                if count < half {
+                    left.push(leaf, count, height - 1);
+                } else {
+                    right.push(leaf, count - half, height - 1);
+                }
+                *root = hash32_concat(left.root().as_bytes(), right.root().as_bytes());
+            }
+            Node::Leaf(_) | Node::Finalized { .. } => {
+                unreachable!("a full subtree is never pushed to")
+            }
+        }
+    }
+
+    /// Replaces the subtrees holding only the first `count` leaves by their roots.
+    fn finalize(&mut self, count: u64, height: usize) {
+        if count == 0 {
+            return;
+        }
+
+        match self {
+            Node::Leaf(_) | Node::Branch { .. } if count >= 1 << height => {
+                *self = Node::Finalized {
+                    count: 1 << height,
+                    root: self.root(),
+                }
+            }
+            Node::Branch { left, right, .. } => {
+                let half = 1 << (height - 1);
+                left.finalize(count, height - 1);
+                if count > half {
+                    right.finalize(count - half, height - 1);
+                }
+            }
+            Node::Zero(_) | Node::Leaf(_) | Node::Finalized { .. } => {}
+        }
+    }
+
+    /// Appends the roots of the finalized subtrees to `roots`, from left to right, and returns the
+    /// number of leaves they hold.
+    fn finalized(&self, roots: &mut Vec<Hash256>) -> u64 {
+        match self {
+            Node::Finalized { count, root } => {
+                roots.push(*root);
+                *count
+            }
+            Node::Branch { left, right, .. } => left.finalized(roots) + right.finalized(roots),
+            Node::Zero(_) | Node::Leaf(_) => 0,
+        }
+    }
+
+    /// Rebuilds a subtree of the given `height` holding `count` leaves, all of which are finalized
+    /// as the subtrees with the given `roots`.
+    fn from_finalized(roots: &[Hash256], count: u64, height: usize) -> Self {
+        match roots.split_first() {
+            None => Node::Zero(height),
+            Some(_) if count == 0 => Node::Zero(height),
+            Some((&root, _)) if count == 1 << height => Node::Finalized { count, root },
+            Some((&root, rest)) => {
+                let half = 1 << (height - 1);
+                if count <= half {
+                    Node::branch(
+                        Node::from_finalized(roots, count, height - 1),
+                        Node::Zero(height - 1),
+                    )
+                } else {
+                    Node::branch(
+                        Node::Finalized { count: half, root },
+                        Node::from_finalized(rest, count - half, height - 1),
+                    )
+                }
+            }
+        }
+    }
+}
+
+/// The incremental Merkle tree of the deposit contract, which can generate proofs for the
+/// deposits it holds.
+///
+/// Deposits which are no longer needed can be finalized, replacing every full subtree of finalized
+/// deposits by its root, as per [EIP-4881](https://eips.ethereum.org/EIPS/eip-4881). Proofs can
+/// only be generated for deposits which are not finalized.
+///
+/// ## Example
+///
+/// ```
+/// use ssz_types::{DepositData, DepositTree};
+///
+/// let mut tree = DepositTree::default();
+/// tree.push(&DepositData::default()).unwrap();
+/// tree.push(&DepositData::default()).unwrap();
+///
+/// let proof = tree.generate_proof(1).unwrap();
+/// assert_eq!(proof.len(), 33);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct DepositTree {
+    tree: Node,
+    deposit_count: u64,
+    finalized_execution_block: Option<(Hash256, u64)>,
+}
+
+impl Default for DepositTree {
+    fn default() -> Self {
+        Self {
+            tree: Node::Zero(DEPOSIT_CONTRACT_TREE_DEPTH),
+            deposit_count: 0,
+            finalized_execution_block: None,
+        }
+    }
+}
+
+impl DepositTree {
+    /// Returns the number of deposits in the tree, including finalized deposits.
+    pub fn deposit_count(&self) -> u64 {
+        self.deposit_count
+    }
+
+    /// Returns the root of the tree, as returned by `get_deposit_root` of the deposit contract.
+    pub fn root(&self) -> Hash256 {
+        mix_in_length(&self.tree.root(), self.deposit_count as usize)
+    }
+
+    /// Appends a deposit to the tree.
+    ///
+    /// Returns `Err` if the tree is full.
+    pub fn push(&mut self, deposit_data: &DepositData) -> Result<(), Error> {
+        let limit = 1 << DEPOSIT_CONTRACT_TREE_DEPTH;
+
+        if self.deposit_count >= limit {
+            return Err(Error::OutOfBounds {
+                i: self.deposit_count as usize,
+                len: limit as usize,
+            });
+        }
+
+        self.tree.push(
+            deposit_data.tree_hash_root(),
+            self.deposit_count,
+            DEPOSIT_CONTRACT_TREE_DEPTH,
+        );
+        self.deposit_count += 1;
+
+        Ok(())
+    }
+
+    /// Returns the proof of the deposit at `index`, as expected by `Deposit::proof`.
+    ///
+    /// Returns `Err` if there is no deposit at `index` or if it has been finalized.
+    pub fn generate_proof(&self, index: u64) -> Result<Vec<Hash256>, Error> {
+        if index >= self.deposit_count {
+            return Err(Error::OutOfBounds {
+                i: index as usize,
+                len: self.deposit_count as usize,
+            });
+        }
+
+        let mut proof = Vec::with_capacity(DEPOSIT_CONTRACT_TREE_DEPTH + 1);
+        let mut node = &self.tree;
+        for height in (0..DEPOSIT_CONTRACT_TREE_DEPTH).rev() {
+            let Node::Branch { left, right, .. } = node else {
+                return Err(Error::FinalizedDeposit { index });
+            };
+
+            if (index >> height) & 1 == 1 {
+                proof.push(left.root());
+                node = right;
+            } else {
+                proof.push(right.root());
+                node = left;
+            }
+        }
+        if !matches!(node, Node::Leaf(_)) {
+            return Err(Error::FinalizedDeposit { index });
+        }
+
+        proof.reverse();
+        proof.push(self.deposit_count.tree_hash_root());
+
+        Ok(proof)
+    }
+
+    /// Finalizes the first `deposit_count` deposits, which are those included in the execution
+    /// block with the given hash and height.
+    ///
+    /// Returns `Err` if the tree holds less than `deposit_count` deposits.
+    pub fn finalize(
+        &mut self,
+        deposit_count: u64,
+        execution_block_hash: Hash256,
+        execution_block_height: u64,
+    ) -> Result<(), Error> {
+        if deposit_count > self.deposit_count {
+            return Err(Error::OutOfBounds {
+                i: deposit_count as usize,
+                len: self.deposit_count as usize,
+            });
+        }
+
+        self.tree
+            .finalize(deposit_count, DEPOSIT_CONTRACT_TREE_DEPTH);
+        self.finalized_execution_block = Some((execution_block_hash, execution_block_height));
+
+        Ok(())
+    }
+
+    /// Returns the snapshot of the finalized deposits, from which the tree can be rebuilt.
+    ///
+    /// Returns `None` if no deposits have been finalized.
+    pub fn snapshot(&self) -> Option<DepositTreeSnapshot> {
+        let (execution_block_hash, execution_block_height) = self.finalized_execution_block?;
+
+        let mut finalized = vec![];
+        let deposit_count = self.tree.finalized(&mut finalized);
+        let mut snapshot = DepositTreeSnapshot {
+            finalized: VariableList::new(finalized)
+                .expect("a tree has at most one finalized subtree per level"),
+            deposit_root: Hash256::zero(),
+            deposit_count,
+            execution_block_hash,
+            execution_block_height,
+        };
+        snapshot.deposit_root = snapshot.calculate_root();
+
+        Some(snapshot)
+    }
+
+    /// Rebuilds the tree of finalized deposits from a snapshot. Further deposits can be appended
+    /// with `push`.
+    ///
+    /// Returns `Err` if the snapshot has more deposits than the tree can hold, if it does not hold
+    /// one root per full subtree of its deposits, or if its `deposit_root` is not the root of
+    /// those subtrees.
+    pub fn from_snapshot(snapshot: &DepositTreeSnapshot) -> Result<Self, Error> {
+        let limit = 1 << DEPOSIT_CONTRACT_TREE_DEPTH;
+        if snapshot.deposit_count >= limit {
+            return Err(Error::OutOfBounds {
+                i: snapshot.deposit_count as usize,
+                len: limit as usize,
+            });
+        }
+        let subtrees = snapshot.deposit_count.count_ones() as usize;
+        if snapshot.finalized.len() != subtrees {
+            return Err(Error::OutOfBounds {
+                i: snapshot.finalized.len(),
+                len: subtrees,
+            });
+        }
+        let found = snapshot.calculate_root();
+        if snapshot.deposit_root != found {
+            return Err(Error::DepositRootMismatch {
+                expected: snapshot.deposit_root,
+                found,
+            });
+        }
+
+        Ok(Self {
+            tree: Node::from_finalized(
+                &snapshot.finalized,
+                snapshot.deposit_count,
+                DEPOSIT_CONTRACT_TREE_DEPTH,
+            ),
+            deposit_count: snapshot.deposit_count,
+            finalized_execution_block: Some((
+                snapshot.execution_block_hash,
+                snapshot.execution_block_height,
+            )),
+        })
+    }
+}
+
+/// The finalized part of a `DepositTree`, as per EIP-4881, from which nodes that sync from a
+/// checkpoint resume tracking deposits.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Encode, Decode, TreeHash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[ssz(struct_behaviour = "container")]
+pub struct DepositTreeSnapshot {
+    /// The roots of the full subtrees of finalized deposits, from left to right.
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "crate::json_schema::HexItems<VariableList<Hash256, typenum::U32>>")
+    )]
+    pub finalized: VariableList<Hash256, typenum::U32>,
+    /// The root of the tree of finalized deposits, as returned by `get_deposit_root` of the
+    /// deposit contract.
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "crate::json_schema::Hex<Hash256>")
+    )]
+    pub deposit_root: Hash256,
+    #[cfg_attr(feature = "serde", serde(with = "serde_utils::quoted_u64"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "crate::json_schema::Quoted<u64>")
+    )]
+    pub deposit_count: u64,
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "crate::json_schema::Hex<Hash256>")
+    )]
+    pub execution_block_hash: Hash256,
+    #[cfg_attr(feature = "serde", serde(with = "serde_utils::quoted_u64"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "crate::json_schema::Quoted<u64>")
+    )]
+    pub execution_block_height: u64,
+}
+
+impl_ssz_reflect_for_container!(
+    DepositTreeSnapshot,
+    finalized,
+    deposit_root,
+    deposit_count,
+    execution_block_hash,
+    execution_block_height,
+);
+
+impl DepositTreeSnapshot {
+    /// Returns the root of the tree of the finalized deposits of the snapshot, as per
+    /// `calculate_root` in EIP-4881.
+    ///
+    /// Each set bit of `deposit_count` is a full subtree of finalized deposits, whose root is
+    /// taken from `finalized` from right to left. Missing roots are taken as zero.
+    pub fn calculate_root(&self) -> Hash256 {
+        let mut size = self.deposit_count;
+        let mut finalized = self.finalized.iter().rev();
+        let mut root = zero_hash(0);
+        for height in 0..DEPOSIT_CONTRACT_TREE_DEPTH {
+            root = if size & 1 == 1 {
+                let left = finalized.next().copied().unwrap_or_default();
+                hash32_concat(left.as_bytes(), root.as_bytes())
+            } else {
+                hash32_concat(root.as_bytes(), zero_hash(height).as_bytes())
+            };
+            size >>= 1;
+        }
+
+        mix_in_length(&root, self.deposit_count as usize)
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for DepositTreeSnapshot {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let finalized = u
+            .arbitrary_iter::<[u8; 32]>()?
+            .take(DEPOSIT_CONTRACT_TREE_DEPTH)
+            .map(|root| root.map(Hash256::from))
+            .collect::<arbitrary::Result<Vec<_>>>()?;
+
+        Ok(Self {
+            finalized: VariableList::new(finalized).expect("at most 32 roots are taken"),
+            deposit_root: Hash256::from(u.arbitrary::<[u8; 32]>()?),
+            deposit_count: u.arbitrary()?,
+            execution_block_hash: Hash256::from(u.arbitrary::<[u8; 32]>()?),
+            execution_block_height: u.arbitrary()?,
+        })
+    }
+}
+
+fn zero_hash(height: usize) -> Hash256 {
+    Hash256::from_slice(&ethereum_hashing::ZERO_HASHES[height])
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Deposit, FixedVector, Gwei};
+    use ssz::{Decode, Encode};
+
+    fn deposit_data(i: u64) -> DepositData {
+        DepositData {
+            amount: Gwei::new(i),
+            ..DepositData::default()
+        }
+    }
+
+    fn contract_root(deposits: &[DepositData]) -> Hash256 {
+        let leaves = deposits
+            .iter()
+            .flat_map(|deposit| deposit.tree_hash_root().0)
+            .collect::<Vec<_>>();
+        let root = tree_hash::merkle_root(&leaves, 1 << DEPOSIT_CONTRACT_TREE_DEPTH);
+
+        tree_hash::mix_in_length(&root, deposits.len())
+    }
+
+    #[test]
+    fn push_and_prove() {
+        let deposits = (0..13).map(deposit_data).collect::<Vec<_>>();
+        let mut tree = DepositTree::default();
+        assert_eq!(tree.root(), contract_root(&[]));
+
+        for (i, deposit) in deposits.iter().enumerate() {
+            tree.push(deposit).unwrap();
+            assert_eq!(tree.root(), contract_root(&deposits[..=i]));
+        }
+
+        let root = FixedVector::new(tree.root().0.to_vec()).unwrap();
+        for index in 0..deposits.len() as u64 {
+            let proof = tree.generate_proof(index).unwrap();
+            let deposit = Deposit {
+                proof: FixedVector::new(
+                    proof
+                        .iter()
+                        .map(|node| FixedVector::new(node.0.to_vec()).unwrap())
+                        .collect(),
+                )
+                .unwrap(),
+                data: deposits[index as usize].clone(),
+            };
+            assert!(deposit.verify_proof(&root, index));
+        }
+        assert_eq!(
+            tree.generate_proof(13),
+            Err(Error::OutOfBounds { i: 13, len: 13 })
+        );
+    }
+
+    #[test]
+    fn finalize_and_snapshot() {
+        let deposits = (0..21).map(deposit_data).collect::<Vec<_>>();
+        let mut tree = DepositTree::default();
+        for deposit in &deposits[..13] {
+            tree.push(deposit).unwrap();
+        }
+        assert_eq!(tree.snapshot(), None);
+
+        let block_hash = Hash256::repeat_byte(1);
+        tree.finalize(11, block_hash, 100).unwrap();
+        assert_eq!(tree.root(), contract_root(&deposits[..13]));
+        // Deposit 11 is in the same subtree as deposit 10, but is not finalized.
+        assert_eq!(
+            tree.generate_proof(10),
+            Err(Error::FinalizedDeposit { index: 10 })
+        );
+        assert!(tree.generate_proof(11).is_ok());
+
+        let snapshot = tree.snapshot().unwrap();
+        assert_eq!(snapshot.deposit_count, 11);
+        // Subtrees of 8, 2 and 1 deposits.
+        assert_eq!(snapshot.finalized.len(), 3);
+        assert_eq!(snapshot.deposit_root, contract_root(&deposits[..11]));
+        assert_eq!(snapshot.execution_block_hash, block_hash);
+        assert_eq!(snapshot.execution_block_height, 100);
+
+        let mut restored = DepositTree::from_snapshot(&snapshot).unwrap();
+        assert_eq!(restored.root(), contract_root(&deposits[..11]));
+        for deposit in &deposits[11..] {
+            restored.push(deposit).unwrap();
+        }
+        for deposit in &deposits[13..] {
+            tree.push(deposit).unwrap();
+        }
+        assert_eq!(restored.root(), contract_root(&deposits));
+        assert_eq!(restored.generate_proof(20), tree.generate_proof(20));
+        assert_eq!(restored.snapshot(), Some(snapshot));
+
+        assert!(tree.finalize(22, block_hash, 101).is_err());
+    }
+
+    #[test]
+    fn snapshot_container() {
+        let deposits = (0..6).map(deposit_data).collect::<Vec<_>>();
+        let mut tree = DepositTree::default();
+        for deposit in &deposits {
+            tree.push(deposit).unwrap();
+        }
+        tree.finalize(6, Hash256::repeat_byte(2), 7).unwrap();
+
+        let snapshot = tree.snapshot().unwrap();
+        let bytes = snapshot.as_ssz_bytes();
+        // Two roots, after the offset of `finalized` and the fixed length fields.
+        assert_eq!(bytes.len(), 4 + 32 + 8 + 32 + 8 + 2 * 32);
+        let decoded = DepositTreeSnapshot::from_ssz_bytes(&bytes).unwrap();
+        assert_eq!(decoded, snapshot);
+        assert_eq!(
+            DepositTree::from_snapshot(&decoded).unwrap().root(),
+            contract_root(&deposits)
+        );
+
+        // The roots must be those of the subtrees of 4 and 2 deposits.
+        let mut invalid = snapshot.clone();
+        invalid.finalized.swap(0, 1);
+        assert_eq!(
+            DepositTree::from_snapshot(&invalid),
+            Err(Error::DepositRootMismatch {
+                expected: snapshot.deposit_root,
+                found: invalid.calculate_root(),
+            })
+        );
+        invalid.finalized.pop();
+        assert_eq!(
+            DepositTree::from_snapshot(&invalid),
+            Err(Error::OutOfBounds { i: 1, len: 2 })
+        );
+
+        // The count must fit in the tree, even when the roots match it.
+        let mut full = DepositTreeSnapshot {
+            finalized: VariableList::new(vec![Hash256::repeat_byte(1)]).unwrap(),
+            deposit_count: 1 << DEPOSIT_CONTRACT_TREE_DEPTH,
+            ..snapshot.clone()
+        };
+        full.deposit_root = full.calculate_root();
+        assert_eq!(
+            DepositTree::from_snapshot(&full),
+            Err(Error::OutOfBounds {
+                i: 1 << DEPOSIT_CONTRACT_TREE_DEPTH,
+                len: 1 << DEPOSIT_CONTRACT_TREE_DEPTH,
+            })
+        );
+
+        let empty = DepositTreeSnapshot {
+            deposit_root: DepositTree::default().root(),
+            ..DepositTreeSnapshot::default()
+        };
+        assert_eq!(empty.calculate_root(), empty.deposit_root);
+        assert_eq!(
+            DepositTree::from_snapshot(&empty).unwrap().root(),
+            contract_root(&[])
+        );
+    }
+}
@@ -0,0 +1,249 @@
+//! The signature domains of the consensus specs.
+//!
+//! Every signed message is signed over the root of its `SigningData`, which mixes in the `Domain`
+//! of the type of the message and of the chain and fork it is signed on. This module holds the
+//! `DomainType` of every signed message of the consensus and builder specs, and computes their
+//! domains.
+//!
+//! ## Example
+//!
+//! ```
+//! use ssz_types::domains::{compute_domain, DOMAIN_RANDAO};
+//! use tree_hash::Hash256;
+//!
+//! let domain = compute_domain(DOMAIN_RANDAO, [0, 0, 0, 0], Hash256::zero());
+//! assert_eq!(domain.domain_type(), DOMAIN_RANDAO);
+//! ```
+
+use crate::signing::{compute_fork_data_root, Version};
+use ssz_derive::{Decode, Encode};
+use tree_hash::{Hash256, TreeHash};
+
+/// The 4 byte type of a signature domain, distinguishing e.g. block proposals from attestations.
+pub type DomainType = [u8; 4];
+
+/// The domain type of block proposals.
+pub const DOMAIN_BEACON_PROPOSER: DomainType = [0, 0, 0, 0];
+
+/// The domain type of attestations.
+pub const DOMAIN_BEACON_ATTESTER: DomainType = [1, 0, 0, 0];
+
+/// The domain type of the RANDAO reveals of proposers.
+pub const DOMAIN_RANDAO: DomainType = [2, 0, 0, 0];
+
+/// The domain type of deposits.
+pub const DOMAIN_DEPOSIT: DomainType = [3, 0, 0, 0];
+
+/// The domain type of voluntary exits.
+pub const DOMAIN_VOLUNTARY_EXIT: DomainType = [4, 0, 0, 0];
+
+/// The domain type of the selection proofs of aggregators of attestations.
+pub const DOMAIN_SELECTION_PROOF: DomainType = [5, 0, 0, 0];
+
+/// The domain type of aggregates of attestations.
+pub const DOMAIN_AGGREGATE_AND_PROOF: DomainType = [6, 0, 0, 0];
+
+/// The domain type of the messages of sync committees.
+pub const DOMAIN_SYNC_COMMITTEE: DomainType = [7, 0, 0, 0];
+
+/// The domain type of the selection proofs of aggregators of sync subcommittees.
+pub const DOMAIN_SYNC_COMMITTEE_SELECTION_PROOF: DomainType = [8, 0, 0, 0];
+
+/// The domain type of aggregates of sync committee messages.
+pub const DOMAIN_CONTRIBUTION_AND_PROOF: DomainType = [9, 0, 0, 0];
+
+/// The domain type of changes of withdrawal credentials from BLS keys to execution addresses.
+pub const DOMAIN_BLS_TO_EXECUTION_CHANGE: DomainType = [0x0a, 0, 0, 0];
+
+/// The mask of the domain types of applications built on the consensus layer, which are kept
+/// apart from the domain types of the consensus specs.
+pub const DOMAIN_APPLICATION_MASK: DomainType = [0, 0, 0, 1];
+
+/// The domain type of the messages of the builder API, i.e. validator registrations and bids.
+pub const DOMAIN_APPLICATION_BUILDER: DomainType = [0, 0, 0, 1];
+
+/// A signature domain: a `DomainType` followed by the first 28 bytes of the root of the fork data
+/// it is valid for.
+///
+/// Mixing the domain into every signing root prevents signatures from being replayed for another
+/// type of message or on another chain.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash, Debug, Encode, Decode)]
+#[ssz(struct_behaviour = "transparent")]
+pub struct Domain(Hash256);
+
+impl Domain {
+    /// Returns the domain with the given bytes.
+    pub fn from_bytes(bytes: [u8; 32]) -> Self {
+        Self(Hash256::from(bytes))
+    }
+
+    /// Returns the type of the domain.
+    pub fn domain_type(&self) -> DomainType {
+        let mut domain_type = DomainType::default();
+        domain_type.copy_from_slice(&self.0[..4]);
+        domain_type
+    }
+
+    /// Returns the bytes of the domain.
+    pub fn as_bytes(&self) -> &[u8] {
+        self.0.as_bytes()
+    }
+}
+
+impl TreeHash for Domain {
+    fn tree_hash_type() -> tree_hash::TreeHashType {
+        Hash256::tree_hash_type()
+    }
+
+    fn tree_hash_packed_encoding(&self) -> tree_hash::PackedEncoding {
+        self.0.tree_hash_packed_encoding()
+    }
+
+    fn tree_hash_packing_factor() -> usize {
+        Hash256::tree_hash_packing_factor()
+    }
+
+    fn tree_hash_root(&self) -> Hash256 {
+        self.0
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Domain {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.0, serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Domain {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <Hash256 as serde::Deserialize>::deserialize(deserializer).map(Self)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for Domain {
+    fn schema_name() -> String {
+        "Domain".to_string()
+    }
+
+    fn json_schema(_: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        crate::json_schema::hex_schema(32, 32)
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Domain {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        u.arbitrary().map(Self::from_bytes)
+    }
+}
+
+/// Returns the domain of `domain_type` for the chain with the given fork version and genesis
+/// validators root, as per `compute_domain` in the consensus specs.
+///
+/// ## Example
+///
+/// ```
+/// use ssz_types::domains::{compute_domain, DOMAIN_BEACON_PROPOSER};
+/// use tree_hash::Hash256;
+///
+/// let domain = compute_domain(DOMAIN_BEACON_PROPOSER, [0, 0, 0, 0], Hash256::zero());
+/// assert_eq!(domain.domain_type(), [0, 0, 0, 0]);
+/// ```
+pub fn compute_domain(
+    domain_type: DomainType,
+    fork_version: Version,
+    genesis_validators_root: Hash256,
+) -> Domain {
+    let fork_data_root = compute_fork_data_root(fork_version, genesis_validators_root);
+
+    let mut domain = [0; 32];
+    domain[..4].copy_from_slice(&domain_type);
+    domain[4..].copy_from_slice(&fork_data_root[..28]);
+    Domain::from_bytes(domain)
+}
+
+/// Returns the domain of deposits on a chain with `genesis_fork_version`.
+///
+/// Deposits are made before the chain starts, and must stay valid across its forks, so their
+/// domain uses the genesis fork version and a zero genesis validators root whatever the fork.
+pub fn compute_deposit_domain(genesis_fork_version: Version) -> Domain {
+    compute_domain(DOMAIN_DEPOSIT, genesis_fork_version, Hash256::zero())
+}
+
+/// Returns the domain of the messages of the builder API on a chain with `genesis_fork_version`.
+///
+/// Like deposits, the messages of the builder API are signed in the genesis fork whatever the
+/// current fork, with a zero genesis validators root.
+pub fn compute_builder_domain(genesis_fork_version: Version) -> Domain {
+    compute_domain(
+        DOMAIN_APPLICATION_BUILDER,
+        genesis_fork_version,
+        Hash256::zero(),
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::ForkData;
+    use ssz::{Decode, Encode};
+
+    #[test]
+    fn domain() {
+        let genesis_validators_root = Hash256::repeat_byte(0x42);
+        let domain = compute_domain([7, 0, 0, 0], [1, 2, 3, 4], genesis_validators_root);
+
+        let fork_data = ForkData {
+            current_version: [1, 2, 3, 4],
+            genesis_validators_root,
+        };
+        assert_eq!(domain.domain_type(), [7, 0, 0, 0]);
+        assert_eq!(domain.as_bytes()[4..], fork_data.tree_hash_root()[..28]);
+
+        assert_eq!(domain.as_ssz_bytes(), domain.as_bytes());
+        assert_eq!(Domain::from_ssz_bytes(domain.as_bytes()), Ok(domain));
+
+        let bytes = fork_data.as_ssz_bytes();
+        assert_eq!(bytes.len(), 4 + 32);
+        assert_eq!(ForkData::from_ssz_bytes(&bytes), Ok(fork_data));
+    }
+
+    #[test]
+    fn domain_types() {
+        let domain_types = [
+            DOMAIN_BEACON_PROPOSER,
+            DOMAIN_BEACON_ATTESTER,
+            DOMAIN_RANDAO,
+            DOMAIN_DEPOSIT,
+            DOMAIN_VOLUNTARY_EXIT,
+            DOMAIN_SELECTION_PROOF,
+            DOMAIN_AGGREGATE_AND_PROOF,
+            DOMAIN_SYNC_COMMITTEE,
+            DOMAIN_SYNC_COMMITTEE_SELECTION_PROOF,
+            DOMAIN_CONTRIBUTION_AND_PROOF,
+            DOMAIN_BLS_TO_EXECUTION_CHANGE,
+        ];
+        // The domain types of the consensus specs are numbered in order, and none is masked as an
+        // application.
+        for (i, domain_type) in domain_types.into_iter().enumerate() {
+            assert_eq!(u32::from_le_bytes(domain_type), i as u32);
+            assert_eq!(domain_type[3] & DOMAIN_APPLICATION_MASK[3], 0);
+        }
+        assert_ne!(
+            DOMAIN_APPLICATION_BUILDER[3] & DOMAIN_APPLICATION_MASK[3],
+            0
+        );
+
+        assert_eq!(
+            compute_builder_domain([1, 2, 3, 4]),
+            compute_domain(DOMAIN_APPLICATION_BUILDER, [1, 2, 3, 4], Hash256::zero())
+        );
+        assert_eq!(
+            compute_deposit_domain([1, 2, 3, 4]).domain_type(),
+            DOMAIN_DEPOSIT
+        );
+    }
+}
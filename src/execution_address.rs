@@ -0,0 +1,382 @@
+use crate::hex::fmt_hex;
+use crate::Error;
+use ssz::{Decode, DecodeError, Encode};
+use std::fmt;
+use std::str::FromStr;
+use tiny_keccak::{Hasher, Keccak};
+use tree_hash::{Hash256, PackedEncoding, TreeHash, TreeHashType};
+
+/// The number of bytes of an execution address.
+const ADDRESS_LEN: usize = 20;
+
+/// A 20 byte address of the execution layer, e.g. a fee recipient or the target of a withdrawal.
+///
+/// Encodes and hashes like a `ByteVector[20]`. Addresses are displayed with the mixed-case
+/// checksum of EIP-55, and parsed from hex in lowercase, uppercase or with a valid checksum.
+///
+/// ## Example
+///
+/// ```
+/// use ssz_types::ExecutionAddress;
+///
+/// let address: ExecutionAddress = "0x5aaeb6053f3e94c9b9a09f33669435e7ef1beaed".parse().unwrap();
+/// assert_eq!(
+///     address.to_string(),
+///     "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed"
+/// );
+///
+/// // A mixed-case address must have a valid checksum.
+/// assert!("0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAeD"
+///     .parse::<ExecutionAddress>()
+///     .is_err());
+/// ```
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct ExecutionAddress([u8; ADDRESS_LEN]);
+
+impl ExecutionAddress {
+    /// The zero address, which is e.g. the fee recipient of blocks of validators that did not set
+    /// one.
+    pub const ZERO: Self = Self([0; ADDRESS_LEN]);
+
+    /// The deposit contract of Ethereum mainnet.
+    pub const MAINNET_DEPOSIT_CONTRACT: Self =
+        Self::from_hex_const("0x00000000219ab540356cBB839Cbe05303d7705Fa");
+
+    /// The system contract which queues the withdrawal requests of EIP-7002.
+    pub const WITHDRAWAL_REQUEST_PREDEPLOY: Self =
+        Self::from_hex_const("0x00000961Ef480Eb55e80D19ad83579A64c007002");
+
+    /// The system contract which queues the consolidation requests of EIP-7251.
+    pub const CONSOLIDATION_REQUEST_PREDEPLOY: Self =
+        Self::from_hex_const("0x0000BBdDc7CE488642fb579F8B00f3a590007251");
+
+    /// Returns the address with the given bytes.
+    pub const fn new(bytes: [u8; ADDRESS_LEN]) -> Self {
+        Self(bytes)
+    }
+
+    /// Returns the address of `hex`, a `0x` prefixed string of 40 hex digits, without checking
+    /// its checksum. This allows addresses to be declared as constants.
+    ///
+    /// Panics if `hex` is malformed, which fails compilation in a constant.
+    pub const fn from_hex_const(hex: &str) -> Self {
+        let hex = hex.as_bytes();
+        assert!(
+            hex.len() == 2 + 2 * ADDRESS_LEN && hex[0] == b'0' && hex[1] == b'x',
+            "an address is 0x followed by 40 hex digits"
+        );
+
+        let mut bytes = [0; ADDRESS_LEN];
+        let mut i = 0;
+        while i < ADDRESS_LEN {
+            bytes[i] = (hex_digit(hex[2 + 2 * i]) << 4) | hex_digit(hex[3 + 2 * i]);
+            i += 1;
+        }
+        Self(bytes)
+    }
+
+    /// Returns the address in `bytes`, which must be 20 bytes long.
+    pub fn from_slice(bytes: &[u8]) -> Result<Self, Error> {
+        <[u8; ADDRESS_LEN]>::try_from(bytes)
+            .map(Self)
+            .map_err(|_| Error::InvalidByteCount {
+                given: bytes.len(),
+                expected: ADDRESS_LEN,
+            })
+    }
+
+    /// Returns the bytes of the address.
+    pub fn as_bytes(&self) -> &[u8; ADDRESS_LEN] {
+        &self.0
+    }
+
+    /// Returns the address in lowercase hex, prefixed with `0x`.
+    pub fn to_lowercase_hex(&self) -> String {
+        let mut hex = String::with_capacity(2 + 2 * ADDRESS_LEN);
+        hex.push_str("0x");
+        for byte in self.0 {
+            hex.push_str(&format!("{:02x}", byte));
+        }
+        hex
+    }
+}
+
+/// Returns the value of the ASCII hex digit `digit`, in a constant context.
+const fn hex_digit(digit: u8) -> u8 {
+    match digit {
+        b'0'..=b'9' => digit - b'0',
+        b'a'..=b'f' => digit - b'a' + 10,
+        b'A'..=b'F' => digit - b'A' + 10,
+        _ => panic!("invalid hex digit in address"),
+    }
+}
+
+/// Returns the Keccak-256 hash of the lowercase hex digits of an address, whose nibbles decide
+/// the case of the digits in the checksummed form.
+fn checksum_hash(lowercase_digits: &str) -> [u8; 32] {
+    let mut hash = [0; 32];
+    let mut keccak = Keccak::v256();
+    keccak.update(lowercase_digits.as_bytes());
+    keccak.finalize(&mut hash);
+    hash
+}
+
+impl fmt::Debug for ExecutionAddress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("ExecutionAddress(")?;
+        fmt_hex(&self.0, f)?;
+        f.write_str(")")
+    }
+}
+
+/// Formats the address as lowercase hex, prefixed by `0x` with the `#` flag.
+impl fmt::LowerHex for ExecutionAddress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let lowercase = self.to_lowercase_hex();
+        if f.alternate() {
+            f.write_str(&lowercase)
+        } else {
+            f.write_str(&lowercase[2..])
+        }
+    }
+}
+
+impl fmt::Display for ExecutionAddress {
+    /// Writes the address with the checksum of EIP-55.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let lowercase = self.to_lowercase_hex();
+        let digits = &lowercase[2..];
+        let hash = checksum_hash(digits);
+
+        f.write_str("0x")?;
+        for (i, digit) in digits.chars().enumerate() {
+            let nibble = (hash[i / 2] >> (4 * (1 - i % 2))) & 0xf;
+            if nibble >= 8 {
+                write!(f, "{}", digit.to_ascii_uppercase())?;
+            } else {
+                write!(f, "{}", digit)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for ExecutionAddress {
+    type Err = Error;
+
+    /// Parses a `0x` prefixed address. Addresses in mixed case must have a valid EIP-55
+    /// checksum.
+    fn from_str(address: &str) -> Result<Self, Error> {
+        let invalid = || Error::InvalidExecutionAddress {
+            address: address.to_string(),
+        };
+
+        let digits = address.strip_prefix("0x").ok_or_else(invalid)?;
+        if digits.len() != 2 * ADDRESS_LEN || !digits.bytes().all(|c| c.is_ascii_hexdigit()) {
+            return Err(invalid());
+        }
+        let mut bytes = [0; ADDRESS_LEN];
+        for (i, byte) in bytes.iter_mut().enumerate() {
+            *byte = u8::from_str_radix(&digits[2 * i..2 * i + 2], 16).map_err(|_| invalid())?;
+        }
+        let parsed = Self(bytes);
+
+        let is_mixed_case = digits.bytes().any(|c| c.is_ascii_lowercase())
+            && digits.bytes().any(|c| c.is_ascii_uppercase());
+        if is_mixed_case && parsed.to_string() != address {
+            return Err(invalid());
+        }
+        Ok(parsed)
+    }
+}
+
+impl Encode for ExecutionAddress {
+    fn is_ssz_fixed_len() -> bool {
+        true
+    }
+
+    fn ssz_fixed_len() -> usize {
+        ADDRESS_LEN
+    }
+
+    fn ssz_bytes_len(&self) -> usize {
+        ADDRESS_LEN
+    }
+
+    fn ssz_append(&self, buf: &mut Vec<u8>) {
+        buf.extend_from_slice(&self.0);
+    }
+}
+
+impl Decode for ExecutionAddress {
+    fn is_ssz_fixed_len() -> bool {
+        true
+    }
+
+    fn ssz_fixed_len() -> usize {
+        ADDRESS_LEN
+    }
+
+    fn from_ssz_bytes(bytes: &[u8]) -> Result<Self, DecodeError> {
+        <[u8; ADDRESS_LEN]>::try_from(bytes)
+            .map(Self)
+            .map_err(|_| DecodeError::InvalidByteLength {
+                len: bytes.len(),
+                expected: ADDRESS_LEN,
+            })
+    }
+}
+
+impl TreeHash for ExecutionAddress {
+    fn tree_hash_type() -> TreeHashType {
+        TreeHashType::Vector
+    }
+
+    fn tree_hash_packed_encoding(&self) -> PackedEncoding {
+        unreachable!("Vector should never be packed.")
+    }
+
+    fn tree_hash_packing_factor() -> usize {
+        unreachable!("Vector should never be packed.")
+    }
+
+    fn tree_hash_root(&self) -> Hash256 {
+        let mut root = Hash256::zero();
+        root[..ADDRESS_LEN].copy_from_slice(&self.0);
+        root
+    }
+}
+
+/// Serializes as lowercase hex, and deserializes from lowercase hex or hex with a valid EIP-55
+/// checksum.
+#[cfg(feature = "serde")]
+impl serde::Serialize for ExecutionAddress {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_lowercase_hex())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for ExecutionAddress {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let address = <String as serde::Deserialize>::deserialize(deserializer)?;
+        address
+            .parse()
+            .map_err(|e| serde::de::Error::custom(format!("{:?}", e)))
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for ExecutionAddress {
+    fn schema_name() -> String {
+        "ExecutionAddress".to_string()
+    }
+
+    fn json_schema(_: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        crate::json_schema::hex_schema(ADDRESS_LEN, ADDRESS_LEN)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::FixedVector;
+
+    /// The test vectors of EIP-55.
+    const CHECKSUMMED: [&str; 4] = [
+        "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed",
+        "0xfB6916095ca1df60bB79Ce92cE3Ea74c37c5d359",
+        "0xdbF03B407c01E7cD3CBea99509d93f8DDDC8C6FB",
+        "0xD1220A0cf47c7B9Be7A2E6BA89F429762e7b9aDb",
+    ];
+
+    #[test]
+    fn checksums() {
+        for checksummed in CHECKSUMMED {
+            let address: ExecutionAddress = checksummed.parse().unwrap();
+            assert_eq!(address.to_string(), checksummed);
+            assert_eq!(address.to_lowercase_hex(), checksummed.to_lowercase());
+            assert_eq!(checksummed.to_lowercase().parse(), Ok(address));
+            assert_eq!(
+                format!("0x{}", checksummed[2..].to_uppercase()).parse(),
+                Ok(address)
+            );
+            assert_eq!(ExecutionAddress::from_hex_const(checksummed), address);
+        }
+
+        assert_eq!(
+            ExecutionAddress::MAINNET_DEPOSIT_CONTRACT.to_string(),
+            "0x00000000219ab540356cBB839Cbe05303d7705Fa"
+        );
+        assert_eq!(
+            ExecutionAddress::WITHDRAWAL_REQUEST_PREDEPLOY.to_string(),
+            "0x00000961Ef480Eb55e80D19ad83579A64c007002"
+        );
+        assert_eq!(
+            ExecutionAddress::CONSOLIDATION_REQUEST_PREDEPLOY.to_string(),
+            "0x0000BBdDc7CE488642fb579F8B00f3a590007251"
+        );
+    }
+
+    #[test]
+    fn invalid_addresses() {
+        let bad_checksum = "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAeD";
+        let invalid = [
+            bad_checksum,
+            "5aaeb6053f3e94c9b9a09f33669435e7ef1beaed",
+            "0x5aaeb6053f3e94c9b9a09f33669435e7ef1bea",
+            "0x5aaeb6053f3e94c9b9a09f33669435e7ef1beaedaa",
+            "0x5aaeb6053f3e94c9b9a09f33669435e7ef1beaeg",
+            "0x+aaeb6053f3e94c9b9a09f33669435e7ef1beaed",
+        ];
+
+        for address in invalid {
+            assert_eq!(
+                address.parse::<ExecutionAddress>(),
+                Err(Error::InvalidExecutionAddress {
+                    address: address.to_string()
+                })
+            );
+        }
+        assert_eq!(
+            ExecutionAddress::from_slice(&[0; 19]),
+            Err(Error::InvalidByteCount {
+                given: 19,
+                expected: 20
+            })
+        );
+    }
+
+    #[test]
+    fn encoding() {
+        let address: ExecutionAddress = CHECKSUMMED[0].parse().unwrap();
+        let vector = FixedVector::<u8, typenum::U20>::new(address.as_bytes().to_vec()).unwrap();
+
+        assert_eq!(address.as_ssz_bytes(), vector.as_ssz_bytes());
+        assert_eq!(
+            ExecutionAddress::from_ssz_bytes(&vector.as_ssz_bytes()),
+            Ok(address)
+        );
+        assert!(ExecutionAddress::from_ssz_bytes(&[0; 21]).is_err());
+        assert_eq!(address.tree_hash_root(), vector.tree_hash_root());
+        assert_eq!(ExecutionAddress::ZERO, ExecutionAddress::default());
+    }
+
+    #[test]
+    fn lower_hex() {
+        let address: ExecutionAddress = CHECKSUMMED[0].parse().unwrap();
+        let lowercase = CHECKSUMMED[0].to_lowercase();
+        assert_eq!(format!("{:#x}", address), lowercase);
+        assert_eq!(format!("{:x}", address), lowercase[2..]);
+    }
+
+    #[test]
+    fn debug() {
+        let address: ExecutionAddress = CHECKSUMMED[0].parse().unwrap();
+        assert_eq!(
+            format!("{:?}", address),
+            format!("ExecutionAddress({})", CHECKSUMMED[0].to_lowercase())
+        );
+    }
+}
@@ -0,0 +1,541 @@
+use crate::beacon_block::{Withdrawal, H256};
+use crate::transaction::U256;
+use crate::{
+    Error, ExecutionAddress, ExecutionPayload, ExecutionPayloadBellatrix, ExecutionPayloadCapella,
+    ExecutionPayloadDeneb, ExecutionRequests, Preset, SignedBeaconBlock,
+};
+use rlp::RlpStream;
+use tiny_keccak::{Hasher, Keccak};
+use tree_hash::Hash256;
+
+/// The hash of the RLP of an empty list, which is the `ommers_hash` of every block since the
+/// merge.
+const EMPTY_OMMERS_HASH: [u8; 32] = [
+    0x1d, 0xcc, 0x4d, 0xe8, 0xde, 0xc7, 0x5d, 0x7a, 0xab, 0x85, 0xb5, 0x67, 0xb6, 0xcc, 0xd4, 0x1a,
+    0xd3, 0x12, 0x45, 0x1b, 0x94, 0x8a, 0x74, 0x13, 0xf0, 0xa1, 0x42, 0xfd, 0x40, 0xd4, 0x93, 0x47,
+];
+
+/// The header of an execution block, whose hash is the Keccak-256 hash of its RLP.
+///
+/// The optional fields were added by later forks of the execution layer, and are encoded only if
+/// they are present: `base_fee_per_gas` by London, `withdrawals_root` by Shanghai,
+/// `blob_gas_used`, `excess_blob_gas` and `parent_beacon_block_root` by Cancun and
+/// `requests_hash` by Prague.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct ExecutionBlockHeader {
+    pub parent_hash: Hash256,
+    pub ommers_hash: Hash256,
+    pub beneficiary: ExecutionAddress,
+    pub state_root: Hash256,
+    pub transactions_root: Hash256,
+    pub receipts_root: Hash256,
+    pub logs_bloom: Vec<u8>,
+    pub difficulty: u64,
+    pub number: u64,
+    pub gas_limit: u64,
+    pub gas_used: u64,
+    pub timestamp: u64,
+    pub extra_data: Vec<u8>,
+    pub mix_hash: Hash256,
+    pub nonce: [u8; 8],
+    pub base_fee_per_gas: Option<U256>,
+    pub withdrawals_root: Option<Hash256>,
+    pub blob_gas_used: Option<u64>,
+    pub excess_blob_gas: Option<u64>,
+    pub parent_beacon_block_root: Option<Hash256>,
+    pub requests_hash: Option<Hash256>,
+}
+
+impl ExecutionBlockHeader {
+    /// Returns the RLP of the header.
+    pub fn rlp_bytes(&self) -> Vec<u8> {
+        let mut stream = RlpStream::new();
+        stream.begin_unbounded_list();
+        stream.append(&self.parent_hash.as_bytes());
+        stream.append(&self.ommers_hash.as_bytes());
+        stream.append(&self.beneficiary.as_bytes().as_slice());
+        stream.append(&self.state_root.as_bytes());
+        stream.append(&self.transactions_root.as_bytes());
+        stream.append(&self.receipts_root.as_bytes());
+        stream.append(&self.logs_bloom);
+        stream.append(&self.difficulty);
+        stream.append(&self.number);
+        stream.append(&self.gas_limit);
+        stream.append(&self.gas_used);
+        stream.append(&self.timestamp);
+        stream.append(&self.extra_data);
+        stream.append(&self.mix_hash.as_bytes());
+        stream.append(&self.nonce.as_slice());
+        if let Some(base_fee_per_gas) = &self.base_fee_per_gas {
+            stream.append(&u256_bytes(base_fee_per_gas).as_slice());
+        }
+        if let Some(withdrawals_root) = &self.withdrawals_root {
+            stream.append(&withdrawals_root.as_bytes());
+        }
+        if let Some(blob_gas_used) = self.blob_gas_used {
+            stream.append(&blob_gas_used);
+        }
+        if let Some(excess_blob_gas) = self.excess_blob_gas {
+            stream.append(&excess_blob_gas);
+        }
+        if let Some(parent_beacon_block_root) = &self.parent_beacon_block_root {
+            stream.append(&parent_beacon_block_root.as_bytes());
+        }
+        if let Some(requests_hash) = &self.requests_hash {
+            stream.append(&requests_hash.as_bytes());
+        }
+        stream.finalize_unbounded_list();
+        stream.out().to_vec()
+    }
+
+    /// Returns the hash of the block, i.e. the Keccak-256 hash of the RLP of its header.
+    pub fn hash(&self) -> Hash256 {
+        keccak256(&self.rlp_bytes())
+    }
+}
+
+/// Returns the minimal big-endian bytes of a 256 bit integer, as RLP encodes integers.
+fn u256_bytes(value: &U256) -> Vec<u8> {
+    let bytes = value
+        .iter()
+        .rev()
+        .flat_map(|limb| limb.to_be_bytes())
+        .collect::<Vec<_>>();
+    let leading_zeros = bytes.iter().take_while(|&&byte| byte == 0).count();
+    bytes[leading_zeros..].to_vec()
+}
+
+fn keccak256(bytes: &[u8]) -> Hash256 {
+    let mut hash = [0; 32];
+    let mut keccak = Keccak::v256();
+    keccak.update(bytes);
+    keccak.finalize(&mut hash);
+    Hash256::from(hash)
+}
+
+fn hash256(root: &H256) -> Hash256 {
+    Hash256::from_slice(root)
+}
+
+/// Returns the root of the Merkle Patricia trie of `values` keyed by the RLP of their indices, as
+/// the transactions and withdrawals of a block are committed to.
+fn ordered_trie_root<V: AsRef<[u8]>>(values: impl IntoIterator<Item = V>) -> Hash256 {
+    let items = values
+        .into_iter()
+        .enumerate()
+        .map(|(index, value)| (rlp::encode(&(index as u64)).to_vec(), value))
+        .collect::<Vec<_>>();
+    trie_root(&items)
+}
+
+/// Returns the root of the Merkle Patricia trie of `items`, which are pairs of keys and values
+/// whose keys are all distinct.
+fn trie_root<K: AsRef<[u8]>, V: AsRef<[u8]>>(items: &[(K, V)]) -> Hash256 {
+    let mut items = items
+        .iter()
+        .map(|(key, value)| {
+            let nibbles = key
+                .as_ref()
+                .iter()
+                .flat_map(|byte| [byte >> 4, byte & 0xf])
+                .collect::<Vec<_>>();
+            (nibbles, value.as_ref())
+        })
+        .collect::<Vec<_>>();
+    items.sort();
+    keccak256(&encode_node(&items, 0))
+}
+
+/// Returns the RLP of the node at `depth` nibbles of the trie of `items`, which are sorted by
+/// key and share their first `depth` nibbles.
+fn encode_node(items: &[(Vec<u8>, &[u8])], depth: usize) -> Vec<u8> {
+    let mut stream = RlpStream::new();
+
+    match items {
+        [] => {
+            stream.append_empty_data();
+        }
+        [(key, value)] => {
+            stream.begin_list(2);
+            stream.append(&hex_prefix(&key[depth..], true));
+            stream.append(value);
+        }
+        [(first, _), .., (last, _)] => {
+            // As the keys are sorted, the prefix shared by the first and last keys is shared by
+            // all of them.
+            let shared = first[depth..]
+                .iter()
+                .zip(&last[depth..])
+                .take_while(|(a, b)| a == b)
+                .count();
+
+            if shared > 0 {
+                stream.begin_list(2);
+                stream.append(&hex_prefix(&first[depth..depth + shared], false));
+                append_child(&mut stream, encode_node(items, depth + shared));
+            } else {
+                // A key that ends at the branch, which sorts first, is the value of the branch.
+                let (value, children) = match items.split_first() {
+                    Some(((key, value), children)) if key.len() == depth => (Some(value), children),
+                    _ => (None, items),
+                };
+
+                stream.begin_list(17);
+                let mut start = 0;
+                for nibble in 0..16 {
+                    let end = start
+                        + children[start..]
+                            .iter()
+                            .take_while(|(key, _)| key[depth] == nibble)
+                            .count();
+                    if start == end {
+                        stream.append_empty_data();
+                    } else {
+                        append_child(&mut stream, encode_node(&children[start..end], depth + 1));
+                    }
+                    start = end;
+                }
+                match value {
+                    Some(value) => stream.append(value),
+                    None => stream.append_empty_data(),
+                };
+            }
+        }
+    }
+
+    stream.out().to_vec()
+}
+
+/// Appends a reference to a child node, which is embedded if its RLP is shorter than a hash.
+fn append_child(stream: &mut RlpStream, node: Vec<u8>) {
+    if node.len() < 32 {
+        stream.append_raw(&node, 1);
+    } else {
+        stream.append(&keccak256(&node).as_bytes());
+    }
+}
+
+/// Returns the compact encoding of a path of nibbles, whose first nibble flags whether the path
+/// is that of a leaf and has an odd length.
+fn hex_prefix(nibbles: &[u8], is_leaf: bool) -> Vec<u8> {
+    let flag = if is_leaf { 2 } else { 0 };
+    let mut bytes = Vec::with_capacity(nibbles.len() / 2 + 1);
+
+    let rest = if nibbles.len() % 2 == 1 {
+        bytes.push(((flag + 1) << 4) | nibbles[0]);
+        &nibbles[1..]
+    } else {
+        bytes.push(flag << 4);
+        nibbles
+    };
+    bytes.extend(rest.chunks(2).map(|pair| (pair[0] << 4) | pair[1]));
+    bytes
+}
+
+/// Returns the root of the trie of the RLP of `withdrawals`, as held by the header of a block.
+fn withdrawals_root<'a>(withdrawals: impl IntoIterator<Item = &'a Withdrawal>) -> Hash256 {
+    ordered_trie_root(withdrawals.into_iter().map(|withdrawal| {
+        let mut stream = RlpStream::new_list(4);
+        stream.append(&withdrawal.index);
+        stream.append(&withdrawal.validator_index.as_u64());
+        stream.append(&withdrawal.address.as_bytes().as_slice());
+        stream.append(&withdrawal.amount.as_u64());
+        stream.out()
+    }))
+}
+
+/// Returns the header of the block of a payload, from the fields that the payloads of all forks
+/// have.
+macro_rules! base_header {
+    ($payload: expr) => {
+        ExecutionBlockHeader {
+            parent_hash: hash256(&$payload.parent_hash),
+            ommers_hash: Hash256::from(EMPTY_OMMERS_HASH),
+            beneficiary: $payload.fee_recipient,
+            state_root: hash256(&$payload.state_root),
+            transactions_root: ordered_trie_root(
+                $payload
+                    .transactions
+                    .iter()
+                    .map(|transaction| &transaction[..]),
+            ),
+            receipts_root: hash256(&$payload.receipts_root),
+            logs_bloom: $payload.logs_bloom.to_vec(),
+            difficulty: 0,
+            number: $payload.block_number,
+            gas_limit: $payload.gas_limit,
+            gas_used: $payload.gas_used,
+            timestamp: $payload.timestamp,
+            extra_data: $payload.extra_data.to_vec(),
+            mix_hash: hash256(&$payload.prev_randao),
+            nonce: [0; 8],
+            base_fee_per_gas: Some($payload.base_fee_per_gas.clone()),
+            withdrawals_root: None,
+            blob_gas_used: None,
+            excess_blob_gas: None,
+            parent_beacon_block_root: None,
+            requests_hash: None,
+        }
+    };
+}
+
+impl<P: Preset> ExecutionPayloadBellatrix<P> {
+    /// Returns the header of the execution block of the payload.
+    pub fn to_execution_block_header(&self) -> ExecutionBlockHeader {
+        base_header!(self)
+    }
+}
+
+impl<P: Preset> ExecutionPayloadCapella<P> {
+    /// Returns the header of the execution block of the payload.
+    pub fn to_execution_block_header(&self) -> ExecutionBlockHeader {
+        ExecutionBlockHeader {
+            withdrawals_root: Some(withdrawals_root(self.withdrawals.iter())),
+            ..base_header!(self)
+        }
+    }
+}
+
+impl<P: Preset> ExecutionPayloadDeneb<P> {
+    /// Returns the header of the execution block of the payload, which also commits to the
+    /// `parent_root` of the beacon block of the payload and, in blocks of Electra, to the
+    /// `execution_requests` of the block.
+    pub fn to_execution_block_header(
+        &self,
+        parent_beacon_block_root: Hash256,
+        execution_requests: Option<&ExecutionRequests<P>>,
+    ) -> ExecutionBlockHeader {
+        ExecutionBlockHeader {
+            withdrawals_root: Some(withdrawals_root(self.withdrawals.iter())),
+            blob_gas_used: Some(self.blob_gas_used),
+            excess_blob_gas: Some(self.excess_blob_gas),
+            parent_beacon_block_root: Some(parent_beacon_block_root),
+            requests_hash: execution_requests.map(ExecutionRequests::requests_hash),
+            ..base_header!(self)
+        }
+    }
+}
+
+impl<P: Preset> ExecutionPayload<P> {
+    /// Returns the hash of the execution block of the payload, computed from its fields rather
+    /// than read from `block_hash`.
+    ///
+    /// `parent_beacon_block_root` and `execution_requests` are only part of the block since
+    /// Deneb and Electra respectively, and are ignored by the payloads of earlier forks.
+    pub fn compute_block_hash(
+        &self,
+        parent_beacon_block_root: Hash256,
+        execution_requests: Option<&ExecutionRequests<P>>,
+    ) -> Hash256 {
+        let header = match self {
+            Self::Bellatrix(payload) => payload.to_execution_block_header(),
+            Self::Capella(payload) => payload.to_execution_block_header(),
+            Self::Deneb(payload) => {
+                payload.to_execution_block_header(parent_beacon_block_root, execution_requests)
+            }
+        };
+        header.hash()
+    }
+
+    /// Verifies that `block_hash` is the hash of the execution block of the payload, as
+    /// `compute_block_hash` computes it.
+    ///
+    /// Returns `Err(Error::BlockHashMismatch)` if it is not.
+    pub fn verify_block_hash(
+        &self,
+        parent_beacon_block_root: Hash256,
+        execution_requests: Option<&ExecutionRequests<P>>,
+    ) -> Result<(), Error> {
+        let expected = hash256(self.block_hash());
+        let found = self.compute_block_hash(parent_beacon_block_root, execution_requests);
+        if expected != found {
+            return Err(Error::BlockHashMismatch { expected, found });
+        }
+        Ok(())
+    }
+}
+
+impl<P: Preset> SignedBeaconBlock<P> {
+    /// Verifies that the `block_hash` of the execution payload of the block is the hash of the
+    /// execution block of the payload, with the parent root and execution requests of the block.
+    ///
+    /// Returns `Ok` for blocks of forks before Bellatrix, which have no execution payload, and
+    /// for Bellatrix blocks before the merge, whose payload is the default, as per
+    /// `is_merge_transition_complete` in the Bellatrix specs.
+    pub fn verify_execution_block_hash(&self) -> Result<(), Error> {
+        let parent_root = hash256(self.parent_root());
+        let payload: ExecutionPayload<P> = match self {
+            Self::Phase0(_) | Self::Altair(_) => return Ok(()),
+            Self::Bellatrix(block) => {
+                let payload = &block.message.body.execution_payload;
+                if *payload == ExecutionPayloadBellatrix::default() {
+                    return Ok(());
+                }
+                payload.clone().into()
+            }
+            Self::Capella(block) => block.message.body.execution_payload.clone().into(),
+            Self::Deneb(block) => block.message.body.execution_payload.clone().into(),
+            Self::Electra(block) => {
+                let body = &block.message.body;
+                return ExecutionPayload::from(body.execution_payload.clone())
+                    .verify_block_hash(parent_root, Some(&body.execution_requests));
+            }
+        };
+        payload.verify_block_hash(parent_root, None)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        Mainnet, SignedBeaconBlockBellatrix, SignedBeaconBlockCapella, SignedBeaconBlockDeneb,
+    };
+    use ssz::Decode;
+
+    fn hash(hex: &str) -> Hash256 {
+        Hash256::from_slice(&serde_utils::hex::decode(hex).unwrap())
+    }
+
+    #[test]
+    fn tries() {
+        assert_eq!(
+            ordered_trie_root(Vec::<Vec<u8>>::new()),
+            hash("0x56e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421")
+        );
+        assert_eq!(Hash256::from(EMPTY_OMMERS_HASH), keccak256(&[0xc0]));
+
+        // The trie tests of the execution layer.
+        assert_eq!(
+            trie_root(&[
+                ("doe", "reindeer"),
+                ("dog", "puppy"),
+                ("dogglesworth", "cat")
+            ]),
+            hash("0x8aad789dff2f538bca5d8ea56e8abe10f4c7ba3a5dea95fea4cd6e7c3a1168d3")
+        );
+        assert_eq!(
+            trie_root(&[("A", "a".repeat(50))]),
+            hash("0xd23786fb4a010da3ce639d66d5e904a11dbc02746d1ce25029e53290cabf28ab")
+        );
+
+        assert_eq!(hex_prefix(&[1, 2, 3], false), [0x11, 0x23]);
+        assert_eq!(hex_prefix(&[1, 2], true), [0x20, 0x12]);
+        assert_eq!(hex_prefix(&[], true), [0x20]);
+    }
+
+    #[test]
+    fn genesis_block_hash() {
+        // The genesis block of mainnet, from before the merge and London.
+        let header = ExecutionBlockHeader {
+            parent_hash: Hash256::zero(),
+            ommers_hash: Hash256::from(EMPTY_OMMERS_HASH),
+            beneficiary: ExecutionAddress::ZERO,
+            state_root: hash("0xd7f8974fb5ac78d9ac099b9ad5018bedc2ce0a72dad1827a1709da30580f0544"),
+            transactions_root: ordered_trie_root(Vec::<Vec<u8>>::new()),
+            receipts_root: ordered_trie_root(Vec::<Vec<u8>>::new()),
+            logs_bloom: vec![0; 256],
+            difficulty: 0x0004_0000_0000,
+            number: 0,
+            gas_limit: 5000,
+            gas_used: 0,
+            timestamp: 0,
+            extra_data: serde_utils::hex::decode(
+                "0x11bbe8db4e347b4e8c937c1c8370e4b5ed33adb3db69cbdb7a38e1e50b1b82fa",
+            )
+            .unwrap(),
+            mix_hash: Hash256::zero(),
+            nonce: [0, 0, 0, 0, 0, 0, 0, 0x42],
+            base_fee_per_gas: None,
+            withdrawals_root: None,
+            blob_gas_used: None,
+            excess_blob_gas: None,
+            parent_beacon_block_root: None,
+            requests_hash: None,
+        };
+        assert_eq!(
+            header.hash(),
+            hash("0xd4e56740f876aef8c010b86a40d5f56745a118d0906a34e69aec8c0db1cb8fa3")
+        );
+    }
+
+    #[test]
+    fn payload_block_hash() {
+        let bytes = std::fs::read("state.ssz").unwrap();
+        let block = SignedBeaconBlockCapella::<Mainnet>::from_ssz_bytes(&bytes).unwrap();
+        let payload = &block.message.body.execution_payload;
+        assert!(payload.transactions.len() > 1);
+
+        let header = payload.to_execution_block_header();
+        assert_eq!(header.hash(), hash256(&payload.block_hash));
+        let block = SignedBeaconBlock::from(block);
+        assert_eq!(block.verify_execution_block_hash(), Ok(()));
+
+        let SignedBeaconBlock::Capella(mut block) = block else {
+            unreachable!()
+        };
+        block.message.body.execution_payload.gas_used += 1;
+        let payload = ExecutionPayload::from(block.message.body.execution_payload.clone());
+        assert_eq!(
+            SignedBeaconBlock::from(block).verify_execution_block_hash(),
+            Err(Error::BlockHashMismatch {
+                expected: hash256(payload.block_hash()),
+                found: payload.compute_block_hash(Hash256::zero(), None),
+            })
+        );
+
+        // Bellatrix blocks before the merge have the default payload.
+        let mut bellatrix = SignedBeaconBlockBellatrix::<Mainnet>::default();
+        assert_eq!(
+            SignedBeaconBlock::from(bellatrix.clone()).verify_execution_block_hash(),
+            Ok(())
+        );
+        bellatrix.message.body.execution_payload.gas_used = 1;
+        assert!(SignedBeaconBlock::from(bellatrix)
+            .verify_execution_block_hash()
+            .is_err());
+    }
+
+    #[test]
+    fn fork_fields() {
+        let deneb = SignedBeaconBlockDeneb::<Mainnet>::default();
+        let payload = &deneb.message.body.execution_payload;
+        let parent_root = Hash256::repeat_byte(1);
+
+        let header = payload.to_execution_block_header(parent_root, None);
+        assert_eq!(header.parent_beacon_block_root, Some(parent_root));
+        assert_eq!(
+            header.withdrawals_root,
+            Some(ordered_trie_root(Vec::<Vec<u8>>::new()))
+        );
+        assert_eq!(header.requests_hash, None);
+
+        // The payload commits to the parent root and requests only since Deneb and Electra.
+        let payload = ExecutionPayload::from(payload.clone());
+        let requests = ExecutionRequests::default();
+        assert_eq!(
+            requests.requests_hash(),
+            hash("0xe3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855")
+        );
+        assert_ne!(
+            payload.compute_block_hash(parent_root, None),
+            payload.compute_block_hash(Hash256::zero(), None)
+        );
+        assert_ne!(
+            payload.compute_block_hash(parent_root, Some(&requests)),
+            payload.compute_block_hash(parent_root, None)
+        );
+        let bellatrix = ExecutionPayload::from(ExecutionPayloadBellatrix::<Mainnet>::default());
+        assert_eq!(
+            bellatrix.compute_block_hash(parent_root, Some(&requests)),
+            bellatrix.compute_block_hash(Hash256::zero(), None)
+        );
+
+        assert_eq!(
+            u256_bytes(&U256::new(vec![0x0102, 0, 0, 0]).unwrap()),
+            [1, 2]
+        );
+        assert_eq!(u256_bytes(&U256::new(vec![0, 0, 0, 1]).unwrap())[0], 1);
+        assert!(u256_bytes(&U256::default()).is_empty());
+    }
+}
@@ -1,10 +1,17 @@
-use crate::tree_hash::vec_tree_hash_root;
-use crate::Error;
+use crate::hex::{fmt_debug_vec, fmt_hex, parse_hex};
+use crate::proof::{vec_gindex_of, vec_tree_hash_node};
+#[cfg(feature = "rayon")]
+use crate::tree_hash::par_vec_tree_hash_root;
+use crate::tree_hash::{slice_tree_hash_root, MaybeSync};
+use crate::{Error, MemorySize, MerklePath, MerkleProof, SszReflect, SszSchema};
 use derivative::Derivative;
-use serde_derive::{Deserialize, Serialize};
+use serde::Deserialize;
+use serde_derive::Serialize;
+use std::fmt;
 use std::marker::PhantomData;
 use std::ops::{Deref, DerefMut, Index, IndexMut};
 use std::slice::SliceIndex;
+use std::str::FromStr;
 use tree_hash::Hash256;
 use typenum::Unsigned;
 
@@ -33,19 +40,22 @@ pub use typenum;
 /// let base: Vec<u64> = vec![1, 2, 3, 4];
 ///
 /// // Create a `FixedVector` from a `Vec` that has the expected length.
-/// let exact: FixedVector<_, typenum::U4> = FixedVector::from(base.clone());
+/// let exact: FixedVector<_, typenum::U4> = FixedVector::try_from(base.clone()).unwrap();
 /// assert_eq!(&exact[..], &[1, 2, 3, 4]);
 ///
-/// // Create a `FixedVector` from a `Vec` that is too long and the `Vec` is truncated.
-/// let short: FixedVector<_, typenum::U3> = FixedVector::from(base.clone());
+/// // Creating a `FixedVector` from a `Vec` of the wrong length is an error.
+/// assert!(FixedVector::<_, typenum::U3>::try_from(base.clone()).is_err());
+///
+/// // Unless explicitly requested, in which case a `Vec` that is too long is truncated.
+/// let short: FixedVector<_, typenum::U3> = FixedVector::new_resized(base.clone());
 /// assert_eq!(&short[..], &[1, 2, 3]);
 ///
-/// // Create a `FixedVector` from a `Vec` that is too short and the missing values are created
-/// // using `std::default::Default`.
-/// let long: FixedVector<_, typenum::U5> = FixedVector::from(base);
+/// // And for a `Vec` that is too short the missing values are created using
+/// // `std::default::Default`.
+/// let long: FixedVector<_, typenum::U5> = FixedVector::new_resized(base);
 /// assert_eq!(&long[..], &[1, 2, 3, 4, 0]);
 /// ```
-#[derive(Debug, Clone, Serialize, Deserialize, Derivative)]
+#[derive(Clone, Serialize, Derivative)]
 #[derivative(PartialEq, Eq, Hash(bound = "T: std::hash::Hash"))]
 #[serde(transparent)]
 pub struct FixedVector<T, N> {
@@ -53,6 +63,44 @@ pub struct FixedVector<T, N> {
     _phantom: PhantomData<N>,
 }
 
+/// Formats byte vectors as `0x`-prefixed hex, e.g. roots, and other vectors by their values.
+impl<T: fmt::Debug + 'static, N> fmt::Debug for FixedVector<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_debug_vec("FixedVector", &self.vec, f)
+    }
+}
+
+/// Formats byte vectors as `0x`-prefixed hex.
+impl<N> fmt::Display for FixedVector<u8, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_hex(&self.vec, f)
+    }
+}
+
+/// Formats byte vectors as hex, prefixed by `0x` with the `#` flag.
+impl<N> fmt::LowerHex for FixedVector<u8, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            return fmt_hex(&self.vec, f);
+        }
+        self.vec
+            .iter()
+            .try_for_each(|byte| write!(f, "{:02x}", byte))
+    }
+}
+
+impl<N: Unsigned> FromStr for FixedVector<u8, N> {
+    type Err = Error;
+
+    /// Parses `0x`-prefixed hex of exactly `N` bytes.
+    fn from_str(hex: &str) -> Result<Self, Error> {
+        Ok(Self {
+            vec: parse_hex(hex, N::to_usize())?,
+            _phantom: PhantomData,
+        })
+    }
+}
+
 impl<T, N: Unsigned> FixedVector<T, N> {
     /// Returns `Ok` if the given `vec` equals the fixed length of `Self`. Otherwise returns
     /// `Err`.
@@ -70,6 +118,31 @@ impl<T, N: Unsigned> FixedVector<T, N> {
         }
     }
 
+    /// Create a new vector from `vec`, truncating it if it is longer than the fixed length of
+    /// `Self` or padding it with `T::default()` if it is shorter.
+    ///
+    /// Prefer `Self::new` or `Self::try_from` unless silently changing the length is intended.
+    pub fn new_resized(mut vec: Vec<T>) -> Self
+    where
+        T: Default,
+    {
+        vec.resize_with(Self::capacity(), Default::default);
+
+        Self {
+            vec,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Moves the values of `vector` into a vector with a different type-level length, without
+    /// copying them.
+    ///
+    /// Returns `Err` unless both lengths are equal, e.g. when converting between distinct
+    /// `typenum` types that represent the same number.
+    pub fn try_from_vector<M: Unsigned>(vector: FixedVector<T, M>) -> Result<Self, Error> {
+        Self::new(vector.vec)
+    }
+
     /// Create a new vector filled with clones of `elem`.
     pub fn from_elem(elem: T) -> Self
     where
@@ -97,17 +170,58 @@ impl<T, N: Unsigned> FixedVector<T, N> {
     pub fn capacity() -> usize {
         N::to_usize()
     }
-}
 
-impl<T: Default, N: Unsigned> From<Vec<T>> for FixedVector<T, N> {
-    fn from(mut vec: Vec<T>) -> Self {
-        vec.resize_with(Self::capacity(), Default::default);
+    /// Returns a reference to the value at `index`, or `None` if it is out of bounds.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.vec.get(index)
+    }
 
-        Self {
-            vec,
+    /// Returns a mutable reference to the value at `index`, or `None` if it is out of bounds.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.vec.get_mut(index)
+    }
+
+    /// Returns a reference to the first value, or `None` if `self` is empty.
+    pub fn first(&self) -> Option<&T> {
+        self.vec.first()
+    }
+
+    /// Returns a reference to the last value, or `None` if `self` is empty.
+    pub fn last(&self) -> Option<&T> {
+        self.vec.last()
+    }
+
+    /// Returns a vector of the same length with `f` applied to each value, in order.
+    pub fn map<U, F>(self, f: F) -> FixedVector<U, N>
+    where
+        F: FnMut(T) -> U,
+    {
+        FixedVector {
+            vec: self.vec.into_iter().map(f).collect(),
             _phantom: PhantomData,
         }
     }
+
+    /// Returns a vector of the same length with `f` applied to each value, in order, or the first
+    /// error returned by `f`.
+    pub fn try_map<U, E, F>(self, f: F) -> Result<FixedVector<U, N>, E>
+    where
+        F: FnMut(T) -> Result<U, E>,
+    {
+        Ok(FixedVector {
+            vec: self.vec.into_iter().map(f).collect::<Result<_, _>>()?,
+            _phantom: PhantomData,
+        })
+    }
+}
+
+impl<T, N: Unsigned> TryFrom<Vec<T>> for FixedVector<T, N> {
+    type Error = Error;
+
+    /// Equivalent to `Self::new`.
+    fn try_from(vec: Vec<T>) -> Result<Self, Error> {
+        Self::new(vec)
+    }
 }
 
 impl<T, N: Unsigned> From<FixedVector<T, N>> for Vec<T> {
@@ -168,6 +282,15 @@ impl<'a, T, N: Unsigned> IntoIterator for &'a FixedVector<T, N> {
     }
 }
 
+impl<'a, T, N: Unsigned> IntoIterator for &'a mut FixedVector<T, N> {
+    type Item = &'a mut T;
+    type IntoIter = std::slice::IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
 impl<T, N: Unsigned> IntoIterator for FixedVector<T, N> {
     type Item = T;
     type IntoIter = std::vec::IntoIter<T>;
@@ -179,7 +302,7 @@ impl<T, N: Unsigned> IntoIterator for FixedVector<T, N> {
 
 impl<T, N: Unsigned> tree_hash::TreeHash for FixedVector<T, N>
 where
-    T: tree_hash::TreeHash,
+    T: tree_hash::TreeHash + MaybeSync,
 {
     fn tree_hash_type() -> tree_hash::TreeHashType {
         tree_hash::TreeHashType::Vector
@@ -194,7 +317,7 @@ where
     }
 
     fn tree_hash_root(&self) -> Hash256 {
-        vec_tree_hash_root::<T, N>(&self.vec)
+        slice_tree_hash_root(&self.vec, N::to_usize())
     }
 }
 
@@ -328,6 +451,108 @@ where
     }
 }
 
+impl<T: MemorySize, N: Unsigned> MemorySize for FixedVector<T, N> {
+    fn heap_size(&self) -> usize {
+        self.vec.heap_size()
+    }
+}
+
+impl<T, N: Unsigned> MerkleProof for FixedVector<T, N>
+where
+    T: tree_hash::TreeHash + MerkleProof,
+{
+    fn tree_hash_node(&self, gindex: u64) -> Result<Hash256, Error> {
+        vec_tree_hash_node(&self.vec, N::to_usize(), gindex)
+    }
+}
+
+impl<T, N: Unsigned> MerklePath for FixedVector<T, N>
+where
+    T: tree_hash::TreeHash + MerklePath,
+{
+    fn gindex_of(path: &[&str]) -> Result<u64, Error> {
+        vec_gindex_of::<T>(N::to_usize(), path)
+    }
+}
+
+impl<T: SszReflect, N: Unsigned> SszReflect for FixedVector<T, N> {
+    fn ssz_schema() -> SszSchema {
+        SszSchema::vector(T::ssz_schema(), N::to_usize())
+    }
+}
+
+impl<'de, T, N> Deserialize<'de> for FixedVector<T, N>
+where
+    T: Deserialize<'de>,
+    N: Unsigned,
+{
+    /// Deserializes a sequence of values, returning an error if its length does not equal the
+    /// fixed length of `Self`.
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let vec = Vec::<T>::deserialize(deserializer)?;
+        Self::new(vec)
+            .map_err(|e| serde::de::Error::custom(format!("invalid fixed vector: {:?}", e)))
+    }
+}
+
+/// The schema of an array of exactly `N` items.
+#[cfg(feature = "schemars")]
+impl<T: schemars::JsonSchema, N: Unsigned> schemars::JsonSchema for FixedVector<T, N> {
+    fn is_referenceable() -> bool {
+        false
+    }
+
+    fn schema_name() -> String {
+        format!("FixedVector_of_{}_{}", T::schema_name(), N::to_usize())
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        crate::json_schema::array_schema(gen.subschema_for::<T>(), N::to_usize(), N::to_usize())
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<T: tree_hash::TreeHash + Sync, N: Unsigned> FixedVector<T, N> {
+    /// Returns the same root as `tree_hash_root`, computing the roots of the values in parallel
+    /// whatever the length of the vector, where `tree_hash_root` only does so for long vectors.
+    pub fn par_tree_hash_root(&self) -> Hash256 {
+        par_vec_tree_hash_root(&self.vec, N::to_usize())
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<T: Send, N: Unsigned> rayon::iter::IntoParallelIterator for FixedVector<T, N> {
+    type Item = T;
+    type Iter = rayon::vec::IntoIter<T>;
+
+    fn into_par_iter(self) -> Self::Iter {
+        rayon::iter::IntoParallelIterator::into_par_iter(self.vec)
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<'a, T: Sync, N: Unsigned> rayon::iter::IntoParallelIterator for &'a FixedVector<T, N> {
+    type Item = &'a T;
+    type Iter = rayon::slice::Iter<'a, T>;
+
+    fn into_par_iter(self) -> Self::Iter {
+        rayon::iter::IntoParallelIterator::into_par_iter(&self.vec)
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<'a, T: Send, N: Unsigned> rayon::iter::IntoParallelIterator for &'a mut FixedVector<T, N> {
+    type Item = &'a mut T;
+    type Iter = rayon::slice::IterMut<'a, T>;
+
+    fn into_par_iter(self) -> Self::Iter {
+        rayon::iter::IntoParallelIterator::into_par_iter(&mut self.vec)
+    }
+}
+
 #[cfg(feature = "arbitrary")]
 impl<'a, T: arbitrary::Arbitrary<'a>, N: 'static + Unsigned> arbitrary::Arbitrary<'a>
     for FixedVector<T, N>
@@ -369,7 +594,7 @@ mod test {
     fn indexing() {
         let vec = vec![1, 2];
 
-        let mut fixed: FixedVector<u64, U8192> = vec.clone().into();
+        let mut fixed: FixedVector<u64, U8192> = FixedVector::new_resized(vec.clone());
 
         assert_eq!(fixed[0], 1);
         assert_eq!(&fixed[0..1], &vec[0..1]);
@@ -382,23 +607,49 @@ mod test {
     #[test]
     fn length() {
         let vec = vec![42; 5];
-        let fixed: FixedVector<u64, U4> = FixedVector::from(vec.clone());
+        let fixed: FixedVector<u64, U4> = FixedVector::new_resized(vec.clone());
         assert_eq!(&fixed[..], &vec[0..4]);
 
         let vec = vec![42; 3];
-        let fixed: FixedVector<u64, U4> = FixedVector::from(vec.clone());
+        let fixed: FixedVector<u64, U4> = FixedVector::new_resized(vec.clone());
         assert_eq!(&fixed[0..3], &vec[..]);
         assert_eq!(&fixed[..], &vec![42, 42, 42, 0][..]);
 
         let vec = vec![];
-        let fixed: FixedVector<u64, U4> = FixedVector::from(vec);
+        let fixed: FixedVector<u64, U4> = FixedVector::new_resized(vec);
         assert_eq!(&fixed[..], &vec![0, 0, 0, 0][..]);
     }
 
+    #[test]
+    fn try_from() {
+        let fixed: Result<FixedVector<u64, U4>, _> = FixedVector::try_from(vec![42; 4]);
+        assert_eq!(fixed.map(Vec::from), Ok(vec![42; 4]));
+
+        let fixed: Result<FixedVector<u64, U4>, _> = vec![42; 5].try_into();
+        assert_eq!(fixed, Err(Error::OutOfBounds { i: 5, len: 4 }));
+
+        let fixed: Result<FixedVector<u64, U4>, _> = vec![42; 3].try_into();
+        assert_eq!(fixed, Err(Error::OutOfBounds { i: 3, len: 4 }));
+    }
+
+    #[test]
+    fn try_from_vector() {
+        let vector: FixedVector<u64, U4> = FixedVector::new(vec![1, 2, 3, 4]).unwrap();
+
+        let same: FixedVector<u64, U4> = FixedVector::try_from_vector(vector.clone()).unwrap();
+        assert_eq!(&same[..], &[1, 2, 3, 4]);
+
+        let larger: Result<FixedVector<u64, U8>, _> = FixedVector::try_from_vector(vector.clone());
+        assert_eq!(larger, Err(Error::OutOfBounds { i: 4, len: 8 }));
+
+        let smaller: Result<FixedVector<u64, U2>, _> = FixedVector::try_from_vector(vector);
+        assert_eq!(smaller, Err(Error::OutOfBounds { i: 4, len: 2 }));
+    }
+
     #[test]
     fn deref() {
         let vec = vec![0, 2, 4, 6];
-        let fixed: FixedVector<u64, U4> = FixedVector::from(vec);
+        let fixed: FixedVector<u64, U4> = FixedVector::try_from(vec).unwrap();
 
         assert_eq!(fixed.first(), Some(&0));
         assert_eq!(fixed.get(3), Some(&6));
@@ -408,7 +659,7 @@ mod test {
     #[test]
     fn iterator() {
         let vec = vec![0, 2, 4, 6];
-        let fixed: FixedVector<u64, U4> = FixedVector::from(vec);
+        let fixed: FixedVector<u64, U4> = FixedVector::try_from(vec).unwrap();
 
         // test the reference version
         assert_eq!((&fixed).into_iter().sum::<u64>(), 12);
@@ -416,9 +667,106 @@ mod test {
         assert_eq!(fixed.into_iter().sum::<u64>(), 12);
     }
 
+    #[test]
+    fn iterator_mut() {
+        let vec = vec![0, 2, 4, 6];
+        let mut list: FixedVector<u64, U4> = FixedVector::try_from(vec).unwrap();
+
+        for x in &mut list {
+            *x += 1;
+        }
+        list.iter_mut().rev().take(1).for_each(|x| *x = 0);
+        assert_eq!(&list[..], &[1, 3, 5, 0]);
+    }
+
+    #[test]
+    fn iterator_exact_size_double_ended() {
+        let vec = vec![0, 2, 4, 6];
+        let list: FixedVector<u64, U4> = FixedVector::try_from(vec).unwrap();
+
+        assert_eq!(list.iter().len(), 4);
+        assert_eq!(
+            list.iter().rev().copied().collect::<Vec<_>>(),
+            vec![6, 4, 2, 0]
+        );
+
+        let mut iter = list.into_iter();
+        assert_eq!(iter.len(), 4);
+        assert_eq!(iter.next_back(), Some(6));
+        assert_eq!(iter.next(), Some(0));
+        assert_eq!(iter.len(), 2);
+        assert_eq!(iter.collect::<Vec<_>>(), vec![2, 4]);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn par_iter() {
+        use rayon::prelude::*;
+
+        let mut list: FixedVector<u64, U1024> = FixedVector::new((0..1024).collect()).unwrap();
+
+        list.par_iter_mut().for_each(|x| *x *= 2);
+        assert_eq!(list.par_iter().sum::<u64>(), 1023 * 1024);
+        assert_eq!(
+            list.clone().into_par_iter().collect::<Vec<_>>(),
+            list.iter().copied().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn get() {
+        let mut vector: FixedVector<u64, U4> = FixedVector::new(vec![1, 2, 3, 4]).unwrap();
+
+        assert_eq!(vector.get(3), Some(&4));
+        assert_eq!(vector.get(4), None);
+        assert_eq!(vector.first(), Some(&1));
+        assert_eq!(vector.last(), Some(&4));
+
+        *vector.get_mut(0).unwrap() = 42;
+        assert_eq!(vector.get_mut(usize::MAX), None);
+        assert_eq!(&vector[..], &[42, 2, 3, 4]);
+
+        let empty: FixedVector<u64, U0> = FixedVector::default();
+        assert_eq!(empty.first(), None);
+        assert_eq!(empty.last(), None);
+    }
+
+    #[test]
+    fn map() {
+        let fixed: FixedVector<u64, U4> = FixedVector::try_from(vec![0, 1, 2, 3]).unwrap();
+
+        let mapped: FixedVector<u8, U4> = fixed.clone().map(|x| x as u8 * 2);
+        assert_eq!(&mapped[..], &[0, 2, 4, 6]);
+
+        let mapped: Result<FixedVector<u8, U4>, _> = fixed.clone().try_map(u8::try_from);
+        assert_eq!(mapped.map(Vec::from), Ok(vec![0, 1, 2, 3]));
+
+        let mut calls = 0;
+        let mapped: Result<FixedVector<u64, U4>, _> = fixed.try_map(|x| {
+            calls += 1;
+            if x == 1 {
+                Err(x)
+            } else {
+                Ok(x)
+            }
+        });
+        assert_eq!(mapped, Err(1));
+        assert_eq!(calls, 2);
+    }
+
+    #[test]
+    fn serde() {
+        let list: FixedVector<u64, U4> = serde_json::from_str("[1,2,3,4]").unwrap();
+        assert_eq!(serde_json::to_string(&list).unwrap(), "[1,2,3,4]");
+
+        assert!(serde_json::from_str::<FixedVector<u64, U4>>("[]").is_err());
+        assert!(serde_json::from_str::<FixedVector<u64, U4>>("[1,2,3]").is_err());
+        assert!(serde_json::from_str::<FixedVector<u64, U4>>("[1,2,3,4,5]").is_err());
+    }
+
     #[test]
     fn ssz_encode() {
-        let vec: FixedVector<u16, U2> = vec![0; 2].into();
+        let vec: FixedVector<u16, U2> = vec![0; 2].try_into().unwrap();
         assert_eq!(vec.as_ssz_bytes(), vec![0, 0, 0, 0]);
         assert_eq!(<FixedVector<u16, U2> as Encode>::ssz_fixed_len(), 4);
     }
@@ -431,26 +779,26 @@ mod test {
 
     #[test]
     fn ssz_round_trip_u16_len_8() {
-        ssz_round_trip::<FixedVector<u16, U8>>(vec![42; 8].into());
-        ssz_round_trip::<FixedVector<u16, U8>>(vec![0; 8].into());
+        ssz_round_trip::<FixedVector<u16, U8>>(vec![42; 8].try_into().unwrap());
+        ssz_round_trip::<FixedVector<u16, U8>>(vec![0; 8].try_into().unwrap());
     }
 
     #[test]
     fn tree_hash_u8() {
-        let fixed: FixedVector<u8, U0> = FixedVector::from(vec![]);
+        let fixed: FixedVector<u8, U0> = FixedVector::try_from(vec![]).unwrap();
         assert_eq!(fixed.tree_hash_root(), merkle_root(&[0; 8], 0));
 
-        let fixed: FixedVector<u8, U1> = FixedVector::from(vec![0; 1]);
+        let fixed: FixedVector<u8, U1> = FixedVector::try_from(vec![0; 1]).unwrap();
         assert_eq!(fixed.tree_hash_root(), merkle_root(&[0; 8], 0));
 
-        let fixed: FixedVector<u8, U8> = FixedVector::from(vec![0; 8]);
+        let fixed: FixedVector<u8, U8> = FixedVector::try_from(vec![0; 8]).unwrap();
         assert_eq!(fixed.tree_hash_root(), merkle_root(&[0; 8], 0));
 
-        let fixed: FixedVector<u8, U16> = FixedVector::from(vec![42; 16]);
+        let fixed: FixedVector<u8, U16> = FixedVector::try_from(vec![42; 16]).unwrap();
         assert_eq!(fixed.tree_hash_root(), merkle_root(&[42; 16], 0));
 
         let source: Vec<u8> = (0..16).collect();
-        let fixed: FixedVector<u8, U16> = FixedVector::from(source.clone());
+        let fixed: FixedVector<u8, U16> = FixedVector::try_from(source.clone()).unwrap();
         assert_eq!(fixed.tree_hash_root(), merkle_root(&source, 0));
     }
 
@@ -474,31 +822,97 @@ mod test {
     fn tree_hash_composite() {
         let a = A { a: 0, b: 1 };
 
-        let fixed: FixedVector<A, U0> = FixedVector::from(vec![]);
+        let fixed: FixedVector<A, U0> = FixedVector::try_from(vec![]).unwrap();
         assert_eq!(fixed.tree_hash_root(), merkle_root(&[0; 32], 0));
 
-        let fixed: FixedVector<A, U1> = FixedVector::from(vec![a]);
+        let fixed: FixedVector<A, U1> = FixedVector::try_from(vec![a]).unwrap();
         assert_eq!(
             fixed.tree_hash_root(),
             merkle_root(a.tree_hash_root().as_bytes(), 0)
         );
 
-        let fixed: FixedVector<A, U8> = FixedVector::from(vec![a; 8]);
+        let fixed: FixedVector<A, U8> = FixedVector::try_from(vec![a; 8]).unwrap();
         assert_eq!(
             fixed.tree_hash_root(),
             merkle_root(&repeat(a.tree_hash_root().as_bytes(), 8), 0)
         );
 
-        let fixed: FixedVector<A, U13> = FixedVector::from(vec![a; 13]);
+        let fixed: FixedVector<A, U13> = FixedVector::try_from(vec![a; 13]).unwrap();
         assert_eq!(
             fixed.tree_hash_root(),
             merkle_root(&repeat(a.tree_hash_root().as_bytes(), 13), 0)
         );
 
-        let fixed: FixedVector<A, U16> = FixedVector::from(vec![a; 16]);
+        let fixed: FixedVector<A, U16> = FixedVector::try_from(vec![a; 16]).unwrap();
         assert_eq!(
             fixed.tree_hash_root(),
             merkle_root(&repeat(a.tree_hash_root().as_bytes(), 16), 0)
         );
     }
+
+    #[test]
+    fn tree_hash_roots() {
+        let roots = (0..13)
+            .map(|i| FixedVector::<u8, U32>::new(vec![i; 32]).unwrap())
+            .collect::<Vec<_>>();
+        let bytes = roots
+            .iter()
+            .flat_map(|root| root.to_vec())
+            .collect::<Vec<_>>();
+
+        assert_eq!(roots[3].tree_hash_root(), Hash256::repeat_byte(3));
+        assert_eq!(
+            crate::batch_tree_hash_root(&roots, 32),
+            merkle_root(&bytes, 32)
+        );
+
+        let fixed: FixedVector<FixedVector<u8, U32>, U13> = FixedVector::new(roots).unwrap();
+        assert_eq!(fixed.tree_hash_root(), merkle_root(&bytes, 0));
+        assert_eq!(fixed.tree_hash_node(16 + 3), Ok(Hash256::repeat_byte(3)));
+    }
+
+    #[test]
+    fn debug() {
+        let bytes = FixedVector::<u8, U4>::new(vec![0, 1, 0xab, 0xff]).unwrap();
+        assert_eq!(format!("{:?}", bytes), "0x0001abff");
+
+        let ints = FixedVector::<u64, U2>::new(vec![1, 2]).unwrap();
+        assert_eq!(format!("{:?}", ints), "FixedVector { vec: [1, 2], .. }");
+
+        // Other single byte types are not bytes.
+        let signed = FixedVector::<i8, U2>::new(vec![-1, 2]).unwrap();
+        assert_eq!(format!("{:?}", signed), "FixedVector { vec: [-1, 2], .. }");
+
+        // Nested byte vectors are hex too.
+        let roots = FixedVector::<FixedVector<u8, U1>, U2>::default();
+        assert_eq!(
+            format!("{:?}", roots),
+            "FixedVector { vec: [0x00, 0x00], .. }"
+        );
+    }
+
+    #[test]
+    fn hex() {
+        let bytes = FixedVector::<u8, U4>::new(vec![0, 1, 0xab, 0xff]).unwrap();
+        assert_eq!(bytes.to_string(), "0x0001abff");
+        assert_eq!(format!("{:x}", bytes), "0001abff");
+        assert_eq!(format!("{:#x}", bytes), "0x0001abff");
+
+        assert_eq!("0x0001abff".parse(), Ok(bytes.clone()));
+        assert_eq!("0x0001ABFF".parse(), Ok(bytes));
+        for hex in [
+            "0001abff",
+            "0X0001abff",
+            "0x0001ab",
+            "0x0001abff00",
+            "0x0001abfg",
+            "0x",
+        ] {
+            assert_eq!(
+                hex.parse::<FixedVector<u8, U4>>(),
+                Err(Error::InvalidHex { hex: hex.into() })
+            );
+        }
+        assert_eq!("0x".parse(), Ok(FixedVector::<u8, U0>::default()));
+    }
 }
@@ -0,0 +1,144 @@
+use crate::{Epoch, Slot};
+use std::fmt;
+
+/// The forks of the consensus layer, in order of activation.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum ForkName {
+    Phase0,
+    Altair,
+    Bellatrix,
+    Capella,
+    Deneb,
+    Electra,
+}
+
+impl ForkName {
+    /// Every fork, in order of activation.
+    pub const ALL: [Self; 6] = [
+        Self::Phase0,
+        Self::Altair,
+        Self::Bellatrix,
+        Self::Capella,
+        Self::Deneb,
+        Self::Electra,
+    ];
+
+    /// Returns the name of the fork in lowercase, as used by the consensus specs.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Phase0 => "phase0",
+            Self::Altair => "altair",
+            Self::Bellatrix => "bellatrix",
+            Self::Capella => "capella",
+            Self::Deneb => "deneb",
+            Self::Electra => "electra",
+        }
+    }
+}
+
+impl fmt::Display for ForkName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// The epochs at which the forks after phase0 activate on a chain.
+///
+/// Forks which are not scheduled are `None`.
+///
+/// ## Example
+///
+/// ```
+/// use ssz_types::{Epoch, ForkName, ForkSchedule, Slot};
+///
+/// let schedule = ForkSchedule::mainnet();
+///
+/// assert_eq!(schedule.fork_at_epoch(Epoch::new(0)), ForkName::Phase0);
+/// assert_eq!(schedule.fork_at_slot(Slot::new(194_048 * 32)), ForkName::Capella);
+/// assert_eq!(schedule.fork_at_slot(Slot::new(194_048 * 32 - 1)), ForkName::Bellatrix);
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct ForkSchedule {
+    pub slots_per_epoch: u64,
+    pub altair_fork_epoch: Option<Epoch>,
+    pub bellatrix_fork_epoch: Option<Epoch>,
+    pub capella_fork_epoch: Option<Epoch>,
+    pub deneb_fork_epoch: Option<Epoch>,
+    pub electra_fork_epoch: Option<Epoch>,
+}
+
+impl ForkSchedule {
+    /// Returns the schedule of Ethereum mainnet.
+    pub fn mainnet() -> Self {
+        Self {
+            slots_per_epoch: 32,
+            altair_fork_epoch: Some(Epoch::new(74_240)),
+            bellatrix_fork_epoch: Some(Epoch::new(144_896)),
+            capella_fork_epoch: Some(Epoch::new(194_048)),
+            deneb_fork_epoch: Some(Epoch::new(269_568)),
+            electra_fork_epoch: Some(Epoch::new(364_032)),
+        }
+    }
+
+    /// Returns the epoch at which `fork` activates, if it is scheduled.
+    pub fn fork_epoch(&self, fork: ForkName) -> Option<Epoch> {
+        match fork {
+            ForkName::Phase0 => Some(Epoch::new(0)),
+            ForkName::Altair => self.altair_fork_epoch,
+            ForkName::Bellatrix => self.bellatrix_fork_epoch,
+            ForkName::Capella => self.capella_fork_epoch,
+            ForkName::Deneb => self.deneb_fork_epoch,
+            ForkName::Electra => self.electra_fork_epoch,
+        }
+    }
+
+    /// Returns the fork that is active at `epoch`.
+    pub fn fork_at_epoch(&self, epoch: Epoch) -> ForkName {
+        ForkName::ALL
+            .into_iter()
+            .rev()
+            .find(|&fork| matches!(self.fork_epoch(fork), Some(fork_epoch) if fork_epoch <= epoch))
+            .unwrap_or(ForkName::Phase0)
+    }
+
+    /// Returns the fork that is active at `slot`.
+    pub fn fork_at_slot(&self, slot: Slot) -> ForkName {
+        self.fork_at_epoch(slot.epoch(self.slots_per_epoch))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn fork_at_epoch() {
+        let schedule = ForkSchedule {
+            slots_per_epoch: 8,
+            altair_fork_epoch: Some(Epoch::new(0)),
+            bellatrix_fork_epoch: Some(Epoch::new(2)),
+            capella_fork_epoch: Some(Epoch::new(2)),
+            deneb_fork_epoch: Some(Epoch::new(5)),
+            electra_fork_epoch: None,
+        };
+
+        assert_eq!(schedule.fork_at_epoch(Epoch::new(0)), ForkName::Altair);
+        assert_eq!(schedule.fork_at_epoch(Epoch::new(1)), ForkName::Altair);
+        // Bellatrix is skipped, since Capella activates at the same epoch.
+        assert_eq!(schedule.fork_at_epoch(Epoch::new(2)), ForkName::Capella);
+        assert_eq!(schedule.fork_at_slot(Slot::new(39)), ForkName::Capella);
+        assert_eq!(schedule.fork_at_slot(Slot::new(40)), ForkName::Deneb);
+        assert_eq!(
+            schedule.fork_at_epoch(Epoch::new(u64::MAX)),
+            ForkName::Deneb
+        );
+    }
+
+    #[test]
+    fn names() {
+        assert!(ForkName::ALL.windows(2).all(|pair| pair[0] < pair[1]));
+        assert_eq!(ForkName::Phase0.to_string(), "phase0");
+        assert_eq!(ForkName::Electra.as_str(), "electra");
+    }
+}
@@ -0,0 +1,242 @@
+use crate::{Error, ForkDigest};
+use std::fmt;
+use std::str::FromStr;
+
+/// The encoding of all messages on the gossip network.
+const SSZ_SNAPPY: &str = "ssz_snappy";
+
+/// The kinds of messages on the gossip network, with the id of the subnet of the kinds that are
+/// split across subnets.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum GossipKind {
+    BeaconBlock,
+    BeaconAggregateAndProof,
+    VoluntaryExit,
+    ProposerSlashing,
+    AttesterSlashing,
+    BeaconAttestation(u64),
+    SyncCommitteeContributionAndProof,
+    SyncCommittee(u64),
+    LightClientFinalityUpdate,
+    LightClientOptimisticUpdate,
+    BlsToExecutionChange,
+    BlobSidecar(u64),
+}
+
+impl GossipKind {
+    /// Returns the name of the topics of the kinds that are not split across subnets.
+    fn name(self) -> Option<&'static str> {
+        match self {
+            Self::BeaconBlock => Some("beacon_block"),
+            Self::BeaconAggregateAndProof => Some("beacon_aggregate_and_proof"),
+            Self::VoluntaryExit => Some("voluntary_exit"),
+            Self::ProposerSlashing => Some("proposer_slashing"),
+            Self::AttesterSlashing => Some("attester_slashing"),
+            Self::SyncCommitteeContributionAndProof => {
+                Some("sync_committee_contribution_and_proof")
+            }
+            Self::LightClientFinalityUpdate => Some("light_client_finality_update"),
+            Self::LightClientOptimisticUpdate => Some("light_client_optimistic_update"),
+            Self::BlsToExecutionChange => Some("bls_to_execution_change"),
+            Self::BeaconAttestation(_) | Self::SyncCommittee(_) | Self::BlobSidecar(_) => None,
+        }
+    }
+}
+
+impl fmt::Display for GossipKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::BeaconAttestation(subnet_id) => write!(f, "beacon_attestation_{}", subnet_id),
+            Self::SyncCommittee(subnet_id) => write!(f, "sync_committee_{}", subnet_id),
+            Self::BlobSidecar(subnet_id) => write!(f, "blob_sidecar_{}", subnet_id),
+            kind => f.write_str(kind.name().expect("kinds without subnets have a name")),
+        }
+    }
+}
+
+impl FromStr for GossipKind {
+    type Err = Error;
+
+    fn from_str(name: &str) -> Result<Self, Error> {
+        const KINDS: [GossipKind; 9] = [
+            GossipKind::BeaconBlock,
+            GossipKind::BeaconAggregateAndProof,
+            GossipKind::VoluntaryExit,
+            GossipKind::ProposerSlashing,
+            GossipKind::AttesterSlashing,
+            GossipKind::SyncCommitteeContributionAndProof,
+            GossipKind::LightClientFinalityUpdate,
+            GossipKind::LightClientOptimisticUpdate,
+            GossipKind::BlsToExecutionChange,
+        ];
+
+        if let Some(kind) = KINDS.into_iter().find(|kind| kind.name() == Some(name)) {
+            Ok(kind)
+        } else if let Some(subnet_id) = subnet_id(name, "beacon_attestation_") {
+            Ok(Self::BeaconAttestation(subnet_id))
+        } else if let Some(subnet_id) = subnet_id(name, "sync_committee_") {
+            Ok(Self::SyncCommittee(subnet_id))
+        } else if let Some(subnet_id) = subnet_id(name, "blob_sidecar_") {
+            Ok(Self::BlobSidecar(subnet_id))
+        } else {
+            Err(Error::InvalidGossipTopic {
+                topic: name.to_string(),
+            })
+        }
+    }
+}
+
+/// Returns the id of the subnet of the topic `name` of a kind whose topics start with `prefix`.
+fn subnet_id(name: &str, prefix: &str) -> Option<u64> {
+    let subnet_id = name.strip_prefix(prefix)?;
+    // Reject the signs and leading zeros that `u64::from_str` accepts, so that every kind has a
+    // single name.
+    if subnet_id.starts_with('+') || (subnet_id.len() > 1 && subnet_id.starts_with('0')) {
+        return None;
+    }
+    subnet_id.parse().ok()
+}
+
+/// The name of a topic of the gossip network, of the form
+/// `/eth2/{fork_digest}/{kind}/ssz_snappy`.
+///
+/// ## Example
+///
+/// ```
+/// use ssz_types::{GossipKind, GossipTopic};
+///
+/// let topic = GossipTopic::new([0x6a, 0x95, 0xa1, 0xa9], GossipKind::BeaconAttestation(5));
+/// assert_eq!(
+///     topic.to_string(),
+///     "/eth2/6a95a1a9/beacon_attestation_5/ssz_snappy"
+/// );
+/// assert_eq!("/eth2/6a95a1a9/beacon_attestation_5/ssz_snappy".parse(), Ok(topic));
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct GossipTopic {
+    pub fork_digest: ForkDigest,
+    pub kind: GossipKind,
+}
+
+impl GossipTopic {
+    pub fn new(fork_digest: ForkDigest, kind: GossipKind) -> Self {
+        Self { fork_digest, kind }
+    }
+}
+
+impl fmt::Display for GossipTopic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("/eth2/")?;
+        for byte in self.fork_digest {
+            write!(f, "{:02x}", byte)?;
+        }
+        write!(f, "/{}/{}", self.kind, SSZ_SNAPPY)
+    }
+}
+
+impl FromStr for GossipTopic {
+    type Err = Error;
+
+    fn from_str(topic: &str) -> Result<Self, Error> {
+        let invalid = || Error::InvalidGossipTopic {
+            topic: topic.to_string(),
+        };
+
+        let mut parts = topic.strip_prefix("/eth2/").ok_or_else(invalid)?.split('/');
+        let (Some(fork_digest), Some(kind), Some(SSZ_SNAPPY), None) =
+            (parts.next(), parts.next(), parts.next(), parts.next())
+        else {
+            return Err(invalid());
+        };
+
+        if fork_digest.len() != 8 || !fork_digest.bytes().all(|c| c.is_ascii_hexdigit()) {
+            return Err(invalid());
+        }
+        let mut digest = ForkDigest::default();
+        for (i, byte) in digest.iter_mut().enumerate() {
+            *byte =
+                u8::from_str_radix(&fork_digest[2 * i..2 * i + 2], 16).map_err(|_| invalid())?;
+        }
+
+        Ok(Self {
+            fork_digest: digest,
+            kind: kind.parse().map_err(|_| invalid())?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let kinds = [
+            GossipKind::BeaconBlock,
+            GossipKind::BeaconAggregateAndProof,
+            GossipKind::VoluntaryExit,
+            GossipKind::ProposerSlashing,
+            GossipKind::AttesterSlashing,
+            GossipKind::BeaconAttestation(0),
+            GossipKind::BeaconAttestation(63),
+            GossipKind::SyncCommitteeContributionAndProof,
+            GossipKind::SyncCommittee(3),
+            GossipKind::LightClientFinalityUpdate,
+            GossipKind::LightClientOptimisticUpdate,
+            GossipKind::BlsToExecutionChange,
+            GossipKind::BlobSidecar(8),
+        ];
+
+        for kind in kinds {
+            let topic = GossipTopic::new([0, 0x0a, 0xb0, 0xff], kind);
+            let name = topic.to_string();
+            assert!(name.starts_with("/eth2/000ab0ff/"), "{}", name);
+            assert!(name.ends_with("/ssz_snappy"), "{}", name);
+            assert_eq!(name.parse(), Ok(topic));
+        }
+
+        assert_eq!(
+            GossipTopic::new([0xb5, 0x30, 0x3f, 0x2a], GossipKind::BeaconBlock).to_string(),
+            "/eth2/b5303f2a/beacon_block/ssz_snappy"
+        );
+        assert_eq!(GossipKind::SyncCommittee(1).to_string(), "sync_committee_1");
+        // The uppercase digest is accepted, but rendered in lowercase.
+        assert_eq!(
+            "/eth2/B5303F2A/voluntary_exit/ssz_snappy".parse(),
+            Ok(GossipTopic::new(
+                [0xb5, 0x30, 0x3f, 0x2a],
+                GossipKind::VoluntaryExit
+            ))
+        );
+    }
+
+    #[test]
+    fn invalid_topics() {
+        for topic in [
+            "",
+            "/eth2/b5303f2a/beacon_block",
+            "/eth2/b5303f2a/beacon_block/ssz",
+            "/eth2/b5303f2a/beacon_block/ssz_snappy/",
+            "eth2/b5303f2a/beacon_block/ssz_snappy",
+            "/eth1/b5303f2a/beacon_block/ssz_snappy",
+            "/eth2/b5303f2/beacon_block/ssz_snappy",
+            "/eth2/+5303f2a/beacon_block/ssz_snappy",
+            "/eth2/b5303f2g/beacon_block/ssz_snappy",
+            "/eth2/b5303f2a/beacon_blocks/ssz_snappy",
+            "/eth2/b5303f2a/beacon_attestation_/ssz_snappy",
+            "/eth2/b5303f2a/beacon_attestation_01/ssz_snappy",
+            "/eth2/b5303f2a/beacon_attestation_+1/ssz_snappy",
+            "/eth2/b5303f2a/blob_sidecar_x/ssz_snappy",
+            "/eth2/b5303f2a/sync_committee/ssz_snappy",
+        ] {
+            assert_eq!(
+                topic.parse::<GossipTopic>(),
+                Err(Error::InvalidGossipTopic {
+                    topic: topic.to_string()
+                })
+            );
+        }
+    }
+}
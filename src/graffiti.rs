@@ -0,0 +1,236 @@
+use crate::hex::fmt_hex;
+use ssz_derive::{Decode, Encode};
+use std::borrow::Cow;
+use std::convert::Infallible;
+use std::fmt;
+use std::str::FromStr;
+use tree_hash::{Hash256, TreeHash};
+
+/// The number of bytes of the graffiti of a block.
+const GRAFFITI_LEN: usize = 32;
+
+/// The 32 bytes of arbitrary data that the proposer of a block includes in its body.
+///
+/// Graffiti is by convention UTF-8 text padded with zero bytes, and clients commonly fill it with
+/// their name and version, as in `Lighthouse/v5.1.3-3058b96`.
+///
+/// ## Example
+///
+/// ```
+/// use ssz_types::Graffiti;
+///
+/// let graffiti: Graffiti = "teku/v24.4.0 hello".parse().unwrap();
+/// assert_eq!(graffiti.as_utf8_lossy(), "teku/v24.4.0 hello");
+///
+/// let client_version = graffiti.client_version().unwrap();
+/// assert_eq!(client_version.client, "teku");
+/// assert_eq!(client_version.version, "v24.4.0");
+/// ```
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash, Encode, Decode)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[ssz(struct_behaviour = "transparent")]
+pub struct Graffiti([u8; GRAFFITI_LEN]);
+
+/// The client name and version at the start of a graffiti, as in `client/version`.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ClientVersion {
+    pub client: String,
+    pub version: String,
+}
+
+impl Graffiti {
+    /// Returns the graffiti with the given bytes.
+    pub const fn new(bytes: [u8; GRAFFITI_LEN]) -> Self {
+        Self(bytes)
+    }
+
+    /// Returns the bytes of the graffiti.
+    pub fn as_bytes(&self) -> &[u8; GRAFFITI_LEN] {
+        &self.0
+    }
+
+    /// Returns the graffiti as text, without the trailing zero bytes and with invalid UTF-8
+    /// replaced by `U+FFFD`.
+    pub fn as_utf8_lossy(&self) -> Cow<'_, str> {
+        let len = self
+            .0
+            .iter()
+            .rposition(|&byte| byte != 0)
+            .map_or(0, |last| last + 1);
+        String::from_utf8_lossy(&self.0[..len])
+    }
+
+    /// Returns the client and version of a graffiti which starts with `client/version`, where
+    /// the version ends at the first whitespace.
+    pub fn client_version(&self) -> Option<ClientVersion> {
+        let text = self.as_utf8_lossy();
+        let (client, rest) = text.trim_start().split_once('/')?;
+        let version = rest.split_whitespace().next()?;
+
+        if client.is_empty() || client.contains(char::is_whitespace) || !rest.starts_with(version) {
+            return None;
+        }
+        Some(ClientVersion {
+            client: client.to_string(),
+            version: version.to_string(),
+        })
+    }
+}
+
+impl FromStr for Graffiti {
+    type Err = Infallible;
+
+    /// Returns the graffiti with the UTF-8 bytes of `text`, padded with zero bytes or truncated
+    /// to the last whole character that fits into 32 bytes.
+    fn from_str(text: &str) -> Result<Self, Infallible> {
+        let mut len = text.len().min(GRAFFITI_LEN);
+        while !text.is_char_boundary(len) {
+            len -= 1;
+        }
+
+        let mut bytes = [0; GRAFFITI_LEN];
+        bytes[..len].copy_from_slice(&text.as_bytes()[..len]);
+        Ok(Self(bytes))
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for Graffiti {
+    fn schema_name() -> String {
+        "Graffiti".to_string()
+    }
+
+    fn json_schema(_: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        crate::json_schema::hex_schema(GRAFFITI_LEN, GRAFFITI_LEN)
+    }
+}
+
+impl fmt::Debug for Graffiti {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Graffiti(")?;
+        fmt_hex(&self.0, f)?;
+        f.write_str(")")
+    }
+}
+
+impl fmt::Display for Graffiti {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.as_utf8_lossy())
+    }
+}
+
+impl TreeHash for Graffiti {
+    fn tree_hash_type() -> tree_hash::TreeHashType {
+        Hash256::tree_hash_type()
+    }
+
+    fn tree_hash_packed_encoding(&self) -> tree_hash::PackedEncoding {
+        self.0.tree_hash_packed_encoding()
+    }
+
+    fn tree_hash_packing_factor() -> usize {
+        Hash256::tree_hash_packing_factor()
+    }
+
+    fn tree_hash_root(&self) -> Hash256 {
+        Hash256::from(self.0)
+    }
+}
+
+/// Serializes as the 0x-prefixed hex of its bytes, rather than as text.
+#[cfg(feature = "serde")]
+impl serde::Serialize for Graffiti {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&serde_utils::hex::encode(self.0))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Graffiti {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let bytes = deserializer.deserialize_str(serde_utils::hex::PrefixedHexVisitor)?;
+        let bytes = bytes.try_into().map_err(|bytes: Vec<u8>| {
+            serde::de::Error::custom(format!(
+                "expected {} bytes of graffiti, got {}",
+                GRAFFITI_LEN,
+                bytes.len()
+            ))
+        })?;
+        Ok(Self(bytes))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::FixedVector;
+    use ssz::{Decode, Encode};
+
+    fn graffiti(text: &str) -> Graffiti {
+        text.parse().unwrap()
+    }
+
+    #[test]
+    fn padding_and_truncation() {
+        let short = graffiti("gm");
+        assert_eq!(&short.as_bytes()[..3], b"gm\0");
+        assert_eq!(short.as_utf8_lossy(), "gm");
+        assert_eq!(short.to_string(), "gm");
+
+        let long = graffiti(&"a".repeat(40));
+        assert_eq!(long.as_utf8_lossy(), "a".repeat(32));
+
+        // The 3 bytes of `€` do not fit after 30 bytes, so the character is dropped.
+        let split = graffiti(&format!("{}€", "a".repeat(30)));
+        assert_eq!(split.as_utf8_lossy(), "a".repeat(30));
+
+        let mut bytes = [0; 32];
+        bytes[..3].copy_from_slice(&[b'o', 0xff, b'k']);
+        assert_eq!(Graffiti::new(bytes).as_utf8_lossy(), "o\u{fffd}k");
+        assert_eq!(Graffiti::default().as_utf8_lossy(), "");
+    }
+
+    #[test]
+    fn client_versions() {
+        let client_version = |text: &str| {
+            graffiti(text)
+                .client_version()
+                .map(|version| (version.client, version.version))
+        };
+
+        assert_eq!(
+            client_version("Lighthouse/v5.1.3-3058b96"),
+            Some(("Lighthouse".into(), "v5.1.3-3058b96".into()))
+        );
+        assert_eq!(
+            client_version("Nimbus/v24.3.0-dc19b0 #solo"),
+            Some(("Nimbus".into(), "v24.3.0-dc19b0".into()))
+        );
+        assert_eq!(client_version("Lighthouse/"), None);
+        assert_eq!(client_version("/v1.0.0"), None);
+        assert_eq!(client_version("my client/v1.0.0"), None);
+        assert_eq!(client_version("Lighthouse/ v1.0.0"), None);
+        assert_eq!(client_version("solo staking"), None);
+    }
+
+    #[test]
+    fn encoding() {
+        let graffiti = graffiti("Lighthouse/v5.1.3-3058b96");
+        let vector = FixedVector::<u8, typenum::U32>::new(graffiti.as_bytes().to_vec()).unwrap();
+
+        assert_eq!(graffiti.as_ssz_bytes(), vector.as_ssz_bytes());
+        assert_eq!(
+            Graffiti::from_ssz_bytes(&vector.as_ssz_bytes()),
+            Ok(graffiti)
+        );
+        assert_eq!(graffiti.tree_hash_root(), vector.tree_hash_root());
+    }
+
+    #[test]
+    fn debug() {
+        assert_eq!(
+            format!("{:?}", graffiti("teku")),
+            format!("Graffiti(0x74656b75{})", "00".repeat(28))
+        );
+    }
+}
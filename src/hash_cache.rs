@@ -0,0 +1,253 @@
+use crate::{Error, MemorySize, MerklePath, MerkleProof, SszReflect, SszSchema};
+use std::ops::Deref;
+use std::sync::OnceLock;
+use tree_hash::{Hash256, TreeHash};
+
+/// Wraps a value and memoizes its tree hash root.
+///
+/// The root is computed on the first call to `tree_hash_root` and reused until the value is
+/// mutated. Mutable access is only available through `Hc::get_mut`, which discards the cached
+/// root, so the cache can never be stale.
+///
+/// Has identical SSZ encoding, serde representation and tree hash root to `T`. Since the root of
+/// a container is computed from the roots of its fields, wrapping large fields (e.g. the body of
+/// a block or a long list) in `Hc` means that modifying one field only rehashes that field.
+///
+/// ## Example
+///
+/// ```
+/// use ssz_types::{Hc, VariableList, typenum};
+/// use tree_hash::TreeHash;
+///
+/// let mut list: Hc<VariableList<u64, typenum::U1024>> = Hc::new(VariableList::empty());
+///
+/// let empty_root = list.tree_hash_root();
+/// assert!(list.is_cached());
+///
+/// list.get_mut().push(1).unwrap();
+/// assert!(!list.is_cached());
+/// assert_ne!(list.tree_hash_root(), empty_root);
+/// ```
+#[derive(Clone, Default)]
+pub struct Hc<T> {
+    value: T,
+    root: OnceLock<Hash256>,
+}
+
+impl<T> Hc<T> {
+    /// Wraps `value`, without computing its root.
+    pub fn new(value: T) -> Self {
+        Self {
+            value,
+            root: OnceLock::new(),
+        }
+    }
+
+    /// Returns a mutable reference to the value, discarding the cached root.
+    pub fn get_mut(&mut self) -> &mut T {
+        self.root = OnceLock::new();
+        &mut self.value
+    }
+
+    /// True if the root of the value has been computed since it was last mutated.
+    pub fn is_cached(&self) -> bool {
+        self.root.get().is_some()
+    }
+
+    /// Returns the wrapped value.
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<T> From<T> for Hc<T> {
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<T> Deref for Hc<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<T> AsRef<T> for Hc<T> {
+    fn as_ref(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<T: std::fmt::Debug> std::fmt::Debug for Hc<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.value.fmt(f)
+    }
+}
+
+impl<T: PartialEq> PartialEq for Hc<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<T: Eq> Eq for Hc<T> {}
+
+impl<T: std::hash::Hash> std::hash::Hash for Hc<T> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.value.hash(state)
+    }
+}
+
+impl<T: MemorySize> MemorySize for Hc<T> {
+    fn heap_size(&self) -> usize {
+        self.value.heap_size()
+    }
+}
+
+impl<T: TreeHash + MerkleProof> MerkleProof for Hc<T> {
+    /// Uses the cached root when `gindex` is the root.
+    fn tree_hash_node(&self, gindex: u64) -> Result<Hash256, Error> {
+        if gindex == 1 {
+            Ok(self.tree_hash_root())
+        } else {
+            self.value.tree_hash_node(gindex)
+        }
+    }
+}
+
+impl<T: MerklePath> MerklePath for Hc<T> {
+    fn gindex_of(path: &[&str]) -> Result<u64, Error> {
+        T::gindex_of(path)
+    }
+}
+
+impl<T: SszReflect> SszReflect for Hc<T> {
+    fn ssz_schema() -> SszSchema {
+        T::ssz_schema()
+    }
+}
+
+impl<T: serde::Serialize> serde::Serialize for Hc<T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.value.serialize(serializer)
+    }
+}
+
+impl<'de, T: serde::Deserialize<'de>> serde::Deserialize<'de> for Hc<T> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        T::deserialize(deserializer).map(Self::new)
+    }
+}
+
+impl<T: TreeHash> TreeHash for Hc<T> {
+    fn tree_hash_type() -> tree_hash::TreeHashType {
+        T::tree_hash_type()
+    }
+
+    fn tree_hash_packed_encoding(&self) -> tree_hash::PackedEncoding {
+        self.value.tree_hash_packed_encoding()
+    }
+
+    fn tree_hash_packing_factor() -> usize {
+        T::tree_hash_packing_factor()
+    }
+
+    fn tree_hash_root(&self) -> Hash256 {
+        *self.root.get_or_init(|| self.value.tree_hash_root())
+    }
+}
+
+impl<T: ssz::Encode> ssz::Encode for Hc<T> {
+    fn is_ssz_fixed_len() -> bool {
+        T::is_ssz_fixed_len()
+    }
+
+    fn ssz_fixed_len() -> usize {
+        T::ssz_fixed_len()
+    }
+
+    fn ssz_bytes_len(&self) -> usize {
+        self.value.ssz_bytes_len()
+    }
+
+    fn ssz_append(&self, buf: &mut Vec<u8>) {
+        self.value.ssz_append(buf)
+    }
+}
+
+impl<T: ssz::Decode> ssz::Decode for Hc<T> {
+    fn is_ssz_fixed_len() -> bool {
+        T::is_ssz_fixed_len()
+    }
+
+    fn ssz_fixed_len() -> usize {
+        T::ssz_fixed_len()
+    }
+
+    fn from_ssz_bytes(bytes: &[u8]) -> Result<Self, ssz::DecodeError> {
+        T::from_ssz_bytes(bytes).map(Self::new)
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a, T: arbitrary::Arbitrary<'a>> arbitrary::Arbitrary<'a> for Hc<T> {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        T::arbitrary(u).map(Self::new)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::VariableList;
+    use ssz::{Decode, Encode};
+    use tree_hash_derive::TreeHash;
+    use typenum::*;
+
+    #[derive(Clone, Default, TreeHash)]
+    struct Container {
+        a: u64,
+        b: Hc<VariableList<u64, U1024>>,
+    }
+
+    #[derive(Clone, Default, TreeHash)]
+    struct Uncached {
+        a: u64,
+        b: VariableList<u64, U1024>,
+    }
+
+    #[test]
+    fn invalidated_on_mutation() {
+        let mut container = Container::default();
+        let mut uncached = Uncached::default();
+        assert_eq!(container.tree_hash_root(), uncached.tree_hash_root());
+        assert!(container.b.is_cached());
+
+        // Changing another field keeps the cached root.
+        container.a = 1;
+        uncached.a = 1;
+        assert!(container.b.is_cached());
+        assert_eq!(container.tree_hash_root(), uncached.tree_hash_root());
+
+        container.b.get_mut().push(42).unwrap();
+        uncached.b.push(42).unwrap();
+        assert!(!container.b.is_cached());
+        assert_eq!(container.tree_hash_root(), uncached.tree_hash_root());
+    }
+
+    #[test]
+    fn transparent() {
+        let list: VariableList<u64, U4> = VariableList::new(vec![1, 2]).unwrap();
+        let cached = Hc::new(list.clone());
+
+        assert_eq!(cached.as_ssz_bytes(), list.as_ssz_bytes());
+        assert_eq!(
+            Hc::<VariableList<u64, U4>>::from_ssz_bytes(&list.as_ssz_bytes()),
+            Ok(cached.clone())
+        );
+        assert_eq!(serde_json::to_string(&cached).unwrap(), "[1,2]");
+        assert_eq!(cached.into_inner(), list);
+    }
+}
@@ -0,0 +1,145 @@
+use std::sync::OnceLock;
+use tree_hash::Hash256;
+
+static BACKEND: OnceLock<&'static dyn Sha256Backend> = OnceLock::new();
+
+/// An implementation of SHA-256 used to merkleize the collections of this crate.
+///
+/// Merkleization only ever hashes pairs of 32 byte nodes, so backends receive whole layers of a
+/// tree at once and are free to hash the pairs in any order, e.g. several at a time with SIMD
+/// instructions.
+///
+/// The backend is selected once per process with `set_sha256_backend`. It is used for the roots of
+/// lists, vectors and bitfields, as well as for Merkle proofs. Containers deriving `TreeHash`
+/// merkleize their own fields through `tree_hash`, but delegate to the backend for any collection
+/// fields, which is where most of the hashing of e.g. a `BeaconBlockBody` happens.
+pub trait Sha256Backend: Send + Sync {
+    /// Writes the hash of each 64 byte block of `input` to the 32 bytes at the same position of
+    /// `output`.
+    ///
+    /// `input.len()` is always a multiple of 64 and twice `output.len()`.
+    fn hash_pairs(&self, input: &[u8], output: &mut [u8]);
+}
+
+/// Hashes with `ethereum_hashing`, which uses the SHA extensions of x86_64 CPUs when they are
+/// available and `ring` otherwise.
+///
+/// This is the backend used unless another is set with `set_sha256_backend`.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct DefaultBackend;
+
+impl Sha256Backend for DefaultBackend {
+    fn hash_pairs(&self, input: &[u8], output: &mut [u8]) {
+        for (pair, hash) in input.chunks_exact(64).zip(output.chunks_exact_mut(32)) {
+            hash.copy_from_slice(&ethereum_hashing::hash_fixed(pair));
+        }
+    }
+}
+
+/// Hashes with the compression function of `sha2`, which uses the SHA extensions of x86 and ARMv8
+/// CPUs when they are available.
+///
+/// Since every input is exactly one block long, the padding block is a constant and the
+/// compression function is called directly, skipping the buffering of a streaming hasher.
+#[cfg(feature = "sha2")]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Sha2Backend;
+
+#[cfg(feature = "sha2")]
+impl Sha256Backend for Sha2Backend {
+    fn hash_pairs(&self, input: &[u8], output: &mut [u8]) {
+        use sha2::digest::generic_array::GenericArray;
+
+        const INITIAL_STATE: [u32; 8] = [
+            0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab,
+            0x5be0cd19,
+        ];
+        // A single set bit after the message, followed by its length of 512 bits.
+        const PADDING: [u8; 64] = {
+            let mut padding = [0; 64];
+            padding[0] = 0x80;
+            padding[62] = 0x02;
+            padding
+        };
+
+        for (pair, hash) in input.chunks_exact(64).zip(output.chunks_exact_mut(32)) {
+            let mut state = INITIAL_STATE;
+            sha2::compress256(
+                &mut state,
+                &[
+                    *GenericArray::from_slice(pair),
+                    *GenericArray::from_slice(&PADDING),
+                ],
+            );
+
+            for (word, bytes) in state.iter().zip(hash.chunks_exact_mut(4)) {
+                bytes.copy_from_slice(&word.to_be_bytes());
+            }
+        }
+    }
+}
+
+/// Sets the backend used for the rest of the process.
+///
+/// Returns `Err` with the given backend if a backend has already been set, or if any value has
+/// already been merkleized with the default backend.
+///
+/// ## Example
+///
+/// ```
+/// use ssz_types::{set_sha256_backend, DefaultBackend};
+///
+/// assert!(set_sha256_backend(&DefaultBackend).is_ok());
+/// assert!(set_sha256_backend(&DefaultBackend).is_err());
+/// ```
+pub fn set_sha256_backend(
+    backend: &'static dyn Sha256Backend,
+) -> Result<(), &'static dyn Sha256Backend> {
+    BACKEND.set(backend)
+}
+
+/// Returns the backend used to merkleize values.
+pub(crate) fn sha256_backend() -> &'static dyn Sha256Backend {
+    *BACKEND.get_or_init(|| &DefaultBackend)
+}
+
+/// Returns the hash of the concatenation of `left` and `right`.
+pub(crate) fn hash32_concat(left: &[u8], right: &[u8]) -> Hash256 {
+    let mut pair = [0; 64];
+    pair[..32].copy_from_slice(left);
+    pair[32..].copy_from_slice(right);
+
+    let mut hash = Hash256::zero();
+    sha256_backend().hash_pairs(&pair, hash.as_bytes_mut());
+    hash
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn assert_matches_default(backend: &dyn Sha256Backend) {
+        let input = (0..64 * 5).map(|i| i as u8).collect::<Vec<_>>();
+        let mut expected = vec![0; 32 * 5];
+        let mut output = vec![0; 32 * 5];
+
+        DefaultBackend.hash_pairs(&input, &mut expected);
+        backend.hash_pairs(&input, &mut output);
+        assert_eq!(output, expected);
+        assert_eq!(
+            &expected[32..64],
+            &ethereum_hashing::hash32_concat(&input[64..96], &input[96..128])[..]
+        );
+    }
+
+    #[test]
+    fn default_backend() {
+        assert_matches_default(&DefaultBackend);
+    }
+
+    #[cfg(feature = "sha2")]
+    #[test]
+    fn sha2_backend() {
+        assert_matches_default(&Sha2Backend);
+    }
+}
@@ -0,0 +1,51 @@
+use crate::Error;
+use std::any::TypeId;
+use std::fmt;
+
+/// Writes `bytes` as `0x`-prefixed lowercase hex.
+pub(crate) fn fmt_hex(bytes: &[u8], f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.write_str("0x")?;
+    for byte in bytes {
+        write!(f, "{:02x}", byte)?;
+    }
+    Ok(())
+}
+
+/// Parses `0x`-prefixed hex of exactly `len` bytes, in either case.
+pub(crate) fn parse_hex(hex: &str, len: usize) -> Result<Vec<u8>, Error> {
+    let invalid = || Error::InvalidHex {
+        hex: hex.to_string(),
+    };
+
+    let digits = hex.strip_prefix("0x").ok_or_else(invalid)?;
+    if digits.len() != 2 * len || !digits.bytes().all(|c| c.is_ascii_hexdigit()) {
+        return Err(invalid());
+    }
+    (0..len)
+        .map(|i| u8::from_str_radix(&digits[2 * i..2 * i + 2], 16).map_err(|_| invalid()))
+        .collect()
+}
+
+/// Writes `values` as `0x`-prefixed hex if they are bytes, and otherwise as the `Debug` of the
+/// `vec` field of the list or vector `name`.
+///
+/// Without specialization, bytes are recognized by the `TypeId` of their type, and written with
+/// the hex flag of their `Debug`, which integers respect.
+pub(crate) fn fmt_debug_vec<T: fmt::Debug + 'static>(
+    name: &str,
+    values: &[T],
+    f: &mut fmt::Formatter<'_>,
+) -> fmt::Result {
+    if TypeId::of::<T>() != TypeId::of::<u8>() {
+        return f
+            .debug_struct(name)
+            .field("vec", &values)
+            .finish_non_exhaustive();
+    }
+
+    f.write_str("0x")?;
+    for byte in values {
+        write!(f, "{:02x?}", byte)?;
+    }
+    Ok(())
+}
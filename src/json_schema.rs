@@ -0,0 +1,196 @@
+//! The JSON schemas of the beacon API formats of types, for the fields whose format is set by a
+//! `serde(with = "...")` attribute, e.g. byte vectors as hex and integers as quoted strings.
+//!
+//! Each format is a marker type, generic over the type of the field, which is given to the
+//! `schemars(with = "...")` attribute of the field.
+
+use crate::{CowList, FixedVector, VariableList};
+use schemars::gen::SchemaGenerator;
+use schemars::schema::{
+    ArrayValidation, InstanceType, Schema, SchemaObject, SingleOrVec, StringValidation,
+};
+use schemars::JsonSchema;
+use std::marker::PhantomData;
+use tree_hash::Hash256;
+use typenum::{Unsigned, U4};
+
+/// The schema of `T` as `0x`-prefixed hex.
+pub(crate) struct Hex<T>(PhantomData<T>);
+
+/// The schema of a list or vector whose items are `0x`-prefixed hex.
+pub(crate) struct HexItems<T>(PhantomData<T>);
+
+/// The schema of `T` as a quoted decimal integer.
+pub(crate) struct Quoted<T>(PhantomData<T>);
+
+/// The schema of a list or vector whose items are quoted decimal integers.
+pub(crate) struct QuotedItems<T>(PhantomData<T>);
+
+/// Returns the schema of `0x`-prefixed hex of `min_len` to `max_len` bytes.
+pub(crate) fn hex_schema(min_len: usize, max_len: usize) -> Schema {
+    let pattern = if min_len == max_len {
+        format!("^0x[0-9a-fA-F]{{{}}}$", 2 * min_len)
+    } else {
+        "^0x([0-9a-fA-F]{2})*$".to_string()
+    };
+    SchemaObject {
+        instance_type: Some(InstanceType::String.into()),
+        string: Some(Box::new(StringValidation {
+            min_length: u32::try_from(2 + 2 * min_len).ok(),
+            max_length: u32::try_from(2 + 2 * max_len).ok(),
+            pattern: Some(pattern),
+        })),
+        ..Default::default()
+    }
+    .into()
+}
+
+/// Returns the schema of an integer of up to `max_digits` decimal digits, as a quoted string.
+pub(crate) fn quoted_schema(max_digits: u32) -> Schema {
+    SchemaObject {
+        instance_type: Some(InstanceType::String.into()),
+        string: Some(Box::new(StringValidation {
+            min_length: Some(1),
+            max_length: Some(max_digits),
+            pattern: Some("^[0-9]+$".to_string()),
+        })),
+        ..Default::default()
+    }
+    .into()
+}
+
+/// Returns the schema of an array of `min_len` to `max_len` items of the schema `items`.
+pub(crate) fn array_schema(items: Schema, min_len: usize, max_len: usize) -> Schema {
+    SchemaObject {
+        instance_type: Some(InstanceType::Array.into()),
+        array: Some(Box::new(ArrayValidation {
+            items: Some(SingleOrVec::Single(Box::new(items))),
+            min_items: u32::try_from(min_len).ok(),
+            max_items: u32::try_from(max_len).ok(),
+            ..Default::default()
+        })),
+        ..Default::default()
+    }
+    .into()
+}
+
+/// Implements `JsonSchema` for a format of `$type`, inlined in the schemas of its containers.
+macro_rules! impl_format_schema {
+    (
+        impl<$($param: ident $(: $bound: path)?),*> $format: ident<$type: ty>
+        $(where $($where_ty: ty: $where_bound: path),*)?
+        => $schema: expr
+    ) => {
+        impl<$($param $(: $bound)?),*> JsonSchema for $format<$type>
+        $(where $($where_ty: $where_bound),*)?
+        {
+            fn is_referenceable() -> bool {
+                false
+            }
+
+            fn schema_name() -> String {
+                stringify!($format).to_string()
+            }
+
+            fn json_schema(gen: &mut SchemaGenerator) -> Schema {
+                $schema(gen)
+            }
+        }
+    };
+}
+
+impl_format_schema!(impl<N: Unsigned> Hex<FixedVector<u8, N>> => |_| {
+    hex_schema(N::to_usize(), N::to_usize())
+});
+impl_format_schema!(impl<N: Unsigned> Hex<VariableList<u8, N>> => |_| {
+    hex_schema(0, N::to_usize())
+});
+impl_format_schema!(impl<N: Unsigned> Hex<CowList<u8, N>> => |_| hex_schema(0, N::to_usize()));
+impl_format_schema!(impl<> Hex<[u8; 4]> => |_| hex_schema(4, 4));
+impl_format_schema!(impl<> Hex<Hash256> => |_| hex_schema(32, 32));
+
+impl_format_schema!(
+    impl<T, N: Unsigned> HexItems<FixedVector<T, N>> where Hex<T>: JsonSchema
+    => |gen: &mut SchemaGenerator| {
+        array_schema(gen.subschema_for::<Hex<T>>(), N::to_usize(), N::to_usize())
+    }
+);
+impl_format_schema!(
+    impl<T, N: Unsigned> HexItems<VariableList<T, N>> where Hex<T>: JsonSchema
+    => |gen: &mut SchemaGenerator| array_schema(gen.subschema_for::<Hex<T>>(), 0, N::to_usize())
+);
+impl_format_schema!(
+    impl<T, N: Unsigned> HexItems<CowList<T, N>> where Hex<T>: JsonSchema
+    => |gen: &mut SchemaGenerator| array_schema(gen.subschema_for::<Hex<T>>(), 0, N::to_usize())
+);
+impl_format_schema!(
+    impl<T> HexItems<Vec<T>> where Hex<T>: JsonSchema
+    => |gen: &mut SchemaGenerator| array_schema(gen.subschema_for::<Hex<T>>(), 0, usize::MAX)
+);
+
+impl_format_schema!(impl<> Quoted<u8> => |_| quoted_schema(3));
+impl_format_schema!(impl<> Quoted<u64> => |_| quoted_schema(20));
+impl_format_schema!(impl<> Quoted<FixedVector<u64, U4>> => |_| quoted_schema(78));
+
+impl_format_schema!(
+    impl<T, N: Unsigned> QuotedItems<VariableList<T, N>> where Quoted<T>: JsonSchema
+    => |gen: &mut SchemaGenerator| {
+        array_schema(gen.subschema_for::<Quoted<T>>(), 0, N::to_usize())
+    }
+);
+impl_format_schema!(
+    impl<T> QuotedItems<Vec<T>> where Quoted<T>: JsonSchema
+    => |gen: &mut SchemaGenerator| array_schema(gen.subschema_for::<Quoted<T>>(), 0, usize::MAX)
+);
+
+#[cfg(test)]
+mod test {
+    use crate::{Mainnet, Minimal, SignedBeaconBlockDeneb};
+    use serde_json::json;
+
+    #[test]
+    fn beacon_block_schema() {
+        let schema = schemars::schema_for!(SignedBeaconBlockDeneb<Mainnet>);
+        let schema = serde_json::to_value(schema).unwrap();
+        let definitions = &schema["definitions"];
+
+        let root = json!({
+            "type": "string",
+            "pattern": "^0x[0-9a-fA-F]{64}$",
+            "minLength": 66,
+            "maxLength": 66,
+        });
+        let block = &definitions["BeaconBlockDeneb_for_Mainnet"]["properties"];
+        assert_eq!(block["parent_root"], root);
+        assert_eq!(block["slot"], json!({ "$ref": "#/definitions/Slot" }));
+        assert_eq!(definitions["Slot"]["type"], "string");
+        assert_eq!(definitions["Slot"]["pattern"], "^[0-9]+$");
+        assert_eq!(definitions["SignatureBytes"]["maxLength"], 2 + 2 * 96);
+
+        let payload = &definitions["ExecutionPayloadDeneb_for_Mainnet"]["properties"];
+        assert_eq!(payload["base_fee_per_gas"]["pattern"], "^[0-9]+$");
+        assert_eq!(payload["transactions"]["type"], "array");
+        assert_eq!(payload["transactions"]["maxItems"], 1 << 20);
+        assert_eq!(
+            payload["transactions"]["items"]["pattern"],
+            "^0x([0-9a-fA-F]{2})*$"
+        );
+
+        let body = &definitions["BeaconBlockBodyDeneb_for_Mainnet"]["properties"];
+        assert_eq!(body["blob_kzg_commitments"]["maxItems"], 4096);
+        assert_eq!(body["blob_kzg_commitments"]["items"]["minLength"], 98);
+
+        // Bit lists include their length bit, bit vectors are exactly their bytes.
+        let attestation = &definitions["Attestation_for_Mainnet"]["properties"];
+        assert_eq!(attestation["aggregation_bits"]["minLength"], 4);
+        assert_eq!(attestation["aggregation_bits"]["maxLength"], 2 + 2 * 257);
+        let sync_aggregate = &definitions["SyncAggregate_for_Mainnet"]["properties"];
+        assert_eq!(sync_aggregate["sync_committee_bits"]["maxLength"], 130);
+
+        // The presets are distinguished in the names of the definitions.
+        let schema = schemars::schema_for!(SignedBeaconBlockDeneb<Minimal>);
+        let schema = serde_json::to_value(schema).unwrap();
+        assert!(schema["definitions"]["BeaconBlockDeneb_for_Minimal"].is_object());
+        assert!(schema["definitions"]["BeaconBlockDeneb_for_Mainnet"].is_null());
+    }
+}
@@ -2,12 +2,62 @@
 //!
 //! - `FixedVector`: A heap-allocated list with a size that is fixed at compile time.
 //! - `VariableList`: A heap-allocated list that cannot grow past a type-level maximum length.
+//! - `RuntimeVariableList`: A heap-allocated list with a maximum length that is set at runtime.
+//! - `SmallVariableList`: A `VariableList` that stores a small number of values inline, without a
+//!   heap allocation.
+//! - `CowList`: A `VariableList` behind an `Arc`, copied only when it is modified.
+//! - `Hc`: A wrapper around any value which memoizes its tree hash root until it is modified.
+//! - `ByteList`: A `VariableList<u8, N>` backed by a shared `bytes::Bytes` buffer, allowing
+//!   zero-copy decoding. Requires the `bytes` feature.
+//! - `PersistentList`/`PersistentVector`: Counterparts to `VariableList`/`FixedVector` which
+//!   share unmodified values between clones.
 //! - `BitList`: A heap-allocated bitfield that with a type-level _maximum_ length.
 //! - `BitVector`: A heap-allocated bitfield that with a type-level _fixed__ length.
+//! - `DynamicBitVector`: A heap-allocated bitfield with a fixed length that is set at runtime.
 //!
 //! These structs are required as SSZ serialization and Merklization rely upon type-level lengths
 //! for padding and verification.
 //!
+//! All collection types, and the beacon block and state containers built from them, implement
+//! `MemorySize` to report their recursive heap usage.
+//!
+//! The beacon block and state containers are generic over a `Preset`, either `Mainnet` or
+//! `Minimal`, which sets the lengths of their lists and vectors. They default to `Mainnet`.
+//!
+//! The same types implement `MerkleProof`, which resolves any node of their Merkle tree by
+//! generalized index and produces Merkle branches for it. Apart from `RuntimeVariableList`, whose
+//! limit is only known at runtime, they also implement `MerklePath`, which computes the generalized
+//! index of a node from a path of field names and indices. `MerkleProof::merkle_tree` exports the
+//! nodes of a tree as a `MerkleTree`, for debugging root mismatches, and `MerkleProof::partial`
+//! serializes the subset of a tree needed to read some of its nodes as a `Partial`.
+//! Types with a `MerklePath` also implement `SszReflect`, which describes their SSZ type at runtime
+//! as an `SszSchema`, e.g. `List[uint64, 2048]`, for tooling which is generic over all types.
+//!
+//! Merkleization hashes through a `Sha256Backend`, which can be replaced process-wide with
+//! `set_sha256_backend`. The `sha2` feature provides `Sha2Backend`.
+//!
+//! Public keys and signatures are held as their compressed bytes, `PublicKeyBytes` and
+//! `SignatureBytes`. The `bls` feature decompresses them with `blst`, and verifies the signatures
+//! of signed blocks, exits, BLS to execution changes, indexed attestations and deposits. It also
+//! aggregates attestations with `Attestation::aggregate`. Without it, `Attestation::union_bits`
+//! merges their bits only, leaving signatures which no longer cover them.
+//!
+//! The `execution` feature decodes the transactions of execution payloads into their EIP-2718
+//! envelopes with `TransactionEnvelope`, and computes the hashes of the execution blocks of
+//! payloads with `ExecutionBlockHeader`.
+//!
+//! The `snappy` feature compresses SSZ with raw snappy, as gossip messages are, with
+//! `encode_snappy` and `decode_snappy`, and frames the requests and responses of the req/resp
+//! protocols with `req_resp`.
+//!
+//! The `serde` feature implements `Serialize` and `Deserialize` for the containers, in the JSON
+//! format of the beacon API: integers are quoted decimal strings and byte vectors and lists are
+//! `0x`-prefixed hex. The `yaml` feature decodes them from the YAML format of the consensus spec
+//! tests, whose integers are unquoted, with `from_yaml_str`. The `spec-tests` feature runs the
+//! `ssz_static` cases of the consensus spec tests against the containers with `spec_tests`.
+//! The `schemars` feature implements `schemars::JsonSchema` for the containers, describing the same
+//! JSON format, with the hex and quoted strings constrained by patterns and lengths.
+//!
 //! Adheres to the Ethereum 2.0 [SSZ
 //! specification](https://github.com/ethereum/eth2.0-specs/blob/v0.12.1/ssz/simple-serialize.md)
 //! at v0.12.1.
@@ -26,8 +76,8 @@
 //! let mut example = Example {
 //!     bit_vector: Bitfield::new(),
 //!     bit_list: Bitfield::with_capacity(4).unwrap(),
-//!     variable_list: <_>::from(vec![0, 1]),
-//!     fixed_vector: <_>::from(vec![2, 3]),
+//!     variable_list: <_>::try_from(vec![0, 1]).unwrap(),
+//!     fixed_vector: FixedVector::new_resized(vec![2, 3]),
 //! };
 //!
 //! assert_eq!(example.bit_vector.len(), 8);
@@ -39,20 +89,186 @@
 
 #[macro_use]
 mod bitfield;
+#[macro_use]
+mod memory_size;
+mod merkle_branch;
+mod merkle_tree;
+mod multiproof;
+#[macro_use]
+mod ssz_schema;
+#[macro_use]
+mod proof;
+mod aggregate_and_proof;
+#[macro_use]
 mod beacon_block;
+mod beacon_state;
+mod bls;
+mod builder;
+#[cfg(feature = "bytes")]
+mod byte_list;
+mod byte_list_hasher;
+mod chain_config;
+#[cfg(feature = "const-generics")]
+pub mod const_generic;
+mod cow_list;
+mod deposit_tree;
+pub mod domains;
+mod execution_address;
+#[cfg(feature = "execution")]
+mod execution_block;
 mod fixed_vector;
+mod fork;
+mod gossip;
+mod graffiti;
+mod hash_cache;
+mod hasher;
+mod hex;
+#[cfg(feature = "schemars")]
+mod json_schema;
+mod light_client;
+mod p2p;
+mod partial;
+mod persistent;
+mod preset;
+mod primitives;
+mod rank_select;
+#[cfg(feature = "snappy")]
+pub mod req_resp;
+mod runtime_variable_list;
 pub mod serde_utils;
+mod signing;
+mod small_list;
+#[cfg(feature = "snappy")]
+mod snappy;
+#[cfg(feature = "spec-tests")]
+pub mod spec_tests;
+mod subnet;
+mod sync_committee;
+#[cfg(feature = "execution")]
+mod transaction;
 mod tree_hash;
 mod variable_list;
+#[cfg(feature = "bls")]
+mod verify;
+#[cfg(feature = "yaml")]
+mod yaml;
 
-pub use beacon_block::SignedBeaconBlock;
-pub use bitfield::{BitList, BitVector, Bitfield};
+pub use crate::tree_hash::batch_tree_hash_root;
+pub use aggregate_and_proof::{
+    is_aggregator, AggregateAndProof, AggregateAndProofElectra, SignedAggregateAndProof,
+    SignedAggregateAndProofElectra, TARGET_AGGREGATORS_PER_COMMITTEE,
+};
+pub use beacon_block::{
+    Attestation, AttestationData, AttestationElectra, AttesterSlashing, AttesterSlashingElectra,
+    BeaconBlockAltair, BeaconBlockBellatrix, BeaconBlockBodyAltair, BeaconBlockBodyBellatrix,
+    BeaconBlockBodyCapella, BeaconBlockBodyDeneb, BeaconBlockBodyElectra, BeaconBlockBodyPhase0,
+    BeaconBlockBodyRef, BeaconBlockCapella, BeaconBlockDeneb, BeaconBlockElectra,
+    BeaconBlockHeader, BeaconBlockPhase0, BlindedBeaconBlockBellatrix,
+    BlindedBeaconBlockBodyBellatrix, BlindedBeaconBlockBodyCapella, BlindedBeaconBlockBodyDeneb,
+    BlindedBeaconBlockBodyElectra, BlindedBeaconBlockCapella, BlindedBeaconBlockDeneb,
+    BlindedBeaconBlockElectra, Blob, BlobSidecar, BlsToExecutionChange, ConsolidationRequest,
+    Deposit, DepositData, DepositMessage, DepositRequest, ExecutionPayload,
+    ExecutionPayloadBellatrix, ExecutionPayloadCapella, ExecutionPayloadDeneb,
+    ExecutionPayloadHeaderBellatrix, ExecutionPayloadHeaderCapella, ExecutionPayloadHeaderDeneb,
+    ExecutionRequests, IndexedAttestation, IndexedAttestationElectra, KzgCommitment, KzgProof,
+    SignedBeaconBlock, SignedBeaconBlockAltair, SignedBeaconBlockBellatrix,
+    SignedBeaconBlockCapella, SignedBeaconBlockDeneb, SignedBeaconBlockElectra,
+    SignedBeaconBlockPhase0, SignedBlindedBeaconBlock, SignedBlindedBeaconBlockBellatrix,
+    SignedBlindedBeaconBlockCapella, SignedBlindedBeaconBlockDeneb,
+    SignedBlindedBeaconBlockElectra, SignedBlobSidecar, SignedBlsToExecutionChange,
+    SignedVoluntaryExit, VoluntaryExit, WithdrawalRequest, CONSOLIDATION_REQUEST_TYPE,
+    DEPOSIT_REQUEST_TYPE, WITHDRAWAL_REQUEST_TYPE,
+};
+pub use beacon_state::{
+    BeaconState, BeaconStateAltair, BeaconStateBellatrix, BeaconStateCapella, BeaconStateDeneb,
+    BeaconStateElectra, BeaconStatePhase0, Fork, HistoricalBatch, HistoricalSummary,
+    PendingAttestation, PendingConsolidation, PendingDeposit, PendingPartialWithdrawal,
+    SyncCommittee, Validator,
+};
+pub use bitfield::{BitList, BitSliceRef, BitVector, Bitfield, DynamicBitVector};
+#[cfg(feature = "bls")]
+pub use bls::{PublicKey, Signature};
+pub use bls::{PublicKeyBytes, SignatureBytes};
+pub use builder::{
+    BlobsBundle, BuilderBid, BuilderBidBellatrix, BuilderBidCapella, BuilderBidDeneb,
+    BuilderBidElectra, ExecutionPayloadAndBlobsBundle, SignedBuilderBid, SignedBuilderBidBellatrix,
+    SignedBuilderBidCapella, SignedBuilderBidDeneb, SignedBuilderBidElectra,
+    SignedValidatorRegistration, ValidatorRegistration,
+};
+#[cfg(feature = "bytes")]
+pub use byte_list::ByteList;
+pub use byte_list_hasher::ByteListHasher;
+pub use chain_config::{ChainConfig, FAR_FUTURE_EPOCH};
+pub use cow_list::CowList;
+pub use deposit_tree::{DepositTree, DepositTreeSnapshot};
+pub use domains::{
+    compute_builder_domain, compute_deposit_domain, compute_domain, Domain, DomainType,
+    DOMAIN_APPLICATION_BUILDER, DOMAIN_BEACON_ATTESTER, DOMAIN_BEACON_PROPOSER,
+    DOMAIN_BLS_TO_EXECUTION_CHANGE, DOMAIN_DEPOSIT, DOMAIN_VOLUNTARY_EXIT,
+};
+pub use execution_address::ExecutionAddress;
+#[cfg(feature = "execution")]
+pub use execution_block::ExecutionBlockHeader;
 pub use fixed_vector::FixedVector;
+pub use fork::{ForkName, ForkSchedule};
+pub use gossip::{GossipKind, GossipTopic};
+pub use graffiti::{ClientVersion, Graffiti};
+pub use hash_cache::Hc;
+#[cfg(feature = "sha2")]
+pub use hasher::Sha2Backend;
+pub use hasher::{set_sha256_backend, DefaultBackend, Sha256Backend};
+pub use light_client::{
+    current_sync_committee_branch, execution_payload_branch, finality_branch,
+    next_sync_committee_branch,
+};
+pub use memory_size::MemorySize;
+pub use merkle_branch::{
+    verify_deposit_branch, verify_finality_branch, verify_merkle_branch,
+    DEPOSIT_CONTRACT_TREE_DEPTH, FINALIZED_ROOT_GINDEX, FINALIZED_ROOT_GINDEX_ELECTRA,
+};
+pub use merkle_tree::MerkleTree;
+pub use multiproof::{helper_indices, Multiproof};
+pub use p2p::{
+    BeaconBlocksByRangeRequest, BeaconBlocksByRootRequest, BlobIdentifier,
+    BlobSidecarsByRangeRequest, BlobSidecarsByRootRequest, ENRForkID, Goodbye, MetaDataV1,
+    MetaDataV2, MetaDataV3, Ping, Status, GOODBYE_CLIENT_SHUTDOWN, GOODBYE_FAULT_OR_ERROR,
+    GOODBYE_IRRELEVANT_NETWORK, GOSSIP_MAX_SIZE, MAX_CHUNK_SIZE, MAX_REQUEST_BLOB_SIDECARS_ELECTRA,
+    MAX_REQUEST_BLOCKS, MAX_REQUEST_BLOCKS_DENEB,
+};
+pub use partial::Partial;
+pub use persistent::{PersistentIter, PersistentList, PersistentVector};
+pub use preset::{Mainnet, Minimal, Preset, PresetLength};
+pub use primitives::{CommitteeIndex, Epoch, Gwei, Slot, ValidatorIndex};
+pub use proof::{gindex_of, MerklePath, MerkleProof};
+pub use rank_select::RankSelect;
+pub use runtime_variable_list::RuntimeVariableList;
+pub use signing::{
+    compute_fork_data_root, compute_fork_digest, compute_signing_root, ForkData, ForkDigest,
+    SignedRoot, SigningData, Version,
+};
+pub use small_list::SmallVariableList;
+#[cfg(feature = "snappy")]
+pub use snappy::{decode_snappy, encode_snappy};
+pub use ssz_schema::{ssz_schema_of, SszReflect, SszSchema};
+pub use subnet::{compute_subnet_for_attestation, SubnetId, ATTESTATION_SUBNET_COUNT};
+pub use sync_committee::{
+    is_sync_committee_aggregator, ContributionAndProof, SignedContributionAndProof,
+    SyncAggregatorSelectionData, SyncCommitteeContribution, SyncCommitteeMessage,
+    SYNC_COMMITTEE_SUBNET_COUNT, TARGET_AGGREGATORS_PER_SYNC_SUBCOMMITTEE,
+};
+#[cfg(feature = "execution")]
+pub use transaction::{
+    AccessListItem, AccessListTransaction, Authorization, BlobTransaction, DynamicFeeTransaction,
+    LegacyTransaction, SetCodeTransaction, TransactionEnvelope, ACCESS_LIST_TRANSACTION_TYPE,
+    BLOB_TRANSACTION_TYPE, DYNAMIC_FEE_TRANSACTION_TYPE, SET_CODE_TRANSACTION_TYPE,
+};
 pub use typenum;
 pub use variable_list::VariableList;
+#[cfg(feature = "yaml")]
+pub use yaml::{from_yaml_slice, from_yaml_str};
 
 pub mod length {
-    pub use crate::bitfield::{Fixed, Variable};
+    pub use crate::bitfield::{Dynamic, Fixed, Variable};
 }
 
 /// Returned when an item encounters an error.
@@ -71,4 +287,70 @@ pub enum Error {
         given: usize,
         expected: usize,
     },
+    /// A generalized index does not refer to a node in the Merkle tree of a value.
+    InvalidGeneralizedIndex {
+        gindex: u64,
+    },
+    /// A Merkle proof does not contain the nodes required to compute its root.
+    InvalidProof,
+    /// An element of a path does not refer to a child of the node it is applied to.
+    InvalidPathElement {
+        element: String,
+    },
+    /// A deposit has been finalized, so that it is no longer possible to prove it.
+    FinalizedDeposit {
+        index: u64,
+    },
+    /// An execution payload does not match the header of a blinded block, as their roots differ.
+    ExecutionPayloadMismatch {
+        expected: ::tree_hash::Hash256,
+        found: ::tree_hash::Hash256,
+    },
+    /// A string is not the name of a topic of the gossip network.
+    InvalidGossipTopic {
+        topic: String,
+    },
+    /// A key of a chain config is missing, or its value is malformed.
+    InvalidChainConfig {
+        key: String,
+    },
+    /// A string is not a hex execution address, or its checksum is invalid.
+    InvalidExecutionAddress {
+        address: String,
+    },
+    /// The bytes of a BLS public key or signature are not a valid point of its group.
+    InvalidBlsPoint,
+    /// A BLS signature is not valid for its message and public keys.
+    InvalidSignature,
+    /// Attestations to aggregate are not of the same data and committees.
+    AttestationMismatch,
+    /// Attestations to aggregate have attesters in common, whose signatures would be counted
+    /// twice.
+    OverlappingAggregationBits,
+    /// An attestation is not of exactly one committee, as unaggregated attestations are.
+    NotSingleCommittee {
+        committees: usize,
+    },
+    /// The bytes of a transaction are not a valid EIP-2718 envelope of a known type.
+    InvalidTransaction {
+        reason: String,
+    },
+    /// The `block_hash` of an execution payload is not the hash of its execution block.
+    BlockHashMismatch {
+        expected: ::tree_hash::Hash256,
+        found: ::tree_hash::Hash256,
+    },
+    /// The `deposit_root` of a `DepositTreeSnapshot` is not the root of its finalized deposits.
+    DepositRootMismatch {
+        expected: ::tree_hash::Hash256,
+        found: ::tree_hash::Hash256,
+    },
+    /// A YAML document is not a valid encoding of a container.
+    InvalidYaml {
+        reason: String,
+    },
+    /// A string is not the `0x`-prefixed hex of the expected number of bytes.
+    InvalidHex {
+        hex: String,
+    },
 }
@@ -0,0 +1,236 @@
+use crate::{gindex_of, Error, MerklePath, MerkleProof};
+use tree_hash::Hash256;
+
+/// Returns the branch proving `execution_payload` against the root of `body`, as in the
+/// `execution_branch` of a `LightClientHeader`.
+///
+/// The generalized index is computed from the type of `body`, so that any fork of
+/// `BeaconBlockBody` with an `execution_payload` can be used.
+///
+/// ## Example
+///
+/// ```
+/// use ssz_types::{
+///     execution_payload_branch, verify_merkle_branch, Mainnet, MerkleProof,
+///     SignedBeaconBlockCapella,
+/// };
+/// use tree_hash::TreeHash;
+///
+/// let body = SignedBeaconBlockCapella::<Mainnet>::default().message.body;
+/// let branch = execution_payload_branch(&body).unwrap();
+///
+/// assert!(verify_merkle_branch(
+///     body.execution_payload.tree_hash_root(),
+///     &branch,
+///     4,
+///     9,
+///     body.tree_hash_root(),
+/// ));
+/// ```
+pub fn execution_payload_branch<B>(body: &B) -> Result<Vec<Hash256>, Error>
+where
+    B: MerkleProof + MerklePath,
+{
+    body.merkle_proof(gindex_of::<B>(&["execution_payload"])?)
+}
+
+/// Returns the branch proving `finalized_checkpoint.root` against the root of `state`, as in the
+/// `finality_branch` of a `LightClientUpdate`.
+pub fn finality_branch<S>(state: &S) -> Result<Vec<Hash256>, Error>
+where
+    S: MerkleProof + MerklePath,
+{
+    state.merkle_proof(gindex_of::<S>(&["finalized_checkpoint", "root"])?)
+}
+
+/// Returns the branch proving `current_sync_committee` against the root of `state`, as in the
+/// `current_sync_committee_branch` of a `LightClientBootstrap`.
+pub fn current_sync_committee_branch<S>(state: &S) -> Result<Vec<Hash256>, Error>
+where
+    S: MerkleProof + MerklePath,
+{
+    state.merkle_proof(gindex_of::<S>(&["current_sync_committee"])?)
+}
+
+/// Returns the branch proving `next_sync_committee` against the root of `state`, as in the
+/// `next_sync_committee_branch` of a `LightClientUpdate`.
+pub fn next_sync_committee_branch<S>(state: &S) -> Result<Vec<Hash256>, Error>
+where
+    S: MerkleProof + MerklePath,
+{
+    state.merkle_proof(gindex_of::<S>(&["next_sync_committee"])?)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::beacon_block::Checkpoint;
+    use crate::{
+        verify_finality_branch, verify_merkle_branch, BeaconStateElectra, Epoch, FixedVector,
+        ForkName, Mainnet, Minimal, SignedBeaconBlockCapella, VariableList,
+    };
+    use ssz::Decode;
+    use tree_hash::TreeHash;
+    use tree_hash_derive::TreeHash;
+    use typenum::*;
+
+    /// A container with the fields of the Altair to Deneb `BeaconState`, where only those used by
+    /// light clients have their actual types.
+    #[derive(TreeHash)]
+    struct State {
+        genesis_time: u64,
+        genesis_validators_root: u64,
+        slot: u64,
+        fork: u64,
+        latest_block_header: u64,
+        block_roots: u64,
+        state_roots: u64,
+        historical_roots: u64,
+        eth1_data: u64,
+        eth1_data_votes: u64,
+        eth1_deposit_index: u64,
+        validators: u64,
+        balances: u64,
+        randao_mixes: u64,
+        slashings: u64,
+        previous_epoch_participation: u64,
+        current_epoch_participation: u64,
+        justification_bits: u64,
+        previous_justified_checkpoint: u64,
+        current_justified_checkpoint: u64,
+        finalized_checkpoint: Checkpoint,
+        inactivity_scores: VariableList<u64, U1024>,
+        current_sync_committee: FixedVector<u64, U4>,
+        next_sync_committee: FixedVector<u64, U4>,
+    }
+
+    impl_merkle_proof_for_container!(
+        State,
+        genesis_time,
+        genesis_validators_root,
+        slot,
+        fork,
+        latest_block_header,
+        block_roots,
+        state_roots,
+        historical_roots,
+        eth1_data,
+        eth1_data_votes,
+        eth1_deposit_index,
+        validators,
+        balances,
+        randao_mixes,
+        slashings,
+        previous_epoch_participation,
+        current_epoch_participation,
+        justification_bits,
+        previous_justified_checkpoint,
+        current_justified_checkpoint,
+        finalized_checkpoint,
+        inactivity_scores,
+        current_sync_committee,
+        next_sync_committee,
+    );
+
+    #[test]
+    fn body() {
+        let bytes = std::fs::read("state.ssz").unwrap();
+        let body = SignedBeaconBlockCapella::<Mainnet>::from_ssz_bytes(&bytes)
+            .unwrap()
+            .message
+            .body;
+        let branch = execution_payload_branch(&body).unwrap();
+
+        assert_eq!(branch.len(), 4);
+        assert!(verify_merkle_branch(
+            body.execution_payload.tree_hash_root(),
+            &branch,
+            4,
+            9,
+            body.tree_hash_root()
+        ));
+    }
+
+    #[test]
+    fn state() {
+        let state = State {
+            genesis_time: 0,
+            genesis_validators_root: 1,
+            slot: 2,
+            fork: 3,
+            latest_block_header: 4,
+            block_roots: 5,
+            state_roots: 6,
+            historical_roots: 7,
+            eth1_data: 8,
+            eth1_data_votes: 9,
+            eth1_deposit_index: 10,
+            validators: 11,
+            balances: 12,
+            randao_mixes: 13,
+            slashings: 14,
+            previous_epoch_participation: 15,
+            current_epoch_participation: 16,
+            justification_bits: 17,
+            previous_justified_checkpoint: 18,
+            current_justified_checkpoint: 19,
+            finalized_checkpoint: Checkpoint {
+                epoch: Epoch::new(42),
+                root: FixedVector::new(vec![0xff; 32]).unwrap(),
+            },
+            inactivity_scores: VariableList::empty(),
+            current_sync_committee: FixedVector::new(vec![1, 2, 3, 4]).unwrap(),
+            next_sync_committee: FixedVector::new(vec![5, 6, 7, 8]).unwrap(),
+        };
+        let root = state.tree_hash_root();
+
+        let branch = finality_branch(&state).unwrap();
+        assert!(verify_finality_branch(
+            Hash256::repeat_byte(0xff),
+            &branch,
+            root,
+            ForkName::Deneb
+        ));
+
+        let branch = current_sync_committee_branch(&state).unwrap();
+        assert!(verify_merkle_branch(
+            state.current_sync_committee.tree_hash_root(),
+            &branch,
+            5,
+            22,
+            root
+        ));
+
+        let branch = next_sync_committee_branch(&state).unwrap();
+        assert!(verify_merkle_branch(
+            state.next_sync_committee.tree_hash_root(),
+            &branch,
+            5,
+            23,
+            root
+        ));
+
+        // The checkpoint moves in Electra.
+        let mut electra = BeaconStateElectra::<Minimal>::default();
+        electra.finalized_checkpoint.root = FixedVector::new(vec![0xff; 32]).unwrap();
+        let branch = finality_branch(&electra).unwrap();
+        assert!(verify_finality_branch(
+            Hash256::repeat_byte(0xff),
+            &branch,
+            electra.tree_hash_root(),
+            ForkName::Electra
+        ));
+        assert!(!verify_finality_branch(
+            Hash256::repeat_byte(0xff),
+            &branch,
+            electra.tree_hash_root(),
+            ForkName::Deneb
+        ));
+
+        // A body has no sync committees.
+        assert!(next_sync_committee_branch(
+            &SignedBeaconBlockCapella::<Mainnet>::default().message.body
+        )
+        .is_err());
+    }
+}
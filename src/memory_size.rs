@@ -0,0 +1,178 @@
+use crate::{CommitteeIndex, Epoch, ExecutionAddress, Graffiti, Gwei, Slot, ValidatorIndex};
+use std::mem::size_of;
+use std::sync::Arc;
+
+/// Reports the memory used by a value, including everything it owns on the heap.
+///
+/// `std::mem::size_of` only accounts for the inline part of a value, which for a `VariableList`
+/// is just a pointer, a capacity and a length. `MemorySize` recursively follows heap
+/// allocations, including those behind an `Arc`.
+///
+/// A value behind an `Arc` is counted in full by every reference to it, so the total for values
+/// that share an allocation is an upper bound.
+///
+/// ## Example
+///
+/// ```
+/// use ssz_types::{MemorySize, VariableList, typenum};
+///
+/// let list: VariableList<u64, typenum::U8> = VariableList::new(vec![0; 4]).unwrap();
+///
+/// assert_eq!(list.heap_size(), 4 * 8);
+/// assert_eq!(list.memory_size(), std::mem::size_of_val(&list) + 4 * 8);
+/// ```
+pub trait MemorySize {
+    /// Returns the number of bytes owned by `self` on the heap, excluding `size_of_val(self)`.
+    fn heap_size(&self) -> usize;
+
+    /// Returns the total number of bytes used by `self`, inline and on the heap.
+    fn memory_size(&self) -> usize {
+        std::mem::size_of_val(self) + self.heap_size()
+    }
+}
+
+macro_rules! impl_memory_size_for_inline {
+    ($($type: ty),*) => {
+        $(
+            impl MemorySize for $type {
+                fn heap_size(&self) -> usize {
+                    0
+                }
+            }
+        )*
+    };
+}
+
+impl_memory_size_for_inline!(bool, u8, u16, u32, u64, u128, usize);
+impl_memory_size_for_inline!(
+    Slot,
+    Epoch,
+    Gwei,
+    ValidatorIndex,
+    CommitteeIndex,
+    ExecutionAddress,
+    Graffiti
+);
+
+impl<T: MemorySize, const N: usize> MemorySize for [T; N] {
+    fn heap_size(&self) -> usize {
+        self.iter().map(MemorySize::heap_size).sum()
+    }
+}
+
+impl<T: MemorySize> MemorySize for Vec<T> {
+    fn heap_size(&self) -> usize {
+        self.capacity() * size_of::<T>() + self.iter().map(MemorySize::heap_size).sum::<usize>()
+    }
+}
+
+impl<T: MemorySize> MemorySize for Box<T> {
+    fn heap_size(&self) -> usize {
+        (**self).memory_size()
+    }
+}
+
+impl<T: MemorySize> MemorySize for Arc<T> {
+    /// Counts the shared value and the reference counts stored alongside it.
+    fn heap_size(&self) -> usize {
+        2 * size_of::<usize>() + (**self).memory_size()
+    }
+}
+
+impl<T: MemorySize> MemorySize for Option<T> {
+    fn heap_size(&self) -> usize {
+        self.as_ref().map_or(0, MemorySize::heap_size)
+    }
+}
+
+/// Implements `MemorySize` for a struct by summing the heap usage of the listed fields.
+///
+/// A struct with a single type parameter is given as `Name<P: Bound>`.
+macro_rules! impl_memory_size_for_container {
+    ($type: ident<$param: ident: $bound: path>, $($field: ident),* $(,)?) => {
+        impl<$param: $bound> $crate::MemorySize for $type<$param> {
+            fn heap_size(&self) -> usize {
+                0 $(+ $crate::MemorySize::heap_size(&self.$field))*
+            }
+        }
+    };
+    ($type: ty, $($field: ident),* $(,)?) => {
+        impl $crate::MemorySize for $type {
+            fn heap_size(&self) -> usize {
+                0 $(+ $crate::MemorySize::heap_size(&self.$field))*
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn vec() {
+        let vec: Vec<u32> = Vec::with_capacity(10);
+        assert_eq!(vec.heap_size(), 40);
+
+        let vec: Vec<Vec<u8>> = vec![vec![0; 3], vec![0; 5]];
+        assert_eq!(
+            vec.heap_size(),
+            vec.capacity() * size_of::<Vec<u8>>() + vec[0].capacity() + vec[1].capacity()
+        );
+    }
+
+    #[test]
+    fn arc() {
+        let arc = Arc::new(vec![0u64; 4]);
+        assert_eq!(
+            arc.heap_size(),
+            2 * size_of::<usize>() + size_of::<Vec<u64>>() + 32
+        );
+        assert_eq!(arc.memory_size(), size_of::<usize>() + arc.heap_size());
+    }
+
+    #[test]
+    fn option() {
+        assert_eq!(None::<Vec<u8>>.heap_size(), 0);
+        assert_eq!(Some(vec![0u8; 8]).heap_size(), 8);
+    }
+
+    #[test]
+    fn collections() {
+        use crate::{BitList, FixedVector, PersistentList, VariableList};
+        use typenum::{U1024, U4, U8};
+
+        let list: VariableList<VariableList<u8, U8>, U4> =
+            VariableList::new(vec![VariableList::new(vec![0; 8]).unwrap()]).unwrap();
+        assert_eq!(
+            list.heap_size(),
+            size_of::<VariableList<u8, U8>>() + list[0].heap_size()
+        );
+        assert_eq!(list[0].heap_size(), 8);
+
+        let vector: FixedVector<u64, U4> = FixedVector::default();
+        assert_eq!(vector.heap_size(), 32);
+
+        let small = BitList::<U8>::with_capacity(8).unwrap();
+        assert_eq!(small.heap_size(), 0);
+        let large = BitList::<U1024>::with_capacity(1024).unwrap();
+        assert!(large.heap_size() >= 1024 / 8);
+
+        let persistent = PersistentList::<u64, U1024>::try_from(vec![0; 100]).unwrap();
+        assert!(persistent.heap_size() >= 100 * size_of::<u64>());
+    }
+
+    #[test]
+    fn execution_payload() {
+        use crate::beacon_block::{ExecutionPayloadCapella, Transaction};
+        use crate::{CowList, Mainnet};
+
+        let mut payload = ExecutionPayloadCapella::<Mainnet>::default();
+        let empty = payload.heap_size();
+
+        payload.transactions =
+            CowList::new(vec![Transaction::<Mainnet>::new(vec![0; 1000]).unwrap()]).unwrap();
+
+        assert!(payload.heap_size() >= empty + 1000 + size_of::<Transaction>());
+    }
+}
@@ -0,0 +1,204 @@
+use crate::hasher::hash32_concat;
+use crate::ForkName;
+use tree_hash::Hash256;
+
+/// The depth of the Merkle tree of the deposit contract, excluding the mixed in deposit count.
+pub const DEPOSIT_CONTRACT_TREE_DEPTH: usize = 32;
+
+/// The generalized index of `finalized_checkpoint.root` in the Altair to Deneb `BeaconState`.
+pub const FINALIZED_ROOT_GINDEX: u64 = 105;
+
+/// The generalized index of `finalized_checkpoint.root` in the Electra `BeaconState`, which has
+/// more than 32 fields.
+pub const FINALIZED_ROOT_GINDEX_ELECTRA: u64 = 169;
+
+/// Returns `true` if `branch` proves that `leaf` is the node at `index` in the layer `depth`
+/// levels below `root`, as per `is_valid_merkle_branch` in the consensus specs.
+///
+/// `index` is relative to the layer, i.e. a node with generalized index `gindex` has `depth =
+/// floor(log2(gindex))` and `index = gindex - 2^depth`. Returns `false` if `branch` does not hold
+/// exactly `depth` nodes.
+///
+/// ## Example
+///
+/// ```
+/// use ssz_types::{verify_merkle_branch, MerkleProof, VariableList, typenum};
+/// use tree_hash::TreeHash;
+///
+/// let list: VariableList<u64, typenum::U8> = VariableList::new(vec![1, 2, 3]).unwrap();
+///
+/// // The second chunk of the data of the list, at generalized index `5`.
+/// let leaf = list.tree_hash_node(5).unwrap();
+/// let branch = list.merkle_proof(5).unwrap();
+/// assert!(verify_merkle_branch(leaf, &branch, 2, 1, list.tree_hash_root()));
+/// assert!(!verify_merkle_branch(leaf, &branch, 2, 0, list.tree_hash_root()));
+/// ```
+pub fn verify_merkle_branch(
+    leaf: Hash256,
+    branch: &[Hash256],
+    depth: usize,
+    index: u64,
+    root: Hash256,
+) -> bool {
+    if branch.len() != depth {
+        return false;
+    }
+
+    let computed = branch.iter().enumerate().fold(leaf, |node, (i, sibling)| {
+        if index.checked_shr(i as u32).unwrap_or(0) & 1 == 1 {
+            hash32_concat(sibling.as_bytes(), node.as_bytes())
+        } else {
+            hash32_concat(node.as_bytes(), sibling.as_bytes())
+        }
+    });
+
+    computed == root
+}
+
+/// Returns `true` if `branch` proves the deposit with root `deposit_data_root` at `index` in the
+/// deposit contract against `deposit_root`, as checked by `process_deposit`.
+///
+/// The branch has `DEPOSIT_CONTRACT_TREE_DEPTH + 1` nodes, the last of which is the deposit count
+/// mixed in by the contract.
+pub fn verify_deposit_branch(
+    deposit_data_root: Hash256,
+    branch: &[Hash256],
+    index: u64,
+    deposit_root: Hash256,
+) -> bool {
+    verify_merkle_branch(
+        deposit_data_root,
+        branch,
+        DEPOSIT_CONTRACT_TREE_DEPTH + 1,
+        index,
+        deposit_root,
+    )
+}
+
+/// Returns `true` if `branch` proves that the finalized checkpoint of the state with root
+/// `state_root` has the block root `finalized_root`, as checked for the `finality_branch` of a
+/// light client update.
+///
+/// The position of the checkpoint depends on `fork_name`, the fork of the state.
+pub fn verify_finality_branch(
+    finalized_root: Hash256,
+    branch: &[Hash256],
+    state_root: Hash256,
+    fork_name: ForkName,
+) -> bool {
+    let gindex = if fork_name >= ForkName::Electra {
+        FINALIZED_ROOT_GINDEX_ELECTRA
+    } else {
+        FINALIZED_ROOT_GINDEX
+    };
+    let depth = gindex.ilog2();
+
+    verify_merkle_branch(
+        finalized_root,
+        branch,
+        depth as usize,
+        gindex - (1 << depth),
+        state_root,
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{FixedVector, MerkleProof};
+    use tree_hash::TreeHash;
+    use typenum::*;
+
+    #[test]
+    fn branches() {
+        let vector: FixedVector<u64, U64> = FixedVector::new((0..64).collect()).unwrap();
+        let root = vector.tree_hash_root();
+
+        assert!(verify_merkle_branch(root, &[], 0, 0, root));
+
+        for gindex in 2..32_u64 {
+            let depth = gindex.ilog2() as usize;
+            let index = gindex - (1 << depth);
+            let leaf = vector.tree_hash_node(gindex).unwrap();
+            let branch = vector.merkle_proof(gindex).unwrap();
+
+            assert!(verify_merkle_branch(leaf, &branch, depth, index, root));
+            assert!(!verify_merkle_branch(leaf, &branch, depth, index ^ 1, root));
+            assert!(!verify_merkle_branch(leaf, &branch, depth + 1, index, root));
+        }
+    }
+
+    #[test]
+    fn deposit() {
+        // A tree of depth 32 holding a single deposit, with the count mixed in.
+        let leaf = Hash256::repeat_byte(1);
+        let mut branch = vec![Hash256::zero()];
+        for i in 0..DEPOSIT_CONTRACT_TREE_DEPTH - 1 {
+            branch.push(Hash256::from(ethereum_hashing::hash32_concat(
+                branch[i].as_bytes(),
+                branch[i].as_bytes(),
+            )));
+        }
+        let mut count = Hash256::zero();
+        count.as_bytes_mut()[0] = 1;
+        branch.push(count);
+
+        let root = tree_hash::mix_in_length(
+            &branch[..DEPOSIT_CONTRACT_TREE_DEPTH]
+                .iter()
+                .fold(leaf, |node, sibling| {
+                    Hash256::from(ethereum_hashing::hash32_concat(
+                        node.as_bytes(),
+                        sibling.as_bytes(),
+                    ))
+                }),
+            1,
+        );
+
+        assert!(verify_deposit_branch(leaf, &branch, 0, root));
+        assert!(!verify_deposit_branch(leaf, &branch, 1, root));
+        assert!(!verify_deposit_branch(
+            leaf,
+            &branch[..DEPOSIT_CONTRACT_TREE_DEPTH],
+            0,
+            root
+        ));
+    }
+
+    #[test]
+    fn finality() {
+        // A container of 32 fields, where the second chunk of field 20 stands in for the root of
+        // the finalized checkpoint.
+        let mut fields = vec![FixedVector::<u64, U8>::default(); 32];
+        fields[20] = FixedVector::new((0..8).collect()).unwrap();
+        let state = FixedVector::<_, U32>::new(fields).unwrap();
+        let state_root = state.tree_hash_root();
+
+        let finalized_root = state.tree_hash_node(FINALIZED_ROOT_GINDEX).unwrap();
+        let branch = state.merkle_proof(FINALIZED_ROOT_GINDEX).unwrap();
+        assert_eq!(finalized_root, chunk(4..8));
+        assert!(verify_finality_branch(
+            finalized_root,
+            &branch,
+            state_root,
+            ForkName::Deneb
+        ));
+        assert!(!verify_finality_branch(
+            Hash256::zero(),
+            &branch,
+            state_root,
+            ForkName::Deneb
+        ));
+        assert!(!verify_finality_branch(
+            finalized_root,
+            &branch,
+            state_root,
+            ForkName::Electra
+        ));
+    }
+
+    fn chunk(values: std::ops::Range<u64>) -> Hash256 {
+        let bytes = values.flat_map(u64::to_le_bytes).collect::<Vec<_>>();
+        Hash256::from_slice(&bytes)
+    }
+}
@@ -0,0 +1,211 @@
+use crate::{Error, MerkleProof};
+use std::collections::VecDeque;
+use std::fmt;
+use tree_hash::Hash256;
+
+/// The nodes of the Merkle tree of a value, materialized for inspection. Created by
+/// `MerkleProof::merkle_tree`.
+///
+/// Comparing the trees of a value built by two implementations narrows a root mismatch down to
+/// the first differing node, and thus to a field or a range of elements. The `Display`
+/// implementation prints one node per line, indented by depth.
+///
+/// Nodes are only expanded while they have children: the leaves of the tree are basic values,
+/// packed chunks, lengths of lists, and subtrees consisting only of zero chunks (e.g. the unused
+/// capacity of a list), whose nodes are all zero hashes.
+///
+/// ## Example
+///
+/// ```
+/// use ssz_types::{MerkleProof, VariableList, typenum};
+/// use tree_hash::TreeHash;
+///
+/// let list: VariableList<u64, typenum::U16> = VariableList::new(vec![1, 2, 3]).unwrap();
+/// let tree = list.merkle_tree(u32::MAX).unwrap();
+///
+/// assert_eq!(tree.hash, list.tree_hash_root());
+/// // The length of the list is a leaf.
+/// assert!(tree.right().unwrap().is_leaf());
+/// // The first of 4 chunks.
+/// assert_eq!(tree.get(8).unwrap().hash, list.tree_hash_node(8).unwrap());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MerkleTree {
+    /// The generalized index of the node in the tree of the value.
+    pub gindex: u64,
+    /// The hash of the node.
+    pub hash: Hash256,
+    /// The left and right children of the node, if it was expanded.
+    pub children: Option<Box<[MerkleTree; 2]>>,
+}
+
+impl MerkleTree {
+    /// Returns the tree of `value` below `gindex`, expanded up to `max_depth` levels.
+    pub(crate) fn build<T: MerkleProof + ?Sized>(
+        value: &T,
+        gindex: u64,
+        max_depth: u32,
+    ) -> Result<Self, Error> {
+        let hash = value.tree_hash_node(gindex)?;
+
+        let children = match gindex.checked_mul(2) {
+            Some(left) if max_depth > 0 && !is_zero_subtree(&hash) => {
+                match (
+                    Self::build(value, left, max_depth - 1),
+                    Self::build(value, left + 1, max_depth - 1),
+                ) {
+                    (Ok(left), Ok(right)) => Some(Box::new([left, right])),
+                    _ => None,
+                }
+            }
+            _ => None,
+        };
+
+        Ok(Self {
+            gindex,
+            hash,
+            children,
+        })
+    }
+
+    /// True if the node was not expanded.
+    pub fn is_leaf(&self) -> bool {
+        self.children.is_none()
+    }
+
+    /// Returns the left child of the node.
+    pub fn left(&self) -> Option<&Self> {
+        self.children.as_ref().map(|children| &children[0])
+    }
+
+    /// Returns the right child of the node.
+    pub fn right(&self) -> Option<&Self> {
+        self.children.as_ref().map(|children| &children[1])
+    }
+
+    /// Returns the node at `gindex`, which is relative to the tree of the value rather than to
+    /// this node.
+    ///
+    /// Returns `None` if the node is not below this node or was not expanded.
+    pub fn get(&self, gindex: u64) -> Option<&Self> {
+        let level = gindex.checked_ilog2()?;
+        let height = level.checked_sub(self.gindex.ilog2())?;
+        if gindex >> height != self.gindex {
+            return None;
+        }
+
+        (0..height)
+            .rev()
+            .try_fold(self, |node, bit| match (gindex >> bit) & 1 {
+                0 => node.left(),
+                _ => node.right(),
+            })
+    }
+
+    /// Returns the number of levels below this node.
+    pub fn depth(&self) -> u32 {
+        self.children.as_ref().map_or(0, |children| {
+            1 + std::cmp::max(children[0].depth(), children[1].depth())
+        })
+    }
+
+    /// Returns an iterator over this node and all nodes below it, in order of generalized index.
+    pub fn iter(&self) -> impl Iterator<Item = &Self> {
+        let mut queue = VecDeque::from([self]);
+
+        std::iter::from_fn(move || {
+            let node = queue.pop_front()?;
+            queue.extend(node.children.iter().flat_map(|children| children.iter()));
+            Some(node)
+        })
+    }
+}
+
+impl fmt::Display for MerkleTree {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut stack = vec![(self, 0)];
+
+        while let Some((node, indent)) = stack.pop() {
+            writeln!(
+                f,
+                "{:indent$}{}: {:?}",
+                "",
+                node.gindex,
+                node.hash,
+                indent = indent
+            )?;
+            if let Some(children) = &node.children {
+                stack.push((&children[1], indent + 2));
+                stack.push((&children[0], indent + 2));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// True if `hash` is the root of a subtree of at least two zero chunks.
+fn is_zero_subtree(hash: &Hash256) -> bool {
+    ethereum_hashing::ZERO_HASHES[1..]
+        .iter()
+        .any(|zero_hash| zero_hash[..] == hash[..])
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::VariableList;
+    use tree_hash::TreeHash;
+    use typenum::*;
+
+    #[test]
+    fn list() {
+        let list: VariableList<VariableList<u64, U4>, U8> = VariableList::new(vec![
+            VariableList::new(vec![1, 2, 3, 4]).unwrap(),
+            VariableList::new(vec![6, 7]).unwrap(),
+        ])
+        .unwrap();
+        let tree = list.merkle_tree(u32::MAX).unwrap();
+
+        assert_eq!(tree.hash, list.tree_hash_root());
+        for node in tree.iter() {
+            assert_eq!(Ok(node.hash), list.tree_hash_node(node.gindex));
+            assert_eq!(tree.get(node.gindex), Some(node));
+        }
+
+        // The length of the list, the unused capacity, and the chunks and lengths of the elements.
+        for gindex in [3, 5, 9, 32, 33, 34, 35] {
+            assert!(tree.get(gindex).unwrap().is_leaf(), "{}", gindex);
+        }
+        assert_eq!(tree.get(18), None);
+        assert_eq!(tree.get(68), None);
+
+        assert_eq!(tree.depth(), 5);
+        assert_eq!(tree.get(2).unwrap().depth(), 4);
+        assert_eq!(tree.get(4).unwrap().get(17).unwrap().depth(), 1);
+        assert_eq!(tree.get(4).unwrap().get(5), None);
+
+        let gindices = tree.iter().map(|node| node.gindex).collect::<Vec<_>>();
+        assert!(gindices.windows(2).all(|pair| pair[0] < pair[1]));
+        assert_eq!(tree.to_string().lines().count(), gindices.len());
+        assert!(tree
+            .to_string()
+            .starts_with(&format!("1: {:?}\n  2: ", tree.hash)));
+    }
+
+    #[test]
+    fn max_depth() {
+        let list: VariableList<u64, U1024> = VariableList::new((0..100).collect()).unwrap();
+
+        let tree = list.merkle_tree(0).unwrap();
+        assert!(tree.is_leaf());
+        assert_eq!(tree.hash, list.tree_hash_root());
+
+        let tree = list.merkle_tree(3).unwrap();
+        assert_eq!(tree.depth(), 3);
+        // The length and the unused capacity are not expanded.
+        assert_eq!(tree.iter().count(), 1 + 2 + 2 + 2);
+        assert!(tree.get(8).unwrap().is_leaf());
+        assert_eq!(tree.get(16), None);
+    }
+}
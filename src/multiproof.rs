@@ -0,0 +1,227 @@
+use crate::hasher::hash32_concat;
+use crate::Error;
+use ssz_derive::{Decode, Encode};
+use std::collections::{BTreeMap, BTreeSet};
+use tree_hash::Hash256;
+
+/// A proof of several nodes of a Merkle tree against its root, as per the [multiproof
+/// specification](https://github.com/ethereum/consensus-specs/blob/dev/ssz/merkle-proofs.md#merkle-multiproofs).
+///
+/// Nodes shared between the branches of the proven nodes are included only once, and nodes which
+/// can be computed from the proven nodes are omitted. Created by `MerkleProof::multiproof`.
+///
+/// ## Example
+///
+/// ```
+/// use ssz_types::{MerkleProof, VariableList, typenum};
+/// use tree_hash::TreeHash;
+///
+/// let list: VariableList<u64, typenum::U16> = VariableList::new((0..16).collect()).unwrap();
+///
+/// // The length of the list and the last of its 4 chunks.
+/// let proof = list.multiproof(&[3, 11]).unwrap();
+/// assert_eq!(proof.helpers.len(), 2);
+/// assert!(proof.verify(list.tree_hash_root()));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Encode, Decode)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[ssz(struct_behaviour = "container")]
+pub struct Multiproof {
+    /// Generalized indices of the proven nodes.
+    #[cfg_attr(feature = "serde", serde(with = "serde_utils::quoted_u64_vec"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "crate::json_schema::QuotedItems<Vec<u64>>")
+    )]
+    pub gindices: Vec<u64>,
+    /// The proven nodes, in the same order as `gindices`.
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "crate::json_schema::HexItems<Vec<Hash256>>")
+    )]
+    pub leaves: Vec<Hash256>,
+    /// The nodes at `helper_indices(&gindices)`, in the same order.
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "crate::json_schema::HexItems<Vec<Hash256>>")
+    )]
+    pub helpers: Vec<Hash256>,
+}
+
+impl Multiproof {
+    /// Computes the root of the tree from the proven nodes and the helper nodes.
+    ///
+    /// Returns `Err` if `gindices` has duplicates or a node which is an ancestor of another, if
+    /// the number of leaves or helpers does not match `gindices`, or if the nodes are not
+    /// sufficient to compute the root.
+    pub fn root(&self) -> Result<Hash256, Error> {
+        // An ancestor would be taken as proven without being computed from its descendants, and
+        // only one of the nodes of a duplicate would be checked.
+        let proven = self.gindices.iter().copied().collect::<BTreeSet<_>>();
+        if proven.len() != self.gindices.len() {
+            return Err(Error::InvalidProof);
+        }
+        for &gindex in &proven {
+            let mut node = gindex;
+            while node > 1 {
+                node /= 2;
+                if proven.contains(&node) {
+                    return Err(Error::InvalidProof);
+                }
+            }
+        }
+
+        let helper_indices = helper_indices(&self.gindices)?;
+
+        if self.leaves.len() != self.gindices.len() || self.helpers.len() != helper_indices.len() {
+            return Err(Error::InvalidProof);
+        }
+
+        let mut nodes = self
+            .gindices
+            .iter()
+            .copied()
+            .zip(self.leaves.iter().copied())
+            .chain(helper_indices.into_iter().zip(self.helpers.iter().copied()))
+            .collect::<BTreeMap<_, _>>();
+
+        // Visit the nodes deepest first so that every parent is computed before it is needed as a
+        // child. No supplied node is an ancestor of another, so a parent is only ever present if
+        // it was computed from its children.
+        let mut pending = nodes.keys().copied().collect::<BTreeSet<_>>();
+        while let Some(gindex) = pending.pop_last() {
+            if gindex <= 1 || nodes.contains_key(&(gindex / 2)) {
+                continue;
+            }
+
+            if let (Some(left), Some(right)) = (nodes.get(&(gindex & !1)), nodes.get(&(gindex | 1)))
+            {
+                let parent = hash32_concat(left.as_bytes(), right.as_bytes());
+                nodes.insert(gindex / 2, parent);
+                pending.insert(gindex / 2);
+            }
+        }
+
+        nodes.get(&1).copied().ok_or(Error::InvalidProof)
+    }
+
+    /// True if the proof is well-formed and proves the leaves against `root`.
+    pub fn verify(&self, root: Hash256) -> bool {
+        self.root() == Ok(root)
+    }
+}
+
+/// Returns the generalized indices of the nodes required to prove the nodes at `gindices`, in
+/// descending order.
+///
+/// These are the siblings of every node on the paths from `gindices` to the root, except for
+/// those which are themselves on one of the paths.
+pub fn helper_indices(gindices: &[u64]) -> Result<Vec<u64>, Error> {
+    let mut branches = BTreeSet::new();
+    let mut paths = BTreeSet::new();
+
+    for &gindex in gindices {
+        if gindex == 0 {
+            return Err(Error::InvalidGeneralizedIndex { gindex });
+        }
+
+        let mut node = gindex;
+        while node > 1 {
+            branches.insert(node ^ 1);
+            paths.insert(node);
+            node /= 2;
+        }
+    }
+
+    let mut helpers = branches.difference(&paths).copied().collect::<Vec<_>>();
+    helpers.reverse();
+
+    Ok(helpers)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{MerkleProof, VariableList};
+    use ssz::{Decode, Encode};
+    use tree_hash::TreeHash;
+    use typenum::*;
+
+    #[test]
+    fn helpers() {
+        assert_eq!(helper_indices(&[1]), Ok(vec![]));
+        assert_eq!(helper_indices(&[8]), Ok(vec![9, 5, 3]));
+        // Siblings are proven by each other.
+        assert_eq!(helper_indices(&[8, 9]), Ok(vec![5, 3]));
+        assert_eq!(helper_indices(&[8, 11]), Ok(vec![10, 9, 3]));
+        assert_eq!(helper_indices(&[8, 3]), Ok(vec![9, 5]));
+        assert_eq!(
+            helper_indices(&[0]),
+            Err(Error::InvalidGeneralizedIndex { gindex: 0 })
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        let list: VariableList<VariableList<u64, U8>, U8> = VariableList::new(
+            (0..5)
+                .map(|i| VariableList::new(vec![i; i as usize]).unwrap())
+                .collect(),
+        )
+        .unwrap();
+        let root = list.tree_hash_root();
+
+        for gindices in [
+            vec![1],
+            vec![3],
+            vec![16, 20],
+            vec![3, 16, 17],
+            vec![18, 32 + 1, 20 * 4 + 1],
+        ] {
+            let proof = list.multiproof(&gindices).unwrap();
+            assert!(proof.verify(root), "{:?}", gindices);
+            assert_eq!(Multiproof::from_ssz_bytes(&proof.as_ssz_bytes()), Ok(proof));
+        }
+    }
+
+    #[test]
+    fn invalid() {
+        let list: VariableList<u64, U16> = VariableList::new((0..16).collect()).unwrap();
+        let root = list.tree_hash_root();
+        let proof = list.multiproof(&[8, 11]).unwrap();
+
+        let mut wrong_leaf = proof.clone();
+        wrong_leaf.leaves[0] = Hash256::zero();
+        assert!(!wrong_leaf.verify(root));
+
+        let mut missing_helper = proof.clone();
+        missing_helper.helpers.pop();
+        assert_eq!(missing_helper.root(), Err(Error::InvalidProof));
+
+        let mut wrong_gindex = proof;
+        wrong_gindex.gindices[1] = 10;
+        assert!(!wrong_gindex.verify(root));
+
+        // A duplicate node is not proven by the other.
+        let mut duplicate = list.multiproof(&[3]).unwrap();
+        duplicate.gindices.push(3);
+        duplicate.leaves.push(Hash256::zero());
+        assert_eq!(duplicate.root(), Err(Error::InvalidProof));
+        assert!(!duplicate.verify(root));
+
+        // A node is not proven by its ancestor.
+        let ancestor = Multiproof {
+            gindices: vec![1, 2],
+            leaves: vec![root, Hash256::zero()],
+            helpers: vec![],
+        };
+        assert_eq!(ancestor.root(), Err(Error::InvalidProof));
+        assert!(!ancestor.verify(root));
+
+        assert!(list.multiproof(&[3, 7]).is_err());
+    }
+}
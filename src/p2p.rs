@@ -0,0 +1,386 @@
+use crate::beacon_block::H256;
+use crate::{BitVector, Epoch, ForkDigest, Slot, VariableList, Version};
+use ssz_derive::{Decode, Encode};
+use tree_hash_derive::TreeHash;
+
+/// The maximum number of blocks in a single request, before Deneb.
+pub const MAX_REQUEST_BLOCKS: u64 = 1024;
+/// The maximum number of blocks in a single request, from Deneb.
+pub const MAX_REQUEST_BLOCKS_DENEB: u64 = 128;
+/// The maximum number of blob sidecars in a single request, from Electra.
+pub const MAX_REQUEST_BLOB_SIDECARS_ELECTRA: u64 = 1152;
+/// The maximum length of the uncompressed SSZ of a gossip message.
+pub const GOSSIP_MAX_SIZE: usize = 10 * 1024 * 1024;
+/// The maximum length of the uncompressed SSZ of a request or response chunk.
+pub const MAX_CHUNK_SIZE: usize = 10 * 1024 * 1024;
+
+/// The reason given in a `Goodbye` message for a client shutting down.
+pub const GOODBYE_CLIENT_SHUTDOWN: u64 = 1;
+/// The reason given in a `Goodbye` message for a peer on another network.
+pub const GOODBYE_IRRELEVANT_NETWORK: u64 = 2;
+/// The reason given in a `Goodbye` message for a fault or error.
+pub const GOODBYE_FAULT_OR_ERROR: u64 = 3;
+
+/// The message exchanged by peers on connection, to check that they follow the same chain.
+#[derive(Clone, Default, Encode, Decode, TreeHash, PartialEq, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[ssz(struct_behaviour = "container")]
+pub struct Status {
+    #[cfg_attr(feature = "serde", serde(with = "serde_utils::bytes_4_hex"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "crate::json_schema::Hex<ForkDigest>")
+    )]
+    pub fork_digest: ForkDigest,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::hex_fixed_vec"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::json_schema::Hex<H256>"))]
+    pub finalized_root: H256,
+    pub finalized_epoch: Epoch,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::hex_fixed_vec"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::json_schema::Hex<H256>"))]
+    pub head_root: H256,
+    pub head_slot: Slot,
+}
+
+/// The fork of a node, advertised under the `eth2` key of its ENR so that peers can skip nodes
+/// that are not on their chain.
+#[derive(Clone, Default, Encode, Decode, TreeHash, PartialEq, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[ssz(struct_behaviour = "container")]
+pub struct ENRForkID {
+    #[cfg_attr(feature = "serde", serde(with = "serde_utils::bytes_4_hex"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "crate::json_schema::Hex<ForkDigest>")
+    )]
+    pub fork_digest: ForkDigest,
+    /// The version of the next scheduled fork, or the current version if none is scheduled.
+    #[cfg_attr(feature = "serde", serde(with = "serde_utils::bytes_4_hex"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "crate::json_schema::Hex<Version>")
+    )]
+    pub next_fork_version: Version,
+    /// The epoch of the next scheduled fork, or `u64::MAX` if none is scheduled.
+    pub next_fork_epoch: Epoch,
+}
+
+/// The reason for a disconnection, e.g. `GOODBYE_CLIENT_SHUTDOWN`.
+pub type Goodbye = u64;
+
+/// The sequence number of the `MetaData` of the sender of a `Ping`.
+pub type Ping = u64;
+
+#[derive(Clone, Default, Encode, Decode, TreeHash, PartialEq, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[ssz(struct_behaviour = "container")]
+pub struct MetaDataV1 {
+    #[cfg_attr(feature = "serde", serde(with = "serde_utils::quoted_u64"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "crate::json_schema::Quoted<u64>")
+    )]
+    pub seq_number: u64,
+    pub attnets: BitVector<typenum::U64>,
+}
+
+/// The `MetaData` of Altair, which adds the sync committee subnets.
+#[derive(Clone, Default, Encode, Decode, TreeHash, PartialEq, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[ssz(struct_behaviour = "container")]
+pub struct MetaDataV2 {
+    #[cfg_attr(feature = "serde", serde(with = "serde_utils::quoted_u64"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "crate::json_schema::Quoted<u64>")
+    )]
+    pub seq_number: u64,
+    pub attnets: BitVector<typenum::U64>,
+    pub syncnets: BitVector<typenum::U4>,
+}
+
+/// The `MetaData` of Fulu, which adds the number of custody groups for data availability
+/// sampling.
+#[derive(Clone, Default, Encode, Decode, TreeHash, PartialEq, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[ssz(struct_behaviour = "container")]
+pub struct MetaDataV3 {
+    #[cfg_attr(feature = "serde", serde(with = "serde_utils::quoted_u64"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "crate::json_schema::Quoted<u64>")
+    )]
+    pub seq_number: u64,
+    pub attnets: BitVector<typenum::U64>,
+    pub syncnets: BitVector<typenum::U4>,
+    #[cfg_attr(feature = "serde", serde(with = "serde_utils::quoted_u64"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "crate::json_schema::Quoted<u64>")
+    )]
+    pub custody_group_count: u64,
+}
+
+/// A request for the blocks of `count` slots from `start_slot`. `step` is deprecated, and must be
+/// 1.
+#[derive(Clone, Default, Encode, Decode, TreeHash, PartialEq, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[ssz(struct_behaviour = "container")]
+pub struct BeaconBlocksByRangeRequest {
+    pub start_slot: Slot,
+    #[cfg_attr(feature = "serde", serde(with = "serde_utils::quoted_u64"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "crate::json_schema::Quoted<u64>")
+    )]
+    pub count: u64,
+    #[cfg_attr(feature = "serde", serde(with = "serde_utils::quoted_u64"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "crate::json_schema::Quoted<u64>")
+    )]
+    pub step: u64,
+}
+
+/// A request for the blocks with the given roots.
+pub type BeaconBlocksByRootRequest = VariableList<H256, typenum::U1024>;
+
+/// A request for the blob sidecars of the blocks of `count` slots from `start_slot`.
+#[derive(Clone, Default, Encode, Decode, TreeHash, PartialEq, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[ssz(struct_behaviour = "container")]
+pub struct BlobSidecarsByRangeRequest {
+    pub start_slot: Slot,
+    #[cfg_attr(feature = "serde", serde(with = "serde_utils::quoted_u64"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "crate::json_schema::Quoted<u64>")
+    )]
+    pub count: u64,
+}
+
+/// Identifies a blob sidecar by the root of its block and its index.
+#[derive(Clone, Default, Encode, Decode, TreeHash, PartialEq, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[ssz(struct_behaviour = "container")]
+pub struct BlobIdentifier {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::hex_fixed_vec"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::json_schema::Hex<H256>"))]
+    pub block_root: H256,
+    #[cfg_attr(feature = "serde", serde(with = "serde_utils::quoted_u64"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "crate::json_schema::Quoted<u64>")
+    )]
+    pub index: u64,
+}
+
+/// A request for the blob sidecars with the given identifiers.
+pub type BlobSidecarsByRootRequest =
+    VariableList<BlobIdentifier, typenum::Sum<typenum::U1024, typenum::U128>>;
+
+impl From<MetaDataV1> for MetaDataV2 {
+    /// Returns the metadata of a node subscribed to no sync committee subnets.
+    fn from(metadata: MetaDataV1) -> Self {
+        Self {
+            seq_number: metadata.seq_number,
+            attnets: metadata.attnets,
+            syncnets: BitVector::new(),
+        }
+    }
+}
+
+impl From<MetaDataV2> for MetaDataV1 {
+    fn from(metadata: MetaDataV2) -> Self {
+        Self {
+            seq_number: metadata.seq_number,
+            attnets: metadata.attnets,
+        }
+    }
+}
+
+impl_memory_size_for_container!(
+    Status,
+    fork_digest,
+    finalized_root,
+    finalized_epoch,
+    head_root,
+    head_slot,
+);
+impl_memory_size_for_container!(ENRForkID, fork_digest, next_fork_version, next_fork_epoch);
+impl_memory_size_for_container!(MetaDataV1, seq_number, attnets);
+impl_memory_size_for_container!(MetaDataV2, seq_number, attnets, syncnets);
+impl_memory_size_for_container!(
+    MetaDataV3,
+    seq_number,
+    attnets,
+    syncnets,
+    custody_group_count,
+);
+impl_memory_size_for_container!(BeaconBlocksByRangeRequest, start_slot, count, step);
+impl_memory_size_for_container!(BlobSidecarsByRangeRequest, start_slot, count);
+impl_memory_size_for_container!(BlobIdentifier, block_root, index);
+
+impl_merkle_proof_for_container!(
+    Status,
+    fork_digest,
+    finalized_root,
+    finalized_epoch,
+    head_root,
+    head_slot,
+);
+impl_merkle_proof_for_container!(ENRForkID, fork_digest, next_fork_version, next_fork_epoch);
+impl_merkle_proof_for_container!(MetaDataV1, seq_number, attnets);
+impl_merkle_proof_for_container!(MetaDataV2, seq_number, attnets, syncnets);
+impl_merkle_proof_for_container!(
+    MetaDataV3,
+    seq_number,
+    attnets,
+    syncnets,
+    custody_group_count,
+);
+impl_merkle_proof_for_container!(BeaconBlocksByRangeRequest, start_slot, count, step);
+impl_merkle_proof_for_container!(BlobSidecarsByRangeRequest, start_slot, count);
+impl_merkle_proof_for_container!(BlobIdentifier, block_root, index);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ssz::{Decode, Encode};
+
+    #[test]
+    fn status() {
+        let status = Status {
+            fork_digest: [0x6a, 0x95, 0xa1, 0xa9],
+            finalized_epoch: Epoch::new(3),
+            head_slot: Slot::new(130),
+            ..Status::default()
+        };
+
+        let bytes = status.as_ssz_bytes();
+        assert_eq!(bytes.len(), 4 + 32 + 8 + 32 + 8);
+        assert_eq!(&bytes[..4], &status.fork_digest);
+        assert_eq!(Status::from_ssz_bytes(&bytes), Ok(status));
+    }
+
+    #[test]
+    fn enr_fork_id() {
+        let enr_fork_id = ENRForkID {
+            fork_digest: crate::compute_fork_digest([4, 0, 0, 0], Default::default()),
+            next_fork_version: [4, 0, 0, 0],
+            next_fork_epoch: Epoch::new(u64::MAX),
+        };
+
+        let bytes = enr_fork_id.as_ssz_bytes();
+        assert_eq!(bytes.len(), 4 + 4 + 8);
+        assert_eq!(ENRForkID::from_ssz_bytes(&bytes), Ok(enr_fork_id));
+    }
+
+    #[test]
+    fn metadata() {
+        let mut v1 = MetaDataV1 {
+            seq_number: 5,
+            attnets: BitVector::new(),
+        };
+        v1.attnets.set(63, true).unwrap();
+
+        let v2 = MetaDataV2::from(v1.clone());
+        assert_eq!(v2.as_ssz_bytes().len(), 8 + 8 + 1);
+        assert_eq!(MetaDataV1::from(v2.clone()), v1);
+
+        let v3 = MetaDataV3 {
+            seq_number: v2.seq_number,
+            attnets: v2.attnets.clone(),
+            syncnets: v2.syncnets.clone(),
+            custody_group_count: 4,
+        };
+        let bytes = v3.as_ssz_bytes();
+        // Each version is a prefix of the next.
+        assert!(bytes.starts_with(&v2.as_ssz_bytes()));
+        assert_eq!(MetaDataV3::from_ssz_bytes(&bytes), Ok(v3));
+        assert!(MetaDataV2::from_ssz_bytes(&bytes).is_err());
+    }
+
+    #[test]
+    fn requests() {
+        let request = BeaconBlocksByRangeRequest {
+            start_slot: Slot::new(64),
+            count: 32,
+            step: 1,
+        };
+        let bytes = request.as_ssz_bytes();
+        assert_eq!(
+            BeaconBlocksByRangeRequest::from_ssz_bytes(&bytes),
+            Ok(request)
+        );
+
+        let roots = BeaconBlocksByRootRequest::new(vec![H256::from_elem(1); 3]).unwrap();
+        assert_eq!(roots.as_ssz_bytes().len(), 3 * 32);
+        assert_eq!(
+            BeaconBlocksByRootRequest::from_ssz_bytes(&roots.as_ssz_bytes()),
+            Ok(roots)
+        );
+
+        let identifiers = BlobSidecarsByRootRequest::new(vec![
+            BlobIdentifier {
+                block_root: H256::from_elem(2),
+                index: 0,
+            },
+            BlobIdentifier {
+                block_root: H256::from_elem(2),
+                index: 5,
+            },
+        ])
+        .unwrap();
+        assert_eq!(
+            BlobSidecarsByRootRequest::max_len() as u64,
+            MAX_REQUEST_BLOB_SIDECARS_ELECTRA
+        );
+        let bytes = identifiers.as_ssz_bytes();
+        assert_eq!(bytes.len(), 2 * 40);
+        assert_eq!(
+            BlobSidecarsByRootRequest::from_ssz_bytes(&bytes),
+            Ok(identifiers)
+        );
+    }
+}
@@ -0,0 +1,290 @@
+use crate::hasher::hash32_concat;
+use crate::{Error, MerklePath, MerkleProof};
+use derivative::Derivative;
+use ssz::Decode;
+use ssz_derive::{Decode, Encode};
+use std::collections::{BTreeMap, BTreeSet};
+use std::marker::PhantomData;
+use tree_hash::{Hash256, TreeHash};
+
+/// A subset of the Merkle tree of a `T`, from which the selected nodes can be read and the root of
+/// the value recomputed. Created by `MerkleProof::partial`.
+///
+/// The tree is pruned to the paths from the root to the selected nodes. It is stored in the
+/// tree-offset format: `leaves` holds the nodes at which the pruned tree ends, from left to right,
+/// and `offsets` holds, for each node above them in depth-first order, the number of leaves below
+/// its left child. Unlike a `Multiproof`, the format needs no generalized indices, as the shape of
+/// the tree is encoded by the offsets.
+///
+/// ## Example
+///
+/// ```
+/// use ssz_types::{gindex_of, Mainnet, MerkleProof, SignedBeaconBlockCapella};
+/// use ssz::Encode;
+/// use tree_hash::TreeHash;
+///
+/// let block = SignedBeaconBlockCapella::<Mainnet>::default();
+/// let path = ["message", "body", "execution_payload", "block_number"];
+/// let partial = block.partial(&[gindex_of::<SignedBeaconBlockCapella>(&path).unwrap()]).unwrap();
+///
+/// // Only a few hundred bytes are needed instead of the whole block.
+/// assert!(partial.as_ssz_bytes().len() < 1000);
+///
+/// assert_eq!(partial.root(), Ok(block.tree_hash_root()));
+/// assert_eq!(partial.get::<u64>(&path), Ok(0));
+/// ```
+#[derive(Derivative, Encode, Decode)]
+#[derivative(
+    Clone(bound = ""),
+    Debug(bound = ""),
+    PartialEq(bound = ""),
+    Eq(bound = ""),
+    Default(bound = "")
+)]
+#[ssz(struct_behaviour = "container")]
+pub struct Partial<T> {
+    /// The number of leaves below the left child of each node above the leaves, in depth-first
+    /// order.
+    pub offsets: Vec<u32>,
+    /// The nodes at which the tree is pruned, from left to right.
+    pub leaves: Vec<Hash256>,
+    #[ssz(skip_serializing, skip_deserializing)]
+    #[derivative(Debug = "ignore")]
+    _phantom: PhantomData<T>,
+}
+
+impl<T> Partial<T> {
+    /// Returns the partial with the given offsets and leaves.
+    pub fn new(offsets: Vec<u32>, leaves: Vec<Hash256>) -> Self {
+        Self {
+            offsets,
+            leaves,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Returns the partial of `value` holding the nodes at `gindices`.
+    pub(crate) fn build(value: &T, gindices: &[u64]) -> Result<Self, Error>
+    where
+        T: MerkleProof,
+    {
+        // The nodes above the selected nodes, which are not included themselves.
+        let mut ancestors = BTreeSet::new();
+        for &gindex in gindices {
+            value.tree_hash_node(gindex)?;
+
+            let mut node = gindex / 2;
+            while node >= 1 && ancestors.insert(node) {
+                node /= 2;
+            }
+        }
+
+        let mut partial = Self::default();
+        partial.push(value, 1, &ancestors)?;
+        Ok(partial)
+    }
+
+    /// Appends the subtree of `value` at `gindex`, pruned below `ancestors`.
+    fn push(&mut self, value: &T, gindex: u64, ancestors: &BTreeSet<u64>) -> Result<(), Error>
+    where
+        T: MerkleProof,
+    {
+        if ancestors.contains(&gindex) {
+            let offset = self.offsets.len();
+            self.offsets.push(0);
+
+            let start = self.leaves.len();
+            self.push(value, 2 * gindex, ancestors)?;
+            self.offsets[offset] = (self.leaves.len() - start) as u32;
+            self.push(value, 2 * gindex + 1, ancestors)
+        } else {
+            self.leaves.push(value.tree_hash_node(gindex)?);
+            Ok(())
+        }
+    }
+
+    /// Recomputes the root of the value.
+    ///
+    /// Returns `Err` if the offsets do not describe a tree with as many leaves as `leaves`.
+    pub fn root(&self) -> Result<Hash256, Error> {
+        self.node(1)
+    }
+
+    /// True if the partial is well-formed and belongs to a value with the given `root`.
+    pub fn verify(&self, root: Hash256) -> bool {
+        self.root() == Ok(root)
+    }
+
+    /// Returns the node at `gindex` in the tree of the value.
+    ///
+    /// Returns `Err` if the partial is malformed or does not include the node, i.e. if `gindex` is
+    /// below a leaf of the partial.
+    pub fn node(&self, gindex: u64) -> Result<Hash256, Error> {
+        self.nodes()?
+            .get(&gindex)
+            .copied()
+            .ok_or(Error::InvalidGeneralizedIndex { gindex })
+    }
+
+    /// Returns every node of the pruned tree.
+    fn nodes(&self) -> Result<BTreeMap<u64, Hash256>, Error> {
+        if self.offsets.len() + 1 != self.leaves.len() {
+            return Err(Error::InvalidProof);
+        }
+
+        let mut nodes = BTreeMap::new();
+        subtree_root(1, &self.offsets, &self.leaves, &mut nodes)?;
+        Ok(nodes)
+    }
+}
+
+impl<T: MerklePath> Partial<T> {
+    /// Returns the node at the end of `path`, as per `MerklePath`.
+    pub fn node_at(&self, path: &[&str]) -> Result<Hash256, Error> {
+        self.node(T::gindex_of(path)?)
+    }
+
+    /// Returns the value at the end of `path`, which must be the only value in its node, e.g. a
+    /// basic field of a container or a 32 byte root.
+    ///
+    /// Returns `Err` if the node is not included or does not hold a single `V`.
+    pub fn get<V: Decode + TreeHash>(&self, path: &[&str]) -> Result<V, Error> {
+        let node = self.node_at(path)?;
+
+        let value = V::from_ssz_bytes(&node[..V::ssz_fixed_len().min(32)])
+            .map_err(|_| Error::InvalidProof)?;
+        if value.tree_hash_root() != node {
+            return Err(Error::InvalidProof);
+        }
+
+        Ok(value)
+    }
+}
+
+/// Inserts the nodes of the subtree at `gindex` described by `offsets` and `leaves` into `nodes`,
+/// returning its root.
+fn subtree_root(
+    gindex: u64,
+    offsets: &[u32],
+    leaves: &[Hash256],
+    nodes: &mut BTreeMap<u64, Hash256>,
+) -> Result<Hash256, Error> {
+    let root = match (offsets.split_first(), leaves) {
+        (None, [leaf]) => *leaf,
+        (Some((&pivot, offsets)), _) => {
+            let pivot = pivot as usize;
+            if pivot == 0 || pivot >= leaves.len() {
+                return Err(Error::InvalidProof);
+            }
+            let left = gindex.checked_mul(2).ok_or(Error::InvalidProof)?;
+
+            let (left_offsets, right_offsets) = offsets.split_at(pivot - 1);
+            let left_root = subtree_root(left, left_offsets, &leaves[..pivot], nodes)?;
+            let right_root = subtree_root(left + 1, right_offsets, &leaves[pivot..], nodes)?;
+            hash32_concat(left_root.as_bytes(), right_root.as_bytes())
+        }
+        _ => return Err(Error::InvalidProof),
+    };
+
+    nodes.insert(gindex, root);
+    Ok(root)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{gindex_of, FixedVector, VariableList};
+    use ssz::Encode;
+    use tree_hash_derive::TreeHash;
+    use typenum::*;
+
+    #[derive(TreeHash)]
+    struct Container {
+        a: u64,
+        b: VariableList<u16, U32>,
+        c: FixedVector<u8, U32>,
+    }
+
+    impl_merkle_proof_for_container!(Container, a, b, c);
+
+    fn container() -> Container {
+        Container {
+            a: 42,
+            b: VariableList::new((0..20).collect()).unwrap(),
+            c: FixedVector::new(vec![7; 32]).unwrap(),
+        }
+    }
+
+    #[test]
+    fn fields() {
+        let container = container();
+        let root = container.tree_hash_root();
+        let gindices = [
+            gindex_of::<Container>(&["a"]).unwrap(),
+            gindex_of::<Container>(&["c"]).unwrap(),
+            gindex_of::<Container>(&["b", "__len__"]).unwrap(),
+        ];
+        let partial = container.partial(&gindices).unwrap();
+
+        // `a` and `c`, the sibling of `c`, and the data and length of `b`.
+        assert_eq!(partial.leaves.len(), 5);
+        assert_eq!(partial.offsets, vec![3, 1, 1, 1]);
+        assert_eq!(partial.root(), Ok(root));
+        assert!(partial.verify(root));
+
+        assert_eq!(partial.get::<u64>(&["a"]), Ok(42));
+        assert_eq!(
+            partial.get::<FixedVector<u8, U32>>(&["c"]),
+            Ok(container.c.clone())
+        );
+        assert_eq!(partial.get::<u64>(&["b", "__len__"]), Ok(20));
+        assert_eq!(partial.node_at(&["b"]), Ok(container.b.tree_hash_root()));
+        assert_eq!(
+            partial.node_at(&["b", "0"]),
+            Err(Error::InvalidGeneralizedIndex { gindex: 20 })
+        );
+        // The node of `c` does not hold a `u64`.
+        assert_eq!(partial.get::<u64>(&["c"]), Err(Error::InvalidProof));
+
+        let bytes = partial.as_ssz_bytes();
+        assert_eq!(Partial::<Container>::from_ssz_bytes(&bytes), Ok(partial));
+    }
+
+    #[test]
+    fn whole_tree() {
+        let container = container();
+
+        let partial = container.partial(&[]).unwrap();
+        assert!(partial.offsets.is_empty());
+        assert_eq!(partial.leaves, vec![container.tree_hash_root()]);
+        assert_eq!(partial.root(), Ok(container.tree_hash_root()));
+
+        // Selecting a node and one of its ancestors includes the node.
+        let partial = container.partial(&[5, 20]).unwrap();
+        assert_eq!(partial.node(20), Ok(container.tree_hash_node(20).unwrap()));
+        assert_eq!(partial.node(5), Ok(container.b.tree_hash_root()));
+
+        assert!(container.partial(&[0]).is_err());
+        assert!(container.partial(&[1 << 20]).is_err());
+    }
+
+    #[test]
+    fn malformed() {
+        let leaves = vec![Hash256::zero(); 3];
+
+        for offsets in [
+            vec![],
+            vec![1],
+            vec![0, 1],
+            vec![3, 1],
+            vec![2, 2],
+            vec![1, 1, 1],
+        ] {
+            let partial = Partial::<Container>::new(offsets.clone(), leaves.clone());
+            assert_eq!(partial.root(), Err(Error::InvalidProof), "{:?}", offsets);
+        }
+
+        assert!(Partial::<Container>::new(vec![1, 1], leaves).root().is_ok());
+        assert!(Partial::<Container>::new(vec![], vec![]).root().is_err());
+    }
+}
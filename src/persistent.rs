@@ -0,0 +1,1027 @@
+use crate::proof::{list_gindex_of, list_tree_hash_node, vec_gindex_of, vec_tree_hash_node};
+use crate::tree_hash::{mix_in_length, vec_tree_hash_root};
+use crate::{
+    Error, FixedVector, MemorySize, MerklePath, MerkleProof, SszReflect, SszSchema, VariableList,
+};
+use std::marker::PhantomData;
+use std::sync::Arc;
+use tree_hash::Hash256;
+use typenum::Unsigned;
+
+/// The number of bits of an index consumed at each level of the tree.
+const BITS_PER_LEVEL: usize = 4;
+/// The number of children of each internal node, and the number of values in each leaf.
+const BRANCHING: usize = 1 << BITS_PER_LEVEL;
+
+/// A node of a `Tree`, shared between all clones that have not modified it.
+enum Node<T> {
+    Leaf(Arc<Vec<T>>),
+    Internal(Arc<Vec<Node<T>>>),
+}
+
+impl<T> Clone for Node<T> {
+    fn clone(&self) -> Self {
+        match self {
+            Node::Leaf(values) => Node::Leaf(values.clone()),
+            Node::Internal(children) => Node::Internal(children.clone()),
+        }
+    }
+}
+
+impl<T> Node<T> {
+    fn empty(level: usize) -> Self {
+        if level == 0 {
+            Node::Leaf(Arc::new(Vec::with_capacity(BRANCHING)))
+        } else {
+            Node::Internal(Arc::new(Vec::with_capacity(BRANCHING)))
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        match self {
+            Node::Leaf(values) => values.is_empty(),
+            Node::Internal(children) => children.is_empty(),
+        }
+    }
+}
+
+impl<T: MemorySize> MemorySize for Node<T> {
+    fn heap_size(&self) -> usize {
+        match self {
+            Node::Leaf(values) => values.heap_size(),
+            Node::Internal(children) => children.heap_size(),
+        }
+    }
+}
+
+/// Returns the index of the child at `level` on the path to the value at index `i`.
+fn child_index(i: usize, level: usize) -> usize {
+    (i >> (BITS_PER_LEVEL * level)) & (BRANCHING - 1)
+}
+
+/// A persistent vector of values, stored as a `BRANCHING`-ary tree of reference-counted nodes.
+///
+/// Cloning is `O(1)`. Modifying a clone copies only the nodes on the path to the modified value,
+/// all other nodes remain shared.
+struct Tree<T> {
+    root: Node<T>,
+    /// The number of internal levels above the leaves.
+    depth: usize,
+    len: usize,
+}
+
+impl<T> Clone for Tree<T> {
+    fn clone(&self) -> Self {
+        Self {
+            root: self.root.clone(),
+            depth: self.depth,
+            len: self.len,
+        }
+    }
+}
+
+impl<T> Tree<T> {
+    fn empty() -> Self {
+        Self {
+            root: Node::empty(0),
+            depth: 0,
+            len: 0,
+        }
+    }
+
+    fn from_vec(vec: Vec<T>) -> Self {
+        let len = vec.len();
+
+        let mut values = vec.into_iter();
+        let mut nodes = vec![];
+        loop {
+            let leaf: Vec<T> = values.by_ref().take(BRANCHING).collect();
+            if leaf.is_empty() {
+                break;
+            }
+            nodes.push(Node::Leaf(Arc::new(leaf)));
+        }
+
+        let mut depth = 0;
+        while nodes.len() > 1 {
+            let mut children = nodes.into_iter();
+            nodes = vec![];
+            loop {
+                let node: Vec<Node<T>> = children.by_ref().take(BRANCHING).collect();
+                if node.is_empty() {
+                    break;
+                }
+                nodes.push(Node::Internal(Arc::new(node)));
+            }
+            depth += 1;
+        }
+
+        match nodes.pop() {
+            Some(root) => Self { root, depth, len },
+            None => Self::empty(),
+        }
+    }
+
+    /// The number of values that can be stored without increasing the depth of the tree.
+    fn capacity(&self) -> usize {
+        BRANCHING
+            .checked_pow(self.depth as u32 + 1)
+            .unwrap_or(usize::MAX)
+    }
+
+    fn get(&self, i: usize) -> Option<&T> {
+        if i >= self.len {
+            return None;
+        }
+
+        let mut node = &self.root;
+        for level in (1..=self.depth).rev() {
+            node = match node {
+                Node::Internal(children) => &children[child_index(i, level)],
+                Node::Leaf(_) => unreachable!("leaves only exist at level 0"),
+            };
+        }
+
+        match node {
+            Node::Leaf(values) => values.get(child_index(i, 0)),
+            Node::Internal(_) => unreachable!("internal nodes never exist at level 0"),
+        }
+    }
+
+    fn iter(&self) -> PersistentIter<'_, T> {
+        PersistentIter {
+            stack: vec![std::slice::from_ref(&self.root).iter()],
+            leaf: [].iter(),
+            remaining: self.len,
+        }
+    }
+}
+
+impl<T: Clone> Tree<T> {
+    fn get_mut(&mut self, i: usize) -> Option<&mut T> {
+        if i >= self.len {
+            return None;
+        }
+
+        let mut node = &mut self.root;
+        for level in (1..=self.depth).rev() {
+            node = match node {
+                Node::Internal(children) => &mut Arc::make_mut(children)[child_index(i, level)],
+                Node::Leaf(_) => unreachable!("leaves only exist at level 0"),
+            };
+        }
+
+        match node {
+            Node::Leaf(values) => Arc::make_mut(values).get_mut(child_index(i, 0)),
+            Node::Internal(_) => unreachable!("internal nodes never exist at level 0"),
+        }
+    }
+
+    fn push(&mut self, value: T) {
+        if self.len == self.capacity() {
+            let root = std::mem::replace(&mut self.root, Node::empty(1));
+            self.root = Node::Internal(Arc::new(vec![root]));
+            self.depth += 1;
+        }
+
+        let i = self.len;
+        let mut node = &mut self.root;
+        for level in (1..=self.depth).rev() {
+            node = match node {
+                Node::Internal(children) => {
+                    let children = Arc::make_mut(children);
+                    let index = child_index(i, level);
+                    if index == children.len() {
+                        children.push(Node::empty(level - 1));
+                    }
+                    &mut children[index]
+                }
+                Node::Leaf(_) => unreachable!("leaves only exist at level 0"),
+            };
+        }
+
+        match node {
+            Node::Leaf(values) => Arc::make_mut(values).push(value),
+            Node::Internal(_) => unreachable!("internal nodes never exist at level 0"),
+        }
+        self.len += 1;
+    }
+
+    fn pop(&mut self) -> Option<T> {
+        fn pop_node<T: Clone>(node: &mut Node<T>) -> Option<T> {
+            match node {
+                Node::Leaf(values) => Arc::make_mut(values).pop(),
+                Node::Internal(children) => {
+                    let children = Arc::make_mut(children);
+                    let last = children.last_mut()?;
+                    let value = pop_node(last);
+                    if last.is_empty() {
+                        children.pop();
+                    }
+                    value
+                }
+            }
+        }
+
+        let value = pop_node(&mut self.root)?;
+        self.len -= 1;
+
+        // Remove levels that are no longer required.
+        while self.depth > 0 {
+            match &self.root {
+                Node::Internal(children) if children.len() <= 1 => {
+                    self.root = children.first().cloned().unwrap_or_else(|| Node::empty(0));
+                    self.depth -= 1;
+                }
+                _ => break,
+            }
+        }
+        if self.len == 0 {
+            *self = Self::empty();
+        }
+
+        Some(value)
+    }
+}
+
+/// An iterator over the values of a `PersistentList` or `PersistentVector`.
+pub struct PersistentIter<'a, T> {
+    stack: Vec<std::slice::Iter<'a, Node<T>>>,
+    leaf: std::slice::Iter<'a, T>,
+    remaining: usize,
+}
+
+impl<'a, T> Iterator for PersistentIter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        loop {
+            if let Some(value) = self.leaf.next() {
+                self.remaining -= 1;
+                return Some(value);
+            }
+
+            match self.stack.last_mut()?.next() {
+                Some(Node::Leaf(values)) => self.leaf = values.iter(),
+                Some(Node::Internal(children)) => self.stack.push(children.iter()),
+                None => {
+                    self.stack.pop();
+                }
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, T> ExactSizeIterator for PersistentIter<'a, T> {}
+
+/// Emulates a SSZ `List`, with structural sharing between clones.
+///
+/// Behaves like `VariableList<T, N>`, with identical SSZ encoding and tree hash root, but stores
+/// its values in a tree of reference-counted nodes. Cloning a `PersistentList` is cheap and a
+/// modified clone copies only the `O(log n)` nodes on the path to each modified value, so many
+/// near-identical versions of a list can be held in memory at once.
+///
+/// Values are read with `get` and `iter` and modified with `get_mut`, `push` and `pop`. Modifying
+/// a shared value requires `T: Clone`.
+///
+/// ## Example
+///
+/// ```
+/// use ssz_types::{PersistentList, typenum};
+///
+/// let mut list: PersistentList<u64, typenum::U1024> = (0..1000).collect::<Vec<_>>().try_into().unwrap();
+///
+/// let mut next = list.clone();
+/// *next.get_mut(10).unwrap() = 42;
+/// next.push(1000).unwrap();
+///
+/// assert_eq!(list.get(10), Some(&10));
+/// assert_eq!(next.get(10), Some(&42));
+/// assert_eq!(list.len(), 1000);
+/// assert_eq!(next.len(), 1001);
+/// ```
+pub struct PersistentList<T, N> {
+    tree: Tree<T>,
+    _phantom: PhantomData<N>,
+}
+
+impl<T, N: Unsigned> PersistentList<T, N> {
+    /// Returns `Ok` if the given `vec` does not exceed the maximum length of `Self`. Otherwise
+    /// returns `Err`.
+    pub fn new(vec: Vec<T>) -> Result<Self, Error> {
+        if vec.len() <= N::to_usize() {
+            Ok(Self {
+                tree: Tree::from_vec(vec),
+                _phantom: PhantomData,
+            })
+        } else {
+            Err(Error::OutOfBounds {
+                i: vec.len(),
+                len: Self::max_len(),
+            })
+        }
+    }
+
+    /// Create an empty list.
+    pub fn empty() -> Self {
+        Self {
+            tree: Tree::empty(),
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Returns the number of values presently in `self`.
+    pub fn len(&self) -> usize {
+        self.tree.len
+    }
+
+    /// True if `self` does not contain any values.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the type-level maximum length.
+    pub fn max_len() -> usize {
+        N::to_usize()
+    }
+
+    /// Returns a reference to the value at index `i`, or `None` if `i >= self.len()`.
+    pub fn get(&self, i: usize) -> Option<&T> {
+        self.tree.get(i)
+    }
+
+    /// Returns an iterator over the values of `self`.
+    pub fn iter(&self) -> PersistentIter<'_, T> {
+        self.tree.iter()
+    }
+}
+
+impl<T: Clone, N: Unsigned> PersistentList<T, N> {
+    /// Returns a mutable reference to the value at index `i`, or `None` if `i >= self.len()`.
+    ///
+    /// Copies any nodes on the path to `i` that are shared with a clone of `self`.
+    pub fn get_mut(&mut self, i: usize) -> Option<&mut T> {
+        self.tree.get_mut(i)
+    }
+
+    /// Appends `value` to the back of `self`.
+    ///
+    /// Returns `Err` when appending `value` would exceed the maximum length.
+    pub fn push(&mut self, value: T) -> Result<(), Error> {
+        if self.len() < Self::max_len() {
+            self.tree.push(value);
+            Ok(())
+        } else {
+            Err(Error::OutOfBounds {
+                i: self.len() + 1,
+                len: Self::max_len(),
+            })
+        }
+    }
+
+    /// Removes the last value from `self` and returns it, or `None` if `self` is empty.
+    pub fn pop(&mut self) -> Option<T> {
+        self.tree.pop()
+    }
+}
+
+impl<T, N> Clone for PersistentList<T, N> {
+    fn clone(&self) -> Self {
+        Self {
+            tree: self.tree.clone(),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<T: std::fmt::Debug, N: Unsigned> std::fmt::Debug for PersistentList<T, N> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: PartialEq, N: Unsigned> PartialEq for PersistentList<T, N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq, N: Unsigned> Eq for PersistentList<T, N> {}
+
+impl<T, N: Unsigned> Default for PersistentList<T, N> {
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+impl<T, N: Unsigned> TryFrom<Vec<T>> for PersistentList<T, N> {
+    type Error = Error;
+
+    fn try_from(vec: Vec<T>) -> Result<Self, Error> {
+        Self::new(vec)
+    }
+}
+
+impl<T: Clone, N: Unsigned> From<&PersistentList<T, N>> for Vec<T> {
+    fn from(list: &PersistentList<T, N>) -> Vec<T> {
+        list.iter().cloned().collect()
+    }
+}
+
+impl<T, N: Unsigned> From<VariableList<T, N>> for PersistentList<T, N> {
+    fn from(list: VariableList<T, N>) -> Self {
+        Self {
+            tree: Tree::from_vec(list.into()),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<T: Clone, N: Unsigned> From<&PersistentList<T, N>> for VariableList<T, N> {
+    fn from(list: &PersistentList<T, N>) -> Self {
+        VariableList::new_truncated(list.into())
+    }
+}
+
+impl<'a, T, N: Unsigned> IntoIterator for &'a PersistentList<T, N> {
+    type Item = &'a T;
+    type IntoIter = PersistentIter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T: serde::Serialize, N: Unsigned> serde::Serialize for PersistentList<T, N> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+impl<'de, T: serde::Deserialize<'de>, N: Unsigned> serde::Deserialize<'de>
+    for PersistentList<T, N>
+{
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        VariableList::<T, N>::deserialize(deserializer).map(Into::into)
+    }
+}
+
+impl<T: MemorySize, N: Unsigned> MemorySize for PersistentList<T, N> {
+    /// Counts every node of the tree, including those shared with other clones.
+    fn heap_size(&self) -> usize {
+        self.tree.root.heap_size()
+    }
+}
+
+impl<T, N: Unsigned> MerkleProof for PersistentList<T, N>
+where
+    T: tree_hash::TreeHash + MerkleProof,
+{
+    fn tree_hash_node(&self, gindex: u64) -> Result<Hash256, Error> {
+        let values = self.iter().collect::<Vec<_>>();
+
+        list_tree_hash_node(gindex, self.len(), |gindex| {
+            vec_tree_hash_node(values.iter().copied(), N::to_usize(), gindex)
+        })
+    }
+}
+
+impl<T, N: Unsigned> MerklePath for PersistentList<T, N>
+where
+    T: tree_hash::TreeHash + MerklePath,
+{
+    fn gindex_of(path: &[&str]) -> Result<u64, Error> {
+        list_gindex_of(path, |path| vec_gindex_of::<T>(N::to_usize(), path))
+    }
+}
+
+impl<T: SszReflect, N: Unsigned> SszReflect for PersistentList<T, N> {
+    fn ssz_schema() -> SszSchema {
+        SszSchema::list(T::ssz_schema(), N::to_usize())
+    }
+}
+
+impl<T, N: Unsigned> tree_hash::TreeHash for PersistentList<T, N>
+where
+    T: tree_hash::TreeHash,
+{
+    fn tree_hash_type() -> tree_hash::TreeHashType {
+        tree_hash::TreeHashType::List
+    }
+
+    fn tree_hash_packed_encoding(&self) -> tree_hash::PackedEncoding {
+        unreachable!("List should never be packed.")
+    }
+
+    fn tree_hash_packing_factor() -> usize {
+        unreachable!("List should never be packed.")
+    }
+
+    fn tree_hash_root(&self) -> Hash256 {
+        let root = vec_tree_hash_root::<T, N>(self.iter());
+
+        mix_in_length(&root, self.len())
+    }
+}
+
+impl<T, N: Unsigned> ssz::Encode for PersistentList<T, N>
+where
+    T: ssz::Encode,
+{
+    fn is_ssz_fixed_len() -> bool {
+        false
+    }
+
+    fn ssz_bytes_len(&self) -> usize {
+        if T::is_ssz_fixed_len() {
+            T::ssz_fixed_len() * self.len()
+        } else {
+            self.iter()
+                .map(|item| item.ssz_bytes_len() + ssz::BYTES_PER_LENGTH_OFFSET)
+                .sum()
+        }
+    }
+
+    fn ssz_append(&self, buf: &mut Vec<u8>) {
+        if T::is_ssz_fixed_len() {
+            buf.reserve(T::ssz_fixed_len() * self.len());
+
+            for item in self.iter() {
+                item.ssz_append(buf);
+            }
+        } else {
+            let mut encoder =
+                ssz::SszEncoder::container(buf, self.len() * ssz::BYTES_PER_LENGTH_OFFSET);
+
+            for item in self.iter() {
+                encoder.append(item);
+            }
+
+            encoder.finalize();
+        }
+    }
+}
+
+impl<T, N: Unsigned> ssz::Decode for PersistentList<T, N>
+where
+    T: ssz::Decode,
+{
+    fn is_ssz_fixed_len() -> bool {
+        false
+    }
+
+    fn from_ssz_bytes(bytes: &[u8]) -> Result<Self, ssz::DecodeError> {
+        VariableList::<T, N>::from_ssz_bytes(bytes).map(Into::into)
+    }
+}
+
+/// Emulates a SSZ `Vector`, with structural sharing between clones.
+///
+/// Behaves like `FixedVector<T, N>`, with identical SSZ encoding and tree hash root. See
+/// `PersistentList` for a description of the sharing between clones.
+pub struct PersistentVector<T, N> {
+    tree: Tree<T>,
+    _phantom: PhantomData<N>,
+}
+
+impl<T, N: Unsigned> PersistentVector<T, N> {
+    /// Returns `Ok` if the given `vec` equals the fixed length of `Self`. Otherwise returns
+    /// `Err`.
+    pub fn new(vec: Vec<T>) -> Result<Self, Error> {
+        if vec.len() == N::to_usize() {
+            Ok(Self {
+                tree: Tree::from_vec(vec),
+                _phantom: PhantomData,
+            })
+        } else {
+            Err(Error::OutOfBounds {
+                i: vec.len(),
+                len: Self::capacity(),
+            })
+        }
+    }
+
+    /// Create a new vector filled with clones of `elem`.
+    pub fn from_elem(elem: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            tree: Tree::from_vec(vec![elem; N::to_usize()]),
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Identical to `Self::capacity`, returns the type-level constant length.
+    pub fn len(&self) -> usize {
+        self.tree.len
+    }
+
+    /// True if the type-level constant length of `self` is zero.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the type-level constant length.
+    pub fn capacity() -> usize {
+        N::to_usize()
+    }
+
+    /// Returns a reference to the value at index `i`, or `None` if `i >= self.len()`.
+    pub fn get(&self, i: usize) -> Option<&T> {
+        self.tree.get(i)
+    }
+
+    /// Returns a mutable reference to the value at index `i`, or `None` if `i >= self.len()`.
+    ///
+    /// Copies any nodes on the path to `i` that are shared with a clone of `self`.
+    pub fn get_mut(&mut self, i: usize) -> Option<&mut T>
+    where
+        T: Clone,
+    {
+        self.tree.get_mut(i)
+    }
+
+    /// Returns an iterator over the values of `self`.
+    pub fn iter(&self) -> PersistentIter<'_, T> {
+        self.tree.iter()
+    }
+}
+
+impl<T, N> Clone for PersistentVector<T, N> {
+    fn clone(&self) -> Self {
+        Self {
+            tree: self.tree.clone(),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<T: std::fmt::Debug, N: Unsigned> std::fmt::Debug for PersistentVector<T, N> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: PartialEq, N: Unsigned> PartialEq for PersistentVector<T, N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq, N: Unsigned> Eq for PersistentVector<T, N> {}
+
+impl<T: Default + Clone, N: Unsigned> Default for PersistentVector<T, N> {
+    fn default() -> Self {
+        Self::from_elem(T::default())
+    }
+}
+
+impl<T, N: Unsigned> TryFrom<Vec<T>> for PersistentVector<T, N> {
+    type Error = Error;
+
+    fn try_from(vec: Vec<T>) -> Result<Self, Error> {
+        Self::new(vec)
+    }
+}
+
+impl<T: Clone, N: Unsigned> From<&PersistentVector<T, N>> for Vec<T> {
+    fn from(vector: &PersistentVector<T, N>) -> Vec<T> {
+        vector.iter().cloned().collect()
+    }
+}
+
+impl<T, N: Unsigned> From<FixedVector<T, N>> for PersistentVector<T, N> {
+    fn from(vector: FixedVector<T, N>) -> Self {
+        Self {
+            tree: Tree::from_vec(vector.into()),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<T: Clone, N: Unsigned> From<&PersistentVector<T, N>> for FixedVector<T, N> {
+    fn from(vector: &PersistentVector<T, N>) -> Self {
+        FixedVector::new(vector.into()).expect("persistent vector has a fixed length")
+    }
+}
+
+impl<'a, T, N: Unsigned> IntoIterator for &'a PersistentVector<T, N> {
+    type Item = &'a T;
+    type IntoIter = PersistentIter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T: serde::Serialize, N: Unsigned> serde::Serialize for PersistentVector<T, N> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+impl<'de, T: serde::Deserialize<'de>, N: Unsigned> serde::Deserialize<'de>
+    for PersistentVector<T, N>
+{
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let vec = Vec::<T>::deserialize(deserializer)?;
+        Self::new(vec).map_err(|e| serde::de::Error::custom(format!("{:?}", e)))
+    }
+}
+
+impl<T: MemorySize, N: Unsigned> MemorySize for PersistentVector<T, N> {
+    /// Counts every node of the tree, including those shared with other clones.
+    fn heap_size(&self) -> usize {
+        self.tree.root.heap_size()
+    }
+}
+
+impl<T, N: Unsigned> MerkleProof for PersistentVector<T, N>
+where
+    T: tree_hash::TreeHash + MerkleProof,
+{
+    fn tree_hash_node(&self, gindex: u64) -> Result<Hash256, Error> {
+        let values = self.iter().collect::<Vec<_>>();
+
+        vec_tree_hash_node(values.iter().copied(), N::to_usize(), gindex)
+    }
+}
+
+impl<T, N: Unsigned> MerklePath for PersistentVector<T, N>
+where
+    T: tree_hash::TreeHash + MerklePath,
+{
+    fn gindex_of(path: &[&str]) -> Result<u64, Error> {
+        vec_gindex_of::<T>(N::to_usize(), path)
+    }
+}
+
+impl<T: SszReflect, N: Unsigned> SszReflect for PersistentVector<T, N> {
+    fn ssz_schema() -> SszSchema {
+        SszSchema::vector(T::ssz_schema(), N::to_usize())
+    }
+}
+
+impl<T, N: Unsigned> tree_hash::TreeHash for PersistentVector<T, N>
+where
+    T: tree_hash::TreeHash,
+{
+    fn tree_hash_type() -> tree_hash::TreeHashType {
+        tree_hash::TreeHashType::Vector
+    }
+
+    fn tree_hash_packed_encoding(&self) -> tree_hash::PackedEncoding {
+        unreachable!("Vector should never be packed.")
+    }
+
+    fn tree_hash_packing_factor() -> usize {
+        unreachable!("Vector should never be packed.")
+    }
+
+    fn tree_hash_root(&self) -> Hash256 {
+        vec_tree_hash_root::<T, N>(self.iter())
+    }
+}
+
+impl<T, N: Unsigned> ssz::Encode for PersistentVector<T, N>
+where
+    T: ssz::Encode,
+{
+    fn is_ssz_fixed_len() -> bool {
+        T::is_ssz_fixed_len()
+    }
+
+    fn ssz_fixed_len() -> usize {
+        if <Self as ssz::Encode>::is_ssz_fixed_len() {
+            T::ssz_fixed_len() * N::to_usize()
+        } else {
+            ssz::BYTES_PER_LENGTH_OFFSET
+        }
+    }
+
+    fn ssz_bytes_len(&self) -> usize {
+        if T::is_ssz_fixed_len() {
+            T::ssz_fixed_len() * self.len()
+        } else {
+            self.iter()
+                .map(|item| item.ssz_bytes_len() + ssz::BYTES_PER_LENGTH_OFFSET)
+                .sum()
+        }
+    }
+
+    fn ssz_append(&self, buf: &mut Vec<u8>) {
+        if T::is_ssz_fixed_len() {
+            buf.reserve(T::ssz_fixed_len() * self.len());
+
+            for item in self.iter() {
+                item.ssz_append(buf);
+            }
+        } else {
+            let mut encoder =
+                ssz::SszEncoder::container(buf, self.len() * ssz::BYTES_PER_LENGTH_OFFSET);
+
+            for item in self.iter() {
+                encoder.append(item);
+            }
+
+            encoder.finalize();
+        }
+    }
+}
+
+impl<T, N: Unsigned> ssz::Decode for PersistentVector<T, N>
+where
+    T: ssz::Decode,
+{
+    fn is_ssz_fixed_len() -> bool {
+        T::is_ssz_fixed_len()
+    }
+
+    fn ssz_fixed_len() -> usize {
+        <FixedVector<T, N> as ssz::Decode>::ssz_fixed_len()
+    }
+
+    fn from_ssz_bytes(bytes: &[u8]) -> Result<Self, ssz::DecodeError> {
+        FixedVector::<T, N>::from_ssz_bytes(bytes).map(Into::into)
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a, T: arbitrary::Arbitrary<'a>, N: 'static + Unsigned> arbitrary::Arbitrary<'a>
+    for PersistentList<T, N>
+{
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        VariableList::arbitrary(u).map(Self::from)
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a, T: arbitrary::Arbitrary<'a>, N: 'static + Unsigned> arbitrary::Arbitrary<'a>
+    for PersistentVector<T, N>
+{
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        FixedVector::arbitrary(u).map(Self::from)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ssz::*;
+    use tree_hash::TreeHash;
+    use typenum::*;
+
+    /// Returns the leaf holding the value at index `i`.
+    fn leaf<T>(tree: &Tree<T>, i: usize) -> &Arc<Vec<T>> {
+        let mut node = &tree.root;
+        for level in (1..=tree.depth).rev() {
+            match node {
+                Node::Internal(children) => node = &children[child_index(i, level)],
+                Node::Leaf(_) => unreachable!(),
+            }
+        }
+        match node {
+            Node::Leaf(values) => values,
+            Node::Internal(_) => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn new() {
+        assert!(PersistentList::<u64, U4>::new(vec![42; 5]).is_err());
+        assert!(PersistentList::<u64, U4>::new(vec![42; 4]).is_ok());
+        assert!(PersistentList::<u64, U4>::new(vec![]).is_ok());
+
+        assert!(PersistentVector::<u64, U4>::new(vec![42; 5]).is_err());
+        assert!(PersistentVector::<u64, U4>::new(vec![42; 3]).is_err());
+        assert!(PersistentVector::<u64, U4>::new(vec![42; 4]).is_ok());
+    }
+
+    #[test]
+    fn from_vec_get_iter() {
+        for len in [0, 1, 15, 16, 17, 255, 256, 257, 1000, 4096, 4097] {
+            let vec: Vec<usize> = (0..len).collect();
+            let list = PersistentList::<usize, U8192>::new(vec.clone()).unwrap();
+
+            assert_eq!(list.len(), len);
+            assert_eq!(list.iter().len(), len);
+            assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec);
+            for i in 0..len {
+                assert_eq!(list.get(i), Some(&i));
+            }
+            assert_eq!(list.get(len), None);
+        }
+    }
+
+    #[test]
+    fn push_pop() {
+        let mut list = PersistentList::<usize, U8192>::empty();
+        let mut vec = vec![];
+
+        for i in 0..4097 {
+            list.push(i).unwrap();
+            vec.push(i);
+            assert_eq!(list.len(), vec.len());
+            assert_eq!(list.get(i), Some(&i));
+        }
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec);
+        assert_eq!(list, PersistentList::new(vec.clone()).unwrap());
+
+        while let Some(value) = list.pop() {
+            assert_eq!(Some(value), vec.pop());
+            assert_eq!(list.len(), vec.len());
+            assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec);
+        }
+        assert!(list.is_empty());
+        assert_eq!(list.tree.depth, 0);
+
+        let mut list = PersistentList::<usize, U2>::empty();
+        list.push(0).unwrap();
+        list.push(1).unwrap();
+        assert_eq!(list.push(2), Err(Error::OutOfBounds { i: 3, len: 2 }));
+    }
+
+    #[test]
+    fn structural_sharing() {
+        let list = PersistentList::<usize, U1024>::new((0..1000).collect()).unwrap();
+
+        let mut next = list.clone();
+        assert!(Arc::ptr_eq(leaf(&list.tree, 0), leaf(&next.tree, 0)));
+
+        *next.get_mut(500).unwrap() = 0;
+        next.push(1000).unwrap();
+
+        assert_eq!(list.get(500), Some(&500));
+        assert_eq!(next.get(500), Some(&0));
+        assert_eq!(list.len(), 1000);
+        assert_eq!(next.len(), 1001);
+
+        // Only the leaves holding index 500 and the pushed value have been copied.
+        let modified = [500 / BRANCHING, 1000 / BRANCHING];
+        for i in (0..1000).step_by(BRANCHING) {
+            assert_eq!(
+                Arc::ptr_eq(leaf(&list.tree, i), leaf(&next.tree, i)),
+                !modified.contains(&(i / BRANCHING))
+            );
+        }
+    }
+
+    #[test]
+    fn vector_sharing() {
+        let vector = PersistentVector::<u64, U64>::from_elem(7);
+
+        let mut next = vector.clone();
+        *next.get_mut(63).unwrap() = 8;
+
+        assert_eq!(vector.get(63), Some(&7));
+        assert_eq!(next.get(63), Some(&8));
+        assert!(Arc::ptr_eq(leaf(&vector.tree, 0), leaf(&next.tree, 0)));
+        assert!(!Arc::ptr_eq(leaf(&vector.tree, 63), leaf(&next.tree, 63)));
+    }
+
+    #[test]
+    fn list_matches_variable_list() {
+        for len in [0, 1, 16, 17, 100] {
+            let vec: Vec<u16> = (0..len).collect();
+            let persistent = PersistentList::<u16, U128>::new(vec.clone()).unwrap();
+            let list = VariableList::<u16, U128>::new(vec).unwrap();
+
+            assert_eq!(persistent.as_ssz_bytes(), list.as_ssz_bytes());
+            assert_eq!(persistent.ssz_bytes_len(), list.ssz_bytes_len());
+            assert_eq!(persistent.tree_hash_root(), list.tree_hash_root());
+            assert_eq!(
+                PersistentList::<u16, U128>::from_ssz_bytes(&list.as_ssz_bytes()),
+                Ok(persistent.clone())
+            );
+            assert_eq!(VariableList::from(&persistent), list);
+            assert_eq!(
+                serde_json::to_string(&persistent).unwrap(),
+                serde_json::to_string(&list).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn vector_matches_fixed_vector() {
+        let vec: Vec<u64> = (0..40).collect();
+        let persistent = PersistentVector::<u64, U40>::new(vec.clone()).unwrap();
+        let vector = FixedVector::<u64, U40>::new(vec).unwrap();
+
+        assert_eq!(
+            <PersistentVector<u64, U40> as Encode>::ssz_fixed_len(),
+            <FixedVector<u64, U40> as Encode>::ssz_fixed_len()
+        );
+        assert_eq!(persistent.as_ssz_bytes(), vector.as_ssz_bytes());
+        assert_eq!(persistent.tree_hash_root(), vector.tree_hash_root());
+        assert_eq!(
+            PersistentVector::<u64, U40>::from_ssz_bytes(&vector.as_ssz_bytes()),
+            Ok(persistent.clone())
+        );
+        assert_eq!(FixedVector::from(&persistent), vector);
+    }
+}
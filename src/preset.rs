@@ -0,0 +1,238 @@
+use std::fmt::Debug;
+use std::hash::Hash;
+use typenum::Unsigned;
+
+/// A type-level length of a preset, with the traits needed by the collections it bounds.
+pub trait PresetLength:
+    Unsigned + Clone + Copy + Default + PartialEq + Eq + Hash + Debug + Send + Sync + 'static
+{
+}
+
+impl<T> PresetLength for T where
+    T: Unsigned + Clone + Copy + Default + PartialEq + Eq + Hash + Debug + Send + Sync + 'static
+{
+}
+
+/// The values of a preset of the consensus specs which bound the lengths of containers.
+///
+/// The beacon block and state containers, and the operations they hold, are generic over a
+/// preset and default to `Mainnet`. Spec tests and local testnets commonly use `Minimal`, whose
+/// containers have different lengths and thus different encodings and roots.
+///
+/// Lengths which are products of values of the specs are given as separate types, as noted on
+/// each of them.
+///
+/// ## Example
+///
+/// ```
+/// use ssz::Encode;
+/// use ssz_types::{BeaconStatePhase0, Mainnet, Minimal, Preset};
+/// use typenum::Unsigned;
+///
+/// assert_eq!(<Minimal as Preset>::SlotsPerEpoch::to_u64(), 8);
+///
+/// let mainnet = BeaconStatePhase0::<Mainnet>::default();
+/// let minimal = BeaconStatePhase0::<Minimal>::default();
+/// assert!(minimal.as_ssz_bytes().len() < mainnet.as_ssz_bytes().len());
+/// ```
+pub trait Preset:
+    Clone + Copy + Default + PartialEq + Eq + Hash + Debug + Send + Sync + 'static
+{
+    /// The name of the preset, as in the paths of the spec tests.
+    const NAME: &'static str;
+
+    // Phase0.
+    type MaxCommitteesPerSlot: PresetLength;
+    type MaxValidatorsPerCommittee: PresetLength;
+    type SlotsPerEpoch: PresetLength;
+    type SlotsPerHistoricalRoot: PresetLength;
+    type EpochsPerHistoricalVector: PresetLength;
+    type EpochsPerSlashingsVector: PresetLength;
+    type HistoricalRootsLimit: PresetLength;
+    type ValidatorRegistryLimit: PresetLength;
+    type MaxProposerSlashings: PresetLength;
+    type MaxAttesterSlashings: PresetLength;
+    type MaxAttestations: PresetLength;
+    type MaxDeposits: PresetLength;
+    type MaxVoluntaryExits: PresetLength;
+    /// `MAX_ATTESTATIONS * SLOTS_PER_EPOCH`.
+    type EpochAttestationsLimit: PresetLength;
+    /// `EPOCHS_PER_ETH1_VOTING_PERIOD * SLOTS_PER_EPOCH`.
+    type Eth1DataVotesLimit: PresetLength;
+
+    // Altair.
+    type SyncCommitteeSize: PresetLength;
+    /// `SYNC_COMMITTEE_SIZE / SYNC_COMMITTEE_SUBNET_COUNT`.
+    type SyncSubcommitteeSize: PresetLength;
+
+    // Bellatrix.
+    type MaxBytesPerTransaction: PresetLength;
+    type MaxTransactionsPerPayload: PresetLength;
+    type BytesPerLogsBloom: PresetLength;
+    type MaxExtraDataBytes: PresetLength;
+
+    // Capella.
+    type MaxBlsToExecutionChanges: PresetLength;
+    type MaxWithdrawalsPerPayload: PresetLength;
+
+    // Deneb.
+    type MaxBlobCommitmentsPerBlock: PresetLength;
+    type KzgCommitmentInclusionProofDepth: PresetLength;
+    /// `FIELD_ELEMENTS_PER_BLOB * BYTES_PER_FIELD_ELEMENT`.
+    type BytesPerBlob: PresetLength;
+
+    // Electra.
+    type MaxAttesterSlashingsElectra: PresetLength;
+    type MaxAttestationsElectra: PresetLength;
+    /// `MAX_VALIDATORS_PER_COMMITTEE * MAX_COMMITTEES_PER_SLOT`.
+    type MaxValidatorsPerSlot: PresetLength;
+    type PendingDepositsLimit: PresetLength;
+    type PendingPartialWithdrawalsLimit: PresetLength;
+    type PendingConsolidationsLimit: PresetLength;
+    type MaxDepositRequestsPerPayload: PresetLength;
+    type MaxWithdrawalRequestsPerPayload: PresetLength;
+    type MaxConsolidationRequestsPerPayload: PresetLength;
+}
+
+/// The preset of Ethereum mainnet and its public testnets.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Mainnet;
+
+impl Preset for Mainnet {
+    const NAME: &'static str = "mainnet";
+
+    type MaxCommitteesPerSlot = typenum::U64;
+    type MaxValidatorsPerCommittee = typenum::U2048;
+    type SlotsPerEpoch = typenum::U32;
+    type SlotsPerHistoricalRoot = typenum::U8192;
+    type EpochsPerHistoricalVector = typenum::U65536;
+    type EpochsPerSlashingsVector = typenum::U8192;
+    type HistoricalRootsLimit = typenum::U16777216;
+    type ValidatorRegistryLimit = typenum::U1099511627776;
+    type MaxProposerSlashings = typenum::U16;
+    type MaxAttesterSlashings = typenum::U2;
+    type MaxAttestations = typenum::U128;
+    type MaxDeposits = typenum::U16;
+    type MaxVoluntaryExits = typenum::U16;
+    type EpochAttestationsLimit = typenum::U4096;
+    type Eth1DataVotesLimit = typenum::U2048;
+
+    type SyncCommitteeSize = typenum::U512;
+    type SyncSubcommitteeSize = typenum::U128;
+
+    type MaxBytesPerTransaction = typenum::U1073741824;
+    type MaxTransactionsPerPayload = typenum::U1048576;
+    type BytesPerLogsBloom = typenum::U256;
+    type MaxExtraDataBytes = typenum::U32;
+
+    type MaxBlsToExecutionChanges = typenum::U16;
+    type MaxWithdrawalsPerPayload = typenum::U16;
+
+    type MaxBlobCommitmentsPerBlock = typenum::U4096;
+    type KzgCommitmentInclusionProofDepth = typenum::U17;
+    type BytesPerBlob = typenum::U131072;
+
+    type MaxAttesterSlashingsElectra = typenum::U1;
+    type MaxAttestationsElectra = typenum::U8;
+    type MaxValidatorsPerSlot = typenum::U131072;
+    type PendingDepositsLimit = typenum::U134217728;
+    type PendingPartialWithdrawalsLimit = typenum::U134217728;
+    type PendingConsolidationsLimit = typenum::U262144;
+    type MaxDepositRequestsPerPayload = typenum::U8192;
+    type MaxWithdrawalRequestsPerPayload = typenum::U16;
+    type MaxConsolidationRequestsPerPayload = typenum::U2;
+}
+
+/// The preset of the minimal spec tests and of local testnets, with fewer slots per epoch and
+/// smaller committees and state vectors.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Minimal;
+
+impl Preset for Minimal {
+    const NAME: &'static str = "minimal";
+
+    type MaxCommitteesPerSlot = typenum::U4;
+    type MaxValidatorsPerCommittee = typenum::U2048;
+    type SlotsPerEpoch = typenum::U8;
+    type SlotsPerHistoricalRoot = typenum::U64;
+    type EpochsPerHistoricalVector = typenum::U64;
+    type EpochsPerSlashingsVector = typenum::U64;
+    type HistoricalRootsLimit = typenum::U16777216;
+    type ValidatorRegistryLimit = typenum::U1099511627776;
+    type MaxProposerSlashings = typenum::U16;
+    type MaxAttesterSlashings = typenum::U2;
+    type MaxAttestations = typenum::U128;
+    type MaxDeposits = typenum::U16;
+    type MaxVoluntaryExits = typenum::U16;
+    type EpochAttestationsLimit = typenum::U1024;
+    type Eth1DataVotesLimit = typenum::U32;
+
+    type SyncCommitteeSize = typenum::U32;
+    type SyncSubcommitteeSize = typenum::U8;
+
+    type MaxBytesPerTransaction = typenum::U1073741824;
+    type MaxTransactionsPerPayload = typenum::U1048576;
+    type BytesPerLogsBloom = typenum::U256;
+    type MaxExtraDataBytes = typenum::U32;
+
+    type MaxBlsToExecutionChanges = typenum::U16;
+    type MaxWithdrawalsPerPayload = typenum::U4;
+
+    type MaxBlobCommitmentsPerBlock = typenum::U32;
+    type KzgCommitmentInclusionProofDepth = typenum::U10;
+    type BytesPerBlob = typenum::U131072;
+
+    type MaxAttesterSlashingsElectra = typenum::U1;
+    type MaxAttestationsElectra = typenum::U8;
+    type MaxValidatorsPerSlot = typenum::U8192;
+    type PendingDepositsLimit = typenum::U134217728;
+    type PendingPartialWithdrawalsLimit = typenum::U64;
+    type PendingConsolidationsLimit = typenum::U64;
+    type MaxDepositRequestsPerPayload = typenum::U4;
+    type MaxWithdrawalRequestsPerPayload = typenum::U2;
+    type MaxConsolidationRequestsPerPayload = typenum::U2;
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Checks the lengths which are products of other values of the specs.
+    fn assert_derived_lengths<P: Preset>(epochs_per_eth1_voting_period: usize) {
+        assert_eq!(
+            P::EpochAttestationsLimit::to_usize(),
+            P::MaxAttestations::to_usize() * P::SlotsPerEpoch::to_usize()
+        );
+        assert_eq!(
+            P::Eth1DataVotesLimit::to_usize(),
+            epochs_per_eth1_voting_period * P::SlotsPerEpoch::to_usize()
+        );
+        assert_eq!(
+            P::SyncSubcommitteeSize::to_u64(),
+            P::SyncCommitteeSize::to_u64() / crate::SYNC_COMMITTEE_SUBNET_COUNT
+        );
+        assert_eq!(P::BytesPerBlob::to_usize(), 4096 * 32);
+        assert_eq!(
+            P::MaxValidatorsPerSlot::to_usize(),
+            P::MaxValidatorsPerCommittee::to_usize() * P::MaxCommitteesPerSlot::to_usize()
+        );
+        // The branch from a commitment to the root of a body: the commitment in its list, the
+        // length of the list, and the list in the body.
+        assert_eq!(
+            P::KzgCommitmentInclusionProofDepth::to_u32(),
+            P::MaxBlobCommitmentsPerBlock::to_u32().trailing_zeros() + 1 + 4
+        );
+    }
+
+    #[test]
+    fn derived_lengths() {
+        assert_derived_lengths::<Mainnet>(64);
+        assert_derived_lengths::<Minimal>(4);
+        assert_eq!(Mainnet::NAME, "mainnet");
+        assert_eq!(Minimal::NAME, "minimal");
+    }
+}
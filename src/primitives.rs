@@ -0,0 +1,233 @@
+use ssz_derive::{Decode, Encode};
+use std::fmt;
+use tree_hash::{Hash256, TreeHash};
+
+/// Implements the conversions, arithmetic and hashing shared by the `u64` newtypes, which encode
+/// and hash exactly like the `u64` they wrap.
+macro_rules! impl_u64_newtype {
+    ($type: ident) => {
+        impl $type {
+            /// Returns the value with the given `u64`.
+            pub const fn new(value: u64) -> Self {
+                Self(value)
+            }
+
+            /// Returns the `u64` of the value.
+            pub const fn as_u64(self) -> u64 {
+                self.0
+            }
+
+            /// Returns `self + rhs`, or `None` on overflow.
+            pub fn checked_add(self, rhs: u64) -> Option<Self> {
+                self.0.checked_add(rhs).map(Self)
+            }
+
+            /// Returns `self - rhs`, or `None` on underflow.
+            pub fn checked_sub(self, rhs: u64) -> Option<Self> {
+                self.0.checked_sub(rhs).map(Self)
+            }
+
+            /// Returns `self * rhs`, or `None` on overflow.
+            pub fn checked_mul(self, rhs: u64) -> Option<Self> {
+                self.0.checked_mul(rhs).map(Self)
+            }
+
+            /// Returns `self / rhs`, or `None` if `rhs` is zero.
+            pub fn checked_div(self, rhs: u64) -> Option<Self> {
+                self.0.checked_div(rhs).map(Self)
+            }
+
+            /// Returns `self + rhs`, clamped to `u64::MAX`.
+            pub fn saturating_add(self, rhs: u64) -> Self {
+                Self(self.0.saturating_add(rhs))
+            }
+
+            /// Returns `self - rhs`, clamped to zero.
+            pub fn saturating_sub(self, rhs: u64) -> Self {
+                Self(self.0.saturating_sub(rhs))
+            }
+        }
+
+        impl From<u64> for $type {
+            fn from(value: u64) -> Self {
+                Self(value)
+            }
+        }
+
+        impl From<$type> for u64 {
+            fn from(value: $type) -> Self {
+                value.0
+            }
+        }
+
+        impl fmt::Display for $type {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                self.0.fmt(f)
+            }
+        }
+
+        impl TreeHash for $type {
+            fn tree_hash_type() -> tree_hash::TreeHashType {
+                u64::tree_hash_type()
+            }
+
+            fn tree_hash_packed_encoding(&self) -> tree_hash::PackedEncoding {
+                self.0.tree_hash_packed_encoding()
+            }
+
+            fn tree_hash_packing_factor() -> usize {
+                u64::tree_hash_packing_factor()
+            }
+
+            fn tree_hash_root(&self) -> Hash256 {
+                self.0.tree_hash_root()
+            }
+        }
+
+        /// Serializes as a quoted decimal string, as the beacon API does for all integers.
+        #[cfg(feature = "serde")]
+        impl serde::Serialize for $type {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serde_utils::quoted_u64::serialize(&self.0, serializer)
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<'de> serde::Deserialize<'de> for $type {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                serde_utils::quoted_u64::deserialize(deserializer).map(Self)
+            }
+        }
+
+        #[cfg(feature = "schemars")]
+        impl schemars::JsonSchema for $type {
+            fn schema_name() -> String {
+                stringify!($type).to_string()
+            }
+
+            fn json_schema(_: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+                crate::json_schema::quoted_schema(20)
+            }
+        }
+    };
+}
+
+/// The number of a slot since genesis.
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Encode, Decode)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[ssz(struct_behaviour = "transparent")]
+pub struct Slot(u64);
+
+/// The number of an epoch since genesis.
+///
+/// ## Example
+///
+/// ```
+/// use ssz_types::{Epoch, Slot};
+///
+/// let slot = Slot::new(100);
+///
+/// assert_eq!(slot.epoch(32), Epoch::new(3));
+/// assert_eq!(slot.epoch(32).start_slot(32), Slot::new(96));
+/// assert_eq!(Epoch::new(3).end_slot(32), Slot::new(127));
+/// ```
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Encode, Decode)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[ssz(struct_behaviour = "transparent")]
+pub struct Epoch(u64);
+
+/// An amount of Gwei, i.e. 10^-9 Ether.
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Encode, Decode)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[ssz(struct_behaviour = "transparent")]
+pub struct Gwei(u64);
+
+/// The index of a validator in the registry of the beacon state.
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Encode, Decode)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[ssz(struct_behaviour = "transparent")]
+pub struct ValidatorIndex(u64);
+
+/// The index of a committee within a slot.
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Encode, Decode)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[ssz(struct_behaviour = "transparent")]
+pub struct CommitteeIndex(u64);
+
+impl_u64_newtype!(Slot);
+impl_u64_newtype!(Epoch);
+impl_u64_newtype!(Gwei);
+impl_u64_newtype!(ValidatorIndex);
+impl_u64_newtype!(CommitteeIndex);
+
+impl Slot {
+    /// Returns the epoch of the slot.
+    ///
+    /// Panics if `slots_per_epoch` is zero.
+    pub fn epoch(self, slots_per_epoch: u64) -> Epoch {
+        Epoch(self.0 / slots_per_epoch)
+    }
+}
+
+impl Epoch {
+    /// Returns the first slot of the epoch, clamped to `u64::MAX`.
+    pub fn start_slot(self, slots_per_epoch: u64) -> Slot {
+        Slot(self.0.saturating_mul(slots_per_epoch))
+    }
+
+    /// Returns the last slot of the epoch, clamped to `u64::MAX`.
+    pub fn end_slot(self, slots_per_epoch: u64) -> Slot {
+        self.saturating_add(1)
+            .start_slot(slots_per_epoch)
+            .saturating_sub(1)
+    }
+}
+
+impl ValidatorIndex {
+    /// Returns the index as a `usize`, to index the registry and balances of a state.
+    ///
+    /// Panics if the index does not fit into a `usize`.
+    pub fn as_usize(self) -> usize {
+        usize::try_from(self.0).expect("validator index does not fit into a usize")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ssz::{Decode, Encode};
+
+    #[test]
+    fn transparent_encoding() {
+        let slot = Slot::new(0x0102_0304);
+
+        assert_eq!(slot.as_ssz_bytes(), 0x0102_0304u64.as_ssz_bytes());
+        assert_eq!(Slot::from_ssz_bytes(&slot.as_ssz_bytes()), Ok(slot));
+        assert_eq!(slot.tree_hash_root(), 0x0102_0304u64.tree_hash_root());
+
+        let balances =
+            crate::VariableList::<Gwei, typenum::U8>::new(vec![Gwei::new(1); 5]).unwrap();
+        let raw = crate::VariableList::<u64, typenum::U8>::new(vec![1; 5]).unwrap();
+        assert_eq!(balances.tree_hash_root(), raw.tree_hash_root());
+    }
+
+    #[test]
+    fn arithmetic() {
+        assert_eq!(Gwei::new(5).checked_add(3), Some(Gwei::new(8)));
+        assert_eq!(Gwei::new(u64::MAX).checked_add(1), None);
+        assert_eq!(Gwei::new(5).checked_sub(6), None);
+        assert_eq!(Gwei::new(5).saturating_sub(6), Gwei::new(0));
+        assert_eq!(Epoch::new(3).checked_mul(u64::MAX), None);
+        assert_eq!(Epoch::new(3).checked_div(0), None);
+        assert!(Slot::new(2) < Slot::new(10));
+    }
+
+    #[test]
+    fn epochs_and_slots() {
+        assert_eq!(Slot::new(31).epoch(32), Epoch::new(0));
+        assert_eq!(Slot::new(32).epoch(32), Epoch::new(1));
+        assert_eq!(Epoch::new(2).start_slot(8), Slot::new(16));
+        assert_eq!(Epoch::new(2).end_slot(8), Slot::new(23));
+        assert_eq!(Epoch::new(u64::MAX).start_slot(32), Slot::new(u64::MAX));
+    }
+}
@@ -0,0 +1,742 @@
+use crate::merkle_tree::MerkleTree;
+use crate::multiproof::{helper_indices, Multiproof};
+use crate::partial::Partial;
+use crate::tree_hash::merkleize;
+use crate::{CommitteeIndex, Epoch, Error, ExecutionAddress, Graffiti, Gwei, Slot, ValidatorIndex};
+use tree_hash::{Hash256, TreeHash, TreeHashType, BYTES_PER_CHUNK};
+
+/// Provides access to any node of the Merkle tree of a value, addressed by generalized index.
+///
+/// Generalized indices number the nodes of a tree breadth-first, as per the consensus specs: the
+/// root is `1` and the children of node `i` are `2 * i` and `2 * i + 1`. The tree of a container
+/// continues into the trees of its fields, so a single index can address a node at any depth,
+/// e.g. `ExecutionPayload::block_hash` inside a `BeaconBlockBody`.
+///
+/// ## Example
+///
+/// ```
+/// use ssz_types::{MerkleProof, VariableList, typenum};
+/// use tree_hash::TreeHash;
+///
+/// let list: VariableList<u64, typenum::U8> = VariableList::new(vec![1, 2, 3]).unwrap();
+///
+/// // The root of a list is the hash of the root of its data (`2`) and its length (`3`).
+/// assert_eq!(list.tree_hash_node(1), Ok(list.tree_hash_root()));
+///
+/// // 8 `u64`s are packed into 2 chunks, the first of which is at `2 * 2`.
+/// let branch = list.merkle_proof(4).unwrap();
+/// assert_eq!(branch.len(), 2);
+/// assert_eq!(branch[0], list.tree_hash_node(5).unwrap());
+/// assert_eq!(branch[1], list.tree_hash_node(3).unwrap());
+/// ```
+pub trait MerkleProof {
+    /// Returns the node at `gindex` in the Merkle tree of `self`.
+    ///
+    /// Returns `Err` if there is no such node, e.g. because `gindex` is below a leaf holding a
+    /// basic value or within an element beyond the length of a list.
+    fn tree_hash_node(&self, gindex: u64) -> Result<Hash256, Error>;
+
+    /// Returns the Merkle branch proving the node at `gindex` against the root of `self`.
+    ///
+    /// The branch starts with the sibling of the node and ends with a child of the root, as
+    /// expected by `is_valid_merkle_branch` in the consensus specs.
+    fn merkle_proof(&self, gindex: u64) -> Result<Vec<Hash256>, Error> {
+        self.tree_hash_node(gindex)?;
+
+        let mut branch = Vec::with_capacity(depth_of(gindex)? as usize);
+        let mut node = gindex;
+        while node > 1 {
+            branch.push(
+                self.tree_hash_node(node ^ 1)
+                    .map_err(|_| Error::InvalidGeneralizedIndex { gindex })?,
+            );
+            node /= 2;
+        }
+
+        Ok(branch)
+    }
+
+    /// Returns a single proof for all of the nodes at `gindices`.
+    ///
+    /// The proof is smaller than the individual branches of the nodes whenever their branches
+    /// share nodes, e.g. when proving several fields of the same container.
+    fn multiproof(&self, gindices: &[u64]) -> Result<Multiproof, Error> {
+        let leaves = gindices
+            .iter()
+            .map(|&gindex| self.tree_hash_node(gindex))
+            .collect::<Result<_, _>>()?;
+        let helpers = helper_indices(gindices)?
+            .into_iter()
+            .map(|gindex| self.tree_hash_node(gindex))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Multiproof {
+            gindices: gindices.to_vec(),
+            leaves,
+            helpers,
+        })
+    }
+
+    /// Returns a `Partial` holding the nodes at `gindices`, from which they can be read and the
+    /// root of `self` recomputed.
+    fn partial(&self, gindices: &[u64]) -> Result<Partial<Self>, Error>
+    where
+        Self: Sized,
+    {
+        Partial::build(self, gindices)
+    }
+
+    /// Returns the nodes of the Merkle tree of `self`, down to `max_depth` levels below the root.
+    ///
+    /// Pass `u32::MAX` for the full tree. Every node is resolved with `tree_hash_node`, so this
+    /// is meant for debugging rather than for hashing large values.
+    fn merkle_tree(&self, max_depth: u32) -> Result<MerkleTree, Error> {
+        MerkleTree::build(self, 1, max_depth)
+    }
+}
+
+/// Maps paths of field names and indices to generalized indices, using only the type of a value.
+///
+/// Each element of a path selects a child of the current node: the name of a field of a
+/// container, the index of a value in a list or vector, or `__len__` for the length of a list.
+/// Indices of basic values select the chunk the value is packed into, as per
+/// `get_generalized_index` in the consensus specs.
+///
+/// ## Example
+///
+/// ```
+/// use ssz_types::{gindex_of, VariableList, typenum};
+///
+/// type List = VariableList<u64, typenum::U16>;
+///
+/// assert_eq!(gindex_of::<List>(&[]), Ok(1));
+/// assert_eq!(gindex_of::<List>(&["__len__"]), Ok(3));
+/// // The 4 `u64`s at indices 4 to 7 are packed into the second of 4 chunks.
+/// assert_eq!(gindex_of::<List>(&["5"]), Ok(9));
+/// ```
+pub trait MerklePath {
+    /// Returns the generalized index of the node at the end of `path`.
+    ///
+    /// Returns `Err` if an element of `path` does not exist in `Self`.
+    fn gindex_of(path: &[&str]) -> Result<u64, Error>;
+}
+
+/// Returns the generalized index of the node at the end of `path` in the Merkle tree of `T`.
+///
+/// See `MerklePath`.
+pub fn gindex_of<T: MerklePath>(path: &[&str]) -> Result<u64, Error> {
+    T::gindex_of(path)
+}
+
+/// Returns the number of levels between the root and the node at `gindex`.
+pub(crate) fn depth_of(gindex: u64) -> Result<u32, Error> {
+    gindex
+        .checked_ilog2()
+        .ok_or(Error::InvalidGeneralizedIndex { gindex })
+}
+
+/// Returns the depth of a Merkle tree with at least `leaves` leaves.
+pub(crate) fn tree_depth(leaves: usize) -> u32 {
+    leaves.next_power_of_two().trailing_zeros()
+}
+
+/// Returns the node at `gindex` in a Merkle tree of `depth` levels.
+///
+/// `leaves` returns the leaves of the tree, which may be fewer than `2^depth` in which case the
+/// rest are zero. Nodes below the leaves are resolved by `descend`, which receives the index of
+/// the leaf and the generalized index relative to it.
+pub(crate) fn merkle_tree_node<L, D>(
+    gindex: u64,
+    depth: u32,
+    leaves: L,
+    descend: D,
+) -> Result<Hash256, Error>
+where
+    L: FnOnce() -> Result<Vec<Hash256>, Error>,
+    D: FnOnce(usize, u64) -> Result<Hash256, Error>,
+{
+    let level = depth_of(gindex)?;
+
+    if level <= depth {
+        let height = depth - level;
+        let leaves = leaves()?;
+        let start = ((gindex - (1 << level)) << height) as usize;
+        let end = std::cmp::min(start + (1 << height), leaves.len());
+
+        let bytes = leaves
+            .get(start..end)
+            .unwrap_or_default()
+            .iter()
+            .flat_map(|leaf| leaf.as_bytes())
+            .copied()
+            .collect::<Vec<_>>();
+
+        Ok(merkleize(bytes, 1 << height))
+    } else {
+        let height = level - depth;
+        let index = ((gindex >> height) - (1 << depth)) as usize;
+        let sub_gindex = (1 << height) | (gindex & ((1 << height) - 1));
+
+        descend(index, sub_gindex).map_err(|e| match e {
+            Error::InvalidGeneralizedIndex { .. } => Error::InvalidGeneralizedIndex { gindex },
+            e => e,
+        })
+    }
+}
+
+/// Splits `bytes` into 32-byte chunks, padding the last with zeros.
+pub(crate) fn pack_chunks(bytes: &[u8]) -> Vec<Hash256> {
+    bytes
+        .chunks(BYTES_PER_CHUNK)
+        .map(|chunk| {
+            let mut leaf = Hash256::zero();
+            leaf.as_bytes_mut()[..chunk.len()].copy_from_slice(chunk);
+            leaf
+        })
+        .collect()
+}
+
+/// Returns the node at `gindex` in the Merkle tree of a list of length `len`, where `data`
+/// resolves nodes in the tree of its values.
+pub(crate) fn list_tree_hash_node<D>(gindex: u64, len: usize, data: D) -> Result<Hash256, Error>
+where
+    D: Fn(u64) -> Result<Hash256, Error>,
+{
+    merkle_tree_node(
+        gindex,
+        1,
+        || Ok(vec![data(1)?, length_chunk(len)]),
+        |index, sub_gindex| match index {
+            0 => data(sub_gindex),
+            _ => Err(Error::InvalidGeneralizedIndex { gindex: sub_gindex }),
+        },
+    )
+}
+
+/// Returns the leaf that is mixed in with the root of a list of length `len`.
+fn length_chunk(len: usize) -> Hash256 {
+    let mut chunk = Hash256::zero();
+    chunk.as_bytes_mut()[..8].copy_from_slice(&(len as u64).to_le_bytes());
+    chunk
+}
+
+/// A helper function providing common functionality between the `MerkleProof` implementations
+/// for `FixedVector` and `VariableList`, resolving `gindex` in the tree of their values.
+pub(crate) fn vec_tree_hash_node<'a, T, I>(
+    items: I,
+    limit: usize,
+    gindex: u64,
+) -> Result<Hash256, Error>
+where
+    T: TreeHash + MerkleProof + 'a,
+    I: IntoIterator<Item = &'a T> + Clone,
+{
+    match T::tree_hash_type() {
+        TreeHashType::Basic => {
+            let packing_factor = T::tree_hash_packing_factor();
+
+            merkle_tree_node(
+                gindex,
+                tree_depth(limit.div_ceil(packing_factor)),
+                || {
+                    let bytes = items
+                        .clone()
+                        .into_iter()
+                        .flat_map(|item| item.tree_hash_packed_encoding())
+                        .collect::<Vec<_>>();
+                    Ok(pack_chunks(&bytes))
+                },
+                |_, sub_gindex| Err(Error::InvalidGeneralizedIndex { gindex: sub_gindex }),
+            )
+        }
+        TreeHashType::Container | TreeHashType::List | TreeHashType::Vector => merkle_tree_node(
+            gindex,
+            tree_depth(limit),
+            || Ok(items.clone().into_iter().map(T::tree_hash_root).collect()),
+            |index, sub_gindex| {
+                items
+                    .clone()
+                    .into_iter()
+                    .nth(index)
+                    .ok_or(Error::InvalidGeneralizedIndex { gindex: sub_gindex })?
+                    .tree_hash_node(sub_gindex)
+            },
+        ),
+    }
+}
+
+/// A helper function providing common functionality for resolving `gindex` in the Merkle tree of
+/// some bytes that represent a bitfield.
+pub(crate) fn bitfield_bytes_tree_hash_node<N: typenum::Unsigned>(
+    bytes: &[u8],
+    gindex: u64,
+) -> Result<Hash256, Error> {
+    let leaf_count = N::to_usize().div_ceil(8).div_ceil(BYTES_PER_CHUNK);
+
+    merkle_tree_node(
+        gindex,
+        tree_depth(leaf_count),
+        || Ok(pack_chunks(bytes)),
+        |_, sub_gindex| Err(Error::InvalidGeneralizedIndex { gindex: sub_gindex }),
+    )
+}
+
+/// Returns the node at `gindex` in the Merkle tree of a container with the given `fields`.
+pub(crate) fn container_tree_hash_node(
+    fields: &[&dyn MerkleProof],
+    gindex: u64,
+) -> Result<Hash256, Error> {
+    merkle_tree_node(
+        gindex,
+        tree_depth(fields.len()),
+        || fields.iter().map(|field| field.tree_hash_node(1)).collect(),
+        |index, sub_gindex| {
+            fields
+                .get(index)
+                .ok_or(Error::InvalidGeneralizedIndex { gindex: sub_gindex })?
+                .tree_hash_node(sub_gindex)
+        },
+    )
+}
+
+/// Returns the generalized index of `child`, relative to the node at `parent`.
+pub(crate) fn concat_gindices(parent: u64, child: u64) -> Result<u64, Error> {
+    let depth = depth_of(child)?;
+
+    if depth_of(parent)? + depth < u64::BITS {
+        Ok((parent << depth) | (child - (1 << depth)))
+    } else {
+        Err(Error::InvalidGeneralizedIndex { gindex: parent })
+    }
+}
+
+/// Returns `Err` for the first element of `path`, if any.
+pub(crate) fn end_of_path(path: &[&str]) -> Result<u64, Error> {
+    match path.first() {
+        None => Ok(1),
+        Some(element) => Err(invalid_path_element(element)),
+    }
+}
+
+fn invalid_path_element(element: &str) -> Error {
+    Error::InvalidPathElement {
+        element: element.to_string(),
+    }
+}
+
+/// A helper function providing common functionality between the `MerklePath` implementations for
+/// `FixedVector` and `VariableList`, resolving `path` in the tree of their values.
+pub(crate) fn vec_gindex_of<T>(limit: usize, path: &[&str]) -> Result<u64, Error>
+where
+    T: TreeHash + MerklePath,
+{
+    let packing_factor = match T::tree_hash_type() {
+        TreeHashType::Basic => Some(T::tree_hash_packing_factor()),
+        TreeHashType::Container | TreeHashType::List | TreeHashType::Vector => None,
+    };
+
+    values_gindex_of(limit, packing_factor, path, T::gindex_of)
+}
+
+/// Resolves `path` in the tree of up to `limit` values, which are packed `packing_factor` to a
+/// chunk if they are basic, and otherwise have their own trees, in which `value` resolves paths.
+pub(crate) fn values_gindex_of<V>(
+    limit: usize,
+    packing_factor: Option<usize>,
+    path: &[&str],
+    value: V,
+) -> Result<u64, Error>
+where
+    V: FnOnce(&[&str]) -> Result<u64, Error>,
+{
+    let Some((element, rest)) = path.split_first() else {
+        return Ok(1);
+    };
+    let index = element
+        .parse::<usize>()
+        .ok()
+        .filter(|&index| index < limit)
+        .ok_or_else(|| invalid_path_element(element))?;
+
+    match packing_factor {
+        Some(packing_factor) => {
+            let depth = tree_depth(limit.div_ceil(packing_factor));
+
+            end_of_path(rest)?;
+            Ok((1 << depth) + (index / packing_factor) as u64)
+        }
+        None => {
+            let depth = tree_depth(limit);
+
+            concat_gindices((1 << depth) + index as u64, value(rest)?)
+        }
+    }
+}
+
+/// Resolves `path` in the tree of a list, where `data` resolves paths in the tree of its values.
+pub(crate) fn list_gindex_of<D>(path: &[&str], data: D) -> Result<u64, Error>
+where
+    D: FnOnce(&[&str]) -> Result<u64, Error>,
+{
+    match path.split_first() {
+        None => Ok(1),
+        Some((&"__len__", rest)) => {
+            end_of_path(rest)?;
+            Ok(3)
+        }
+        Some(_) => concat_gindices(2, data(path)?),
+    }
+}
+
+/// A helper function providing common functionality for resolving `path` in the Merkle tree of a
+/// bitfield of up to `len` bits.
+pub(crate) fn bitfield_gindex_of(len: usize, path: &[&str]) -> Result<u64, Error> {
+    let Some((element, rest)) = path.split_first() else {
+        return Ok(1);
+    };
+    let index = element
+        .parse::<usize>()
+        .ok()
+        .filter(|&index| index < len)
+        .ok_or_else(|| invalid_path_element(element))?;
+    let depth = tree_depth(len.div_ceil(8).div_ceil(BYTES_PER_CHUNK));
+
+    end_of_path(rest)?;
+    Ok((1 << depth) + (index / (BYTES_PER_CHUNK * 8)) as u64)
+}
+
+/// Resolves `path` in the tree of field `index` of a container with `num_fields` fields, where
+/// `field` is only used to infer the type of the field.
+pub(crate) fn field_gindex_of<C, F: MerklePath>(
+    index: usize,
+    num_fields: usize,
+    _field: fn(&C) -> &F,
+    path: &[&str],
+) -> Result<u64, Error> {
+    concat_gindices(
+        (1 << tree_depth(num_fields)) + index as u64,
+        F::gindex_of(path)?,
+    )
+}
+
+/// Resolves `path` in the tree of a container with the given `fields`, where `field` resolves
+/// the rest of `path` once the field at its start has been found.
+pub(crate) fn container_gindex_of<F>(fields: &[&str], path: &[&str], field: F) -> Result<u64, Error>
+where
+    F: FnOnce(usize, &[&str]) -> Option<Result<u64, Error>>,
+{
+    let Some((element, rest)) = path.split_first() else {
+        return Ok(1);
+    };
+
+    fields
+        .iter()
+        .position(|name| name == element)
+        .and_then(|index| field(index, rest))
+        .unwrap_or_else(|| Err(invalid_path_element(element)))
+}
+
+macro_rules! impl_merkle_proof_for_basic {
+    ($($type: ty),*) => {
+        $(
+            impl MerkleProof for $type {
+                fn tree_hash_node(&self, gindex: u64) -> Result<Hash256, Error> {
+                    if gindex == 1 {
+                        Ok(self.tree_hash_root())
+                    } else {
+                        Err(Error::InvalidGeneralizedIndex { gindex })
+                    }
+                }
+            }
+
+            impl MerklePath for $type {
+                fn gindex_of(path: &[&str]) -> Result<u64, Error> {
+                    end_of_path(path)
+                }
+            }
+        )*
+    };
+}
+
+impl_merkle_proof_for_basic!(
+    bool,
+    u8,
+    u16,
+    u32,
+    u64,
+    usize,
+    [u8; 4],
+    Hash256,
+    Slot,
+    Epoch,
+    Gwei,
+    ValidatorIndex,
+    CommitteeIndex,
+    ExecutionAddress,
+    Graffiti
+);
+
+/// Implements `MerkleProof`, `MerklePath` and `SszReflect` for a struct whose tree hash root is
+/// that of a container with the listed fields, in order.
+///
+/// A struct with a single type parameter is given as `Name<P: Bound>`.
+macro_rules! impl_merkle_proof_for_container {
+    ($type: ident<$param: ident: $bound: path>, $($field: ident),* $(,)?) => {
+        impl<$param: $bound> $crate::MerkleProof for $type<$param> {
+            fn tree_hash_node(&self, gindex: u64) -> Result<::tree_hash::Hash256, $crate::Error> {
+                $crate::proof::container_tree_hash_node(
+                    &[$(&self.$field as &dyn $crate::MerkleProof),*],
+                    gindex,
+                )
+            }
+        }
+
+        impl<$param: $bound> $crate::MerklePath for $type<$param> {
+            fn gindex_of(path: &[&str]) -> Result<u64, $crate::Error> {
+                let fields = [$(stringify!($field)),*];
+
+                $crate::proof::container_gindex_of(&fields, path, |index, rest| {
+                    $(
+                        if fields[index] == stringify!($field) {
+                            return Some($crate::proof::field_gindex_of(
+                                index,
+                                fields.len(),
+                                |value: &$type<$param>| &value.$field,
+                                rest,
+                            ));
+                        }
+                    )*
+                    None
+                })
+            }
+        }
+
+        impl_ssz_reflect_for_container!($type<$param: $bound>, $($field),*);
+    };
+    ($type: ty, $($field: ident),* $(,)?) => {
+        impl $crate::MerkleProof for $type {
+            fn tree_hash_node(&self, gindex: u64) -> Result<::tree_hash::Hash256, $crate::Error> {
+                $crate::proof::container_tree_hash_node(
+                    &[$(&self.$field as &dyn $crate::MerkleProof),*],
+                    gindex,
+                )
+            }
+        }
+
+        impl $crate::MerklePath for $type {
+            fn gindex_of(path: &[&str]) -> Result<u64, $crate::Error> {
+                let fields = [$(stringify!($field)),*];
+
+                $crate::proof::container_gindex_of(&fields, path, |index, rest| {
+                    $(
+                        if fields[index] == stringify!($field) {
+                            return Some($crate::proof::field_gindex_of(
+                                index,
+                                fields.len(),
+                                |value: &$type| &value.$field,
+                                rest,
+                            ));
+                        }
+                    )*
+                    None
+                })
+            }
+        }
+
+        impl_ssz_reflect_for_container!($type, $($field),*);
+    };
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{verify_merkle_branch, FixedVector, VariableList};
+    use tree_hash_derive::TreeHash;
+    use typenum::*;
+
+    #[derive(TreeHash)]
+    struct Container {
+        a: u64,
+        b: VariableList<u16, U32>,
+        c: VariableList<VariableList<u8, U4>, U2>,
+    }
+
+    impl_merkle_proof_for_container!(Container, a, b, c);
+
+    fn verify_branch(leaf: Hash256, branch: &[Hash256], gindex: u64, root: Hash256) -> bool {
+        let depth = depth_of(gindex).unwrap();
+        verify_merkle_branch(leaf, branch, depth as usize, gindex - (1 << depth), root)
+    }
+
+    fn container() -> Container {
+        Container {
+            a: 42,
+            b: VariableList::new((0..20).collect()).unwrap(),
+            c: VariableList::new(vec![VariableList::new(vec![1, 2, 3]).unwrap()]).unwrap(),
+        }
+    }
+
+    #[test]
+    fn nodes() {
+        let container = container();
+
+        assert_eq!(container.tree_hash_node(1), Ok(container.tree_hash_root()));
+        assert_eq!(
+            container.tree_hash_node(4),
+            Ok(container.a.tree_hash_root())
+        );
+        assert_eq!(
+            container.tree_hash_node(5),
+            Ok(container.b.tree_hash_root())
+        );
+        assert_eq!(
+            container.tree_hash_node(6),
+            Ok(container.c.tree_hash_root())
+        );
+        assert_eq!(container.tree_hash_node(7), Ok(Hash256::zero()));
+
+        // Length of `b`.
+        let mut length = Hash256::zero();
+        length.as_bytes_mut()[0] = 20;
+        assert_eq!(container.tree_hash_node(11), Ok(length));
+        // First of the two chunks of `b`, holding values 0 to 15.
+        let mut chunk = Hash256::zero();
+        for i in 0..16 {
+            chunk.as_bytes_mut()[i * 2] = i as u8;
+        }
+        assert_eq!(container.tree_hash_node(20), Ok(chunk));
+
+        // The root of the first list in `c`.
+        assert_eq!(
+            container.tree_hash_node(6 * 4),
+            Ok(container.c[0].tree_hash_root())
+        );
+        // The packed bytes of the first list in `c`.
+        assert_eq!(
+            container.tree_hash_node(6 * 8),
+            Ok(pack_chunks(&[1, 2, 3])[0])
+        );
+    }
+
+    #[test]
+    fn invalid_gindex() {
+        let container = container();
+
+        for gindex in [0, 8, 26, 50, 96, 1 << 40] {
+            assert_eq!(
+                container.tree_hash_node(gindex),
+                Err(Error::InvalidGeneralizedIndex { gindex }),
+                "{}",
+                gindex
+            );
+            assert!(container.merkle_proof(gindex).is_err());
+        }
+    }
+
+    fn assert_consistent<T: TreeHash + MerkleProof>(value: &T, gindices: &[u64]) {
+        let root = value.tree_hash_root();
+        assert_eq!(value.tree_hash_node(1), Ok(root));
+
+        for &gindex in gindices {
+            let leaf = value.tree_hash_node(gindex).unwrap();
+            let branch = value.merkle_proof(gindex).unwrap();
+            assert!(verify_branch(leaf, &branch, gindex, root), "{}", gindex);
+        }
+    }
+
+    #[test]
+    fn collections() {
+        use crate::{
+            BitList, BitVector, CowList, FixedVector, Hc, PersistentList, PersistentVector,
+            RuntimeVariableList, SmallVariableList,
+        };
+
+        let values = (0..100).collect::<Vec<u64>>();
+
+        let vector = FixedVector::<u64, U128>::new_resized(values.clone());
+        assert_consistent(&vector, &[2, 32, 63]);
+        let list = VariableList::<u64, U128>::new(values.clone()).unwrap();
+        assert_consistent(&list, &[2, 3, 64, 95]);
+        assert_consistent(&CowList::from(list.clone()), &[2, 3, 64, 95]);
+        assert_consistent(&Hc::new(list.clone()), &[2, 3, 64, 95]);
+        assert_consistent(
+            &SmallVariableList::<[u64; 4], U128>::from(list.clone()),
+            &[2, 3, 64, 95],
+        );
+        assert_consistent(&PersistentList::from(list.clone()), &[2, 3, 64, 95]);
+        assert_consistent(&PersistentVector::from(vector), &[2, 32, 63]);
+        assert_consistent(
+            &RuntimeVariableList::new(values, 128).unwrap(),
+            &[2, 3, 64, 95],
+        );
+
+        let mut bits = BitList::<U1024>::with_capacity(700).unwrap();
+        bits.set(600, true).unwrap();
+        assert_consistent(&bits, &[2, 3, 8, 10, 11]);
+        let mut bits = BitVector::<U1024>::new();
+        bits.set(600, true).unwrap();
+        assert_consistent(&bits, &[4, 5, 7]);
+
+        let nested: VariableList<FixedVector<u8, U48>, U4> =
+            VariableList::new(vec![FixedVector::default(); 3]).unwrap();
+        assert_consistent(&nested, &[2, 3, 8, 9, 10, 11, 16, 17, 21]);
+    }
+
+    #[test]
+    fn paths() {
+        assert_eq!(gindex_of::<Container>(&[]), Ok(1));
+        assert_eq!(gindex_of::<Container>(&["a"]), Ok(4));
+        assert_eq!(gindex_of::<Container>(&["b", "__len__"]), Ok(11));
+        // 16 `u16`s are packed into each chunk.
+        assert_eq!(gindex_of::<Container>(&["b", "15"]), Ok(20));
+        assert_eq!(gindex_of::<Container>(&["b", "16"]), Ok(21));
+        assert_eq!(gindex_of::<Container>(&["c", "1"]), Ok(25));
+        assert_eq!(gindex_of::<Container>(&["c", "0", "__len__"]), Ok(49));
+        // All 4 bytes are packed into a single chunk.
+        assert_eq!(gindex_of::<Container>(&["c", "0", "3"]), Ok(48));
+
+        for path in [
+            &["d"][..],
+            &["a", "0"],
+            &["b", "32"],
+            &["b", "x"],
+            &["b", "-1"],
+            &["c", "0", "4"],
+            &["c", "__len__", "0"],
+        ] {
+            assert_eq!(
+                gindex_of::<Container>(path),
+                Err(Error::InvalidPathElement {
+                    element: path.last().unwrap().to_string()
+                }),
+                "{:?}",
+                path
+            );
+        }
+
+        let container = container();
+        for path in [&["b", "19"][..], &["c", "0"], &["c", "0", "2"]] {
+            let gindex = gindex_of::<Container>(path).unwrap();
+            assert!(container.tree_hash_node(gindex).is_ok(), "{:?}", path);
+        }
+
+        // Paths which are too deep for a `u64` generalized index.
+        type Deep = FixedVector<FixedVector<FixedVector<u64, U1024>, U1048576>, U1073741824>;
+        assert!(gindex_of::<Deep>(&["0", "0"]).is_ok());
+        assert!(gindex_of::<VariableList<Deep, U1048576>>(&["0", "0", "0"]).is_err());
+    }
+
+    #[test]
+    fn proofs() {
+        let container = container();
+        let root = container.tree_hash_root();
+
+        for gindex in [1, 2, 3, 4, 5, 6, 7, 10, 11, 20, 21, 12, 13, 24, 25, 48, 49] {
+            let branch = container.merkle_proof(gindex).unwrap();
+            let leaf = container.tree_hash_node(gindex).unwrap();
+
+            assert_eq!(branch.len(), depth_of(gindex).unwrap() as usize);
+            assert!(verify_branch(leaf, &branch, gindex, root), "{}", gindex);
+        }
+    }
+}
@@ -0,0 +1,213 @@
+use crate::bitfield::{words, BitSliceRef, BITS_PER_WORD, BYTES_PER_WORD};
+use crate::Error;
+
+/// The number of set bits between the samples of the select index of a `RankSelect`.
+const SELECT_SAMPLE_RATE: usize = 64;
+
+/// An index over a bitfield supporting fast `rank` and `select` queries.
+///
+/// Building the index takes a single pass over the bitfield and stores one `u64` word and one
+/// cumulative count per 64 bits, and the word holding every 64th set bit. Afterwards:
+///
+/// - `rank(i)` (the number of set bits before `i`) is answered in constant time.
+/// - `select(k)` (the index of the `k`'th set bit) starts from the sampled word of the nearest
+///   preceding 64th set bit and searches the words up to the next sample, followed by a
+///   constant-time search within a single word. This is constant time for bitfields whose set
+///   bits are spread over a bounded number of words per 64 set bits, e.g. attestation bits.
+///   For sparse bitfields, it is logarithmic in the number of words between two samples rather
+///   than strictly constant.
+///
+/// The index is a snapshot; it does not observe later modifications to the bitfield it was built
+/// from.
+///
+/// ## Example
+///
+/// ```
+/// use ssz_types::{BitList, RankSelect, typenum};
+///
+/// let bitlist = BitList::<typenum::U2048>::from_indices(200, &[3, 70, 199]).unwrap();
+/// let index = RankSelect::new(bitlist.as_bit_slice());
+///
+/// assert_eq!(index.rank(71), Ok(2));
+/// assert_eq!(index.select(2), Some(199));
+/// assert_eq!(index.select(3), None);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RankSelect {
+    words: Vec<u64>,
+    /// `counts[i]` is the number of set bits in `words[..i]`. Has one more entry than `words`.
+    counts: Vec<usize>,
+    /// `samples[j]` is the index of the word holding the `j * SELECT_SAMPLE_RATE`'th set bit.
+    samples: Vec<usize>,
+    len: usize,
+}
+
+impl RankSelect {
+    /// Builds an index over the bits of `bits`.
+    pub fn new(bits: BitSliceRef<'_>) -> Self {
+        let mut words: Vec<u64> = words(bits.whole_bytes()).collect();
+
+        // Merge the trailing partial byte (if any) into its word.
+        let trailing = bits.trailing_byte();
+        if trailing != 0 {
+            let byte_index = bits.len() / 8;
+            let word_index = byte_index / BYTES_PER_WORD;
+            if words.len() <= word_index {
+                words.push(0);
+            }
+            words[word_index] |= u64::from(trailing) << ((byte_index % BYTES_PER_WORD) * 8);
+        }
+
+        let mut counts = Vec::with_capacity(words.len() + 1);
+        let mut samples = vec![];
+        let mut total = 0;
+        counts.push(total);
+        for (word_index, word) in words.iter().enumerate() {
+            total += word.count_ones() as usize;
+            counts.push(total);
+            while samples.len() * SELECT_SAMPLE_RATE < total {
+                samples.push(word_index);
+            }
+        }
+
+        Self {
+            words,
+            counts,
+            samples,
+            len: bits.len(),
+        }
+    }
+
+    /// Returns the number of bits in the indexed bitfield.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the indexed bitfield has a length of zero.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the number of bits that are set to `true`.
+    pub fn num_set_bits(&self) -> usize {
+        *self.counts.last().expect("counts is never empty")
+    }
+
+    /// Returns the number of set bits at indices strictly less than `i`.
+    ///
+    /// Returns `Err` if `i > self.len()`.
+    pub fn rank(&self, i: usize) -> Result<usize, Error> {
+        if i > self.len {
+            return Err(Error::OutOfBounds { i, len: self.len });
+        }
+
+        let (word_index, bit_index) = (i / BITS_PER_WORD, i % BITS_PER_WORD);
+        let partial = match self.words.get(word_index) {
+            Some(word) if bit_index > 0 => (word & ((1 << bit_index) - 1)).count_ones() as usize,
+            _ => 0,
+        };
+
+        Ok(self.counts[std::cmp::min(word_index, self.words.len())] + partial)
+    }
+
+    /// Returns the index of the `k`'th set bit (counting from zero), or `None` if fewer than
+    /// `k + 1` bits are set.
+    pub fn select(&self, k: usize) -> Option<usize> {
+        if k >= self.num_set_bits() {
+            return None;
+        }
+
+        // The bit is in the word of the preceding sample, the word of the next sample or a word
+        // between them: the last whose preceding count does not exceed `k`.
+        let sample = k / SELECT_SAMPLE_RATE;
+        let first = self.samples[sample];
+        let last = self
+            .samples
+            .get(sample + 1)
+            .copied()
+            .unwrap_or(self.words.len() - 1);
+        let word_index = first + self.counts[first + 1..=last].partition_point(|&count| count <= k);
+        let mut word = self.words[word_index];
+        for _ in 0..k - self.counts[word_index] {
+            // Clear the lowest set bit.
+            word &= word - 1;
+        }
+
+        Some(word_index * BITS_PER_WORD + word.trailing_zeros() as usize)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{BitList, BitVector};
+    use typenum::{U1024, U16};
+
+    #[test]
+    fn rank_select() {
+        for len in [0, 1, 7, 8, 9, 63, 64, 65, 200, 1024] {
+            let bitlist: BitList<U1024> =
+                ssz::TryFromIter::try_from_iter((0..len).map(|i| i % 3 == 0 || i % 7 == 0))
+                    .unwrap();
+            let index = RankSelect::new(bitlist.as_bit_slice());
+
+            assert_eq!(index.len(), len);
+            assert_eq!(index.num_set_bits(), bitlist.num_set_bits());
+
+            let mut rank = 0;
+            for i in 0..len {
+                assert_eq!(index.rank(i), Ok(rank), "len = {len}, i = {i}");
+                if bitlist.get(i).unwrap() {
+                    assert_eq!(index.select(rank), Some(i), "len = {len}, i = {i}");
+                    rank += 1;
+                }
+            }
+            assert_eq!(index.rank(len), Ok(rank));
+            assert_eq!(
+                index.rank(len + 1),
+                Err(Error::OutOfBounds { i: len + 1, len })
+            );
+            assert_eq!(index.select(rank), None);
+        }
+    }
+
+    #[test]
+    fn select_samples() {
+        // Dense and sparse runs, so that samples are both within a word and many words apart.
+        let indices = (0..300)
+            .chain((1000..100_000).step_by(997))
+            .chain((200_000..200_500).filter(|i| i % 5 != 0))
+            .collect::<Vec<_>>();
+        let bitlist = BitList::<typenum::U262144>::from_indices(250_000, &indices).unwrap();
+        let index = RankSelect::new(bitlist.as_bit_slice());
+
+        assert_eq!(index.num_set_bits(), indices.len());
+        for (k, &i) in indices.iter().enumerate() {
+            assert_eq!(index.select(k), Some(i), "k = {k}");
+            assert_eq!(index.rank(i), Ok(k));
+        }
+        assert_eq!(index.select(indices.len()), None);
+    }
+
+    #[test]
+    fn ssz_bytes_view() {
+        // The length bit of an SSZ-encoded `BitList` is not indexed.
+        let bytes = [0b1000_0001, 0b0000_0011];
+        let view = BitList::<U16>::view_ssz_bytes(&bytes).unwrap();
+        let index = RankSelect::new(view);
+
+        assert_eq!(index.len(), 9);
+        assert_eq!(index.num_set_bits(), 3);
+        assert_eq!(index.select(2), Some(8));
+        assert_eq!(index.select(3), None);
+    }
+
+    #[test]
+    fn bitvector() {
+        let bitvector = BitVector::<U16>::from_indices(&[2, 15]).unwrap();
+        let index = RankSelect::new(bitvector.as_bit_slice());
+
+        assert_eq!(index.rank(16), Ok(2));
+        assert_eq!(index.select(1), Some(15));
+    }
+}
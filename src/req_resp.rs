@@ -0,0 +1,433 @@
+//! The `ssz_snappy` wire format of the req/resp protocols of the consensus p2p specs.
+//!
+//! A request is the length of its SSZ as an unsigned varint, followed by the SSZ compressed with
+//! the snappy framing format. A response is a stream of chunks, each prefixed by a result byte
+//! and, for successful chunks of some protocols, the `ForkDigest` of the fork of its value.
+//! Unsuccessful chunks hold an error message instead of a value.
+//!
+//! Chunks are read one at a time from any `Read`, consuming exactly the bytes of the chunk, so
+//! that responses can be processed while they are streamed.
+//!
+//! ## Example
+//!
+//! ```
+//! use ssz_types::req_resp::{read_response_chunk, write_response_chunk, ResponseCode};
+//! use ssz_types::{Slot, MAX_CHUNK_SIZE};
+//!
+//! let mut stream = vec![];
+//! for slot in [1, 2] {
+//!     write_response_chunk(&mut stream, None, &Slot::new(slot)).unwrap();
+//! }
+//!
+//! let mut reader = &stream[..];
+//! let mut slots = vec![];
+//! while let Some(chunk) = read_response_chunk(&mut reader, false, MAX_CHUNK_SIZE).unwrap() {
+//!     assert_eq!(chunk.code, ResponseCode::Success);
+//!     slots.push(chunk.decode::<Slot>().unwrap());
+//! }
+//! assert_eq!(slots, [Slot::new(1), Slot::new(2)]);
+//! ```
+
+use crate::{ForkDigest, VariableList};
+use snap::read::FrameDecoder;
+use snap::write::FrameEncoder;
+use ssz::{Decode, DecodeError, Encode};
+use std::io::{self, Read, Write};
+
+/// The maximum length of an unsigned varint, which holds up to 64 bits.
+const MAX_VARINT_LEN: usize = 10;
+
+/// The maximum length of the uncompressed data of a frame of the snappy framing format.
+const MAX_FRAME_DATA_LEN: usize = 1 << 16;
+
+/// The message of an unsuccessful response chunk, usually UTF-8.
+pub type ErrorMessage = VariableList<u8, typenum::U256>;
+
+/// The result byte of a response chunk.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum ResponseCode {
+    Success,
+    InvalidRequest,
+    ServerError,
+    ResourceUnavailable,
+    /// A code which is not defined by the specs, which is treated as an error.
+    Unknown(u8),
+}
+
+impl ResponseCode {
+    /// Returns the result byte of the code.
+    pub fn as_u8(self) -> u8 {
+        match self {
+            Self::Success => 0,
+            Self::InvalidRequest => 1,
+            Self::ServerError => 2,
+            Self::ResourceUnavailable => 3,
+            Self::Unknown(code) => code,
+        }
+    }
+}
+
+impl From<u8> for ResponseCode {
+    fn from(code: u8) -> Self {
+        match code {
+            0 => Self::Success,
+            1 => Self::InvalidRequest,
+            2 => Self::ServerError,
+            3 => Self::ResourceUnavailable,
+            code => Self::Unknown(code),
+        }
+    }
+}
+
+/// A chunk of a response, with its SSZ decompressed but not yet decoded, since its type may depend
+/// on its `context`.
+#[derive(Clone, PartialEq, Debug)]
+pub struct ResponseChunk {
+    pub code: ResponseCode,
+    /// The fork digest of a successful chunk, for protocols whose values depend on the fork.
+    pub context: Option<ForkDigest>,
+    /// The SSZ of the value of a successful chunk, or of the `ErrorMessage` of an unsuccessful one.
+    pub ssz_bytes: Vec<u8>,
+}
+
+impl ResponseChunk {
+    /// Decodes the value of the chunk.
+    pub fn decode<T: Decode>(&self) -> Result<T, DecodeError> {
+        T::from_ssz_bytes(&self.ssz_bytes)
+    }
+
+    /// Returns the error message of an unsuccessful chunk, with invalid UTF-8 replaced, or `None`
+    /// if the chunk is successful or its message is not a valid `ErrorMessage`.
+    pub fn error_message(&self) -> Option<String> {
+        if self.code == ResponseCode::Success {
+            return None;
+        }
+        let message = self.decode::<ErrorMessage>().ok()?;
+        Some(String::from_utf8_lossy(&message).into_owned())
+    }
+}
+
+/// Writes `value` as a request.
+pub fn write_request<W: Write, T: Encode>(writer: W, value: &T) -> io::Result<()> {
+    write_payload(writer, &value.as_ssz_bytes())
+}
+
+/// Reads a request, returning its SSZ.
+///
+/// Returns `Err` if the SSZ is longer than `max_len`, e.g. `MAX_CHUNK_SIZE`, which is checked
+/// before anything is decompressed, or if the request is not valid.
+pub fn read_request<R: Read>(reader: R, max_len: usize) -> io::Result<Vec<u8>> {
+    read_payload(reader, max_len)
+}
+
+/// Writes `value` as a successful response chunk, preceded by `context` for protocols whose values
+/// depend on the fork.
+pub fn write_response_chunk<W: Write, T: Encode>(
+    mut writer: W,
+    context: Option<ForkDigest>,
+    value: &T,
+) -> io::Result<()> {
+    writer.write_all(&[ResponseCode::Success.as_u8()])?;
+    if let Some(fork_digest) = context {
+        writer.write_all(&fork_digest)?;
+    }
+    write_payload(writer, &value.as_ssz_bytes())
+}
+
+/// Writes an unsuccessful response chunk with `message`, truncated to the maximum length of an
+/// `ErrorMessage`.
+///
+/// Returns `Err` with `io::ErrorKind::InvalidInput`, without writing anything, if `code` is
+/// `ResponseCode::Success`.
+pub fn write_error_response<W: Write>(
+    mut writer: W,
+    code: ResponseCode,
+    message: &str,
+) -> io::Result<()> {
+    if code == ResponseCode::Success {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "error responses are unsuccessful",
+        ));
+    }
+    let message = ErrorMessage::new(
+        message
+            .bytes()
+            .take(<typenum::U256 as typenum::Unsigned>::to_usize())
+            .collect(),
+    )
+    .expect("the message is truncated to the maximum length");
+
+    writer.write_all(&[code.as_u8()])?;
+    write_payload(writer, &message.as_ssz_bytes())
+}
+
+/// Reads the next chunk of a response, or returns `None` if the response has ended.
+///
+/// `with_context` is whether successful chunks of the protocol are preceded by a fork digest.
+/// Returns `Err` if the SSZ is longer than `max_len`, which is checked before anything is
+/// decompressed, or if the chunk is not valid or ends early.
+pub fn read_response_chunk<R: Read>(
+    mut reader: R,
+    with_context: bool,
+    max_len: usize,
+) -> io::Result<Option<ResponseChunk>> {
+    let mut code = [0];
+    if reader.read(&mut code)? == 0 {
+        return Ok(None);
+    }
+    let code = ResponseCode::from(code[0]);
+
+    let context = if with_context && code == ResponseCode::Success {
+        let mut fork_digest = ForkDigest::default();
+        reader.read_exact(&mut fork_digest)?;
+        Some(fork_digest)
+    } else {
+        None
+    };
+
+    let ssz_bytes = read_payload(reader, max_len)?;
+    Ok(Some(ResponseChunk {
+        code,
+        context,
+        ssz_bytes,
+    }))
+}
+
+fn write_payload<W: Write>(mut writer: W, ssz_bytes: &[u8]) -> io::Result<()> {
+    let mut len = [0; MAX_VARINT_LEN];
+    let len_len = encode_varint(ssz_bytes.len() as u64, &mut len);
+    writer.write_all(&len[..len_len])?;
+
+    let mut encoder = FrameEncoder::new(writer);
+    encoder.write_all(ssz_bytes)?;
+    encoder.flush()
+}
+
+fn read_payload<R: Read>(mut reader: R, max_len: usize) -> io::Result<Vec<u8>> {
+    let len = read_varint(&mut reader)?;
+    let len = usize::try_from(len)
+        .ok()
+        .filter(|&len| len <= max_len)
+        .ok_or_else(|| {
+            invalid_data(format!(
+                "payload length {} exceeds the maximum of {}",
+                len, max_len
+            ))
+        })?;
+
+    // Bound the compressed bytes too, so that a peer cannot stream frames without data forever.
+    let compressed = reader.take(max_framed_len(len) as u64);
+    let mut ssz_bytes = Vec::with_capacity(len);
+    FrameDecoder::new(compressed)
+        .take(len as u64)
+        .read_to_end(&mut ssz_bytes)?;
+    if ssz_bytes.len() != len {
+        return Err(io::Error::new(
+            io::ErrorKind::UnexpectedEof,
+            format!(
+                "payload of length {} ends after {} bytes",
+                len,
+                ssz_bytes.len()
+            ),
+        ));
+    }
+    Ok(ssz_bytes)
+}
+
+/// Returns the maximum length of `len` bytes compressed with the snappy framing format, i.e. the
+/// stream identifier followed by frames of at most `MAX_FRAME_DATA_LEN` bytes each.
+fn max_framed_len(len: usize) -> usize {
+    let frames = len.div_ceil(MAX_FRAME_DATA_LEN).max(1);
+    10 + frames * (8 + snap::raw::max_compress_len(MAX_FRAME_DATA_LEN))
+}
+
+/// Writes `value` to `buf` as an unsigned LEB128 varint, returning its length.
+fn encode_varint(mut value: u64, buf: &mut [u8; MAX_VARINT_LEN]) -> usize {
+    let mut len = 0;
+    loop {
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+        if value == 0 {
+            buf[len] = byte;
+            return len + 1;
+        }
+        buf[len] = byte | 0x80;
+        len += 1;
+    }
+}
+
+fn read_varint<R: Read>(reader: &mut R) -> io::Result<u64> {
+    let mut value = 0_u64;
+    for i in 0..MAX_VARINT_LEN {
+        let mut byte = [0];
+        reader.read_exact(&mut byte)?;
+        let bits = u64::from(byte[0] & 0x7f);
+        // The tenth byte holds the single most significant bit.
+        if i == MAX_VARINT_LEN - 1 && bits > 1 {
+            break;
+        }
+        value |= bits << (7 * i);
+        if byte[0] & 0x80 == 0 {
+            return Ok(value);
+        }
+    }
+    Err(invalid_data("varint exceeds 64 bits".into()))
+}
+
+fn invalid_data(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Epoch, Mainnet, SignedBeaconBlockCapella, Slot, Status, MAX_CHUNK_SIZE};
+
+    #[test]
+    fn varints() {
+        for value in [0, 1, 127, 128, 300, 1 << 32, u64::MAX] {
+            let mut buf = [0; MAX_VARINT_LEN];
+            let len = encode_varint(value, &mut buf);
+            assert_eq!(read_varint(&mut &buf[..len]).unwrap(), value);
+        }
+
+        let mut buf = [0; MAX_VARINT_LEN];
+        assert_eq!(encode_varint(300, &mut buf), 2);
+        assert_eq!(buf[..2], [0xac, 0x02]);
+        assert_eq!(encode_varint(u64::MAX, &mut buf), MAX_VARINT_LEN);
+
+        let too_long = [0xff; MAX_VARINT_LEN];
+        assert_eq!(
+            read_varint(&mut &too_long[..]).unwrap_err().kind(),
+            io::ErrorKind::InvalidData
+        );
+        let overflowing = [0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x02];
+        assert_eq!(
+            read_varint(&mut &overflowing[..]).unwrap_err().kind(),
+            io::ErrorKind::InvalidData
+        );
+        assert_eq!(
+            read_varint(&mut &[0x80][..]).unwrap_err().kind(),
+            io::ErrorKind::UnexpectedEof
+        );
+    }
+
+    #[test]
+    fn requests() {
+        let status = Status {
+            finalized_epoch: Epoch::new(3),
+            head_slot: Slot::new(100),
+            ..Status::default()
+        };
+
+        let mut bytes = vec![];
+        write_request(&mut bytes, &status).unwrap();
+        // The length of the SSZ, then the stream identifier of the snappy framing format.
+        assert_eq!(bytes[0], 84);
+        assert_eq!(bytes[1..11], *b"\xff\x06\x00\x00sNaPpY");
+
+        let ssz_bytes = read_request(&bytes[..], MAX_CHUNK_SIZE).unwrap();
+        assert_eq!(Status::from_ssz_bytes(&ssz_bytes), Ok(status));
+
+        assert_eq!(
+            read_request(&bytes[..], 83).unwrap_err().kind(),
+            io::ErrorKind::InvalidData
+        );
+        assert_eq!(
+            read_request(&bytes[..bytes.len() - 1], MAX_CHUNK_SIZE)
+                .unwrap_err()
+                .kind(),
+            io::ErrorKind::UnexpectedEof
+        );
+
+        // A length longer than the compressed SSZ.
+        bytes[0] = 85;
+        assert_eq!(
+            read_request(&bytes[..], MAX_CHUNK_SIZE).unwrap_err().kind(),
+            io::ErrorKind::UnexpectedEof
+        );
+    }
+
+    #[test]
+    fn responses() {
+        let bytes = std::fs::read("state.ssz").unwrap();
+        let block = SignedBeaconBlockCapella::<Mainnet>::from_ssz_bytes(&bytes).unwrap();
+        let fork_digest = [0xbb, 0xa4, 0xda, 0x96];
+
+        let mut stream = vec![];
+        write_response_chunk(&mut stream, Some(fork_digest), &block).unwrap();
+        write_response_chunk(&mut stream, Some(fork_digest), &block).unwrap();
+        write_error_response(&mut stream, ResponseCode::ResourceUnavailable, "pruned").unwrap();
+
+        let mut reader = &stream[..];
+        for _ in 0..2 {
+            let chunk = read_response_chunk(&mut reader, true, MAX_CHUNK_SIZE)
+                .unwrap()
+                .unwrap();
+            assert_eq!(chunk.code, ResponseCode::Success);
+            assert_eq!(chunk.context, Some(fork_digest));
+            assert_eq!(chunk.error_message(), None);
+            assert_eq!(chunk.decode(), Ok(block.clone()));
+        }
+
+        // Unsuccessful chunks have no context.
+        let chunk = read_response_chunk(&mut reader, true, MAX_CHUNK_SIZE)
+            .unwrap()
+            .unwrap();
+        assert_eq!(chunk.code, ResponseCode::ResourceUnavailable);
+        assert_eq!(chunk.context, None);
+        assert_eq!(chunk.error_message(), Some("pruned".into()));
+
+        assert_eq!(
+            read_response_chunk(&mut reader, true, MAX_CHUNK_SIZE).unwrap(),
+            None
+        );
+
+        // A chunk which ends in its context.
+        assert_eq!(
+            read_response_chunk(&stream[..3], true, MAX_CHUNK_SIZE)
+                .unwrap_err()
+                .kind(),
+            io::ErrorKind::UnexpectedEof
+        );
+        assert_eq!(
+            read_response_chunk(&stream[..], true, bytes.len() - 1)
+                .unwrap_err()
+                .kind(),
+            io::ErrorKind::InvalidData
+        );
+    }
+
+    #[test]
+    fn error_responses() {
+        let mut stream = vec![];
+        write_error_response(&mut stream, ResponseCode::from(7), &"x".repeat(300)).unwrap();
+        write_error_response(&mut stream, ResponseCode::InvalidRequest, "bad").unwrap();
+
+        let mut reader = &stream[..];
+        let chunk = read_response_chunk(&mut reader, false, MAX_CHUNK_SIZE)
+            .unwrap()
+            .unwrap();
+        assert_eq!(chunk.code, ResponseCode::Unknown(7));
+        assert_eq!(chunk.error_message(), Some("x".repeat(256)));
+
+        let chunk = read_response_chunk(&mut reader, false, MAX_CHUNK_SIZE)
+            .unwrap()
+            .unwrap();
+        assert_eq!(chunk.code.as_u8(), 1);
+        assert_eq!(chunk.error_message(), Some("bad".into()));
+    }
+
+    #[test]
+    fn successful_error_response() {
+        let mut buf = vec![];
+        assert_eq!(
+            write_error_response(&mut buf, ResponseCode::Success, "")
+                .unwrap_err()
+                .kind(),
+            io::ErrorKind::InvalidInput
+        );
+        assert!(buf.is_empty());
+    }
+}
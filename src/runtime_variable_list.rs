@@ -0,0 +1,357 @@
+use crate::proof::{list_tree_hash_node, vec_tree_hash_node};
+use crate::tree_hash::{mix_in_length, vec_tree_hash_root_with_limit};
+use crate::{Error, MemorySize, MerkleProof, VariableList};
+use serde_derive::Serialize;
+use std::ops::{Deref, DerefMut, Index, IndexMut};
+use std::slice::SliceIndex;
+use tree_hash::{Hash256, TreeHash};
+use typenum::Unsigned;
+
+/// Emulates a SSZ `List` with a maximum length that is set at runtime.
+///
+/// Behaves like `VariableList`, except that the maximum length is supplied when the list is
+/// created or decoded rather than as a type-level constant. This is useful when the limit of a
+/// list depends on a runtime configuration, such as a preset or a request parameter.
+///
+/// Since the maximum length is not part of the type, `RuntimeVariableList` does not implement
+/// `ssz::Decode` or `serde::Deserialize`. Use `RuntimeVariableList::from_ssz_bytes` instead.
+///
+/// ## Example
+///
+/// ```
+/// use ssz::Encode;
+/// use ssz_types::RuntimeVariableList;
+///
+/// let mut list = RuntimeVariableList::new(vec![1_u64, 2], 3).unwrap();
+/// list.push(3).unwrap();
+/// assert!(list.push(4).is_err());
+///
+/// let bytes = list.as_ssz_bytes();
+/// assert_eq!(RuntimeVariableList::<u64>::from_ssz_bytes(&bytes, 3), Ok(list));
+/// assert!(RuntimeVariableList::<u64>::from_ssz_bytes(&bytes, 2).is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+#[serde(transparent)]
+pub struct RuntimeVariableList<T> {
+    vec: Vec<T>,
+    #[serde(skip)]
+    max_len: usize,
+}
+
+impl<T> RuntimeVariableList<T> {
+    /// Returns `Ok` if the given `vec` does not exceed `max_len`. Otherwise returns `Err`.
+    pub fn new(vec: Vec<T>, max_len: usize) -> Result<Self, Error> {
+        if vec.len() <= max_len {
+            Ok(Self { vec, max_len })
+        } else {
+            Err(Error::OutOfBounds {
+                i: vec.len(),
+                len: max_len,
+            })
+        }
+    }
+
+    /// Create a new list from `vec`, truncating it if it is longer than `max_len`.
+    ///
+    /// Prefer `Self::new` unless silently dropping values is intended.
+    pub fn new_truncated(mut vec: Vec<T>, max_len: usize) -> Self {
+        vec.truncate(max_len);
+        Self { vec, max_len }
+    }
+
+    /// Create an empty list with the given `max_len`.
+    pub fn empty(max_len: usize) -> Self {
+        Self {
+            vec: vec![],
+            max_len,
+        }
+    }
+
+    /// Returns the number of values presently in `self`.
+    pub fn len(&self) -> usize {
+        self.vec.len()
+    }
+
+    /// True if `self` does not contain any values.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the maximum length of `self`.
+    pub fn max_len(&self) -> usize {
+        self.max_len
+    }
+
+    /// Appends `value` to the back of `self`.
+    ///
+    /// Returns `Err` when appending `value` would exceed the maximum length.
+    pub fn push(&mut self, value: T) -> Result<(), Error> {
+        if self.vec.len() < self.max_len {
+            self.vec.push(value);
+            Ok(())
+        } else {
+            Err(Error::OutOfBounds {
+                i: self.vec.len() + 1,
+                len: self.max_len,
+            })
+        }
+    }
+
+    /// Removes the last value from `self` and returns it, or `None` if `self` is empty.
+    pub fn pop(&mut self) -> Option<T> {
+        self.vec.pop()
+    }
+
+    /// Shortens `self` to `len` values, dropping the rest.
+    ///
+    /// Has no effect if `len` is greater than or equal to `self.len()`.
+    pub fn truncate(&mut self, len: usize) {
+        self.vec.truncate(len)
+    }
+
+    /// Converts `self` into a `VariableList` with a type-level maximum length.
+    ///
+    /// Returns `Err` if `self` does not fit in a `VariableList<T, N>`.
+    pub fn into_variable_list<N: Unsigned>(self) -> Result<VariableList<T, N>, Error> {
+        VariableList::new(self.vec)
+    }
+}
+
+impl<T: ssz::Decode> RuntimeVariableList<T> {
+    /// Decodes `bytes` as a SSZ list of at most `max_len` values.
+    pub fn from_ssz_bytes(bytes: &[u8], max_len: usize) -> Result<Self, ssz::DecodeError> {
+        if bytes.is_empty() {
+            Ok(Self::empty(max_len))
+        } else if T::is_ssz_fixed_len() {
+            let num_items = bytes
+                .len()
+                .checked_div(T::ssz_fixed_len())
+                .ok_or(ssz::DecodeError::ZeroLengthItem)?;
+
+            if num_items > max_len {
+                return Err(ssz::DecodeError::BytesInvalid(format!(
+                    "RuntimeVariableList of {} items exceeds maximum of {}",
+                    num_items, max_len
+                )));
+            }
+
+            bytes
+                .chunks(T::ssz_fixed_len())
+                .map(T::from_ssz_bytes)
+                .collect::<Result<Vec<_>, _>>()
+                .map(|vec| Self { vec, max_len })
+        } else {
+            ssz::decode_list_of_variable_length_items(bytes, Some(max_len))
+                .map(|vec| Self { vec, max_len })
+        }
+    }
+}
+
+impl<T, N: Unsigned> From<VariableList<T, N>> for RuntimeVariableList<T> {
+    fn from(list: VariableList<T, N>) -> Self {
+        Self {
+            vec: list.into(),
+            max_len: N::to_usize(),
+        }
+    }
+}
+
+impl<T> From<RuntimeVariableList<T>> for Vec<T> {
+    fn from(list: RuntimeVariableList<T>) -> Vec<T> {
+        list.vec
+    }
+}
+
+impl<T, I: SliceIndex<[T]>> Index<I> for RuntimeVariableList<T> {
+    type Output = I::Output;
+
+    #[inline]
+    fn index(&self, index: I) -> &Self::Output {
+        Index::index(&self.vec, index)
+    }
+}
+
+impl<T, I: SliceIndex<[T]>> IndexMut<I> for RuntimeVariableList<T> {
+    #[inline]
+    fn index_mut(&mut self, index: I) -> &mut Self::Output {
+        IndexMut::index_mut(&mut self.vec, index)
+    }
+}
+
+impl<T> Deref for RuntimeVariableList<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        &self.vec[..]
+    }
+}
+
+impl<T> DerefMut for RuntimeVariableList<T> {
+    fn deref_mut(&mut self) -> &mut [T] {
+        &mut self.vec[..]
+    }
+}
+
+impl<'a, T> IntoIterator for &'a RuntimeVariableList<T> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut RuntimeVariableList<T> {
+    type Item = &'a mut T;
+    type IntoIter = std::slice::IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<T> IntoIterator for RuntimeVariableList<T> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.vec.into_iter()
+    }
+}
+
+impl<T: MemorySize> MemorySize for RuntimeVariableList<T> {
+    fn heap_size(&self) -> usize {
+        self.vec.heap_size()
+    }
+}
+
+impl<T: TreeHash + MerkleProof> MerkleProof for RuntimeVariableList<T> {
+    fn tree_hash_node(&self, gindex: u64) -> Result<Hash256, Error> {
+        list_tree_hash_node(gindex, self.len(), |gindex| {
+            vec_tree_hash_node(&self.vec, self.max_len, gindex)
+        })
+    }
+}
+
+impl<T: TreeHash> TreeHash for RuntimeVariableList<T> {
+    fn tree_hash_type() -> tree_hash::TreeHashType {
+        tree_hash::TreeHashType::List
+    }
+
+    fn tree_hash_packed_encoding(&self) -> tree_hash::PackedEncoding {
+        unreachable!("List should never be packed.")
+    }
+
+    fn tree_hash_packing_factor() -> usize {
+        unreachable!("List should never be packed.")
+    }
+
+    fn tree_hash_root(&self) -> Hash256 {
+        let root = vec_tree_hash_root_with_limit(&self.vec, self.max_len);
+
+        mix_in_length(&root, self.len())
+    }
+}
+
+impl<T: ssz::Encode> ssz::Encode for RuntimeVariableList<T> {
+    fn is_ssz_fixed_len() -> bool {
+        <Vec<T>>::is_ssz_fixed_len()
+    }
+
+    fn ssz_fixed_len() -> usize {
+        <Vec<T>>::ssz_fixed_len()
+    }
+
+    fn ssz_bytes_len(&self) -> usize {
+        self.vec.ssz_bytes_len()
+    }
+
+    fn ssz_append(&self, buf: &mut Vec<u8>) {
+        self.vec.ssz_append(buf)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ssz::*;
+    use typenum::*;
+
+    #[test]
+    fn new() {
+        assert!(RuntimeVariableList::new(vec![42; 5], 4).is_err());
+        assert!(RuntimeVariableList::new(vec![42; 4], 4).is_ok());
+        assert!(RuntimeVariableList::new(vec![42; 0], 0).is_ok());
+
+        let list = RuntimeVariableList::new_truncated(vec![42; 5], 4);
+        assert_eq!(&list[..], &[42; 4]);
+        assert_eq!(list.max_len(), 4);
+    }
+
+    #[test]
+    fn push_pop() {
+        let mut list = RuntimeVariableList::empty(2);
+
+        list.push(1).unwrap();
+        list.push(2).unwrap();
+        assert_eq!(list.push(3), Err(Error::OutOfBounds { i: 3, len: 2 }));
+        assert_eq!(list.pop(), Some(2));
+        assert_eq!(&list[..], &[1]);
+    }
+
+    fn assert_matches_variable_list<T, N>(vec: Vec<T>)
+    where
+        T: Encode + Decode + TreeHash + Sync + Clone + PartialEq + std::fmt::Debug + 'static,
+        N: Unsigned + std::fmt::Debug,
+    {
+        let list = VariableList::<T, N>::new(vec.clone()).unwrap();
+        let runtime = RuntimeVariableList::new(vec, N::to_usize()).unwrap();
+
+        assert_eq!(RuntimeVariableList::from(list.clone()), runtime);
+        assert_eq!(runtime.as_ssz_bytes(), list.as_ssz_bytes());
+        assert_eq!(runtime.tree_hash_root(), list.tree_hash_root());
+        assert_eq!(
+            RuntimeVariableList::from_ssz_bytes(&list.as_ssz_bytes(), N::to_usize()),
+            Ok(runtime.clone())
+        );
+        assert_eq!(runtime.into_variable_list::<N>(), Ok(list));
+    }
+
+    #[test]
+    fn matches_variable_list() {
+        assert_matches_variable_list::<u8, U0>(vec![]);
+        assert_matches_variable_list::<u8, U13>(vec![1; 13]);
+        assert_matches_variable_list::<u64, U13>(vec![1, 2, 3]);
+        assert_matches_variable_list::<u64, U1024>(vec![]);
+        assert_matches_variable_list::<VariableList<u8, U4>, U8>(vec![
+            VariableList::new(vec![1, 2]).unwrap(),
+            VariableList::empty(),
+        ]);
+    }
+
+    #[test]
+    fn decode_too_long() {
+        let bytes = vec![0u64; 5].as_ssz_bytes();
+        assert!(RuntimeVariableList::<u64>::from_ssz_bytes(&bytes, 4).is_err());
+        assert!(RuntimeVariableList::<u64>::from_ssz_bytes(&bytes, 5).is_ok());
+
+        let bytes = vec![vec![0u8]; 5].as_ssz_bytes();
+        assert!(RuntimeVariableList::<VariableList<u8, U1>>::from_ssz_bytes(&bytes, 4).is_err());
+        assert!(RuntimeVariableList::<VariableList<u8, U1>>::from_ssz_bytes(&bytes, 5).is_ok());
+    }
+
+    #[test]
+    fn into_variable_list_too_long() {
+        let list = RuntimeVariableList::new(vec![0u64; 5], 8).unwrap();
+        assert_eq!(
+            list.into_variable_list::<U4>(),
+            Err(Error::OutOfBounds { i: 5, len: 4 })
+        );
+    }
+
+    #[test]
+    fn serialize() {
+        let list = RuntimeVariableList::new(vec![1u64, 2], 4).unwrap();
+        assert_eq!(serde_json::to_string(&list).unwrap(), "[1,2]");
+    }
+}
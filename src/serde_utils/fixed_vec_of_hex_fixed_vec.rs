@@ -0,0 +1,65 @@
+//! Serialize `FixedVector<FixedVector<u8, M>, N>` as list of 0x-prefixed hex string.
+use crate::{FixedVector, VariableList};
+use serde::{Deserializer, Serializer};
+use typenum::Unsigned;
+
+pub fn serialize<S, M, N>(
+    vector: &FixedVector<FixedVector<u8, M>, N>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    M: Unsigned,
+    N: Unsigned,
+{
+    // A list with the limit of the vector has the same elements.
+    let list = VariableList::<_, N>::new(vector.to_vec()).expect("the vector has N elements");
+    crate::serde_utils::list_of_hex_fixed_vec::serialize(&list, serializer)
+}
+
+pub fn deserialize<'de, D, M, N>(
+    deserializer: D,
+) -> Result<FixedVector<FixedVector<u8, M>, N>, D::Error>
+where
+    D: Deserializer<'de>,
+    M: Unsigned,
+    N: Unsigned,
+{
+    let list: VariableList<_, N> =
+        crate::serde_utils::list_of_hex_fixed_vec::deserialize(deserializer)?;
+    FixedVector::new(list.into())
+        .map_err(|e| serde::de::Error::custom(format!("invalid fixed vector: {:?}", e)))
+}
+
+#[cfg(test)]
+mod test {
+    use crate::FixedVector;
+    use serde_derive::{Deserialize, Serialize};
+    use typenum::{U2, U3};
+
+    #[derive(Debug, Serialize, Deserialize)]
+    struct Obj {
+        #[serde(with = "crate::serde_utils::fixed_vec_of_hex_fixed_vec")]
+        vectors: FixedVector<FixedVector<u8, U2>, U3>,
+    }
+
+    #[test]
+    fn round_trip() {
+        let obj = Obj {
+            vectors: FixedVector::new(vec![FixedVector::new(vec![0x01, 0xab]).unwrap(); 3])
+                .unwrap(),
+        };
+        let json = serde_json::to_string(&obj).unwrap();
+        assert_eq!(json, r#"{"vectors":["0x01ab","0x01ab","0x01ab"]}"#);
+        assert_eq!(
+            serde_json::from_str::<Obj>(&json).unwrap().vectors,
+            obj.vectors
+        );
+    }
+
+    #[test]
+    fn wrong_length_err() {
+        serde_json::from_str::<Obj>(r#"{"vectors":["0x01ab","0x01ab"]}"#).unwrap_err();
+        serde_json::from_str::<Obj>(r#"{"vectors":["0x01ab","0x01ab","0x01"]}"#).unwrap_err();
+    }
+}
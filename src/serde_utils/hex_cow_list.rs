@@ -0,0 +1,46 @@
+//! Serialize `CowList<u8, N>` as 0x-prefixed hex string.
+use crate::{CowList, VariableList};
+use serde::{Deserializer, Serializer};
+use typenum::Unsigned;
+
+pub fn serialize<S, N>(bytes: &CowList<u8, N>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    N: Unsigned,
+{
+    crate::serde_utils::hex_var_list::serialize(bytes, serializer)
+}
+
+pub fn deserialize<'de, D, N>(deserializer: D) -> Result<CowList<u8, N>, D::Error>
+where
+    D: Deserializer<'de>,
+    N: Unsigned,
+{
+    crate::serde_utils::hex_var_list::deserialize(deserializer).map(VariableList::into)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::CowList;
+    use serde_derive::{Deserialize, Serialize};
+    use typenum::U4;
+
+    #[derive(Debug, Serialize, Deserialize)]
+    struct Obj {
+        #[serde(with = "crate::serde_utils::hex_cow_list")]
+        bytes: CowList<u8, U4>,
+    }
+
+    #[test]
+    fn round_trip() {
+        let obj = Obj {
+            bytes: crate::VariableList::try_from(vec![0x01, 0xab])
+                .unwrap()
+                .into(),
+        };
+        let json = serde_json::to_string(&obj).unwrap();
+        assert_eq!(json, r#"{"bytes":"0x01ab"}"#);
+        assert_eq!(serde_json::from_str::<Obj>(&json).unwrap().bytes, obj.bytes);
+        serde_json::from_str::<Obj>(r#"{"bytes":"0x0102030405"}"#).unwrap_err();
+    }
+}
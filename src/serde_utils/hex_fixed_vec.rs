@@ -1,3 +1,4 @@
+//! Serialize `FixedVector<u8, N>` as 0x-prefixed hex string.
 use crate::FixedVector;
 use serde::{Deserializer, Serializer};
 use serde_utils::hex::{self, PrefixedHexVisitor};
@@ -20,3 +21,38 @@ where
     FixedVector::new(vec)
         .map_err(|e| serde::de::Error::custom(format!("invalid fixed vector: {:?}", e)))
 }
+
+#[cfg(test)]
+mod test {
+    use crate::FixedVector;
+    use serde_derive::{Deserialize, Serialize};
+    use typenum::U4;
+
+    #[derive(Debug, Serialize, Deserialize)]
+    struct Obj {
+        #[serde(with = "crate::serde_utils::hex_fixed_vec")]
+        bytes: FixedVector<u8, U4>,
+    }
+
+    #[test]
+    fn round_trip() {
+        let obj = Obj {
+            bytes: FixedVector::try_from(vec![0x01, 0x23, 0xab, 0xff]).unwrap(),
+        };
+        let json = serde_json::to_string(&obj).unwrap();
+        assert_eq!(json, r#"{"bytes":"0x0123abff"}"#);
+        assert_eq!(serde_json::from_str::<Obj>(&json).unwrap().bytes, obj.bytes);
+    }
+
+    #[test]
+    fn wrong_length_err() {
+        serde_json::from_str::<Obj>(r#"{ "bytes": "0x" }"#).unwrap_err();
+        serde_json::from_str::<Obj>(r#"{ "bytes": "0x010203" }"#).unwrap_err();
+        serde_json::from_str::<Obj>(r#"{ "bytes": "0x0102030405" }"#).unwrap_err();
+    }
+
+    #[test]
+    fn missing_prefix_err() {
+        serde_json::from_str::<Obj>(r#"{ "bytes": "01020304" }"#).unwrap_err();
+    }
+}
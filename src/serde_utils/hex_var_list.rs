@@ -21,3 +21,38 @@ where
     VariableList::new(bytes)
         .map_err(|e| serde::de::Error::custom(format!("invalid variable list: {:?}", e)))
 }
+
+#[cfg(test)]
+mod test {
+    use crate::VariableList;
+    use serde_derive::{Deserialize, Serialize};
+    use typenum::U4;
+
+    #[derive(Debug, Serialize, Deserialize)]
+    struct Obj {
+        #[serde(with = "crate::serde_utils::hex_var_list")]
+        bytes: VariableList<u8, U4>,
+    }
+
+    #[test]
+    fn round_trip() {
+        for bytes in [vec![], vec![0x01], vec![0x01, 0x23, 0xab, 0xff]] {
+            let obj = Obj {
+                bytes: VariableList::try_from(bytes).unwrap(),
+            };
+            let json = serde_json::to_string(&obj).unwrap();
+            assert_eq!(serde_json::from_str::<Obj>(&json).unwrap().bytes, obj.bytes);
+        }
+    }
+
+    #[test]
+    fn empty_list() {
+        let obj: Obj = serde_json::from_str(r#"{ "bytes": "0x" }"#).unwrap();
+        assert!(obj.bytes.is_empty());
+    }
+
+    #[test]
+    fn long_list_err() {
+        serde_json::from_str::<Obj>(r#"{ "bytes": "0x0102030405" }"#).unwrap_err();
+    }
+}
@@ -0,0 +1,54 @@
+//! Serialize `CowList<VariableList<u8, M>, N>` as list of 0x-prefixed hex string.
+use crate::{CowList, VariableList};
+use serde::{Deserializer, Serializer};
+use typenum::Unsigned;
+
+pub fn serialize<S, M, N>(
+    list: &CowList<VariableList<u8, M>, N>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    M: Unsigned,
+    N: Unsigned,
+{
+    crate::serde_utils::list_of_hex_var_list::serialize(list, serializer)
+}
+
+pub fn deserialize<'de, D, M, N>(
+    deserializer: D,
+) -> Result<CowList<VariableList<u8, M>, N>, D::Error>
+where
+    D: Deserializer<'de>,
+    M: Unsigned,
+    N: Unsigned,
+{
+    crate::serde_utils::list_of_hex_var_list::deserialize(deserializer).map(VariableList::into)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{CowList, VariableList};
+    use serde_derive::{Deserialize, Serialize};
+    use typenum::U4;
+
+    #[derive(Debug, Serialize, Deserialize)]
+    struct Obj {
+        #[serde(with = "crate::serde_utils::list_of_hex_cow_list")]
+        lists: CowList<VariableList<u8, U4>, U4>,
+    }
+
+    #[test]
+    fn round_trip() {
+        let lists = vec![
+            VariableList::try_from(vec![0x01, 0xab]).unwrap(),
+            VariableList::default(),
+        ];
+        let obj = Obj {
+            lists: VariableList::try_from(lists).unwrap().into(),
+        };
+        let json = serde_json::to_string(&obj).unwrap();
+        assert_eq!(json, r#"{"lists":["0x01ab","0x"]}"#);
+        assert_eq!(serde_json::from_str::<Obj>(&json).unwrap().lists, obj.lists);
+    }
+}
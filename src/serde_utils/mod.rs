@@ -1,6 +1,11 @@
+pub mod fixed_vec_of_hex_fixed_vec;
+pub mod hex_cow_list;
 pub mod hex_fixed_vec;
 pub mod hex_var_list;
+pub mod list_of_hex_cow_list;
 pub mod list_of_hex_fixed_vec;
 pub mod list_of_hex_var_list;
+pub mod quoted_u256;
 pub mod quoted_u64_fixed_vec;
 pub mod quoted_u64_var_list;
+pub mod quoted_u8_var_list;
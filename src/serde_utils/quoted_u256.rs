@@ -0,0 +1,111 @@
+//! Serialize a 256 bit integer held as `FixedVector<u64, U4>`, least significant limb first, as a
+//! quoted decimal string.
+//!
+//! E.g., `FixedVector::try_from(vec![1, 1, 0, 0])` serializes as `"18446744073709551617"`.
+use crate::FixedVector;
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serializer};
+use typenum::U4;
+
+pub fn serialize<S>(limbs: &FixedVector<u64, U4>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    let mut limbs = limbs.to_vec();
+    let mut digits = vec![];
+    // Divide by 10 from the most significant limb down until the integer is zero.
+    loop {
+        let mut remainder = 0_u128;
+        for limb in limbs.iter_mut().rev() {
+            let dividend = (remainder << 64) | u128::from(*limb);
+            *limb = (dividend / 10) as u64;
+            remainder = dividend % 10;
+        }
+        digits.push(b'0' + remainder as u8);
+        if limbs.iter().all(|&limb| limb == 0) {
+            break;
+        }
+    }
+    digits.reverse();
+
+    serializer.serialize_str(std::str::from_utf8(&digits).expect("digits are ascii"))
+}
+
+pub fn deserialize<'de, D>(deserializer: D) -> Result<FixedVector<u64, U4>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let string = String::deserialize(deserializer)?;
+    if string.is_empty() || !string.bytes().all(|c| c.is_ascii_digit()) {
+        return Err(D::Error::custom(format!(
+            "invalid decimal integer: {string}"
+        )));
+    }
+
+    let mut limbs = [0_u64; 4];
+    for digit in string.bytes() {
+        // Multiply by 10 and add the digit, from the least significant limb up.
+        let mut carry = u128::from(digit - b'0');
+        for limb in limbs.iter_mut() {
+            let product = u128::from(*limb) * 10 + carry;
+            *limb = product as u64;
+            carry = product >> 64;
+        }
+        if carry != 0 {
+            return Err(D::Error::custom(format!(
+                "integer exceeds 256 bits: {string}"
+            )));
+        }
+    }
+
+    Ok(FixedVector::new(limbs.to_vec()).expect("there are 4 limbs"))
+}
+
+#[cfg(test)]
+mod test {
+    use crate::FixedVector;
+    use serde_derive::{Deserialize, Serialize};
+    use typenum::U4;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Obj {
+        #[serde(with = "crate::serde_utils::quoted_u256")]
+        value: FixedVector<u64, U4>,
+    }
+
+    fn obj(limbs: [u64; 4]) -> Obj {
+        Obj {
+            value: FixedVector::new(limbs.to_vec()).unwrap(),
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        for (limbs, json) in [
+            ([0, 0, 0, 0], r#"{"value":"0"}"#),
+            ([1000, 0, 0, 0], r#"{"value":"1000"}"#),
+            ([0, 1, 0, 0], r#"{"value":"18446744073709551616"}"#),
+            (
+                [u64::MAX; 4],
+                r#"{"value":"115792089237316195423570985008687907853269984665640564039457584007913129639935"}"#,
+            ),
+        ] {
+            assert_eq!(serde_json::to_string(&obj(limbs)).unwrap(), json);
+            assert_eq!(serde_json::from_str::<Obj>(json).unwrap(), obj(limbs));
+        }
+    }
+
+    #[test]
+    fn invalid_err() {
+        for json in [
+            r#"{"value":""}"#,
+            r#"{"value":"-1"}"#,
+            r#"{"value":"0x10"}"#,
+            r#"{"value":1000}"#,
+            // 2^256.
+            r#"{"value":"115792089237316195423570985008687907853269984665640564039457584007913129639936"}"#,
+        ] {
+            serde_json::from_str::<Obj>(json).unwrap_err();
+        }
+    }
+}
@@ -0,0 +1,75 @@
+//! Formats `VariableList<u8, N>` as a list of quoted integers, as the beacon API formats the
+//! participation flags of validators.
+//!
+//! E.g., `VariableList::try_from(vec![0, 1, 7])` serializes as `["0", "1", "7"]`.
+//!
+//! Quotes are optional during decoding. If the length of the list is greater than `N`,
+//! deserialization fails.
+use crate::VariableList;
+use serde::ser::SerializeSeq;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use typenum::Unsigned;
+
+#[derive(Serialize, Deserialize)]
+#[serde(transparent)]
+struct QuotedU8(#[serde(with = "serde_utils::quoted_u8")] u8);
+
+pub fn serialize<S, N>(list: &VariableList<u8, N>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    N: Unsigned,
+{
+    let mut seq = serializer.serialize_seq(Some(list.len()))?;
+    for &int in list.iter() {
+        seq.serialize_element(&QuotedU8(int))?;
+    }
+    seq.end()
+}
+
+pub fn deserialize<'de, D, N>(deserializer: D) -> Result<VariableList<u8, N>, D::Error>
+where
+    D: Deserializer<'de>,
+    N: Unsigned,
+{
+    let ints = Vec::<QuotedU8>::deserialize(deserializer)?;
+    VariableList::new(ints.into_iter().map(|QuotedU8(int)| int).collect())
+        .map_err(|e| serde::de::Error::custom(format!("invalid variable list: {:?}", e)))
+}
+
+#[cfg(test)]
+mod test {
+    use crate::VariableList;
+    use serde_derive::{Deserialize, Serialize};
+    use typenum::U4;
+
+    #[derive(Debug, Serialize, Deserialize)]
+    struct Obj {
+        #[serde(with = "crate::serde_utils::quoted_u8_var_list")]
+        values: VariableList<u8, U4>,
+    }
+
+    #[test]
+    fn round_trip() {
+        let obj = Obj {
+            values: VariableList::try_from(vec![0, 1, 7]).unwrap(),
+        };
+        let json = serde_json::to_string(&obj).unwrap();
+        assert_eq!(json, r#"{"values":["0","1","7"]}"#);
+        assert_eq!(
+            serde_json::from_str::<Obj>(&json).unwrap().values,
+            obj.values
+        );
+        assert_eq!(
+            serde_json::from_str::<Obj>(r#"{"values":[0,"1",7]}"#)
+                .unwrap()
+                .values,
+            obj.values
+        );
+    }
+
+    #[test]
+    fn invalid_err() {
+        serde_json::from_str::<Obj>(r#"{"values":["1","2","3","4","5"]}"#).unwrap_err();
+        serde_json::from_str::<Obj>(r#"{"values":["256"]}"#).unwrap_err();
+    }
+}
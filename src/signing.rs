@@ -0,0 +1,172 @@
+use crate::hasher::hash32_concat;
+use crate::Domain;
+use ssz_derive::{Decode, Encode};
+use tree_hash::{Hash256, TreeHash};
+use tree_hash_derive::TreeHash;
+
+/// The 4 byte version of a fork.
+pub type Version = [u8; 4];
+
+/// The first 4 bytes of the root of the `ForkData` of a fork, which identifies the fork and chain
+/// of messages on the network.
+pub type ForkDigest = [u8; 4];
+
+/// The fork version and genesis validators root of a chain, whose root identifies the chain in
+/// signature domains and fork digests.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash, Debug, Encode, Decode, TreeHash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[ssz(struct_behaviour = "container")]
+pub struct ForkData {
+    #[cfg_attr(feature = "serde", serde(with = "serde_utils::bytes_4_hex"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "crate::json_schema::Hex<Version>")
+    )]
+    pub current_version: Version,
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "crate::json_schema::Hex<Hash256>")
+    )]
+    pub genesis_validators_root: Hash256,
+}
+
+impl_ssz_reflect_for_container!(ForkData, current_version, genesis_validators_root);
+
+/// The root of a signed object and the domain it is signed in. The root of the container is the
+/// signing root of the object.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash, Debug, Encode, Decode, TreeHash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[ssz(struct_behaviour = "container")]
+pub struct SigningData {
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "crate::json_schema::Hex<Hash256>")
+    )]
+    pub object_root: Hash256,
+    pub domain: Domain,
+}
+
+impl_ssz_reflect_for_container!(SigningData, object_root, domain);
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for ForkData {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Self {
+            current_version: u.arbitrary()?,
+            genesis_validators_root: Hash256::from(u.arbitrary::<[u8; 32]>()?),
+        })
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for SigningData {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Self {
+            object_root: Hash256::from(u.arbitrary::<[u8; 32]>()?),
+            domain: u.arbitrary()?,
+        })
+    }
+}
+
+/// Returns the root of the `ForkData` of `current_version` and `genesis_validators_root`, as per
+/// `compute_fork_data_root` in the consensus specs.
+pub fn compute_fork_data_root(
+    current_version: Version,
+    genesis_validators_root: Hash256,
+) -> Hash256 {
+    // Computed without the intermediate container.
+    let mut version_chunk = Hash256::zero();
+    version_chunk[..4].copy_from_slice(&current_version);
+    hash32_concat(version_chunk.as_bytes(), genesis_validators_root.as_bytes())
+}
+
+/// Returns the digest of the fork with `current_version` on the chain with
+/// `genesis_validators_root`, as per `compute_fork_digest` in the consensus specs.
+///
+/// ## Example
+///
+/// ```
+/// use ssz_types::compute_fork_digest;
+/// use tree_hash::Hash256;
+///
+/// // The genesis fork digest of mainnet.
+/// let genesis_validators_root = Hash256::from_slice(
+///     &serde_utils::hex::decode(
+///         "0x4b363db94e286120d76eb905340fdd4e54bfe9f06bf33ff6cf5ad27f511bfe95",
+///     )
+///     .unwrap(),
+/// );
+/// assert_eq!(
+///     compute_fork_digest([0, 0, 0, 0], genesis_validators_root),
+///     [0xb5, 0x30, 0x3f, 0x2a]
+/// );
+/// ```
+pub fn compute_fork_digest(
+    current_version: Version,
+    genesis_validators_root: Hash256,
+) -> ForkDigest {
+    let mut fork_digest = ForkDigest::default();
+    fork_digest
+        .copy_from_slice(&compute_fork_data_root(current_version, genesis_validators_root)[..4]);
+    fork_digest
+}
+
+/// Returns the root that is signed to sign `object` in `domain`, as per `compute_signing_root` in
+/// the consensus specs.
+pub fn compute_signing_root<T: TreeHash + ?Sized>(object: &T, domain: Domain) -> Hash256 {
+    // The root of the `SigningData` of the root of the object and the domain.
+    hash32_concat(object.tree_hash_root().as_bytes(), domain.as_bytes())
+}
+
+/// Implemented by the messages that are signed directly, rather than as part of another message.
+pub trait SignedRoot: TreeHash {
+    /// Returns the root that is signed to sign `self` in `domain`.
+    fn signing_root(&self, domain: Domain) -> Hash256 {
+        compute_signing_root(self, domain)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::compute_domain;
+
+    #[test]
+    fn fork_digest() {
+        let fork_data = ForkData {
+            current_version: [4, 0, 0, 0],
+            genesis_validators_root: Hash256::repeat_byte(3),
+        };
+        let root =
+            compute_fork_data_root(fork_data.current_version, fork_data.genesis_validators_root);
+
+        assert_eq!(root, fork_data.tree_hash_root());
+        assert_eq!(
+            compute_fork_digest(fork_data.current_version, fork_data.genesis_validators_root)[..],
+            root[..4]
+        );
+    }
+
+    #[test]
+    fn signing_root() {
+        let domain = compute_domain([1, 0, 0, 0], [0; 4], Hash256::zero());
+        let object = 42_u64;
+
+        assert_eq!(
+            compute_signing_root(&object, domain),
+            SigningData {
+                object_root: object.tree_hash_root(),
+                domain,
+            }
+            .tree_hash_root()
+        );
+    }
+}
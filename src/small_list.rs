@@ -0,0 +1,518 @@
+use crate::proof::{list_gindex_of, list_tree_hash_node, vec_gindex_of, vec_tree_hash_node};
+use crate::tree_hash::{mix_in_length, vec_tree_hash_root};
+use crate::{Error, MemorySize, MerklePath, MerkleProof, SszReflect, SszSchema, VariableList};
+use derivative::Derivative;
+use serde::de::Error as _;
+use smallvec::{Array, SmallVec};
+use std::marker::PhantomData;
+use std::ops::{Deref, DerefMut, Index, IndexMut};
+use std::slice::SliceIndex;
+use tree_hash::Hash256;
+use typenum::Unsigned;
+
+/// Emulates a SSZ `List`, storing up to `A::size()` values inline.
+///
+/// Behaves like `VariableList<A::Item, N>`, with identical SSZ encoding and tree hash root, but
+/// is backed by a `SmallVec<A>` rather than a `Vec`. Lists that never grow beyond the inline
+/// capacity of `A` do not allocate, which is useful for containers with small limits that are
+/// decoded in bulk.
+///
+/// ## Example
+///
+/// ```
+/// use ssz_types::{SmallVariableList, typenum};
+///
+/// let mut list: SmallVariableList<[u64; 2], typenum::U4> = SmallVariableList::empty();
+///
+/// list.push(1).unwrap();
+/// list.push(2).unwrap();
+/// assert!(!list.spilled());
+///
+/// // Exceeding the inline capacity moves the values to the heap.
+/// list.push(3).unwrap();
+/// assert!(list.spilled());
+///
+/// list.push(4).unwrap();
+/// assert_eq!(&list[..], &[1, 2, 3, 4]);
+///
+/// // Exceeding `N` is an error.
+/// assert!(list.push(5).is_err());
+/// ```
+#[derive(Derivative)]
+#[derivative(
+    Debug(bound = "A::Item: std::fmt::Debug"),
+    Clone(bound = "A::Item: Clone"),
+    PartialEq(bound = "A::Item: PartialEq"),
+    Eq(bound = "A::Item: Eq"),
+    Hash(bound = "A::Item: std::hash::Hash")
+)]
+pub struct SmallVariableList<A: Array, N> {
+    vec: SmallVec<A>,
+    _phantom: PhantomData<N>,
+}
+
+impl<A: Array, N: Unsigned> SmallVariableList<A, N> {
+    /// Returns `Ok` if the given `vec` does not exceed the maximum length of `Self`. Otherwise
+    /// returns `Err`.
+    pub fn new(vec: SmallVec<A>) -> Result<Self, Error> {
+        if vec.len() <= N::to_usize() {
+            Ok(Self {
+                vec,
+                _phantom: PhantomData,
+            })
+        } else {
+            Err(Error::OutOfBounds {
+                i: vec.len(),
+                len: Self::max_len(),
+            })
+        }
+    }
+
+    /// Create an empty list.
+    pub fn empty() -> Self {
+        Self {
+            vec: SmallVec::new(),
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Returns the number of values presently in `self`.
+    pub fn len(&self) -> usize {
+        self.vec.len()
+    }
+
+    /// True if `self` does not contain any values.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the type-level maximum length.
+    pub fn max_len() -> usize {
+        N::to_usize()
+    }
+
+    /// Returns the number of values that can be stored without a heap allocation.
+    pub fn inline_size() -> usize {
+        A::size()
+    }
+
+    /// True if the values of `self` have been moved to the heap.
+    pub fn spilled(&self) -> bool {
+        self.vec.spilled()
+    }
+
+    /// Appends `value` to the back of `self`.
+    ///
+    /// Returns `Err` when appending `value` would exceed the maximum length.
+    pub fn push(&mut self, value: A::Item) -> Result<(), Error> {
+        if self.vec.len() < Self::max_len() {
+            self.vec.push(value);
+            Ok(())
+        } else {
+            Err(Error::OutOfBounds {
+                i: self.vec.len() + 1,
+                len: Self::max_len(),
+            })
+        }
+    }
+
+    /// Removes the last value from `self` and returns it, or `None` if `self` is empty.
+    pub fn pop(&mut self) -> Option<A::Item> {
+        self.vec.pop()
+    }
+
+    /// Shortens `self` to `len` values, dropping the rest.
+    ///
+    /// Has no effect if `len` is greater than or equal to `self.len()`.
+    pub fn truncate(&mut self, len: usize) {
+        self.vec.truncate(len)
+    }
+}
+
+impl<A: Array, N: Unsigned> TryFrom<Vec<A::Item>> for SmallVariableList<A, N> {
+    type Error = Error;
+
+    fn try_from(vec: Vec<A::Item>) -> Result<Self, Error> {
+        Self::new(SmallVec::from_vec(vec))
+    }
+}
+
+impl<A: Array, N: Unsigned> From<SmallVariableList<A, N>> for Vec<A::Item> {
+    fn from(list: SmallVariableList<A, N>) -> Vec<A::Item> {
+        list.vec.into_vec()
+    }
+}
+
+impl<A: Array, N: Unsigned> From<VariableList<A::Item, N>> for SmallVariableList<A, N> {
+    fn from(list: VariableList<A::Item, N>) -> Self {
+        Self {
+            vec: SmallVec::from_vec(list.into()),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<A: Array, N: Unsigned> From<SmallVariableList<A, N>> for VariableList<A::Item, N> {
+    fn from(list: SmallVariableList<A, N>) -> Self {
+        VariableList::new_truncated(list.into())
+    }
+}
+
+impl<A: Array, N: Unsigned> Default for SmallVariableList<A, N> {
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+impl<A: Array, N: Unsigned, I: SliceIndex<[A::Item]>> Index<I> for SmallVariableList<A, N> {
+    type Output = I::Output;
+
+    #[inline]
+    fn index(&self, index: I) -> &Self::Output {
+        Index::index(&self.vec[..], index)
+    }
+}
+
+impl<A: Array, N: Unsigned, I: SliceIndex<[A::Item]>> IndexMut<I> for SmallVariableList<A, N> {
+    #[inline]
+    fn index_mut(&mut self, index: I) -> &mut Self::Output {
+        IndexMut::index_mut(&mut self.vec[..], index)
+    }
+}
+
+impl<A: Array, N: Unsigned> Deref for SmallVariableList<A, N> {
+    type Target = [A::Item];
+
+    fn deref(&self) -> &[A::Item] {
+        &self.vec[..]
+    }
+}
+
+impl<A: Array, N: Unsigned> DerefMut for SmallVariableList<A, N> {
+    fn deref_mut(&mut self) -> &mut [A::Item] {
+        &mut self.vec[..]
+    }
+}
+
+impl<'a, A: Array, N: Unsigned> IntoIterator for &'a SmallVariableList<A, N> {
+    type Item = &'a A::Item;
+    type IntoIter = std::slice::Iter<'a, A::Item>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, A: Array, N: Unsigned> IntoIterator for &'a mut SmallVariableList<A, N> {
+    type Item = &'a mut A::Item;
+    type IntoIter = std::slice::IterMut<'a, A::Item>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<A: Array, N: Unsigned> IntoIterator for SmallVariableList<A, N> {
+    type Item = A::Item;
+    type IntoIter = smallvec::IntoIter<A>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.vec.into_iter()
+    }
+}
+
+impl<A: Array, N: Unsigned> MemorySize for SmallVariableList<A, N>
+where
+    A::Item: MemorySize,
+{
+    fn heap_size(&self) -> usize {
+        let allocation = if self.vec.spilled() {
+            self.vec.capacity() * std::mem::size_of::<A::Item>()
+        } else {
+            0
+        };
+        allocation + self.iter().map(MemorySize::heap_size).sum::<usize>()
+    }
+}
+
+impl<A: Array, N: Unsigned> MerkleProof for SmallVariableList<A, N>
+where
+    A::Item: tree_hash::TreeHash + MerkleProof,
+{
+    fn tree_hash_node(&self, gindex: u64) -> Result<Hash256, Error> {
+        list_tree_hash_node(gindex, self.len(), |gindex| {
+            vec_tree_hash_node(&self.vec, N::to_usize(), gindex)
+        })
+    }
+}
+
+impl<A: Array, N: Unsigned> MerklePath for SmallVariableList<A, N>
+where
+    A::Item: tree_hash::TreeHash + MerklePath,
+{
+    fn gindex_of(path: &[&str]) -> Result<u64, Error> {
+        list_gindex_of(path, |path| vec_gindex_of::<A::Item>(N::to_usize(), path))
+    }
+}
+
+impl<A: Array, N: Unsigned> SszReflect for SmallVariableList<A, N>
+where
+    A::Item: SszReflect,
+{
+    fn ssz_schema() -> SszSchema {
+        SszSchema::list(A::Item::ssz_schema(), N::to_usize())
+    }
+}
+
+impl<A: Array, N: Unsigned> serde::Serialize for SmallVariableList<A, N>
+where
+    A::Item: serde::Serialize,
+{
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+impl<'de, A: Array, N: Unsigned> serde::Deserialize<'de> for SmallVariableList<A, N>
+where
+    A::Item: serde::Deserialize<'de>,
+{
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let vec = Vec::<A::Item>::deserialize(deserializer)?;
+        Self::try_from(vec).map_err(|e| D::Error::custom(format!("{:?}", e)))
+    }
+}
+
+impl<A: Array, N: Unsigned> tree_hash::TreeHash for SmallVariableList<A, N>
+where
+    A::Item: tree_hash::TreeHash,
+{
+    fn tree_hash_type() -> tree_hash::TreeHashType {
+        tree_hash::TreeHashType::List
+    }
+
+    fn tree_hash_packed_encoding(&self) -> tree_hash::PackedEncoding {
+        unreachable!("List should never be packed.")
+    }
+
+    fn tree_hash_packing_factor() -> usize {
+        unreachable!("List should never be packed.")
+    }
+
+    fn tree_hash_root(&self) -> Hash256 {
+        let root = vec_tree_hash_root::<A::Item, N>(&self.vec);
+
+        mix_in_length(&root, self.len())
+    }
+}
+
+impl<A: Array, N: Unsigned> ssz::Encode for SmallVariableList<A, N>
+where
+    A::Item: ssz::Encode,
+{
+    fn is_ssz_fixed_len() -> bool {
+        false
+    }
+
+    fn ssz_bytes_len(&self) -> usize {
+        if <A::Item as ssz::Encode>::is_ssz_fixed_len() {
+            <A::Item as ssz::Encode>::ssz_fixed_len() * self.len()
+        } else {
+            self.iter()
+                .map(|item| item.ssz_bytes_len() + ssz::BYTES_PER_LENGTH_OFFSET)
+                .sum()
+        }
+    }
+
+    fn ssz_append(&self, buf: &mut Vec<u8>) {
+        if <A::Item as ssz::Encode>::is_ssz_fixed_len() {
+            buf.reserve(<A::Item as ssz::Encode>::ssz_fixed_len() * self.len());
+
+            for item in self.iter() {
+                item.ssz_append(buf);
+            }
+        } else {
+            let mut encoder =
+                ssz::SszEncoder::container(buf, self.len() * ssz::BYTES_PER_LENGTH_OFFSET);
+
+            for item in self.iter() {
+                encoder.append(item);
+            }
+
+            encoder.finalize();
+        }
+    }
+}
+
+impl<A: Array, N: Unsigned> ssz::TryFromIter<A::Item> for SmallVariableList<A, N> {
+    type Error = Error;
+
+    fn try_from_iter<I>(value: I) -> Result<Self, Self::Error>
+    where
+        I: IntoIterator<Item = A::Item>,
+    {
+        let mut l = Self::empty();
+        for item in value {
+            l.push(item)?;
+        }
+        Ok(l)
+    }
+}
+
+impl<A: Array, N: Unsigned> ssz::Decode for SmallVariableList<A, N>
+where
+    A::Item: ssz::Decode,
+{
+    fn is_ssz_fixed_len() -> bool {
+        false
+    }
+
+    fn from_ssz_bytes(bytes: &[u8]) -> Result<Self, ssz::DecodeError> {
+        let max_len = N::to_usize();
+
+        if bytes.is_empty() {
+            Ok(Self::empty())
+        } else if <A::Item as ssz::Decode>::is_ssz_fixed_len() {
+            let item_len = <A::Item as ssz::Decode>::ssz_fixed_len();
+            let num_items = bytes
+                .len()
+                .checked_div(item_len)
+                .ok_or(ssz::DecodeError::ZeroLengthItem)?;
+
+            if num_items > max_len {
+                return Err(ssz::DecodeError::BytesInvalid(format!(
+                    "SmallVariableList of {} items exceeds maximum of {}",
+                    num_items, max_len
+                )));
+            }
+
+            bytes
+                .chunks(item_len)
+                .map(A::Item::from_ssz_bytes)
+                .collect::<Result<SmallVec<A>, _>>()
+                .map(|vec| Self {
+                    vec,
+                    _phantom: PhantomData,
+                })
+        } else {
+            ssz::decode_list_of_variable_length_items(bytes, Some(max_len))
+        }
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a, A, N> arbitrary::Arbitrary<'a> for SmallVariableList<A, N>
+where
+    A: Array,
+    A::Item: arbitrary::Arbitrary<'a>,
+    N: 'static + Unsigned,
+{
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        VariableList::<A::Item, N>::arbitrary(u).map(Self::from)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ssz::*;
+    use tree_hash::TreeHash;
+    use typenum::*;
+
+    type List<T> = SmallVariableList<[T; 2], U4>;
+
+    #[test]
+    fn new() {
+        assert!(List::<u64>::try_from(vec![42; 5]).is_err());
+        assert!(List::<u64>::try_from(vec![42; 4]).is_ok());
+        assert!(List::<u64>::try_from(vec![]).is_ok());
+    }
+
+    #[test]
+    fn inline_storage() {
+        let mut list = List::<u64>::empty();
+        assert_eq!(List::<u64>::inline_size(), 2);
+
+        list.push(0).unwrap();
+        list.push(1).unwrap();
+        assert!(!list.spilled());
+
+        list.push(2).unwrap();
+        assert!(list.spilled());
+        assert_eq!(list.pop(), Some(2));
+
+        let decoded = List::<u64>::from_ssz_bytes(&list.as_ssz_bytes()).unwrap();
+        assert!(!decoded.spilled());
+        assert_eq!(decoded, list);
+    }
+
+    #[test]
+    fn push_pop_truncate() {
+        let mut list = List::<u64>::empty();
+
+        for i in 0..4 {
+            list.push(i).unwrap();
+        }
+        assert_eq!(list.push(4), Err(Error::OutOfBounds { i: 5, len: 4 }));
+        assert_eq!(&list[..], &[0, 1, 2, 3]);
+
+        assert_eq!(list.pop(), Some(3));
+        list.truncate(1);
+        assert_eq!(&list[..], &[0]);
+    }
+
+    fn assert_matches_variable_list<T>(vec: Vec<T>)
+    where
+        T: Encode + Decode + TreeHash + Sync + Clone + PartialEq + std::fmt::Debug,
+    {
+        let small = List::<T>::try_from(vec.clone()).unwrap();
+        let list = VariableList::<T, U4>::try_from(vec).unwrap();
+
+        assert_eq!(small.as_ssz_bytes(), list.as_ssz_bytes());
+        assert_eq!(small.ssz_bytes_len(), list.ssz_bytes_len());
+        assert_eq!(small.tree_hash_root(), list.tree_hash_root());
+        assert_eq!(List::<T>::from_ssz_bytes(&list.as_ssz_bytes()), Ok(small));
+    }
+
+    #[test]
+    fn matches_variable_list() {
+        assert_matches_variable_list::<u16>(vec![]);
+        assert_matches_variable_list::<u16>(vec![1, 2, 3]);
+        assert_matches_variable_list::<u64>(vec![u64::MAX; 4]);
+        assert_matches_variable_list::<VariableList<u8, U2>>(vec![]);
+        assert_matches_variable_list::<VariableList<u8, U2>>(vec![
+            VariableList::empty(),
+            VariableList::try_from(vec![1, 2]).unwrap(),
+            VariableList::try_from(vec![3]).unwrap(),
+        ]);
+    }
+
+    #[test]
+    fn decode_too_long() {
+        let bytes = vec![0u64; 5].as_ssz_bytes();
+        assert!(List::<u64>::from_ssz_bytes(&bytes).is_err());
+
+        let bytes = vec![vec![0u8]; 5].as_ssz_bytes();
+        assert!(List::<Vec<u8>>::from_ssz_bytes(&bytes).is_err());
+    }
+
+    #[test]
+    fn variable_list_conversion() {
+        let list = VariableList::<u64, U4>::try_from(vec![1, 2, 3]).unwrap();
+        let small = List::<u64>::from(list.clone());
+
+        assert_eq!(&small[..], &list[..]);
+        assert_eq!(VariableList::from(small), list);
+    }
+
+    #[test]
+    fn serde() {
+        let list = List::<u64>::try_from(vec![1, 2, 3]).unwrap();
+
+        let json = serde_json::to_string(&list).unwrap();
+        assert_eq!(json, "[1,2,3]");
+        assert_eq!(serde_json::from_str::<List<u64>>(&json).unwrap(), list);
+
+        assert!(serde_json::from_str::<List<u64>>("[1,2,3,4,5]").is_err());
+    }
+}
@@ -0,0 +1,97 @@
+use ssz::{Decode, DecodeError, Encode};
+
+/// Returns the SSZ of `value` compressed with raw snappy, as it is published on gossip.
+///
+/// ## Panics
+///
+/// Panics if the SSZ is longer than snappy can compress, about 4 GiB.
+pub fn encode_snappy<T: Encode>(value: &T) -> Vec<u8> {
+    snap::raw::Encoder::new()
+        .compress_vec(&value.as_ssz_bytes())
+        .expect("the SSZ is shorter than the maximum length of snappy")
+}
+
+/// Decodes a value from its SSZ compressed with raw snappy, as received on gossip.
+///
+/// `max_len` bounds the length of the decompressed SSZ, e.g. to `GOSSIP_MAX_SIZE`. It is checked
+/// against the length declared by the snappy header before anything is decompressed, so a small
+/// message cannot allocate an arbitrarily large buffer.
+///
+/// ## Example
+///
+/// ```
+/// use ssz_types::{decode_snappy, encode_snappy, Slot, GOSSIP_MAX_SIZE};
+///
+/// let bytes = encode_snappy(&Slot::new(42));
+/// assert_eq!(decode_snappy(&bytes, GOSSIP_MAX_SIZE), Ok(Slot::new(42)));
+/// assert!(decode_snappy::<Slot>(&bytes, 4).is_err());
+/// ```
+pub fn decode_snappy<T: Decode>(bytes: &[u8], max_len: usize) -> Result<T, DecodeError> {
+    let len = snap::raw::decompress_len(bytes)
+        .map_err(|e| DecodeError::BytesInvalid(format!("Invalid snappy: {}", e)))?;
+    if len > max_len {
+        return Err(DecodeError::BytesInvalid(format!(
+            "Decompressed length {} exceeds the maximum of {}",
+            len, max_len
+        )));
+    }
+
+    let ssz_bytes = snap::raw::Decoder::new()
+        .decompress_vec(bytes)
+        .map_err(|e| DecodeError::BytesInvalid(format!("Invalid snappy: {}", e)))?;
+    T::from_ssz_bytes(&ssz_bytes)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Mainnet, SignedBeaconBlockCapella, GOSSIP_MAX_SIZE};
+
+    #[test]
+    fn round_trip() {
+        let bytes = std::fs::read("state.ssz").unwrap();
+        let block = SignedBeaconBlockCapella::<Mainnet>::from_ssz_bytes(&bytes).unwrap();
+
+        let compressed = encode_snappy(&block);
+        assert!(compressed.len() < bytes.len());
+        assert_eq!(snap::raw::decompress_len(&compressed).unwrap(), bytes.len());
+        assert_eq!(
+            decode_snappy::<SignedBeaconBlockCapella<Mainnet>>(&compressed, GOSSIP_MAX_SIZE),
+            Ok(block.clone())
+        );
+        assert_eq!(
+            decode_snappy::<SignedBeaconBlockCapella<Mainnet>>(&compressed, bytes.len()),
+            Ok(block)
+        );
+    }
+
+    #[test]
+    fn invalid_bytes() {
+        let compressed = encode_snappy(&vec![7_u64; 1000]);
+
+        // The length is checked before decompressing.
+        assert_eq!(
+            decode_snappy::<Vec<u64>>(&compressed, 7999),
+            Err(DecodeError::BytesInvalid(
+                "Decompressed length 8000 exceeds the maximum of 7999".into()
+            ))
+        );
+        assert!(matches!(
+            decode_snappy::<Vec<u64>>(&compressed[..compressed.len() - 1], 8000),
+            Err(DecodeError::BytesInvalid(_))
+        ));
+        // A header declaring 2^32 bytes, without any data.
+        assert!(matches!(
+            decode_snappy::<Vec<u64>>(&[0x80, 0x80, 0x80, 0x80, 0x10], usize::MAX),
+            Err(DecodeError::BytesInvalid(_))
+        ));
+        // Valid snappy of invalid SSZ.
+        assert!(matches!(
+            decode_snappy::<u64>(&encode_snappy(&7_u32), 8),
+            Err(DecodeError::InvalidByteLength {
+                len: 4,
+                expected: 8
+            })
+        ));
+    }
+}
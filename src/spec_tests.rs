@@ -0,0 +1,478 @@
+//! Runs the `ssz_static` cases of the [consensus spec
+//! tests](https://github.com/ethereum/consensus-spec-tests) against the containers of the crate.
+//!
+//! Each case of a type holds a random or zeroed value of the type as `serialized.ssz_snappy`, its
+//! tree hash root in `roots.yaml`, and the value itself in `value.yaml`. A case passes if the SSZ
+//! bytes decode, encode back to the same bytes, hash to the root and match the YAML value.
+//!
+//! `run_ssz_static` walks the `tests/<preset>/<fork>/ssz_static/<type>/<suite>/<case>`
+//! directories of an extracted release, runs the cases of every type defined by the crate and
+//! reports the types it does not cover.
+//!
+//! ## Example
+//!
+//! ```no_run
+//! use ssz_types::spec_tests::run_ssz_static;
+//!
+//! let report = run_ssz_static("consensus-spec-tests").unwrap();
+//! println!("{report}");
+//! assert!(report.is_success());
+//! ```
+
+use crate::aggregate_and_proof::{
+    AggregateAndProof, AggregateAndProofElectra, SignedAggregateAndProof,
+    SignedAggregateAndProofElectra,
+};
+use crate::beacon_block::*;
+use crate::beacon_state::*;
+use crate::p2p::BlobIdentifier;
+use crate::signing::{ForkData, SigningData};
+use crate::sync_committee::*;
+use crate::{from_yaml_slice, ForkName, Mainnet, Minimal, Preset};
+use serde::de::DeserializeOwned;
+use ssz::{Decode, Encode};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::{self, Debug};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use tree_hash::{Hash256, TreeHash};
+
+/// A type of the consensus specs, in a preset and fork.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct SpecType {
+    pub preset: String,
+    pub fork: String,
+    pub name: String,
+}
+
+impl fmt::Display for SpecType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}/{}", self.preset, self.fork, self.name)
+    }
+}
+
+/// A case which did not pass, with the reason it failed.
+#[derive(Clone, PartialEq, Debug)]
+pub struct CaseFailure {
+    pub case: PathBuf,
+    pub reason: String,
+}
+
+/// The outcome of the cases of a type.
+#[derive(Clone, Default, PartialEq, Debug)]
+pub struct TypeOutcome {
+    pub passed: usize,
+    pub failures: Vec<CaseFailure>,
+}
+
+/// The outcome of the `ssz_static` cases of a directory of consensus spec tests.
+#[derive(Clone, Default, PartialEq, Debug)]
+pub struct SszStaticReport {
+    /// The types defined by the crate, with the outcome of their cases.
+    pub covered: BTreeMap<SpecType, TypeOutcome>,
+    /// The types of the spec tests which the crate does not define, or whose preset or fork it
+    /// does not know.
+    pub uncovered: BTreeSet<SpecType>,
+}
+
+impl SszStaticReport {
+    /// Returns the number of cases which passed.
+    pub fn passed(&self) -> usize {
+        self.covered.values().map(|outcome| outcome.passed).sum()
+    }
+
+    /// Returns every case which failed, with its type.
+    pub fn failures(&self) -> impl Iterator<Item = (&SpecType, &CaseFailure)> {
+        self.covered
+            .iter()
+            .flat_map(|(ty, outcome)| outcome.failures.iter().map(move |failure| (ty, failure)))
+    }
+
+    /// Returns `true` if every case of the covered types passed.
+    pub fn is_success(&self) -> bool {
+        self.failures().next().is_none()
+    }
+}
+
+/// Summarizes the coverage of every preset and fork, then lists the failed cases.
+impl fmt::Display for SszStaticReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut forks = BTreeMap::<_, (usize, usize, usize, Vec<&str>)>::new();
+        for (ty, outcome) in &self.covered {
+            let fork = forks.entry((&ty.preset, &ty.fork)).or_default();
+            fork.0 += 1;
+            fork.1 += outcome.passed;
+            fork.2 += outcome.failures.len();
+        }
+        for ty in &self.uncovered {
+            let fork = forks.entry((&ty.preset, &ty.fork)).or_default();
+            fork.3.push(&ty.name);
+        }
+
+        for ((preset, fork), (types, passed, failed, uncovered)) in forks {
+            write!(
+                f,
+                "{preset}/{fork}: {types} types covered, {passed} cases passed, {failed} failed"
+            )?;
+            if !uncovered.is_empty() {
+                write!(f, "; not covered: {}", uncovered.join(", "))?;
+            }
+            writeln!(f)?;
+        }
+        for (ty, failure) in self.failures() {
+            writeln!(
+                f,
+                "FAILED {ty} {}: {}",
+                failure.case.display(),
+                failure.reason
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Runs the `ssz_static` cases of `dir`, the root of an extracted consensus spec tests release
+/// which holds the `tests` directory.
+///
+/// Returns `Err` if the directories cannot be read. The failures of the cases themselves are
+/// collected in the report.
+pub fn run_ssz_static(dir: impl AsRef<Path>) -> io::Result<SszStaticReport> {
+    let mut report = SszStaticReport::default();
+
+    for preset_dir in sub_dirs(&dir.as_ref().join("tests"))? {
+        let preset = file_name(&preset_dir);
+        for fork_dir in sub_dirs(&preset_dir)? {
+            let ssz_static_dir = fork_dir.join("ssz_static");
+            if !ssz_static_dir.is_dir() {
+                continue;
+            }
+            let fork_name = file_name(&fork_dir);
+            let fork = ForkName::ALL
+                .into_iter()
+                .find(|fork| fork.as_str() == fork_name);
+
+            for type_dir in sub_dirs(&ssz_static_dir)? {
+                let ty = SpecType {
+                    preset: preset.clone(),
+                    fork: fork_name.clone(),
+                    name: file_name(&type_dir),
+                };
+                let outcome = match (preset.as_str(), fork) {
+                    ("mainnet", Some(fork)) => run_spec_type::<Mainnet>(fork, &ty.name, &type_dir),
+                    ("minimal", Some(fork)) => run_spec_type::<Minimal>(fork, &ty.name, &type_dir),
+                    _ => None,
+                };
+                match outcome {
+                    Some(outcome) => {
+                        report.covered.insert(ty, outcome?);
+                    }
+                    None => {
+                        report.uncovered.insert(ty);
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(report)
+}
+
+/// Runs the cases of the type `name` of `fork`, or returns `None` if the crate does not define it.
+fn run_spec_type<P: Preset>(
+    fork: ForkName,
+    name: &str,
+    type_dir: &Path,
+) -> Option<io::Result<TypeOutcome>> {
+    use ForkName::*;
+
+    macro_rules! run {
+        ($ty:ty) => {
+            run_type::<$ty>(type_dir)
+        };
+    }
+
+    let outcome = match (fork, name) {
+        (Phase0, "BeaconBlock") => run!(BeaconBlockPhase0<P>),
+        (Altair, "BeaconBlock") => run!(BeaconBlockAltair<P>),
+        (Bellatrix, "BeaconBlock") => run!(BeaconBlockBellatrix<P>),
+        (Capella, "BeaconBlock") => run!(BeaconBlockCapella<P>),
+        (Deneb, "BeaconBlock") => run!(BeaconBlockDeneb<P>),
+        (Electra, "BeaconBlock") => run!(BeaconBlockElectra<P>),
+        (Phase0, "BeaconBlockBody") => run!(BeaconBlockBodyPhase0<P>),
+        (Altair, "BeaconBlockBody") => run!(BeaconBlockBodyAltair<P>),
+        (Bellatrix, "BeaconBlockBody") => run!(BeaconBlockBodyBellatrix<P>),
+        (Capella, "BeaconBlockBody") => run!(BeaconBlockBodyCapella<P>),
+        (Deneb, "BeaconBlockBody") => run!(BeaconBlockBodyDeneb<P>),
+        (Electra, "BeaconBlockBody") => run!(BeaconBlockBodyElectra<P>),
+        (Phase0, "SignedBeaconBlock") => run!(SignedBeaconBlockPhase0<P>),
+        (Altair, "SignedBeaconBlock") => run!(SignedBeaconBlockAltair<P>),
+        (Bellatrix, "SignedBeaconBlock") => run!(SignedBeaconBlockBellatrix<P>),
+        (Capella, "SignedBeaconBlock") => run!(SignedBeaconBlockCapella<P>),
+        (Deneb, "SignedBeaconBlock") => run!(SignedBeaconBlockDeneb<P>),
+        (Electra, "SignedBeaconBlock") => run!(SignedBeaconBlockElectra<P>),
+        (Phase0, "BeaconState") => run!(BeaconStatePhase0<P>),
+        (Altair, "BeaconState") => run!(BeaconStateAltair<P>),
+        (Bellatrix, "BeaconState") => run!(BeaconStateBellatrix<P>),
+        (Capella, "BeaconState") => run!(BeaconStateCapella<P>),
+        (Deneb, "BeaconState") => run!(BeaconStateDeneb<P>),
+        (Electra, "BeaconState") => run!(BeaconStateElectra<P>),
+        (Bellatrix, "ExecutionPayload") => run!(ExecutionPayloadBellatrix<P>),
+        (Capella, "ExecutionPayload") => run!(ExecutionPayloadCapella<P>),
+        (Deneb | Electra, "ExecutionPayload") => run!(ExecutionPayloadDeneb<P>),
+        (Bellatrix, "ExecutionPayloadHeader") => run!(ExecutionPayloadHeaderBellatrix<P>),
+        (Capella, "ExecutionPayloadHeader") => run!(ExecutionPayloadHeaderCapella<P>),
+        (Deneb | Electra, "ExecutionPayloadHeader") => run!(ExecutionPayloadHeaderDeneb<P>),
+        (Electra, "Attestation") => run!(AttestationElectra<P>),
+        (_, "Attestation") => run!(Attestation<P>),
+        (Electra, "AttesterSlashing") => run!(AttesterSlashingElectra<P>),
+        (_, "AttesterSlashing") => run!(AttesterSlashing<P>),
+        (Electra, "IndexedAttestation") => run!(IndexedAttestationElectra<P>),
+        (_, "IndexedAttestation") => run!(IndexedAttestation<P>),
+        (Electra, "AggregateAndProof") => run!(AggregateAndProofElectra<P>),
+        (_, "AggregateAndProof") => run!(AggregateAndProof<P>),
+        (Electra, "SignedAggregateAndProof") => run!(SignedAggregateAndProofElectra<P>),
+        (_, "SignedAggregateAndProof") => run!(SignedAggregateAndProof<P>),
+        (_, "AttestationData") => run!(AttestationData),
+        (_, "BeaconBlockHeader") => run!(BeaconBlockHeader),
+        (_, "BlobIdentifier") => run!(BlobIdentifier),
+        (_, "BlobSidecar") => run!(BlobSidecar<P>),
+        (_, "BLSToExecutionChange") => run!(BlsToExecutionChange),
+        (_, "Checkpoint") => run!(Checkpoint),
+        (_, "ConsolidationRequest") => run!(ConsolidationRequest),
+        (_, "ContributionAndProof") => run!(ContributionAndProof<P>),
+        (_, "Deposit") => run!(Deposit),
+        (_, "DepositData") => run!(DepositData),
+        (_, "DepositMessage") => run!(DepositMessage),
+        (_, "DepositRequest") => run!(DepositRequest),
+        (_, "Eth1Data") => run!(Eth1Data),
+        (_, "ExecutionRequests") => run!(ExecutionRequests<P>),
+        (_, "Fork") => run!(Fork),
+        (_, "ForkData") => run!(ForkData),
+        (_, "HistoricalBatch") => run!(HistoricalBatch<P>),
+        (_, "HistoricalSummary") => run!(HistoricalSummary),
+        (_, "PendingAttestation") => run!(PendingAttestation<P>),
+        (_, "PendingConsolidation") => run!(PendingConsolidation),
+        (_, "PendingDeposit") => run!(PendingDeposit),
+        (_, "PendingPartialWithdrawal") => run!(PendingPartialWithdrawal),
+        (_, "ProposerSlashing") => run!(ProposerSlashing),
+        (_, "SignedBeaconBlockHeader") => run!(SignedBeaconBlockHeader),
+        (_, "SignedBLSToExecutionChange") => run!(SignedBlsToExecutionChange),
+        (_, "SignedContributionAndProof") => run!(SignedContributionAndProof<P>),
+        (_, "SignedVoluntaryExit") => run!(SignedVoluntaryExit),
+        (_, "SigningData") => run!(SigningData),
+        (_, "SyncAggregate") => run!(SyncAggregate<P>),
+        (_, "SyncAggregatorSelectionData") => run!(SyncAggregatorSelectionData),
+        (_, "SyncCommittee") => run!(SyncCommittee<P>),
+        (_, "SyncCommitteeContribution") => run!(SyncCommitteeContribution<P>),
+        (_, "SyncCommitteeMessage") => run!(SyncCommitteeMessage),
+        (_, "Validator") => run!(Validator),
+        (_, "VoluntaryExit") => run!(VoluntaryExit),
+        (_, "Withdrawal") => run!(Withdrawal),
+        (_, "WithdrawalRequest") => run!(WithdrawalRequest),
+        _ => return None,
+    };
+    Some(outcome)
+}
+
+/// Runs every case of every suite, e.g. `ssz_random`, of a type.
+fn run_type<T>(type_dir: &Path) -> io::Result<TypeOutcome>
+where
+    T: Encode + Decode + TreeHash + DeserializeOwned + PartialEq + Debug,
+{
+    let mut outcome = TypeOutcome::default();
+    for suite_dir in sub_dirs(type_dir)? {
+        for case in sub_dirs(&suite_dir)? {
+            match run_case::<T>(&case) {
+                Ok(()) => outcome.passed += 1,
+                Err(reason) => outcome.failures.push(CaseFailure { case, reason }),
+            }
+        }
+    }
+    Ok(outcome)
+}
+
+#[derive(serde_derive::Deserialize)]
+struct Roots {
+    root: Hash256,
+}
+
+fn run_case<T>(case: &Path) -> Result<(), String>
+where
+    T: Encode + Decode + TreeHash + DeserializeOwned + PartialEq + Debug,
+{
+    let read =
+        |file: &str| fs::read(case.join(file)).map_err(|e| format!("cannot read {file}: {e}"));
+
+    let bytes = snap::raw::Decoder::new()
+        .decompress_vec(&read("serialized.ssz_snappy")?)
+        .map_err(|e| format!("invalid snappy: {e}"))?;
+    let value = T::from_ssz_bytes(&bytes).map_err(|e| format!("invalid SSZ: {e:?}"))?;
+    if value.as_ssz_bytes() != bytes {
+        return Err("SSZ encoding differs from serialized.ssz_snappy".into());
+    }
+
+    let roots: Roots =
+        from_yaml_slice(&read("roots.yaml")?).map_err(|e| format!("invalid roots.yaml: {e:?}"))?;
+    let root = value.tree_hash_root();
+    if root != roots.root {
+        return Err(format!(
+            "tree hash root {root:?} differs from roots.yaml {:?}",
+            roots.root
+        ));
+    }
+
+    let yaml_value: T =
+        from_yaml_slice(&read("value.yaml")?).map_err(|e| format!("invalid value.yaml: {e:?}"))?;
+    if yaml_value != value {
+        return Err("value.yaml differs from serialized.ssz_snappy".into());
+    }
+
+    Ok(())
+}
+
+/// Returns the directories in `dir`, sorted so that reports are deterministic.
+fn sub_dirs(dir: &Path) -> io::Result<Vec<PathBuf>> {
+    let mut dirs = vec![];
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            dirs.push(path);
+        }
+    }
+    dirs.sort();
+    Ok(dirs)
+}
+
+fn file_name(path: &Path) -> String {
+    path.file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Epoch;
+
+    /// Writes a case of `value` to `dir`, with `root` as its root.
+    fn write_case<T: Encode + serde::Serialize>(dir: &Path, value: &T, root: Hash256) {
+        fs::create_dir_all(dir).unwrap();
+        let serialized = snap::raw::Encoder::new()
+            .compress_vec(&value.as_ssz_bytes())
+            .unwrap();
+        fs::write(dir.join("serialized.ssz_snappy"), serialized).unwrap();
+        fs::write(dir.join("roots.yaml"), format!("{{root: '{root:?}'}}\n")).unwrap();
+        fs::write(
+            dir.join("value.yaml"),
+            serde_yaml::to_string(value).unwrap(),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn ssz_static() {
+        let dir = std::env::temp_dir().join(format!("ssz_types_spec_tests_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        let tests = dir.join("tests");
+
+        let checkpoint = Checkpoint {
+            epoch: Epoch::new(7),
+            root: H256::from_elem(0x11),
+        };
+        let ssz_static = tests.join("minimal/phase0/ssz_static");
+        write_case(
+            &ssz_static.join("Checkpoint/ssz_random/case_0"),
+            &checkpoint,
+            checkpoint.tree_hash_root(),
+        );
+        write_case(
+            &ssz_static.join("Checkpoint/ssz_random/case_1"),
+            &checkpoint,
+            Hash256::zero(),
+        );
+        let fork = Fork::default();
+        write_case(
+            &ssz_static.join("Fork/ssz_zero/case_0"),
+            &fork,
+            fork.tree_hash_root(),
+        );
+        // Types the crate does not define, and forks it does not know, are not covered.
+        fs::create_dir_all(ssz_static.join("Eth1Block/ssz_zero/case_0")).unwrap();
+        fs::create_dir_all(tests.join("minimal/fulu/ssz_static/Fork/ssz_zero/case_0")).unwrap();
+        // Other runners are skipped.
+        fs::create_dir_all(tests.join("minimal/phase0/operations/deposit")).unwrap();
+
+        // The Electra attestation differs from the phase0 attestation.
+        let attestation = AttestationElectra::<Mainnet>::default();
+        write_case(
+            &tests.join("mainnet/electra/ssz_static/Attestation/ssz_zero/case_0"),
+            &attestation,
+            attestation.tree_hash_root(),
+        );
+
+        let report = run_ssz_static(&dir).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+
+        let spec_type = |preset: &str, fork: &str, name: &str| SpecType {
+            preset: preset.into(),
+            fork: fork.into(),
+            name: name.into(),
+        };
+        assert_eq!(report.covered.len(), 3);
+        assert_eq!(report.passed(), 3);
+        assert_eq!(
+            report.uncovered,
+            BTreeSet::from([
+                spec_type("minimal", "fulu", "Fork"),
+                spec_type("minimal", "phase0", "Eth1Block"),
+            ])
+        );
+
+        let failures = report.failures().collect::<Vec<_>>();
+        assert_eq!(failures.len(), 1);
+        assert_eq!(*failures[0].0, spec_type("minimal", "phase0", "Checkpoint"));
+        assert!(failures[0].1.case.ends_with("ssz_random/case_1"));
+        assert!(failures[0].1.reason.contains("roots.yaml"));
+        assert!(!report.is_success());
+
+        let summary = report.to_string();
+        assert!(summary.contains("mainnet/electra: 1 types covered, 1 cases passed, 0 failed\n"));
+        assert!(summary.contains(
+            "minimal/phase0: 2 types covered, 2 cases passed, 1 failed; not covered: Eth1Block\n"
+        ));
+        assert!(summary.contains("FAILED minimal/phase0/Checkpoint"));
+    }
+
+    #[test]
+    fn invalid_cases() {
+        let dir = std::env::temp_dir().join(format!("ssz_types_spec_case_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+
+        let fork = Fork::default();
+        write_case(&dir, &fork, fork.tree_hash_root());
+        assert_eq!(run_case::<Fork>(&dir), Ok(()));
+
+        // The SSZ of a checkpoint is too long for a fork.
+        assert!(run_case::<Checkpoint>(&dir)
+            .unwrap_err()
+            .starts_with("invalid SSZ"));
+
+        fs::write(dir.join("value.yaml"), "epoch: 1").unwrap();
+        assert!(run_case::<Fork>(&dir)
+            .unwrap_err()
+            .starts_with("invalid value.yaml"));
+
+        fs::remove_file(dir.join("serialized.ssz_snappy")).unwrap();
+        assert!(run_case::<Fork>(&dir)
+            .unwrap_err()
+            .starts_with("cannot read serialized.ssz_snappy"));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn missing_dir() {
+        assert!(run_ssz_static("does-not-exist").is_err());
+    }
+}
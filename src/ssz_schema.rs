@@ -0,0 +1,440 @@
+use crate::proof::{
+    bitfield_gindex_of, concat_gindices, container_gindex_of, end_of_path, list_gindex_of,
+    tree_depth, values_gindex_of,
+};
+use crate::{CommitteeIndex, Epoch, Error, ExecutionAddress, Graffiti, Gwei, Slot, ValidatorIndex};
+use std::fmt;
+use tree_hash::{Hash256, BYTES_PER_CHUNK};
+
+/// The SSZ type of a value, as described by the consensus specs, e.g. `List[uint64, 2048]`.
+///
+/// Types holding bytes in the specs, such as `Root` or `BLSPubkey`, are vectors or lists of
+/// `uint8`. 256-bit integers are held as `FixedVector<u64, U4>`, so they are vectors of four
+/// `uint64`s, which encode and hash identically to `uint256`.
+///
+/// `Display` formats the schema in the notation of the specs, listing the fields of containers
+/// on one line, or indented on a line each with `{:#}`.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub enum SszSchema {
+    /// A `boolean`.
+    Boolean,
+    /// An unsigned integer of `bits` bits, i.e. `uint8` to `uint64`.
+    Uint { bits: usize },
+    /// A vector of exactly `len` values of `elem`.
+    Vector { elem: Box<SszSchema>, len: usize },
+    /// A list of up to `limit` values of `elem`.
+    List { elem: Box<SszSchema>, limit: usize },
+    /// A bit list of up to `len` bits if `is_list`, otherwise a bit vector of exactly `len` bits.
+    Bitfield { len: usize, is_list: bool },
+    /// A container of named fields, in order.
+    Container {
+        fields: Vec<(&'static str, SszSchema)>,
+    },
+}
+
+impl SszSchema {
+    /// Returns the schema of a vector of exactly `len` values of `elem`.
+    pub fn vector(elem: SszSchema, len: usize) -> Self {
+        Self::Vector {
+            elem: Box::new(elem),
+            len,
+        }
+    }
+
+    /// Returns the schema of a list of up to `limit` values of `elem`.
+    pub fn list(elem: SszSchema, limit: usize) -> Self {
+        Self::List {
+            elem: Box::new(elem),
+            limit,
+        }
+    }
+
+    /// Returns true if the values of the schema are packed into chunks when they are hashed.
+    pub fn is_basic(&self) -> bool {
+        matches!(self, Self::Boolean | Self::Uint { .. })
+    }
+
+    /// Returns the length of the SSZ of every value of the schema, or `None` if it is variable.
+    pub fn fixed_size(&self) -> Option<usize> {
+        match self {
+            Self::Boolean => Some(1),
+            Self::Uint { bits } => Some(bits / 8),
+            Self::Vector { elem, len } => elem.fixed_size()?.checked_mul(*len),
+            Self::Bitfield {
+                len,
+                is_list: false,
+            } => Some(len.div_ceil(8)),
+            Self::List { .. } | Self::Bitfield { is_list: true, .. } => None,
+            Self::Container { fields } => fields.iter().try_fold(0_usize, |size, (_, field)| {
+                size.checked_add(field.fixed_size()?)
+            }),
+        }
+    }
+
+    /// Returns the generalized index of the node at the end of `path`, in the same way as
+    /// `MerklePath::gindex_of` does for the type of the schema.
+    ///
+    /// The bytes of vectors of up to 32 bytes resolve to the single chunk of the vector, even for
+    /// types such as `Hash256` and `Graffiti` whose `MerklePath` only resolves them as a whole.
+    ///
+    /// Returns `Err` if an element of `path` does not exist in the schema.
+    pub fn gindex_of(&self, path: &[&str]) -> Result<u64, Error> {
+        match self {
+            Self::Boolean | Self::Uint { .. } => end_of_path(path),
+            Self::Vector { elem, len } => elem.values_gindex_of(*len, path),
+            Self::List { elem, limit } => {
+                list_gindex_of(path, |path| elem.values_gindex_of(*limit, path))
+            }
+            Self::Bitfield {
+                len,
+                is_list: false,
+            } => bitfield_gindex_of(*len, path),
+            Self::Bitfield { len, is_list: true } => {
+                list_gindex_of(path, |path| bitfield_gindex_of(*len, path))
+            }
+            Self::Container { fields } => {
+                let names = fields.iter().map(|(name, _)| *name).collect::<Vec<_>>();
+
+                container_gindex_of(&names, path, |index, rest| {
+                    Some(fields[index].1.gindex_of(rest).and_then(|gindex| {
+                        concat_gindices((1 << tree_depth(fields.len())) + index as u64, gindex)
+                    }))
+                })
+            }
+        }
+    }
+
+    /// Resolves `path` in the tree of up to `limit` values of `self`.
+    fn values_gindex_of(&self, limit: usize, path: &[&str]) -> Result<u64, Error> {
+        let packing_factor = match self {
+            Self::Boolean => Some(BYTES_PER_CHUNK),
+            Self::Uint { bits } => Some(BYTES_PER_CHUNK * 8 / bits),
+            _ => None,
+        };
+
+        values_gindex_of(limit, packing_factor, path, |path| self.gindex_of(path))
+    }
+
+    fn fmt_indented(&self, f: &mut fmt::Formatter<'_>, indent: usize) -> fmt::Result {
+        match self {
+            Self::Boolean => write!(f, "boolean"),
+            Self::Uint { bits } => write!(f, "uint{}", bits),
+            Self::Vector { elem, len } => {
+                write!(f, "Vector[")?;
+                elem.fmt_indented(f, indent)?;
+                write!(f, ", {}]", len)
+            }
+            Self::List { elem, limit } => {
+                write!(f, "List[")?;
+                elem.fmt_indented(f, indent)?;
+                write!(f, ", {}]", limit)
+            }
+            Self::Bitfield {
+                len,
+                is_list: false,
+            } => write!(f, "Bitvector[{}]", len),
+            Self::Bitfield { len, is_list: true } => write!(f, "Bitlist[{}]", len),
+            Self::Container { fields } if f.alternate() => {
+                writeln!(f, "Container {{")?;
+                for (name, field) in fields {
+                    write!(f, "{:width$}{}: ", "", name, width = indent + 4)?;
+                    field.fmt_indented(f, indent + 4)?;
+                    writeln!(f, ",")?;
+                }
+                write!(f, "{:width$}}}", "", width = indent)
+            }
+            Self::Container { fields } => {
+                write!(f, "Container {{ ")?;
+                for (i, (name, field)) in fields.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}: ", name)?;
+                    field.fmt_indented(f, indent)?;
+                }
+                write!(f, " }}")
+            }
+        }
+    }
+}
+
+impl fmt::Display for SszSchema {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_indented(f, 0)
+    }
+}
+
+/// Describes the SSZ type of a type at runtime, for tooling which is generic over all types,
+/// e.g. to print them, compute generalized indices or compare the containers of two forks.
+///
+/// ## Example
+///
+/// ```
+/// use ssz_types::{ssz_schema_of, Fork, SszSchema};
+///
+/// let schema = ssz_schema_of::<Fork>();
+/// assert_eq!(
+///     schema.to_string(),
+///     "Container { previous_version: Vector[uint8, 4], current_version: Vector[uint8, 4], \
+///      epoch: uint64 }"
+/// );
+/// assert_eq!(schema.fixed_size(), Some(16));
+/// assert_eq!(schema.gindex_of(&["epoch"]), Ok(6));
+///
+/// let SszSchema::Container { fields } = schema else {
+///     unreachable!()
+/// };
+/// assert_eq!(fields[2], ("epoch", SszSchema::Uint { bits: 64 }));
+/// ```
+pub trait SszReflect {
+    /// Returns the SSZ schema of `Self`.
+    fn ssz_schema() -> SszSchema;
+}
+
+/// Returns the SSZ schema of `T`.
+///
+/// See `SszReflect`.
+pub fn ssz_schema_of<T: SszReflect>() -> SszSchema {
+    T::ssz_schema()
+}
+
+/// Returns the schema of a field of a container, where `field` is only used to infer the type of
+/// the field.
+pub(crate) fn field_schema<C, F: SszReflect>(_field: fn(&C) -> &F) -> SszSchema {
+    F::ssz_schema()
+}
+
+impl SszReflect for bool {
+    fn ssz_schema() -> SszSchema {
+        SszSchema::Boolean
+    }
+}
+
+macro_rules! impl_ssz_reflect_for_uint {
+    ($($type: ty: $bits: expr),*) => {
+        $(
+            impl SszReflect for $type {
+                fn ssz_schema() -> SszSchema {
+                    SszSchema::Uint { bits: $bits }
+                }
+            }
+        )*
+    };
+}
+
+impl_ssz_reflect_for_uint!(
+    u8: 8,
+    u16: 16,
+    u32: 32,
+    u64: 64,
+    usize: 64,
+    Slot: 64,
+    Epoch: 64,
+    Gwei: 64,
+    ValidatorIndex: 64,
+    CommitteeIndex: 64
+);
+
+macro_rules! impl_ssz_reflect_for_bytes {
+    ($($type: ty: $len: expr),*) => {
+        $(
+            impl SszReflect for $type {
+                fn ssz_schema() -> SszSchema {
+                    SszSchema::vector(SszSchema::Uint { bits: 8 }, $len)
+                }
+            }
+        )*
+    };
+}
+
+impl_ssz_reflect_for_bytes!(
+    [u8; 4]: 4,
+    Hash256: 32,
+    ExecutionAddress: 20,
+    Graffiti: 32,
+    crate::Domain: 32
+);
+
+/// Implements `SszReflect` for a struct whose tree hash root is that of a container with the
+/// listed fields, in order.
+///
+/// A struct with a single type parameter is given as `Name<P: Bound>`.
+macro_rules! impl_ssz_reflect_for_container {
+    ($type: ident<$param: ident: $bound: path>, $($field: ident),* $(,)?) => {
+        impl<$param: $bound> $crate::SszReflect for $type<$param> {
+            fn ssz_schema() -> $crate::SszSchema {
+                $crate::SszSchema::Container {
+                    fields: vec![$((
+                        stringify!($field),
+                        $crate::ssz_schema::field_schema(|value: &$type<$param>| &value.$field),
+                    )),*],
+                }
+            }
+        }
+    };
+    ($type: ty, $($field: ident),* $(,)?) => {
+        impl $crate::SszReflect for $type {
+            fn ssz_schema() -> $crate::SszSchema {
+                $crate::SszSchema::Container {
+                    fields: vec![$((
+                        stringify!($field),
+                        $crate::ssz_schema::field_schema(|value: &$type| &value.$field),
+                    )),*],
+                }
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        BeaconBlockBodyCapella, BeaconBlockBodyDeneb, BeaconBlockHeader, BeaconStateDeneb, Mainnet,
+        MerklePath, Minimal, SignedBeaconBlockDeneb, Validator,
+    };
+    use ssz::Decode;
+
+    #[test]
+    fn display() {
+        let schema = ssz_schema_of::<BeaconBlockHeader>();
+        assert_eq!(
+            schema.to_string(),
+            "Container { slot: uint64, proposer_index: uint64, parent_root: Vector[uint8, 32], \
+             state_root: Vector[uint8, 32], body_root: Vector[uint8, 32] }"
+        );
+
+        let schema = SszSchema::list(
+            SszSchema::Container {
+                fields: vec![
+                    ("a", SszSchema::Boolean),
+                    ("b", ssz_schema_of::<crate::BitVector<typenum::U4>>()),
+                ],
+            },
+            8,
+        );
+        assert_eq!(
+            format!("{:#}", schema),
+            "List[Container {\n    a: boolean,\n    b: Bitvector[4],\n}, 8]"
+        );
+    }
+
+    #[test]
+    fn fixed_size() {
+        fn assert_fixed_size<T: SszReflect + Decode>() {
+            let schema = T::ssz_schema();
+            assert_eq!(schema.fixed_size().is_some(), T::is_ssz_fixed_len());
+            if let Some(size) = schema.fixed_size() {
+                assert_eq!(size, T::ssz_fixed_len());
+            }
+        }
+
+        assert_fixed_size::<bool>();
+        assert_fixed_size::<BeaconBlockHeader>();
+        assert_fixed_size::<Validator>();
+        assert_fixed_size::<crate::BitVector<typenum::U12>>();
+        assert_fixed_size::<crate::BitList<typenum::U12>>();
+        assert_fixed_size::<crate::FixedVector<u64, typenum::U4>>();
+        assert_fixed_size::<BeaconBlockBodyDeneb<Minimal>>();
+        assert_fixed_size::<BeaconStateDeneb<Minimal>>();
+    }
+
+    #[test]
+    fn gindices() {
+        fn assert_gindices<T: SszReflect + MerklePath>(paths: &[&[&str]]) {
+            let schema = T::ssz_schema();
+            for path in paths {
+                assert_eq!(schema.gindex_of(path), T::gindex_of(path), "{:?}", path);
+            }
+        }
+
+        assert_gindices::<SignedBeaconBlockDeneb<Mainnet>>(&[
+            &[],
+            &["message", "slot"],
+            &["message", "body", "execution_payload", "block_hash"],
+            &[
+                "message",
+                "body",
+                "execution_payload",
+                "transactions",
+                "5",
+                "100",
+            ],
+            &[
+                "message",
+                "body",
+                "execution_payload",
+                "base_fee_per_gas",
+                "2",
+            ],
+            &["message", "body", "blob_kzg_commitments", "3"],
+            &["message", "body", "blob_kzg_commitments", "__len__"],
+            &[
+                "message",
+                "body",
+                "sync_aggregate",
+                "sync_committee_bits",
+                "300",
+            ],
+            &[
+                "message",
+                "body",
+                "attestations",
+                "1",
+                "aggregation_bits",
+                "2000",
+            ],
+            &[
+                "message",
+                "body",
+                "attestations",
+                "1",
+                "aggregation_bits",
+                "2048",
+            ],
+            &["message", "body", "graffiti"],
+            &["message", "body", "unknown"],
+            &["signature", "95"],
+        ]);
+
+        // Bytes of a single chunk resolve to the chunk.
+        let schema = ssz_schema_of::<SignedBeaconBlockDeneb>();
+        assert_eq!(
+            schema.gindex_of(&["message", "body", "graffiti", "31"]),
+            schema.gindex_of(&["message", "body", "graffiti"])
+        );
+        assert!(schema
+            .gindex_of(&["message", "body", "graffiti", "32"])
+            .is_err());
+
+        assert_gindices::<BeaconStateDeneb<Mainnet>>(&[
+            &["validators", "1000", "withdrawal_credentials"],
+            &["balances", "7"],
+            &["balances", "__len__"],
+            &["previous_epoch_participation", "33"],
+            &["justification_bits", "3"],
+            &["current_sync_committee", "pubkeys", "511"],
+            &["current_sync_committee", "pubkeys", "512"],
+            &["latest_execution_payload_header", "withdrawals_root"],
+        ]);
+    }
+
+    #[test]
+    fn forks() {
+        let SszSchema::Container { fields: capella } = ssz_schema_of::<BeaconBlockBodyCapella>()
+        else {
+            panic!("not a container")
+        };
+        let SszSchema::Container { fields: deneb } = ssz_schema_of::<BeaconBlockBodyDeneb>() else {
+            panic!("not a container")
+        };
+
+        let added = deneb
+            .iter()
+            .filter(|field| !capella.contains(field))
+            .map(|(name, _)| *name)
+            .collect::<Vec<_>>();
+        assert_eq!(added, ["execution_payload", "blob_kzg_commitments"]);
+        assert_eq!(deneb.len(), capella.len() + 1);
+    }
+}
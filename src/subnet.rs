@@ -0,0 +1,165 @@
+use crate::{Attestation, AttestationElectra, CommitteeIndex, Error, GossipKind, Preset, Slot};
+use std::fmt;
+use typenum::Unsigned;
+
+/// The number of subnets that attestations are gossiped on.
+pub const ATTESTATION_SUBNET_COUNT: u64 = 64;
+
+/// The id of an attestation subnet, below `ATTESTATION_SUBNET_COUNT`.
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct SubnetId(u64);
+
+impl SubnetId {
+    /// Returns the subnet with the given id.
+    pub const fn new(id: u64) -> Self {
+        Self(id)
+    }
+
+    /// Returns the id of the subnet.
+    pub const fn as_u64(self) -> u64 {
+        self.0
+    }
+
+    /// Returns the kind of the gossip topic of the subnet.
+    pub fn gossip_kind(self) -> GossipKind {
+        GossipKind::BeaconAttestation(self.0)
+    }
+}
+
+impl From<u64> for SubnetId {
+    fn from(id: u64) -> Self {
+        Self(id)
+    }
+}
+
+impl From<SubnetId> for u64 {
+    fn from(subnet_id: SubnetId) -> Self {
+        subnet_id.0
+    }
+}
+
+impl fmt::Display for SubnetId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// Returns the subnet of the attestations of the committee `committee_index` at `slot`, as per
+/// `compute_subnet_for_attestation` in the consensus specs. `committees_per_slot` is the number
+/// of committees at each slot of the epoch of `slot`.
+///
+/// ## Example
+///
+/// ```
+/// use ssz_types::{compute_subnet_for_attestation, CommitteeIndex, Mainnet, Slot, SubnetId};
+///
+/// // The committees are numbered across the slots of the epoch.
+/// let subnet_id =
+///     compute_subnet_for_attestation::<Mainnet>(4, Slot::new(33), CommitteeIndex::new(2));
+/// assert_eq!(subnet_id, SubnetId::new(4 + 2));
+/// ```
+pub fn compute_subnet_for_attestation<P: Preset>(
+    committees_per_slot: u64,
+    slot: Slot,
+    committee_index: CommitteeIndex,
+) -> SubnetId {
+    let slots_since_epoch_start = slot.as_u64() % P::SlotsPerEpoch::to_u64();
+    // Wrapping does not change the subnet, as `ATTESTATION_SUBNET_COUNT` divides `2^64`.
+    let committees_since_epoch_start = committees_per_slot.wrapping_mul(slots_since_epoch_start);
+
+    SubnetId(
+        committees_since_epoch_start.wrapping_add(committee_index.as_u64())
+            % ATTESTATION_SUBNET_COUNT,
+    )
+}
+
+impl<P: Preset> Attestation<P> {
+    /// Returns the subnet that the attestation is gossiped on, with `committees_per_slot`
+    /// committees at each slot of its epoch.
+    pub fn subnet_id(&self, committees_per_slot: u64) -> SubnetId {
+        compute_subnet_for_attestation::<P>(committees_per_slot, self.data.slot, self.data.index)
+    }
+}
+
+impl<P: Preset> AttestationElectra<P> {
+    /// Returns the subnet that the attestation is gossiped on, with `committees_per_slot`
+    /// committees at each slot of its epoch. Since Electra, the committee of an unaggregated
+    /// attestation is the single committee of its `committee_bits`, as `data.index` is zero.
+    ///
+    /// Returns `Err` if the attestation is not of exactly one committee.
+    pub fn subnet_id(&self, committees_per_slot: u64) -> Result<SubnetId, Error> {
+        let committee_indices = self.committee_indices();
+        let [committee_index] = committee_indices[..] else {
+            return Err(Error::NotSingleCommittee {
+                committees: committee_indices.len(),
+            });
+        };
+        Ok(compute_subnet_for_attestation::<P>(
+            committees_per_slot,
+            self.data.slot,
+            committee_index,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Mainnet, Minimal};
+
+    #[test]
+    fn attestation_subnets() {
+        let subnet_id = |committees_per_slot, slot, committee_index| {
+            compute_subnet_for_attestation::<Mainnet>(
+                committees_per_slot,
+                Slot::new(slot),
+                CommitteeIndex::new(committee_index),
+            )
+        };
+        assert_eq!(subnet_id(1, 0, 0), SubnetId::new(0));
+        assert_eq!(subnet_id(4, 33, 2), SubnetId::new(6));
+        // The subnets wrap around once there are more than 64 committees in an epoch.
+        assert_eq!(subnet_id(64, 31, 5), SubnetId::new(5));
+        assert_eq!(subnet_id(3, 21, 2), SubnetId::new(65 % 64));
+        // Any number of committees is accepted, even if it is not of a valid state.
+        assert_eq!(subnet_id(u64::MAX, 31, 5), SubnetId::new(64 - 31 + 5));
+        assert_eq!(subnet_id(1, 0, u64::MAX), SubnetId::new(63));
+
+        // The slot within the epoch depends on the preset.
+        assert_eq!(
+            compute_subnet_for_attestation::<Minimal>(4, Slot::new(9), CommitteeIndex::new(0)),
+            SubnetId::new(4)
+        );
+
+        assert_eq!(
+            SubnetId::new(7).gossip_kind(),
+            GossipKind::BeaconAttestation(7)
+        );
+        assert_eq!(SubnetId::from(7).to_string(), "7");
+    }
+
+    #[test]
+    fn attestation_subnet_ids() {
+        let mut attestation = Attestation::<Mainnet>::default();
+        attestation.data.slot = Slot::new(66);
+        attestation.data.index = CommitteeIndex::new(3);
+        assert_eq!(attestation.subnet_id(2), SubnetId::new(2 * 2 + 3));
+
+        let mut electra = AttestationElectra::<Mainnet>::default();
+        electra.data.slot = Slot::new(66);
+        assert_eq!(
+            electra.subnet_id(2),
+            Err(Error::NotSingleCommittee { committees: 0 })
+        );
+
+        electra.committee_bits.set(3, true).unwrap();
+        assert_eq!(electra.subnet_id(2), Ok(SubnetId::new(2 * 2 + 3)));
+
+        electra.committee_bits.set(1, true).unwrap();
+        assert_eq!(
+            electra.subnet_id(2),
+            Err(Error::NotSingleCommittee { committees: 2 })
+        );
+    }
+}
@@ -0,0 +1,264 @@
+use crate::beacon_block::H256;
+use crate::{BitVector, Error, Mainnet, Preset, SignatureBytes, SignedRoot, Slot, ValidatorIndex};
+use ssz_derive::{Decode, Encode};
+use tree_hash_derive::TreeHash;
+use typenum::Unsigned;
+
+/// The number of subnets, and thus of subcommittees, of a sync committee.
+pub const SYNC_COMMITTEE_SUBNET_COUNT: u64 = 4;
+/// The number of aggregators that are expected to be selected in each subcommittee.
+pub const TARGET_AGGREGATORS_PER_SYNC_SUBCOMMITTEE: u64 = 16;
+
+/// The signature of a member of the sync committee over the root of the head block.
+#[derive(Clone, Default, Encode, Decode, TreeHash, PartialEq, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[ssz(struct_behaviour = "container")]
+pub struct SyncCommitteeMessage {
+    pub slot: Slot,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::hex_fixed_vec"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::json_schema::Hex<H256>"))]
+    pub beacon_block_root: H256,
+    pub validator_index: ValidatorIndex,
+    pub signature: SignatureBytes,
+}
+
+/// The aggregate of the `SyncCommitteeMessage`s of the members of a subcommittee.
+#[derive(Clone, Default, Encode, Decode, TreeHash, PartialEq, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(bound = "P: Preset"))]
+#[cfg_attr(
+    feature = "schemars",
+    schemars(bound = "P: Preset + schemars::JsonSchema")
+)]
+#[ssz(struct_behaviour = "container")]
+pub struct SyncCommitteeContribution<P: Preset = Mainnet> {
+    pub slot: Slot,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::hex_fixed_vec"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::json_schema::Hex<H256>"))]
+    pub beacon_block_root: H256,
+    #[cfg_attr(feature = "serde", serde(with = "serde_utils::quoted_u64"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "crate::json_schema::Quoted<u64>")
+    )]
+    pub subcommittee_index: u64,
+    pub aggregation_bits: BitVector<P::SyncSubcommitteeSize>,
+    pub signature: SignatureBytes,
+}
+
+#[derive(Clone, Default, Encode, Decode, TreeHash, PartialEq, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(bound = "P: Preset"))]
+#[cfg_attr(
+    feature = "schemars",
+    schemars(bound = "P: Preset + schemars::JsonSchema")
+)]
+#[ssz(struct_behaviour = "container")]
+pub struct ContributionAndProof<P: Preset = Mainnet> {
+    pub aggregator_index: ValidatorIndex,
+    pub contribution: SyncCommitteeContribution<P>,
+    pub selection_proof: SignatureBytes,
+}
+
+#[derive(Clone, Default, Encode, Decode, TreeHash, PartialEq, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(bound = "P: Preset"))]
+#[cfg_attr(
+    feature = "schemars",
+    schemars(bound = "P: Preset + schemars::JsonSchema")
+)]
+#[ssz(struct_behaviour = "container")]
+pub struct SignedContributionAndProof<P: Preset = Mainnet> {
+    pub message: ContributionAndProof<P>,
+    pub signature: SignatureBytes,
+}
+
+/// The message signed by a member of a subcommittee to produce its selection proof.
+#[derive(Clone, Default, Encode, Decode, TreeHash, PartialEq, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_derive::Serialize, serde_derive::Deserialize)
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[ssz(struct_behaviour = "container")]
+pub struct SyncAggregatorSelectionData {
+    pub slot: Slot,
+    #[cfg_attr(feature = "serde", serde(with = "serde_utils::quoted_u64"))]
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(with = "crate::json_schema::Quoted<u64>")
+    )]
+    pub subcommittee_index: u64,
+}
+
+impl<P: Preset> SyncCommitteeContribution<P> {
+    /// Returns the positions in the sync committee of the members of the subcommittee that
+    /// participated in the contribution, i.e. the bits to set in the `sync_committee_bits` of a
+    /// `SyncAggregate`.
+    ///
+    /// Returns `Err` if `subcommittee_index` is not the index of a subcommittee.
+    pub fn sync_committee_indices(&self) -> Result<Vec<usize>, Error> {
+        if self.subcommittee_index >= SYNC_COMMITTEE_SUBNET_COUNT {
+            return Err(Error::OutOfBounds {
+                i: self.subcommittee_index as usize,
+                len: SYNC_COMMITTEE_SUBNET_COUNT as usize,
+            });
+        }
+
+        let offset = self.subcommittee_index as usize * self.aggregation_bits.len();
+        Ok(self
+            .aggregation_bits
+            .iter()
+            .enumerate()
+            .filter(|(_, bit)| *bit)
+            .map(|(index, _)| offset + index)
+            .collect())
+    }
+}
+
+/// True if `selection_proof` selects its signer as an aggregator of its subcommittee in the sync
+/// committees of preset `P`, as per `is_sync_committee_aggregator` in the consensus specs.
+pub fn is_sync_committee_aggregator<P: Preset>(selection_proof: &SignatureBytes) -> bool {
+    let modulo = std::cmp::max(
+        1,
+        P::SyncSubcommitteeSize::to_u64() / TARGET_AGGREGATORS_PER_SYNC_SUBCOMMITTEE,
+    );
+    let hash = ethereum_hashing::hash(selection_proof.as_bytes());
+    let mut bytes = [0; 8];
+    bytes.copy_from_slice(&hash[..8]);
+
+    u64::from_le_bytes(bytes) % modulo == 0
+}
+
+impl_memory_size_for_container!(
+    SyncCommitteeMessage,
+    slot,
+    beacon_block_root,
+    validator_index,
+    signature,
+);
+impl_memory_size_for_container!(
+    SyncCommitteeContribution<P: Preset>,
+    slot,
+    beacon_block_root,
+    subcommittee_index,
+    aggregation_bits,
+    signature,
+);
+impl_memory_size_for_container!(
+    ContributionAndProof<P: Preset>,
+    aggregator_index,
+    contribution,
+    selection_proof,
+);
+impl_memory_size_for_container!(SignedContributionAndProof<P: Preset>, message, signature);
+impl_memory_size_for_container!(SyncAggregatorSelectionData, slot, subcommittee_index);
+
+impl_merkle_proof_for_container!(
+    SyncCommitteeMessage,
+    slot,
+    beacon_block_root,
+    validator_index,
+    signature,
+);
+impl_merkle_proof_for_container!(
+    SyncCommitteeContribution<P: Preset>,
+    slot,
+    beacon_block_root,
+    subcommittee_index,
+    aggregation_bits,
+    signature,
+);
+impl_merkle_proof_for_container!(
+    ContributionAndProof<P: Preset>,
+    aggregator_index,
+    contribution,
+    selection_proof,
+);
+impl_merkle_proof_for_container!(SignedContributionAndProof<P: Preset>, message, signature);
+impl_merkle_proof_for_container!(SyncAggregatorSelectionData, slot, subcommittee_index);
+
+impl<P: Preset> SignedRoot for ContributionAndProof<P> {}
+impl SignedRoot for SyncAggregatorSelectionData {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::gindex_of;
+    use ssz::{Decode, Encode};
+
+    #[test]
+    fn contribution_indices() {
+        let mut contribution = SyncCommitteeContribution::<Mainnet> {
+            subcommittee_index: 2,
+            aggregation_bits: BitVector::new(),
+            ..SyncCommitteeContribution::default()
+        };
+        contribution.aggregation_bits.set(0, true).unwrap();
+        contribution.aggregation_bits.set(127, true).unwrap();
+
+        assert_eq!(contribution.sync_committee_indices(), Ok(vec![256, 383]));
+
+        contribution.subcommittee_index = 4;
+        assert_eq!(
+            contribution.sync_committee_indices(),
+            Err(Error::OutOfBounds { i: 4, len: 4 })
+        );
+    }
+
+    #[test]
+    fn signed_contribution() {
+        let signed = SignedContributionAndProof::<Mainnet> {
+            message: ContributionAndProof {
+                aggregator_index: ValidatorIndex::new(9),
+                ..ContributionAndProof::default()
+            },
+            signature: SignatureBytes::new([1; 96]),
+        };
+
+        let bytes = signed.as_ssz_bytes();
+        // Every field is fixed-size.
+        assert_eq!(bytes.len(), 8 + 8 + 32 + 8 + 16 + 96 + 96 + 96);
+        assert_eq!(
+            SignedContributionAndProof::from_ssz_bytes(&bytes),
+            Ok(signed)
+        );
+        // The second of 3 fields of the message, and the first of 5 fields of the contribution.
+        assert_eq!(
+            gindex_of::<SignedContributionAndProof>(&["message", "contribution", "slot"]),
+            Ok((2 * 4 + 1) * 8)
+        );
+    }
+
+    #[test]
+    fn aggregator_selection() {
+        // With 128 members and 16 aggregators per subcommittee, one in 8 proofs is selected.
+        let selected = (0..=255)
+            .filter(|&byte| {
+                is_sync_committee_aggregator::<Mainnet>(&SignatureBytes::new([byte; 96]))
+            })
+            .count();
+        assert!(selected > 0 && selected < 128, "{}", selected);
+    }
+}
@@ -0,0 +1,700 @@
+use crate::{Error, ExecutionAddress, FixedVector, VariableList};
+use rlp::{Decodable, DecoderError, Rlp};
+use tree_hash::Hash256;
+use typenum::Unsigned;
+
+/// A 256 bit integer, in the representation of the execution payloads: four `u64` limbs with
+/// the least significant first.
+pub(crate) type U256 = FixedVector<u64, typenum::U4>;
+
+/// The type of an EIP-2930 transaction, with an access list.
+pub const ACCESS_LIST_TRANSACTION_TYPE: u8 = 0x01;
+
+/// The type of an EIP-1559 transaction, with a priority fee.
+pub const DYNAMIC_FEE_TRANSACTION_TYPE: u8 = 0x02;
+
+/// The type of an EIP-4844 transaction, which carries blobs.
+pub const BLOB_TRANSACTION_TYPE: u8 = 0x03;
+
+/// The type of an EIP-7702 transaction, which sets the code of accounts.
+pub const SET_CODE_TRANSACTION_TYPE: u8 = 0x04;
+
+/// An address and the storage keys of it that a transaction declares it accesses.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct AccessListItem {
+    pub address: ExecutionAddress,
+    pub storage_keys: Vec<Hash256>,
+}
+
+/// A signed authorization of EIP-7702 for an account to take the code of `address`.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Authorization {
+    pub chain_id: U256,
+    pub address: ExecutionAddress,
+    pub nonce: u64,
+    pub y_parity: bool,
+    pub r: U256,
+    pub s: U256,
+}
+
+/// A transaction from before EIP-2718, whose `v` holds the chain id as per EIP-155.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct LegacyTransaction {
+    pub nonce: u64,
+    pub gas_price: U256,
+    pub gas: u64,
+    pub to: Option<ExecutionAddress>,
+    pub value: U256,
+    pub input: Vec<u8>,
+    pub v: u64,
+    pub r: U256,
+    pub s: U256,
+}
+
+/// A transaction of EIP-2930.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct AccessListTransaction {
+    pub chain_id: u64,
+    pub nonce: u64,
+    pub gas_price: U256,
+    pub gas: u64,
+    pub to: Option<ExecutionAddress>,
+    pub value: U256,
+    pub input: Vec<u8>,
+    pub access_list: Vec<AccessListItem>,
+    pub y_parity: bool,
+    pub r: U256,
+    pub s: U256,
+}
+
+/// A transaction of EIP-1559.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct DynamicFeeTransaction {
+    pub chain_id: u64,
+    pub nonce: u64,
+    pub max_priority_fee_per_gas: U256,
+    pub max_fee_per_gas: U256,
+    pub gas: u64,
+    pub to: Option<ExecutionAddress>,
+    pub value: U256,
+    pub input: Vec<u8>,
+    pub access_list: Vec<AccessListItem>,
+    pub y_parity: bool,
+    pub r: U256,
+    pub s: U256,
+}
+
+/// A transaction of EIP-4844, which cannot create a contract.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct BlobTransaction {
+    pub chain_id: u64,
+    pub nonce: u64,
+    pub max_priority_fee_per_gas: U256,
+    pub max_fee_per_gas: U256,
+    pub gas: u64,
+    pub to: ExecutionAddress,
+    pub value: U256,
+    pub input: Vec<u8>,
+    pub access_list: Vec<AccessListItem>,
+    pub max_fee_per_blob_gas: U256,
+    pub blob_versioned_hashes: Vec<Hash256>,
+    pub y_parity: bool,
+    pub r: U256,
+    pub s: U256,
+}
+
+/// A transaction of EIP-7702, which cannot create a contract.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct SetCodeTransaction {
+    pub chain_id: u64,
+    pub nonce: u64,
+    pub max_priority_fee_per_gas: U256,
+    pub max_fee_per_gas: U256,
+    pub gas: u64,
+    pub to: ExecutionAddress,
+    pub value: U256,
+    pub input: Vec<u8>,
+    pub access_list: Vec<AccessListItem>,
+    pub authorization_list: Vec<Authorization>,
+    pub y_parity: bool,
+    pub r: U256,
+    pub s: U256,
+}
+
+/// A transaction of an execution payload, decoded from its EIP-2718 envelope: either a legacy
+/// transaction, which is an RLP list, or a type byte followed by the RLP of a typed transaction.
+///
+/// ## Example
+///
+/// ```
+/// use ssz_types::{TransactionEnvelope, VariableList};
+///
+/// // The signed transaction of the example of EIP-155.
+/// let bytes = serde_utils::hex::decode(concat!(
+///     "0xf86c098504a817c800825208943535353535353535353535353535353535353535880de0b6b3a7640000",
+///     "8025a028ef61340bd939bc2195fe537567866003e1a15d3c71ff63e1590620aa636276a067cbe9d8997f76",
+///     "1aecb703304b3800ccf555c9f3dc64214b297fb1966a3b6d83",
+/// ))
+/// .unwrap();
+/// let transaction = VariableList::<u8, typenum::U1024>::new(bytes).unwrap();
+///
+/// let envelope = transaction.decode_envelope().unwrap();
+/// assert_eq!(envelope.transaction_type(), 0);
+/// assert_eq!(envelope.gas(), 21_000);
+/// assert_eq!(envelope.value()[0], 1_000_000_000_000_000_000);
+/// assert!(envelope.blob_versioned_hashes().is_empty());
+/// ```
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum TransactionEnvelope {
+    Legacy(LegacyTransaction),
+    AccessList(AccessListTransaction),
+    DynamicFee(DynamicFeeTransaction),
+    Blob(BlobTransaction),
+    SetCode(SetCodeTransaction),
+}
+
+/// Evaluates `$expr` with `$transaction` bound to the transaction of any type of envelope.
+macro_rules! map_transaction {
+    ($envelope: expr, $transaction: ident => $expr: expr) => {
+        match $envelope {
+            Self::Legacy($transaction) => $expr,
+            Self::AccessList($transaction) => $expr,
+            Self::DynamicFee($transaction) => $expr,
+            Self::Blob($transaction) => $expr,
+            Self::SetCode($transaction) => $expr,
+        }
+    };
+}
+
+impl TransactionEnvelope {
+    /// Decodes the envelope of a transaction.
+    ///
+    /// Returns `Err(Error::InvalidTransaction)` if the type of the transaction is unknown, or
+    /// if the RLP of the transaction is invalid, has trailing bytes or has the wrong number of
+    /// fields for its type.
+    pub fn decode(bytes: &[u8]) -> Result<Self, Error> {
+        let invalid = |reason: String| Error::InvalidTransaction { reason };
+
+        let (&first, payload) = bytes
+            .split_first()
+            .ok_or_else(|| invalid("empty transaction".into()))?;
+        // A legacy transaction starts with the prefix of an RLP list, which is at least 0xc0.
+        let (transaction_type, payload) = match first {
+            0xc0.. => (0, bytes),
+            ACCESS_LIST_TRANSACTION_TYPE..=SET_CODE_TRANSACTION_TYPE => (first, payload),
+            _ => return Err(invalid(format!("unknown transaction type {}", first))),
+        };
+
+        let rlp = Rlp::new(payload);
+        let decode = || -> Result<Self, DecoderError> {
+            if rlp.payload_info()?.total() != payload.len() {
+                return Err(DecoderError::RlpInconsistentLengthAndData);
+            }
+            match transaction_type {
+                0 => decode_legacy(&rlp).map(Self::Legacy),
+                ACCESS_LIST_TRANSACTION_TYPE => {
+                    decode_access_list_transaction(&rlp).map(Self::AccessList)
+                }
+                DYNAMIC_FEE_TRANSACTION_TYPE => decode_dynamic_fee(&rlp).map(Self::DynamicFee),
+                BLOB_TRANSACTION_TYPE => decode_blob(&rlp).map(Self::Blob),
+                _ => decode_set_code(&rlp).map(Self::SetCode),
+            }
+        };
+        decode().map_err(|error| {
+            invalid(format!(
+                "transaction of type {}: {:?}",
+                transaction_type, error
+            ))
+        })
+    }
+
+    /// Returns the EIP-2718 type of the transaction, which is 0 for legacy transactions.
+    pub fn transaction_type(&self) -> u8 {
+        match self {
+            Self::Legacy(_) => 0,
+            Self::AccessList(_) => ACCESS_LIST_TRANSACTION_TYPE,
+            Self::DynamicFee(_) => DYNAMIC_FEE_TRANSACTION_TYPE,
+            Self::Blob(_) => BLOB_TRANSACTION_TYPE,
+            Self::SetCode(_) => SET_CODE_TRANSACTION_TYPE,
+        }
+    }
+
+    /// Returns the chain id of the transaction, which legacy transactions only have if they are
+    /// signed as per EIP-155.
+    pub fn chain_id(&self) -> Option<u64> {
+        match self {
+            Self::Legacy(transaction) => transaction.v.checked_sub(35).map(|v| v / 2),
+            Self::AccessList(transaction) => Some(transaction.chain_id),
+            Self::DynamicFee(transaction) => Some(transaction.chain_id),
+            Self::Blob(transaction) => Some(transaction.chain_id),
+            Self::SetCode(transaction) => Some(transaction.chain_id),
+        }
+    }
+
+    /// Returns the nonce of the sender of the transaction.
+    pub fn nonce(&self) -> u64 {
+        map_transaction!(self, transaction => transaction.nonce)
+    }
+
+    /// Returns the gas limit of the transaction.
+    pub fn gas(&self) -> u64 {
+        map_transaction!(self, transaction => transaction.gas)
+    }
+
+    /// Returns the recipient of the transaction, or `None` if it creates a contract.
+    pub fn to(&self) -> Option<ExecutionAddress> {
+        match self {
+            Self::Legacy(transaction) => transaction.to,
+            Self::AccessList(transaction) => transaction.to,
+            Self::DynamicFee(transaction) => transaction.to,
+            Self::Blob(transaction) => Some(transaction.to),
+            Self::SetCode(transaction) => Some(transaction.to),
+        }
+    }
+
+    /// Returns the wei that the transaction transfers to its recipient.
+    pub fn value(&self) -> &U256 {
+        map_transaction!(self, transaction => &transaction.value)
+    }
+
+    /// Returns the call data of the transaction, or the init code if it creates a contract.
+    pub fn input(&self) -> &[u8] {
+        map_transaction!(self, transaction => &transaction.input)
+    }
+
+    /// Returns the versioned hashes of the blobs of the transaction, which only blob
+    /// transactions have.
+    pub fn blob_versioned_hashes(&self) -> &[Hash256] {
+        match self {
+            Self::Blob(transaction) => &transaction.blob_versioned_hashes,
+            _ => &[],
+        }
+    }
+}
+
+impl<N: Unsigned> VariableList<u8, N> {
+    /// Decodes the list as the EIP-2718 envelope of a transaction, as held in the `transactions`
+    /// of an execution payload.
+    pub fn decode_envelope(&self) -> Result<TransactionEnvelope, Error> {
+        TransactionEnvelope::decode(self)
+    }
+}
+
+impl Decodable for ExecutionAddress {
+    fn decode(rlp: &Rlp) -> Result<Self, DecoderError> {
+        rlp.decoder().decode_value(|bytes| {
+            ExecutionAddress::from_slice(bytes).map_err(|_| DecoderError::RlpInvalidLength)
+        })
+    }
+}
+
+/// Decodes an integer of up to 256 bits, without leading zeros.
+fn decode_u256(rlp: &Rlp) -> Result<U256, DecoderError> {
+    if !rlp.is_data() {
+        return Err(DecoderError::RlpExpectedToBeData);
+    }
+    let bytes = rlp.data()?;
+    if bytes.len() > 32 {
+        return Err(DecoderError::RlpIsTooBig);
+    }
+    if bytes.first() == Some(&0) {
+        return Err(DecoderError::RlpInvalidIndirection);
+    }
+
+    let mut be_bytes = [0; 32];
+    be_bytes[32 - bytes.len()..].copy_from_slice(bytes);
+    let limbs = be_bytes
+        .chunks(8)
+        .rev()
+        .map(|limb| u64::from_be_bytes(limb.try_into().expect("limbs are 8 bytes")))
+        .collect();
+    Ok(FixedVector::new(limbs).expect("there are 4 limbs"))
+}
+
+/// Decodes a hash of 32 bytes.
+fn decode_hash(rlp: &Rlp) -> Result<Hash256, DecoderError> {
+    if !rlp.is_data() {
+        return Err(DecoderError::RlpExpectedToBeData);
+    }
+    match rlp.data()? {
+        bytes if bytes.len() == 32 => Ok(Hash256::from_slice(bytes)),
+        _ => Err(DecoderError::RlpInvalidLength),
+    }
+}
+
+/// Decodes the recipient of a transaction which may create a contract, whose recipient is
+/// then empty.
+fn decode_to(rlp: &Rlp) -> Result<Option<ExecutionAddress>, DecoderError> {
+    if rlp.is_empty() {
+        Ok(None)
+    } else {
+        rlp.as_val().map(Some)
+    }
+}
+
+/// Decodes the parity of the y coordinate of the point of a signature.
+fn decode_y_parity(rlp: &Rlp) -> Result<bool, DecoderError> {
+    match rlp.as_val::<u8>()? {
+        0 => Ok(false),
+        1 => Ok(true),
+        _ => Err(DecoderError::Custom("y parity is not 0 or 1")),
+    }
+}
+
+fn decode_access_list(rlp: &Rlp) -> Result<Vec<AccessListItem>, DecoderError> {
+    rlp.iter()
+        .map(|item| {
+            if item.item_count()? != 2 {
+                return Err(DecoderError::RlpIncorrectListLen);
+            }
+            Ok(AccessListItem {
+                address: item.val_at(0)?,
+                storage_keys: item
+                    .at(1)?
+                    .iter()
+                    .map(|key| decode_hash(&key))
+                    .collect::<Result<_, _>>()?,
+            })
+        })
+        .collect()
+}
+
+/// Returns `Err` unless `rlp` is a list of `count` items.
+fn check_item_count(rlp: &Rlp, count: usize) -> Result<(), DecoderError> {
+    if rlp.item_count()? != count {
+        return Err(DecoderError::RlpIncorrectListLen);
+    }
+    Ok(())
+}
+
+fn decode_legacy(rlp: &Rlp) -> Result<LegacyTransaction, DecoderError> {
+    check_item_count(rlp, 9)?;
+    Ok(LegacyTransaction {
+        nonce: rlp.val_at(0)?,
+        gas_price: decode_u256(&rlp.at(1)?)?,
+        gas: rlp.val_at(2)?,
+        to: decode_to(&rlp.at(3)?)?,
+        value: decode_u256(&rlp.at(4)?)?,
+        input: rlp.val_at(5)?,
+        v: rlp.val_at(6)?,
+        r: decode_u256(&rlp.at(7)?)?,
+        s: decode_u256(&rlp.at(8)?)?,
+    })
+}
+
+fn decode_access_list_transaction(rlp: &Rlp) -> Result<AccessListTransaction, DecoderError> {
+    check_item_count(rlp, 11)?;
+    Ok(AccessListTransaction {
+        chain_id: rlp.val_at(0)?,
+        nonce: rlp.val_at(1)?,
+        gas_price: decode_u256(&rlp.at(2)?)?,
+        gas: rlp.val_at(3)?,
+        to: decode_to(&rlp.at(4)?)?,
+        value: decode_u256(&rlp.at(5)?)?,
+        input: rlp.val_at(6)?,
+        access_list: decode_access_list(&rlp.at(7)?)?,
+        y_parity: decode_y_parity(&rlp.at(8)?)?,
+        r: decode_u256(&rlp.at(9)?)?,
+        s: decode_u256(&rlp.at(10)?)?,
+    })
+}
+
+fn decode_dynamic_fee(rlp: &Rlp) -> Result<DynamicFeeTransaction, DecoderError> {
+    check_item_count(rlp, 12)?;
+    Ok(DynamicFeeTransaction {
+        chain_id: rlp.val_at(0)?,
+        nonce: rlp.val_at(1)?,
+        max_priority_fee_per_gas: decode_u256(&rlp.at(2)?)?,
+        max_fee_per_gas: decode_u256(&rlp.at(3)?)?,
+        gas: rlp.val_at(4)?,
+        to: decode_to(&rlp.at(5)?)?,
+        value: decode_u256(&rlp.at(6)?)?,
+        input: rlp.val_at(7)?,
+        access_list: decode_access_list(&rlp.at(8)?)?,
+        y_parity: decode_y_parity(&rlp.at(9)?)?,
+        r: decode_u256(&rlp.at(10)?)?,
+        s: decode_u256(&rlp.at(11)?)?,
+    })
+}
+
+fn decode_blob(rlp: &Rlp) -> Result<BlobTransaction, DecoderError> {
+    check_item_count(rlp, 14)?;
+    Ok(BlobTransaction {
+        chain_id: rlp.val_at(0)?,
+        nonce: rlp.val_at(1)?,
+        max_priority_fee_per_gas: decode_u256(&rlp.at(2)?)?,
+        max_fee_per_gas: decode_u256(&rlp.at(3)?)?,
+        gas: rlp.val_at(4)?,
+        to: rlp.val_at(5)?,
+        value: decode_u256(&rlp.at(6)?)?,
+        input: rlp.val_at(7)?,
+        access_list: decode_access_list(&rlp.at(8)?)?,
+        max_fee_per_blob_gas: decode_u256(&rlp.at(9)?)?,
+        blob_versioned_hashes: rlp
+            .at(10)?
+            .iter()
+            .map(|hash| decode_hash(&hash))
+            .collect::<Result<_, _>>()?,
+        y_parity: decode_y_parity(&rlp.at(11)?)?,
+        r: decode_u256(&rlp.at(12)?)?,
+        s: decode_u256(&rlp.at(13)?)?,
+    })
+}
+
+fn decode_set_code(rlp: &Rlp) -> Result<SetCodeTransaction, DecoderError> {
+    check_item_count(rlp, 13)?;
+    Ok(SetCodeTransaction {
+        chain_id: rlp.val_at(0)?,
+        nonce: rlp.val_at(1)?,
+        max_priority_fee_per_gas: decode_u256(&rlp.at(2)?)?,
+        max_fee_per_gas: decode_u256(&rlp.at(3)?)?,
+        gas: rlp.val_at(4)?,
+        to: rlp.val_at(5)?,
+        value: decode_u256(&rlp.at(6)?)?,
+        input: rlp.val_at(7)?,
+        access_list: decode_access_list(&rlp.at(8)?)?,
+        authorization_list: rlp
+            .at(9)?
+            .iter()
+            .map(|authorization| {
+                check_item_count(&authorization, 6)?;
+                Ok(Authorization {
+                    chain_id: decode_u256(&authorization.at(0)?)?,
+                    address: authorization.val_at(1)?,
+                    nonce: authorization.val_at(2)?,
+                    y_parity: decode_y_parity(&authorization.at(3)?)?,
+                    r: decode_u256(&authorization.at(4)?)?,
+                    s: decode_u256(&authorization.at(5)?)?,
+                })
+            })
+            .collect::<Result<_, DecoderError>>()?,
+        y_parity: decode_y_parity(&rlp.at(10)?)?,
+        r: decode_u256(&rlp.at(11)?)?,
+        s: decode_u256(&rlp.at(12)?)?,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rlp::RlpStream;
+
+    fn u256(value: u64) -> U256 {
+        FixedVector::new(vec![value, 0, 0, 0]).unwrap()
+    }
+
+    fn address(byte: u8) -> ExecutionAddress {
+        ExecutionAddress::new([byte; 20])
+    }
+
+    /// Appends the fields shared by the typed transactions after EIP-1559, up to and including
+    /// the access list.
+    fn append_fee_fields(stream: &mut RlpStream, to: &[u8]) {
+        stream.append(&1_u64).append(&7_u64);
+        stream
+            .append(&1_000_000_000_u64)
+            .append(&30_000_000_000_u64);
+        stream.append(&21_000_u64).append(&to).append(&5_u64);
+        stream.append(&vec![0xab_u8, 0xcd]);
+        stream
+            .begin_list(1)
+            .begin_list(2)
+            .append(&[0x11_u8; 20].as_slice());
+        stream.begin_list(1).append(&[0x22_u8; 32].as_slice());
+    }
+
+    fn typed(transaction_type: u8, stream: RlpStream) -> Vec<u8> {
+        let mut bytes = vec![transaction_type];
+        bytes.extend_from_slice(&stream.out());
+        bytes
+    }
+
+    #[test]
+    fn legacy_transaction() {
+        // The signed transaction of the example of EIP-155.
+        let bytes = serde_utils::hex::decode(concat!(
+            "0xf86c098504a817c800825208943535353535353535353535353535353535353535880de0b6b3a7640000",
+            "8025a028ef61340bd939bc2195fe537567866003e1a15d3c71ff63e1590620aa636276a067cbe9d8997f76",
+            "1aecb703304b3800ccf555c9f3dc64214b297fb1966a3b6d83",
+        ))
+        .unwrap();
+
+        let envelope = TransactionEnvelope::decode(&bytes).unwrap();
+        assert_eq!(envelope.transaction_type(), 0);
+        assert_eq!(envelope.chain_id(), Some(1));
+        assert_eq!(envelope.nonce(), 9);
+        assert_eq!(envelope.gas(), 21_000);
+        assert_eq!(envelope.to(), Some(address(0x35)));
+        assert_eq!(envelope.value(), &u256(1_000_000_000_000_000_000));
+        assert!(envelope.input().is_empty());
+
+        let TransactionEnvelope::Legacy(transaction) = envelope else {
+            panic!("not a legacy transaction");
+        };
+        assert_eq!(transaction.gas_price, u256(20_000_000_000));
+        assert_eq!(transaction.v, 37);
+        assert_eq!(transaction.r[3], 0x28ef61340bd939bc);
+        assert_eq!(transaction.s[0], 0x297fb1966a3b6d83);
+
+        // Trailing bytes after the list.
+        let mut trailing = bytes.clone();
+        trailing.push(0);
+        assert!(TransactionEnvelope::decode(&trailing).is_err());
+        assert!(TransactionEnvelope::decode(&bytes[..bytes.len() - 1]).is_err());
+    }
+
+    #[test]
+    fn typed_transactions() {
+        let mut stream = RlpStream::new_list(12);
+        append_fee_fields(&mut stream, &[]);
+        stream.append(&1_u8).append(&2_u8).append(&3_u8);
+        let envelope = TransactionEnvelope::decode(&typed(2, stream)).unwrap();
+
+        assert_eq!(envelope.transaction_type(), DYNAMIC_FEE_TRANSACTION_TYPE);
+        assert_eq!(envelope.chain_id(), Some(1));
+        assert_eq!(envelope.nonce(), 7);
+        // A transaction without a recipient creates a contract.
+        assert_eq!(envelope.to(), None);
+        assert_eq!(envelope.value(), &u256(5));
+        assert_eq!(envelope.input(), [0xab, 0xcd]);
+        let TransactionEnvelope::DynamicFee(transaction) = envelope else {
+            panic!("not a dynamic fee transaction");
+        };
+        assert_eq!(transaction.max_fee_per_gas, u256(30_000_000_000));
+        assert_eq!(
+            transaction.access_list,
+            [AccessListItem {
+                address: address(0x11),
+                storage_keys: vec![Hash256::repeat_byte(0x22)],
+            }]
+        );
+        assert!(transaction.y_parity);
+
+        let mut stream = RlpStream::new_list(11);
+        stream
+            .append(&1_u64)
+            .append(&3_u64)
+            .append(&20_000_000_000_u64);
+        stream
+            .append(&50_000_u64)
+            .append(&[0x33_u8; 20].as_slice())
+            .append(&0_u64);
+        stream.append_empty_data().begin_list(0);
+        stream.append(&0_u8).append(&2_u8).append(&3_u8);
+        let envelope = TransactionEnvelope::decode(&typed(1, stream)).unwrap();
+
+        assert_eq!(envelope.transaction_type(), ACCESS_LIST_TRANSACTION_TYPE);
+        assert_eq!(envelope.gas(), 50_000);
+        assert_eq!(envelope.to(), Some(address(0x33)));
+        assert_eq!(envelope.value(), &u256(0));
+
+        let mut stream = RlpStream::new_list(14);
+        append_fee_fields(&mut stream, &[0x33; 20]);
+        stream.append(&1_u64);
+        stream.begin_list(2);
+        stream
+            .append(&[0x01; 32].as_slice())
+            .append(&[0x02; 32].as_slice());
+        stream.append(&0_u8).append(&2_u8).append(&3_u8);
+        let envelope = TransactionEnvelope::decode(&typed(3, stream)).unwrap();
+
+        assert_eq!(envelope.transaction_type(), BLOB_TRANSACTION_TYPE);
+        assert_eq!(envelope.to(), Some(address(0x33)));
+        assert_eq!(
+            envelope.blob_versioned_hashes(),
+            [Hash256::repeat_byte(1), Hash256::repeat_byte(2)]
+        );
+
+        let mut stream = RlpStream::new_list(13);
+        append_fee_fields(&mut stream, &[0x33; 20]);
+        stream.begin_list(1).begin_list(6);
+        stream
+            .append(&1_u64)
+            .append(&[0x44; 20].as_slice())
+            .append(&8_u64);
+        stream.append(&1_u8).append(&2_u8).append(&3_u8);
+        stream.append(&0_u8).append(&2_u8).append(&3_u8);
+        let envelope = TransactionEnvelope::decode(&typed(4, stream)).unwrap();
+
+        assert_eq!(envelope.transaction_type(), SET_CODE_TRANSACTION_TYPE);
+        assert!(envelope.blob_versioned_hashes().is_empty());
+        let TransactionEnvelope::SetCode(transaction) = envelope else {
+            panic!("not a set code transaction");
+        };
+        assert_eq!(transaction.authorization_list[0].address, address(0x44));
+        assert_eq!(transaction.authorization_list[0].nonce, 8);
+    }
+
+    #[test]
+    fn invalid_transactions() {
+        let mut stream = RlpStream::new_list(12);
+        append_fee_fields(&mut stream, &[]);
+        stream.append(&1_u8).append(&2_u8).append(&3_u8);
+        let dynamic_fee = stream.out().to_vec();
+
+        // The fields of a dynamic fee transaction are one short of a blob transaction.
+        assert!(TransactionEnvelope::decode(&typed(3, RlpStream::new())).is_err());
+        let mut bytes = vec![BLOB_TRANSACTION_TYPE];
+        bytes.extend_from_slice(&dynamic_fee);
+        assert!(TransactionEnvelope::decode(&bytes).is_err());
+
+        bytes[0] = 0x05;
+        assert_eq!(
+            TransactionEnvelope::decode(&bytes),
+            Err(Error::InvalidTransaction {
+                reason: "unknown transaction type 5".into()
+            })
+        );
+        bytes[0] = 0x00;
+        assert!(TransactionEnvelope::decode(&bytes).is_err());
+        assert!(TransactionEnvelope::decode(&[]).is_err());
+
+        // A y parity of 2.
+        let mut stream = RlpStream::new_list(12);
+        append_fee_fields(&mut stream, &[]);
+        stream.append(&2_u8).append(&2_u8).append(&3_u8);
+        assert!(TransactionEnvelope::decode(&typed(2, stream)).is_err());
+
+        // A fee with a leading zero byte.
+        let mut stream = RlpStream::new_list(12);
+        stream.append(&1_u64).append(&7_u64);
+        stream.append(&[0_u8, 1].as_slice());
+        for _ in 3..12 {
+            stream.append_empty_data();
+        }
+        assert!(TransactionEnvelope::decode(&typed(2, stream)).is_err());
+
+        // Lists in place of a fee and of a storage key, whose payloads are a valid fee and hash.
+        let with_lists = |fee_list: bool, key_list: bool| {
+            let mut stream = RlpStream::new_list(12);
+            stream.append(&1_u64).append(&7_u64);
+            if fee_list {
+                stream.begin_list(1).append(&1_u8);
+            } else {
+                stream.append(&1_u8);
+            }
+            stream.append(&30_000_000_000_u64).append(&21_000_u64);
+            stream
+                .append_empty_data()
+                .append(&5_u64)
+                .append_empty_data();
+            stream
+                .begin_list(1)
+                .begin_list(2)
+                .append(&[0x11_u8; 20].as_slice());
+            if key_list {
+                stream
+                    .begin_list(1)
+                    .begin_list(1)
+                    .append(&[0x22_u8; 31].as_slice());
+            } else {
+                stream.begin_list(1).append(&[0x22_u8; 32].as_slice());
+            }
+            stream.append(&1_u8).append(&2_u8).append(&3_u8);
+            TransactionEnvelope::decode(&typed(2, stream))
+        };
+        assert!(with_lists(false, false).is_ok());
+        assert!(with_lists(true, false).is_err());
+        assert!(with_lists(false, true).is_err());
+    }
+}
@@ -1,41 +1,156 @@
-use tree_hash::{Hash256, MerkleHasher, TreeHash, TreeHashType, BYTES_PER_CHUNK};
+use crate::hasher::{hash32_concat, sha256_backend};
+use tree_hash::{Hash256, TreeHash, TreeHashType, BYTES_PER_CHUNK};
 use typenum::Unsigned;
 
 /// A helper function providing common functionality between the `TreeHash` implementations for
 /// `FixedVector` and `VariableList`.
-pub fn vec_tree_hash_root<T, N>(vec: &[T]) -> Hash256
+pub fn vec_tree_hash_root<'a, T, N>(vec: impl IntoIterator<Item = &'a T>) -> Hash256
 where
-    T: TreeHash,
+    T: TreeHash + 'a,
     N: Unsigned,
+{
+    vec_tree_hash_root_with_limit(vec, N::to_usize())
+}
+
+/// `Sync` with the `rayon` feature, which hashes the values of long lists and vectors in parallel,
+/// and implemented by all types otherwise.
+#[cfg(feature = "rayon")]
+pub trait MaybeSync: Sync {}
+
+#[cfg(feature = "rayon")]
+impl<T: Sync + ?Sized> MaybeSync for T {}
+
+#[cfg(not(feature = "rayon"))]
+pub trait MaybeSync {}
+
+#[cfg(not(feature = "rayon"))]
+impl<T: ?Sized> MaybeSync for T {}
+
+/// The number of composite values from which `slice_tree_hash_root` computes their roots in
+/// parallel, below which the overhead of splitting the work between threads outweighs hashing.
+#[cfg(feature = "rayon")]
+const PAR_TREE_HASH_MIN_LEN: usize = 64;
+
+/// As per `vec_tree_hash_root_with_limit`, but with the `rayon` feature computes the roots of
+/// composite values in parallel if there are at least `PAR_TREE_HASH_MIN_LEN` of them, e.g. the
+/// transactions of an `ExecutionPayload`.
+pub fn slice_tree_hash_root<T: TreeHash + MaybeSync>(vec: &[T], limit: usize) -> Hash256 {
+    #[cfg(feature = "rayon")]
+    if vec.len() >= PAR_TREE_HASH_MIN_LEN {
+        return par_vec_tree_hash_root(vec, limit);
+    }
+
+    vec_tree_hash_root_with_limit(vec, limit)
+}
+
+/// As per `vec_tree_hash_root`, but with a maximum length that is only known at runtime.
+pub fn vec_tree_hash_root_with_limit<'a, T>(
+    vec: impl IntoIterator<Item = &'a T>,
+    limit: usize,
+) -> Hash256
+where
+    T: TreeHash + 'a,
 {
     match T::tree_hash_type() {
         TreeHashType::Basic => {
-            let mut hasher = MerkleHasher::with_leaves(
-                (N::to_usize() + T::tree_hash_packing_factor() - 1) / T::tree_hash_packing_factor(),
-            );
+            let leaves = limit.div_ceil(T::tree_hash_packing_factor());
+            if leaves <= 1 {
+                return single_chunk(vec);
+            }
+
+            let mut bytes = vec![];
 
             for item in vec {
-                hasher
-                    .write(&item.tree_hash_packed_encoding())
-                    .expect("ssz_types variable vec should not contain more elements than max");
+                bytes.extend_from_slice(&item.tree_hash_packed_encoding());
             }
 
-            hasher
-                .finish()
-                .expect("ssz_types variable vec should not have a remaining buffer")
+            merkleize(bytes, leaves)
         }
         TreeHashType::Container | TreeHashType::List | TreeHashType::Vector => {
-            let mut hasher = MerkleHasher::with_leaves(N::to_usize());
+            let vec = vec.into_iter();
+            // Leave room for the zero chunk padding the first layer to an even length.
+            let mut bytes = Vec::with_capacity((vec.size_hint().0 + 1) * BYTES_PER_CHUNK);
 
             for item in vec {
-                hasher
-                    .write(item.tree_hash_root().as_bytes())
-                    .expect("ssz_types vec should not contain more elements than max");
+                bytes.extend_from_slice(item.tree_hash_root().as_bytes());
             }
 
-            hasher
-                .finish()
-                .expect("ssz_types vec should not have a remaining buffer")
+            merkleize(bytes, limit)
+        }
+    }
+}
+
+/// Returns the root of a vector or list of `limit` composite values, such as roots or containers,
+/// without mixing in the length.
+///
+/// The roots of the values are written into a single buffer which is then merkleized, with the
+/// zero padding of each layer added in place.
+///
+/// ## Example
+///
+/// ```
+/// use ssz_types::{batch_tree_hash_root, FixedVector, MerkleProof, typenum::U8};
+/// use tree_hash::{Hash256, TreeHash};
+///
+/// let roots = (0..8).map(Hash256::from_low_u64_be).collect::<Vec<_>>();
+/// let vector = FixedVector::<Hash256, U8>::new(roots.clone()).unwrap();
+///
+/// assert_eq!(batch_tree_hash_root(&roots, 8), vector.tree_hash_root());
+/// // The root of the first half of the vector.
+/// assert_eq!(batch_tree_hash_root(&roots[..4], 4), vector.tree_hash_node(2).unwrap());
+/// ```
+///
+/// ## Panics
+///
+/// Panics if there are more than `limit` values.
+pub fn batch_tree_hash_root<T: TreeHash>(values: &[T], limit: usize) -> Hash256 {
+    vec_tree_hash_root_with_limit(values, limit)
+}
+
+/// Returns the root of basic values which are packed into a single chunk, e.g. the bytes of a
+/// 32 byte root, which is the chunk itself.
+fn single_chunk<'a, T: TreeHash + 'a>(vec: impl IntoIterator<Item = &'a T>) -> Hash256 {
+    let mut chunk = Hash256::zero();
+    let mut len = 0;
+
+    for item in vec {
+        let bytes = item.tree_hash_packed_encoding();
+        assert!(
+            len + bytes.len() <= BYTES_PER_CHUNK,
+            "ssz_types vec should not contain more elements than max"
+        );
+
+        chunk[len..len + bytes.len()].copy_from_slice(&bytes);
+        len += bytes.len();
+    }
+
+    chunk
+}
+
+/// As per `vec_tree_hash_root_with_limit`, but computes the roots of composite values in parallel.
+#[cfg(feature = "rayon")]
+pub fn par_vec_tree_hash_root<T>(vec: &[T], limit: usize) -> Hash256
+where
+    T: TreeHash + Sync,
+{
+    use rayon::prelude::*;
+
+    match T::tree_hash_type() {
+        TreeHashType::Basic => vec_tree_hash_root_with_limit(vec, limit),
+        TreeHashType::Container | TreeHashType::List | TreeHashType::Vector => {
+            let roots = vec
+                .par_iter()
+                .map(TreeHash::tree_hash_root)
+                .collect::<Vec<_>>();
+
+            merkleize(
+                roots
+                    .iter()
+                    .flat_map(|root| root.as_bytes())
+                    .copied()
+                    .collect(),
+                limit,
+            )
         }
     }
 }
@@ -46,13 +161,82 @@ pub fn bitfield_bytes_tree_hash_root<N: Unsigned>(bytes: &[u8]) -> Hash256 {
     let byte_size = (N::to_usize() + 7) / 8;
     let leaf_count = (byte_size + BYTES_PER_CHUNK - 1) / BYTES_PER_CHUNK;
 
-    let mut hasher = MerkleHasher::with_leaves(leaf_count);
+    merkleize(bytes.to_vec(), leaf_count)
+}
+
+/// Returns the root of the smallest tree with at least `leaves` leaves, the first of which are the
+/// chunks of `bytes`, padded with zeros.
+///
+/// Each layer of the tree is hashed with a single call to the `Sha256Backend`, or with one call per
+/// thread for large layers with the `rayon` feature.
+///
+/// ## Panics
+///
+/// Panics if `bytes` does not fit into `leaves` chunks.
+pub(crate) fn merkleize(mut bytes: Vec<u8>, leaves: usize) -> Hash256 {
+    let depth = leaves.max(1).next_power_of_two().trailing_zeros() as usize;
+
+    assert!(
+        bytes.len() <= BYTES_PER_CHUNK << depth,
+        "ssz_types vec should not contain more elements than max"
+    );
+
+    if bytes.is_empty() {
+        return zero_hash(depth);
+    }
+    bytes.resize(bytes.len().div_ceil(BYTES_PER_CHUNK) * BYTES_PER_CHUNK, 0);
+
+    // Each layer is hashed into the other of two buffers, which have room for the padding of the
+    // layers they hold.
+    let mut parents = Vec::with_capacity(bytes.len() / 2 + BYTES_PER_CHUNK);
+
+    for height in 0..depth {
+        if (bytes.len() / BYTES_PER_CHUNK) % 2 == 1 {
+            bytes.extend_from_slice(zero_hash(height).as_bytes());
+        }
+
+        parents.clear();
+        parents.resize(bytes.len() / 2, 0);
+        hash_layer(&bytes, &mut parents);
+        std::mem::swap(&mut bytes, &mut parents);
+    }
+
+    Hash256::from_slice(&bytes)
+}
+
+/// The number of parent nodes hashed by each thread when a layer is split between threads.
+#[cfg(feature = "rayon")]
+const NODES_PER_TASK: usize = 1024;
+
+/// Hashes each pair of nodes in `layer` into the node at the same position of `parents`.
+///
+/// With the `rayon` feature, layers with at least 4 tasks worth of nodes are split between
+/// threads. Smaller layers are hashed on the current thread, since splitting them would cost more
+/// than it saves.
+fn hash_layer(layer: &[u8], parents: &mut [u8]) {
+    #[cfg(feature = "rayon")]
+    if parents.len() >= 4 * NODES_PER_TASK * BYTES_PER_CHUNK {
+        use rayon::prelude::*;
 
-    hasher
-        .write(bytes)
-        .expect("bitfield should not exceed tree hash leaf limit");
+        layer
+            .par_chunks(2 * NODES_PER_TASK * BYTES_PER_CHUNK)
+            .zip(parents.par_chunks_mut(NODES_PER_TASK * BYTES_PER_CHUNK))
+            .for_each(|(layer, parents)| sha256_backend().hash_pairs(layer, parents));
+        return;
+    }
+
+    sha256_backend().hash_pairs(layer, parents);
+}
+
+/// Returns the root of a list from the root of its data and its length.
+pub(crate) fn mix_in_length(root: &Hash256, length: usize) -> Hash256 {
+    let mut length_chunk = Hash256::zero();
+    length_chunk.as_bytes_mut()[..8].copy_from_slice(&(length as u64).to_le_bytes());
+
+    hash32_concat(root.as_bytes(), length_chunk.as_bytes())
+}
 
-    hasher
-        .finish()
-        .expect("bitfield tree hash buffer should not exceed leaf limit")
+/// Returns the root of a tree of `2^height` zero chunks.
+pub(crate) fn zero_hash(height: usize) -> Hash256 {
+    Hash256::from_slice(&ethereum_hashing::ZERO_HASHES[height])
 }
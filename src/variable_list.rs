@@ -1,7 +1,13 @@
-use crate::tree_hash::vec_tree_hash_root;
-use crate::Error;
+use crate::hex::fmt_debug_vec;
+use crate::proof::{list_gindex_of, list_tree_hash_node, vec_gindex_of, vec_tree_hash_node};
+#[cfg(feature = "rayon")]
+use crate::tree_hash::par_vec_tree_hash_root;
+use crate::tree_hash::{mix_in_length, slice_tree_hash_root, MaybeSync};
+use crate::{Error, MemorySize, MerklePath, MerkleProof, SszReflect, SszSchema};
 use derivative::Derivative;
-use serde_derive::{Deserialize, Serialize};
+use serde::Deserialize;
+use serde_derive::Serialize;
+use std::fmt;
 use std::marker::PhantomData;
 use std::ops::{Deref, DerefMut, Index, IndexMut};
 use std::slice::SliceIndex;
@@ -29,15 +35,18 @@ pub use typenum;
 /// let base: Vec<u64> = vec![1, 2, 3, 4];
 ///
 /// // Create a `VariableList` from a `Vec` that has the expected length.
-/// let exact: VariableList<_, typenum::U4> = VariableList::from(base.clone());
+/// let exact: VariableList<_, typenum::U4> = VariableList::try_from(base.clone()).unwrap();
 /// assert_eq!(&exact[..], &[1, 2, 3, 4]);
 ///
-/// // Create a `VariableList` from a `Vec` that is too long and the `Vec` is truncated.
-/// let short: VariableList<_, typenum::U3> = VariableList::from(base.clone());
+/// // Creating a `VariableList` from a `Vec` that is too long is an error.
+/// assert!(VariableList::<_, typenum::U3>::try_from(base.clone()).is_err());
+///
+/// // Unless explicitly requested, in which case the `Vec` is truncated.
+/// let short: VariableList<_, typenum::U3> = VariableList::new_truncated(base.clone());
 /// assert_eq!(&short[..], &[1, 2, 3]);
 ///
 /// // Create a `VariableList` from a `Vec` that is shorter than the maximum.
-/// let mut long: VariableList<_, typenum::U5> = VariableList::from(base);
+/// let mut long: VariableList<_, typenum::U5> = VariableList::try_from(base).unwrap();
 /// assert_eq!(&long[..], &[1, 2, 3, 4]);
 ///
 /// // Push a value to if it does not exceed the maximum
@@ -47,7 +56,7 @@ pub use typenum;
 /// // Push a value to if it _does_ exceed the maximum.
 /// assert!(long.push(6).is_err());
 /// ```
-#[derive(Debug, Clone, Serialize, Deserialize, Derivative)]
+#[derive(Clone, Serialize, Derivative)]
 #[derivative(PartialEq, Eq, Hash(bound = "T: std::hash::Hash"))]
 #[serde(transparent)]
 pub struct VariableList<T, N> {
@@ -55,6 +64,13 @@ pub struct VariableList<T, N> {
     _phantom: PhantomData<N>,
 }
 
+/// Formats byte lists as `0x`-prefixed hex, e.g. transactions, and other lists by their values.
+impl<T: fmt::Debug + 'static, N> fmt::Debug for VariableList<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_debug_vec("VariableList", &self.vec, f)
+    }
+}
+
 /// Maximum number of elements to pre-allocate in `try_from_iter`.
 ///
 /// Some variable lists have *very long* maximum lengths such that we can't actually fit them
@@ -80,6 +96,27 @@ impl<T, N: Unsigned> VariableList<T, N> {
         }
     }
 
+    /// Create a new list from `vec`, truncating it if it is longer than the maximum length of
+    /// `Self`.
+    ///
+    /// Prefer `Self::new` or `Self::try_from` unless silently dropping values is intended.
+    pub fn new_truncated(mut vec: Vec<T>) -> Self {
+        vec.truncate(N::to_usize());
+
+        Self {
+            vec,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Moves the values of `list` into a list with a different maximum length, without copying
+    /// them.
+    ///
+    /// Returns `Err` if `list` is longer than the maximum length of `Self`.
+    pub fn try_from_list<M: Unsigned>(list: VariableList<T, M>) -> Result<Self, Error> {
+        Self::new(list.vec)
+    }
+
     /// Create an empty list.
     pub fn empty() -> Self {
         Self {
@@ -99,8 +136,39 @@ impl<T, N: Unsigned> VariableList<T, N> {
     }
 
     /// Returns the type-level maximum length.
-    pub fn max_len() -> usize {
-        N::to_usize()
+    pub const fn max_len() -> usize {
+        N::USIZE
+    }
+
+    /// Returns the number of values that can be appended to `self` before reaching the maximum
+    /// length.
+    pub fn remaining_capacity(&self) -> usize {
+        Self::max_len().saturating_sub(self.len())
+    }
+
+    /// True if `self` contains the maximum number of values, such that `push` would fail.
+    pub fn is_full(&self) -> bool {
+        self.remaining_capacity() == 0
+    }
+
+    /// Returns a reference to the value at `index`, or `None` if it is out of bounds.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.vec.get(index)
+    }
+
+    /// Returns a mutable reference to the value at `index`, or `None` if it is out of bounds.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.vec.get_mut(index)
+    }
+
+    /// Returns a reference to the first value, or `None` if `self` is empty.
+    pub fn first(&self) -> Option<&T> {
+        self.vec.first()
+    }
+
+    /// Returns a reference to the last value, or `None` if `self` is empty.
+    pub fn last(&self) -> Option<&T> {
+        self.vec.last()
     }
 
     /// Appends `value` to the back of `self`.
@@ -117,19 +185,178 @@ impl<T, N: Unsigned> VariableList<T, N> {
             })
         }
     }
-}
 
-impl<T, N: Unsigned> From<Vec<T>> for VariableList<T, N> {
-    fn from(mut vec: Vec<T>) -> Self {
-        vec.truncate(N::to_usize());
+    /// Inserts `value` at position `index`, shifting all values after it to the right.
+    ///
+    /// Returns `Err` when `index > self.len()` or when inserting `value` would exceed the maximum
+    /// length.
+    pub fn insert(&mut self, index: usize, value: T) -> Result<(), Error> {
+        if index > self.vec.len() {
+            Err(Error::OutOfBounds {
+                i: index,
+                len: self.vec.len(),
+            })
+        } else if self.vec.len() >= Self::max_len() {
+            Err(Error::OutOfBounds {
+                i: self.vec.len() + 1,
+                len: Self::max_len(),
+            })
+        } else {
+            self.vec.insert(index, value);
+            Ok(())
+        }
+    }
 
-        Self {
-            vec,
-            _phantom: PhantomData,
+    /// Removes and returns the value at position `index`, shifting all values after it to the
+    /// left.
+    ///
+    /// Returns `Err` when `index >= self.len()`.
+    pub fn remove(&mut self, index: usize) -> Result<T, Error> {
+        if index < self.vec.len() {
+            Ok(self.vec.remove(index))
+        } else {
+            Err(Error::OutOfBounds {
+                i: index,
+                len: self.vec.len(),
+            })
+        }
+    }
+
+    /// Removes the last value from `self` and returns it, or `None` if `self` is empty.
+    pub fn pop(&mut self) -> Option<T> {
+        self.vec.pop()
+    }
+
+    /// Shortens `self` to `len` values, dropping the rest.
+    ///
+    /// Has no effect if `len` is greater than or equal to `self.len()`.
+    pub fn truncate(&mut self, len: usize) {
+        self.vec.truncate(len)
+    }
+
+    /// Retains only the values for which `f` returns `true`, preserving their order.
+    pub fn retain<F>(&mut self, f: F)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.vec.retain(f)
+    }
+
+    /// Removes the values in `range` from `self`, returning them as an iterator.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the start of `range` is greater than its end or its end is greater than
+    /// `self.len()`, as per `Vec::drain`.
+    pub fn drain<R>(&mut self, range: R) -> std::vec::Drain<'_, T>
+    where
+        R: std::ops::RangeBounds<usize>,
+    {
+        self.vec.drain(range)
+    }
+
+    /// Splits `self` in two at `at`, leaving the values before `at` in `self` and returning the
+    /// rest.
+    ///
+    /// Returns `Err` when `at > self.len()`.
+    pub fn split_off(&mut self, at: usize) -> Result<Self, Error> {
+        if at <= self.vec.len() {
+            Ok(Self {
+                vec: self.vec.split_off(at),
+                _phantom: PhantomData,
+            })
+        } else {
+            Err(Error::OutOfBounds {
+                i: at,
+                len: self.vec.len(),
+            })
+        }
+    }
+
+    /// Moves all values of `other` to the back of `self`.
+    ///
+    /// Returns `Err` without modifying `self` when the combined length would exceed the maximum
+    /// length.
+    pub fn extend_from_list<M: Unsigned>(
+        &mut self,
+        other: VariableList<T, M>,
+    ) -> Result<(), Error> {
+        let len = self.vec.len() + other.len();
+
+        if len <= Self::max_len() {
+            self.vec.extend(other.vec);
+            Ok(())
+        } else {
+            Err(Error::OutOfBounds {
+                i: len,
+                len: Self::max_len(),
+            })
+        }
+    }
+
+    /// Joins `lists` into a single list, in order.
+    ///
+    /// Returns `Err` when the combined length exceeds the maximum length.
+    pub fn concat<M, I>(lists: I) -> Result<Self, Error>
+    where
+        M: Unsigned,
+        I: IntoIterator<Item = VariableList<T, M>>,
+    {
+        lists
+            .into_iter()
+            .try_fold(Self::empty(), |mut list, other| {
+                list.extend_from_list(other).map(|()| list)
+            })
+    }
+
+    /// Binary searches `self` with the comparator `f`, as per `slice::binary_search_by`.
+    ///
+    /// Returns `Ok` with the index of a matching value, or `Err` with the index where a matching
+    /// value could be inserted while maintaining the order. `self` must be sorted consistently with
+    /// `f`, otherwise the result is unspecified.
+    pub fn binary_search_by<F>(&self, f: F) -> Result<usize, usize>
+    where
+        F: FnMut(&T) -> std::cmp::Ordering,
+    {
+        self.vec.binary_search_by(f)
+    }
+
+    /// Returns `true` if `compare` returns `true` for every pair of adjacent values.
+    ///
+    /// Use `|a, b| a <= b` to check that `self` is sorted and `|a, b| a < b` to check that it is
+    /// sorted and free of duplicates.
+    pub fn is_sorted_by<F>(&self, mut compare: F) -> bool
+    where
+        F: FnMut(&T, &T) -> bool,
+    {
+        self.vec.windows(2).all(|pair| compare(&pair[0], &pair[1]))
+    }
+}
+
+impl<T: Ord, N: Unsigned> VariableList<T, N> {
+    /// Inserts `value` into `self`, which must be sorted in ascending order, keeping it sorted.
+    ///
+    /// Returns `Ok(false)` without modifying `self` if an equal value is already present, so a
+    /// list built only through this method remains sorted and free of duplicates.
+    ///
+    /// Returns `Err` when inserting `value` would exceed the maximum length.
+    pub fn insert_sorted(&mut self, value: T) -> Result<bool, Error> {
+        match self.vec.binary_search(&value) {
+            Ok(_) => Ok(false),
+            Err(index) => self.insert(index, value).map(|()| true),
         }
     }
 }
 
+impl<T, N: Unsigned> TryFrom<Vec<T>> for VariableList<T, N> {
+    type Error = Error;
+
+    /// Equivalent to `Self::new`.
+    fn try_from(vec: Vec<T>) -> Result<Self, Error> {
+        Self::new(vec)
+    }
+}
+
 impl<T, N: Unsigned> From<VariableList<T, N>> for Vec<T> {
     fn from(list: VariableList<T, N>) -> Vec<T> {
         list.vec
@@ -184,6 +411,15 @@ impl<'a, T, N: Unsigned> IntoIterator for &'a VariableList<T, N> {
     }
 }
 
+impl<'a, T, N: Unsigned> IntoIterator for &'a mut VariableList<T, N> {
+    type Item = &'a mut T;
+    type IntoIter = std::slice::IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
 impl<T, N: Unsigned> IntoIterator for VariableList<T, N> {
     type Item = T;
     type IntoIter = std::vec::IntoIter<T>;
@@ -195,7 +431,7 @@ impl<T, N: Unsigned> IntoIterator for VariableList<T, N> {
 
 impl<T, N: Unsigned> tree_hash::TreeHash for VariableList<T, N>
 where
-    T: tree_hash::TreeHash,
+    T: tree_hash::TreeHash + MaybeSync,
 {
     fn tree_hash_type() -> tree_hash::TreeHashType {
         tree_hash::TreeHashType::List
@@ -210,9 +446,9 @@ where
     }
 
     fn tree_hash_root(&self) -> Hash256 {
-        let root = vec_tree_hash_root::<T, N>(&self.vec);
+        let root = slice_tree_hash_root(&self.vec, N::to_usize());
 
-        tree_hash::mix_in_length(&root, self.len())
+        mix_in_length(&root, self.len())
     }
 }
 
@@ -273,7 +509,7 @@ where
         let max_len = N::to_usize();
 
         if bytes.is_empty() {
-            Ok(vec![].into())
+            Ok(Self::empty())
         } else if T::is_ssz_fixed_len() {
             let num_items = bytes
                 .len()
@@ -293,22 +529,125 @@ where
                     vec.push(T::from_ssz_bytes(chunk)?);
                     Ok(vec)
                 })
-                .map(Into::into)
+                .map(Self::new_truncated)
         } else {
-            ssz::decode_list_of_variable_length_items(bytes, Some(max_len))
-                .map(|vec: Vec<_>| vec.into())
+            ssz::decode_list_of_variable_length_items(bytes, Some(max_len)).map(Self::new_truncated)
         }
     }
 }
 
+impl<T: MemorySize, N: Unsigned> MemorySize for VariableList<T, N> {
+    fn heap_size(&self) -> usize {
+        self.vec.heap_size()
+    }
+}
+
+impl<T, N: Unsigned> MerkleProof for VariableList<T, N>
+where
+    T: tree_hash::TreeHash + MerkleProof,
+{
+    fn tree_hash_node(&self, gindex: u64) -> Result<Hash256, Error> {
+        list_tree_hash_node(gindex, self.len(), |gindex| {
+            vec_tree_hash_node(&self.vec, N::to_usize(), gindex)
+        })
+    }
+}
+
+impl<T, N: Unsigned> MerklePath for VariableList<T, N>
+where
+    T: tree_hash::TreeHash + MerklePath,
+{
+    fn gindex_of(path: &[&str]) -> Result<u64, Error> {
+        list_gindex_of(path, |path| vec_gindex_of::<T>(N::to_usize(), path))
+    }
+}
+
+impl<T: SszReflect, N: Unsigned> SszReflect for VariableList<T, N> {
+    fn ssz_schema() -> SszSchema {
+        SszSchema::list(T::ssz_schema(), N::to_usize())
+    }
+}
+
+impl<'de, T, N> Deserialize<'de> for VariableList<T, N>
+where
+    T: Deserialize<'de>,
+    N: Unsigned,
+{
+    /// Deserializes a sequence of values, returning an error if its length does not fit in the
+    /// maximum length of `Self`.
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let vec = Vec::<T>::deserialize(deserializer)?;
+        Self::new(vec)
+            .map_err(|e| serde::de::Error::custom(format!("invalid variable list: {:?}", e)))
+    }
+}
+
+/// The schema of an array of up to `N` items.
+#[cfg(feature = "schemars")]
+impl<T: schemars::JsonSchema, N: Unsigned> schemars::JsonSchema for VariableList<T, N> {
+    fn is_referenceable() -> bool {
+        false
+    }
+
+    fn schema_name() -> String {
+        format!("VariableList_of_{}_{}", T::schema_name(), N::to_usize())
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        crate::json_schema::array_schema(gen.subschema_for::<T>(), 0, N::to_usize())
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<T: tree_hash::TreeHash + Sync, N: Unsigned> VariableList<T, N> {
+    /// Returns the same root as `tree_hash_root`, computing the roots of the values in parallel
+    /// whatever the length of the list, where `tree_hash_root` only does so for long lists.
+    pub fn par_tree_hash_root(&self) -> Hash256 {
+        let root = par_vec_tree_hash_root(&self.vec, N::to_usize());
+
+        mix_in_length(&root, self.len())
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<T: Send, N: Unsigned> rayon::iter::IntoParallelIterator for VariableList<T, N> {
+    type Item = T;
+    type Iter = rayon::vec::IntoIter<T>;
+
+    fn into_par_iter(self) -> Self::Iter {
+        rayon::iter::IntoParallelIterator::into_par_iter(self.vec)
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<'a, T: Sync, N: Unsigned> rayon::iter::IntoParallelIterator for &'a VariableList<T, N> {
+    type Item = &'a T;
+    type Iter = rayon::slice::Iter<'a, T>;
+
+    fn into_par_iter(self) -> Self::Iter {
+        rayon::iter::IntoParallelIterator::into_par_iter(&self.vec)
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<'a, T: Send, N: Unsigned> rayon::iter::IntoParallelIterator for &'a mut VariableList<T, N> {
+    type Item = &'a mut T;
+    type Iter = rayon::slice::IterMut<'a, T>;
+
+    fn into_par_iter(self) -> Self::Iter {
+        rayon::iter::IntoParallelIterator::into_par_iter(&mut self.vec)
+    }
+}
+
 #[cfg(feature = "arbitrary")]
 impl<'a, T: arbitrary::Arbitrary<'a>, N: 'static + Unsigned> arbitrary::Arbitrary<'a>
     for VariableList<T, N>
 {
     fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
-        let max_size = N::to_usize();
-        let rand = usize::arbitrary(u)?;
-        let size = std::cmp::min(rand, max_size);
+        let size = std::cmp::min(u.arbitrary_len::<T>()?, N::to_usize());
         let mut vec: Vec<T> = Vec::with_capacity(size);
         for _ in 0..size {
             vec.push(<T>::arbitrary(u)?);
@@ -344,7 +683,7 @@ mod test {
     fn indexing() {
         let vec = vec![1, 2];
 
-        let mut fixed: VariableList<u64, U8192> = vec.clone().into();
+        let mut fixed: VariableList<u64, U8192> = vec.clone().try_into().unwrap();
 
         assert_eq!(fixed[0], 1);
         assert_eq!(&fixed[0..1], &vec[0..1]);
@@ -357,32 +696,380 @@ mod test {
     #[test]
     fn length() {
         let vec = vec![42; 5];
-        let fixed: VariableList<u64, U4> = VariableList::from(vec.clone());
+        let fixed: VariableList<u64, U4> = VariableList::new_truncated(vec.clone());
         assert_eq!(&fixed[..], &vec[0..4]);
 
         let vec = vec![42; 3];
-        let fixed: VariableList<u64, U4> = VariableList::from(vec.clone());
+        let fixed: VariableList<u64, U4> = VariableList::new_truncated(vec.clone());
         assert_eq!(&fixed[0..3], &vec[..]);
         assert_eq!(&fixed[..], &vec![42, 42, 42][..]);
 
         let vec = vec![];
-        let fixed: VariableList<u64, U4> = VariableList::from(vec);
+        let fixed: VariableList<u64, U4> = VariableList::new_truncated(vec);
         assert_eq!(&fixed[..], &[] as &[u64]);
     }
 
+    #[test]
+    fn try_from() {
+        let list: Result<VariableList<u64, U4>, _> = VariableList::try_from(vec![42; 4]);
+        assert_eq!(list.map(Vec::from), Ok(vec![42; 4]));
+
+        let list: Result<VariableList<u64, U4>, _> = vec![42; 3].try_into();
+        assert_eq!(list.map(Vec::from), Ok(vec![42; 3]));
+
+        let list: Result<VariableList<u64, U4>, _> = vec![42; 5].try_into();
+        assert_eq!(list, Err(Error::OutOfBounds { i: 5, len: 4 }));
+    }
+
+    #[test]
+    fn try_from_list() {
+        let list: VariableList<u64, U4> = VariableList::new(vec![1, 2, 3]).unwrap();
+
+        let larger: VariableList<u64, U8> = VariableList::try_from_list(list.clone()).unwrap();
+        assert_eq!(&larger[..], &[1, 2, 3]);
+
+        let exact: VariableList<u64, U3> = VariableList::try_from_list(list.clone()).unwrap();
+        assert_eq!(&exact[..], &[1, 2, 3]);
+
+        let smaller: Result<VariableList<u64, U2>, _> = VariableList::try_from_list(list);
+        assert_eq!(smaller, Err(Error::OutOfBounds { i: 3, len: 2 }));
+    }
+
+    #[test]
+    fn capacity() {
+        const MAX_LEN: usize = VariableList::<u64, U4>::max_len();
+        assert_eq!(MAX_LEN, 4);
+
+        let mut list: VariableList<u64, U4> = VariableList::empty();
+        for i in 0..4 {
+            assert_eq!(list.remaining_capacity(), 4 - i);
+            assert!(!list.is_full());
+            list.push(i as u64).unwrap();
+        }
+        assert_eq!(list.remaining_capacity(), 0);
+        assert!(list.is_full());
+
+        let list: VariableList<u64, U0> = VariableList::empty();
+        assert!(list.is_full());
+    }
+
+    #[test]
+    fn push_insert_remove() {
+        let mut list: VariableList<u64, U4> = VariableList::empty();
+
+        list.push(1).unwrap();
+        list.insert(0, 0).unwrap();
+        list.insert(2, 3).unwrap();
+        list.insert(2, 2).unwrap();
+        assert_eq!(&list[..], &[0, 1, 2, 3]);
+
+        assert_eq!(list.push(4), Err(Error::OutOfBounds { i: 5, len: 4 }));
+        assert_eq!(list.insert(0, 4), Err(Error::OutOfBounds { i: 5, len: 4 }));
+        assert_eq!(&list[..], &[0, 1, 2, 3]);
+
+        assert_eq!(list.remove(4), Err(Error::OutOfBounds { i: 4, len: 4 }));
+        assert_eq!(list.remove(1), Ok(1));
+        assert_eq!(&list[..], &[0, 2, 3]);
+
+        assert_eq!(list.insert(4, 4), Err(Error::OutOfBounds { i: 4, len: 3 }));
+        list.insert(3, 4).unwrap();
+        assert_eq!(&list[..], &[0, 2, 3, 4]);
+    }
+
+    #[test]
+    fn pop_truncate() {
+        let mut list: VariableList<u64, U4> = VariableList::new(vec![0, 1, 2, 3]).unwrap();
+
+        assert_eq!(list.pop(), Some(3));
+        assert_eq!(&list[..], &[0, 1, 2]);
+
+        list.truncate(5);
+        assert_eq!(&list[..], &[0, 1, 2]);
+
+        list.truncate(1);
+        assert_eq!(&list[..], &[0]);
+
+        assert_eq!(list.pop(), Some(0));
+        assert_eq!(list.pop(), None);
+        assert!(list.is_empty());
+
+        list.push(7).unwrap();
+        assert_eq!(&list[..], &[7]);
+    }
+
+    #[test]
+    fn get() {
+        let mut list: VariableList<u64, U4> = VariableList::new(vec![1, 2]).unwrap();
+
+        assert_eq!(list.get(1), Some(&2));
+        assert_eq!(list.get(2), None);
+        assert_eq!(list.first(), Some(&1));
+        assert_eq!(list.last(), Some(&2));
+
+        *list.get_mut(1).unwrap() = 42;
+        assert_eq!(list.get_mut(2), None);
+        assert_eq!(&list[..], &[1, 42]);
+
+        let empty: VariableList<u64, U4> = VariableList::empty();
+        assert_eq!(empty.first(), None);
+        assert_eq!(empty.last(), None);
+    }
+
+    #[test]
+    fn split_concat() {
+        let mut list: VariableList<u64, U8> = VariableList::new((0..6).collect()).unwrap();
+
+        assert_eq!(list.split_off(7), Err(Error::OutOfBounds { i: 7, len: 6 }));
+        let back = list.split_off(4).unwrap();
+        assert_eq!(&list[..], &[0, 1, 2, 3]);
+        assert_eq!(&back[..], &[4, 5]);
+        assert!(list.split_off(4).unwrap().is_empty());
+
+        let chunks: Vec<VariableList<u64, U4>> = vec![
+            VariableList::new(vec![0, 1, 2]).unwrap(),
+            VariableList::new(vec![3, 4, 5, 6]).unwrap(),
+            VariableList::new(vec![7]).unwrap(),
+        ];
+        let joined = VariableList::<u64, U8>::concat(chunks.clone()).unwrap();
+        assert_eq!(&joined[..], &(0..8).collect::<Vec<_>>()[..]);
+
+        let mut chunks = chunks;
+        chunks.push(VariableList::new(vec![8]).unwrap());
+        assert_eq!(
+            VariableList::<u64, U8>::concat(chunks),
+            Err(Error::OutOfBounds { i: 9, len: 8 })
+        );
+
+        let mut list: VariableList<u64, U4> = VariableList::new(vec![0, 1, 2]).unwrap();
+        let other: VariableList<u64, U2> = VariableList::new(vec![3, 4]).unwrap();
+        assert_eq!(
+            list.extend_from_list(other),
+            Err(Error::OutOfBounds { i: 5, len: 4 })
+        );
+        assert_eq!(&list[..], &[0, 1, 2]);
+        list.extend_from_list(VariableList::<u64, U2>::new(vec![3]).unwrap())
+            .unwrap();
+        assert_eq!(&list[..], &[0, 1, 2, 3]);
+    }
+
+    #[cfg(feature = "arbitrary")]
+    #[test]
+    fn arbitrary_respects_limits() {
+        use crate::{BitList, BitVector};
+        use arbitrary::{Arbitrary, Unstructured};
+
+        let data = [0xff; 256];
+
+        let list = VariableList::<u64, U4>::arbitrary(&mut Unstructured::new(&data)).unwrap();
+        assert!(list.len() <= 4);
+        let bits = BitList::<U10>::arbitrary(&mut Unstructured::new(&data)).unwrap();
+        assert!(bits.len() <= 10);
+        let bits = BitVector::<U10>::arbitrary(&mut Unstructured::new(&data)).unwrap();
+        assert_eq!(bits.len(), 10);
+        assert_eq!(bits.num_set_bits(), 10);
+    }
+
+    #[test]
+    fn sorted() {
+        let mut list: VariableList<u64, U4> = VariableList::empty();
+
+        assert_eq!(list.insert_sorted(5), Ok(true));
+        assert_eq!(list.insert_sorted(1), Ok(true));
+        assert_eq!(list.insert_sorted(3), Ok(true));
+        assert_eq!(list.insert_sorted(3), Ok(false));
+        assert_eq!(&list[..], &[1, 3, 5]);
+        assert!(list.is_sorted_by(|a, b| a < b));
+
+        assert_eq!(list.binary_search_by(|x| x.cmp(&3)), Ok(1));
+        assert_eq!(list.binary_search_by(|x| x.cmp(&4)), Err(2));
+
+        assert_eq!(list.insert_sorted(4), Ok(true));
+        assert_eq!(
+            list.insert_sorted(2),
+            Err(Error::OutOfBounds { i: 5, len: 4 })
+        );
+        assert_eq!(&list[..], &[1, 3, 4, 5]);
+
+        let list: VariableList<u64, U4> = VariableList::new(vec![1, 1, 2]).unwrap();
+        assert!(list.is_sorted_by(|a, b| a <= b));
+        assert!(!list.is_sorted_by(|a, b| a < b));
+        assert!(VariableList::<u64, U4>::empty().is_sorted_by(|a, b| a < b));
+    }
+
+    #[test]
+    fn retain_drain() {
+        let mut list: VariableList<u64, U8> = VariableList::new((0..8).collect()).unwrap();
+
+        list.retain(|x| x % 2 == 0);
+        assert_eq!(&list[..], &[0, 2, 4, 6]);
+
+        assert_eq!(list.drain(1..3).collect::<Vec<_>>(), vec![2, 4]);
+        assert_eq!(&list[..], &[0, 6]);
+
+        assert_eq!(list.drain(..).collect::<Vec<_>>(), vec![0, 6]);
+        assert!(list.is_empty());
+
+        for i in 0..8 {
+            list.push(i).unwrap();
+        }
+        assert!(list.push(8).is_err());
+    }
+
     #[test]
     fn deref() {
         let vec = vec![0, 2, 4, 6];
-        let fixed: VariableList<u64, U4> = VariableList::from(vec);
+        let fixed: VariableList<u64, U4> = VariableList::try_from(vec).unwrap();
 
         assert_eq!(fixed.first(), Some(&0));
         assert_eq!(fixed.get(3), Some(&6));
         assert_eq!(fixed.get(4), None);
     }
 
+    #[test]
+    fn iterator() {
+        let vec = vec![0, 2, 4, 6];
+        let list: VariableList<u64, U4> = VariableList::try_from(vec).unwrap();
+
+        // test the reference version
+        assert_eq!((&list).into_iter().sum::<u64>(), 12);
+        // test the owned version
+        assert_eq!(list.into_iter().sum::<u64>(), 12);
+    }
+
+    #[test]
+    fn iterator_mut() {
+        let vec = vec![0, 2, 4, 6];
+        let mut list: VariableList<u64, U4> = VariableList::try_from(vec).unwrap();
+
+        for x in &mut list {
+            *x += 1;
+        }
+        list.iter_mut().rev().take(1).for_each(|x| *x = 0);
+        assert_eq!(&list[..], &[1, 3, 5, 0]);
+    }
+
+    #[test]
+    fn iterator_exact_size_double_ended() {
+        let vec = vec![0, 2, 4];
+        let list: VariableList<u64, U4> = VariableList::try_from(vec).unwrap();
+
+        assert_eq!(list.iter().len(), 3);
+        assert_eq!(
+            list.iter().rev().copied().collect::<Vec<_>>(),
+            vec![4, 2, 0]
+        );
+
+        let mut iter = list.into_iter();
+        assert_eq!(iter.len(), 3);
+        assert_eq!(iter.next_back(), Some(4));
+        assert_eq!(iter.next(), Some(0));
+        assert_eq!(iter.len(), 1);
+        assert_eq!(iter.collect::<Vec<_>>(), vec![2]);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn par_iter() {
+        use rayon::prelude::*;
+
+        let mut list: VariableList<u64, U1024> = VariableList::new((0..1024).collect()).unwrap();
+
+        list.par_iter_mut().for_each(|x| *x *= 2);
+        assert_eq!(list.par_iter().sum::<u64>(), 1023 * 1024);
+        assert_eq!(
+            list.clone().into_par_iter().collect::<Vec<_>>(),
+            list.iter().copied().collect::<Vec<_>>()
+        );
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn par_tree_hash_root() {
+        let list: VariableList<VariableList<u8, U1024>, U1048576> = VariableList::new(
+            (0..10_000)
+                .map(|i| VariableList::new(vec![i as u8; i % 100]).unwrap())
+                .collect(),
+        )
+        .unwrap();
+        assert_eq!(list.par_tree_hash_root(), list.tree_hash_root());
+
+        // Large enough for the layers of the tree to be split between threads.
+        let list: VariableList<u64, U262144> = VariableList::new((0..100_000).collect()).unwrap();
+        let mut hasher = tree_hash::MerkleHasher::with_leaves(262144 / 4);
+        for value in list.iter() {
+            hasher.write(&value.to_le_bytes()).unwrap();
+        }
+        assert_eq!(
+            list.tree_hash_root(),
+            tree_hash::mix_in_length(&hasher.finish().unwrap(), list.len())
+        );
+        assert_eq!(list.par_tree_hash_root(), list.tree_hash_root());
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn derived_container_par_tree_hash_root() {
+        #[derive(TreeHash)]
+        struct Payload {
+            block_number: u64,
+            transactions: VariableList<VariableList<u8, U1024>, U1048576>,
+            roots: crate::FixedVector<crate::FixedVector<u8, U32>, U128>,
+        }
+
+        let payload = Payload {
+            block_number: 7,
+            transactions: VariableList::new(
+                (0..1000)
+                    .map(|i| VariableList::new(vec![i as u8; i % 200]).unwrap())
+                    .collect(),
+            )
+            .unwrap(),
+            roots: crate::FixedVector::new(
+                (0..128)
+                    .map(|i| crate::FixedVector::new(vec![i as u8; 32]).unwrap())
+                    .collect(),
+            )
+            .unwrap(),
+        };
+
+        // The roots of the values, hashed sequentially.
+        let mut hasher = tree_hash::MerkleHasher::with_leaves(1048576);
+        for transaction in payload.transactions.iter() {
+            hasher
+                .write(transaction.tree_hash_root().as_bytes())
+                .unwrap();
+        }
+        let transactions_root = tree_hash::mix_in_length(&hasher.finish().unwrap(), 1000);
+        let mut hasher = tree_hash::MerkleHasher::with_leaves(128);
+        for root in payload.roots.iter() {
+            hasher.write(root.tree_hash_root().as_bytes()).unwrap();
+        }
+        let roots_root = hasher.finish().unwrap();
+
+        let mut hasher = tree_hash::MerkleHasher::with_leaves(3);
+        hasher
+            .write(payload.block_number.tree_hash_root().as_bytes())
+            .unwrap();
+        hasher.write(transactions_root.as_bytes()).unwrap();
+        hasher.write(roots_root.as_bytes()).unwrap();
+        assert_eq!(payload.tree_hash_root(), hasher.finish().unwrap());
+    }
+
+    #[test]
+    fn serde() {
+        let list: VariableList<u64, U4> = serde_json::from_str("[]").unwrap();
+        assert_eq!(serde_json::to_string(&list).unwrap(), "[]");
+        let list: VariableList<u64, U4> = serde_json::from_str("[1,2,3]").unwrap();
+        assert_eq!(serde_json::to_string(&list).unwrap(), "[1,2,3]");
+        let list: VariableList<u64, U4> = serde_json::from_str("[1,2,3,4]").unwrap();
+        assert_eq!(serde_json::to_string(&list).unwrap(), "[1,2,3,4]");
+
+        assert!(serde_json::from_str::<VariableList<u64, U4>>("[1,2,3,4,5]").is_err());
+    }
+
     #[test]
     fn encode() {
-        let vec: VariableList<u16, U2> = vec![0; 2].into();
+        let vec: VariableList<u16, U2> = vec![0; 2].try_into().unwrap();
         assert_eq!(vec.as_ssz_bytes(), vec![0, 0, 0, 0]);
         assert_eq!(<VariableList<u16, U2> as Encode>::ssz_fixed_len(), 4);
     }
@@ -395,8 +1082,8 @@ mod test {
 
     #[test]
     fn u16_len_8() {
-        round_trip::<VariableList<u16, U8>>(vec![42; 8].into());
-        round_trip::<VariableList<u16, U8>>(vec![0; 8].into());
+        round_trip::<VariableList<u16, U8>>(vec![42; 8].try_into().unwrap());
+        round_trip::<VariableList<u16, U8>>(vec![0; 8].try_into().unwrap());
     }
 
     fn root_with_length(bytes: &[u8], len: usize) -> Hash256 {
@@ -406,31 +1093,31 @@ mod test {
 
     #[test]
     fn tree_hash_u8() {
-        let fixed: VariableList<u8, U0> = VariableList::from(vec![]);
+        let fixed: VariableList<u8, U0> = VariableList::try_from(vec![]).unwrap();
         assert_eq!(fixed.tree_hash_root(), root_with_length(&[0; 8], 0));
 
         for i in 0..=1 {
-            let fixed: VariableList<u8, U1> = VariableList::from(vec![0; i]);
+            let fixed: VariableList<u8, U1> = VariableList::try_from(vec![0; i]).unwrap();
             assert_eq!(fixed.tree_hash_root(), root_with_length(&vec![0; i], i));
         }
 
         for i in 0..=8 {
-            let fixed: VariableList<u8, U8> = VariableList::from(vec![0; i]);
+            let fixed: VariableList<u8, U8> = VariableList::try_from(vec![0; i]).unwrap();
             assert_eq!(fixed.tree_hash_root(), root_with_length(&vec![0; i], i));
         }
 
         for i in 0..=13 {
-            let fixed: VariableList<u8, U13> = VariableList::from(vec![0; i]);
+            let fixed: VariableList<u8, U13> = VariableList::try_from(vec![0; i]).unwrap();
             assert_eq!(fixed.tree_hash_root(), root_with_length(&vec![0; i], i));
         }
 
         for i in 0..=16 {
-            let fixed: VariableList<u8, U16> = VariableList::from(vec![0; i]);
+            let fixed: VariableList<u8, U16> = VariableList::try_from(vec![0; i]).unwrap();
             assert_eq!(fixed.tree_hash_root(), root_with_length(&vec![0; i], i));
         }
 
         let source: Vec<u8> = (0..16).collect();
-        let fixed: VariableList<u8, U16> = VariableList::from(source.clone());
+        let fixed: VariableList<u8, U16> = VariableList::try_from(source.clone()).unwrap();
         assert_eq!(fixed.tree_hash_root(), root_with_length(&source, 16));
     }
 
@@ -459,14 +1146,14 @@ mod test {
     fn tree_hash_composite() {
         let a = A { a: 0, b: 1 };
 
-        let fixed: VariableList<A, U0> = VariableList::from(vec![]);
+        let fixed: VariableList<A, U0> = VariableList::try_from(vec![]).unwrap();
         assert_eq!(
             fixed.tree_hash_root(),
             padded_root_with_length(&[0; 32], 0, 0),
         );
 
         for i in 0..=1 {
-            let fixed: VariableList<A, U1> = VariableList::from(vec![a; i]);
+            let fixed: VariableList<A, U1> = VariableList::try_from(vec![a; i]).unwrap();
             assert_eq!(
                 fixed.tree_hash_root(),
                 padded_root_with_length(&repeat(a.tree_hash_root().as_bytes(), i), i, 1),
@@ -476,7 +1163,7 @@ mod test {
         }
 
         for i in 0..=8 {
-            let fixed: VariableList<A, U8> = VariableList::from(vec![a; i]);
+            let fixed: VariableList<A, U8> = VariableList::try_from(vec![a; i]).unwrap();
             assert_eq!(
                 fixed.tree_hash_root(),
                 padded_root_with_length(&repeat(a.tree_hash_root().as_bytes(), i), i, 8),
@@ -486,7 +1173,7 @@ mod test {
         }
 
         for i in 0..=13 {
-            let fixed: VariableList<A, U13> = VariableList::from(vec![a; i]);
+            let fixed: VariableList<A, U13> = VariableList::try_from(vec![a; i]).unwrap();
             assert_eq!(
                 fixed.tree_hash_root(),
                 padded_root_with_length(&repeat(a.tree_hash_root().as_bytes(), i), i, 13),
@@ -496,7 +1183,7 @@ mod test {
         }
 
         for i in 0..=16 {
-            let fixed: VariableList<A, U16> = VariableList::from(vec![a; i]);
+            let fixed: VariableList<A, U16> = VariableList::try_from(vec![a; i]).unwrap();
             assert_eq!(
                 fixed.tree_hash_root(),
                 padded_root_with_length(&repeat(a.tree_hash_root().as_bytes(), i), i, 16),
@@ -548,4 +1235,14 @@ mod test {
             List::try_from_iter(wonky_iter).unwrap()
         );
     }
+
+    #[test]
+    fn debug() {
+        let bytes = VariableList::<u8, U4>::new(vec![0x12, 0x34]).unwrap();
+        assert_eq!(format!("{:?}", bytes), "0x1234");
+        assert_eq!(format!("{:?}", VariableList::<u8, U4>::default()), "0x");
+
+        let ints = VariableList::<u16, U4>::new(vec![0x12, 0x34]).unwrap();
+        assert_eq!(format!("{:?}", ints), "VariableList { vec: [18, 52], .. }");
+    }
 }
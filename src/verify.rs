@@ -0,0 +1,403 @@
+use crate::domains::{
+    compute_deposit_domain, compute_domain, DOMAIN_BEACON_ATTESTER, DOMAIN_BEACON_PROPOSER,
+    DOMAIN_BLS_TO_EXECUTION_CHANGE, DOMAIN_VOLUNTARY_EXIT,
+};
+use crate::signing::{SignedRoot, Version};
+use crate::{
+    DepositData, Error, Fork, IndexedAttestation, IndexedAttestationElectra, Preset, PublicKey,
+    SignatureBytes, SignedBeaconBlock, SignedBlsToExecutionChange, SignedVoluntaryExit,
+};
+use blst::BLST_ERROR;
+use tree_hash::Hash256;
+use typenum::Unsigned;
+
+/// The domain separation tag of the proof of possession scheme of the BLS signature draft, which
+/// consensus signatures are signed with.
+const DST: &[u8] = b"BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_";
+
+/// Verifies that `signature` is the signature of `public_key` over `signing_root`.
+fn verify(
+    signature: &SignatureBytes,
+    public_key: &PublicKey,
+    signing_root: Hash256,
+) -> Result<(), Error> {
+    // The signature is checked to be in its subgroup when it is decompressed, and the public key
+    // when it was decompressed.
+    let result =
+        signature
+            .decompress()?
+            .verify(false, signing_root.as_bytes(), DST, &[], public_key, false);
+    match result {
+        BLST_ERROR::BLST_SUCCESS => Ok(()),
+        _ => Err(Error::InvalidSignature),
+    }
+}
+
+/// Verifies that `signature` is the aggregate of the signatures of `public_keys` over
+/// `signing_root`, as per `FastAggregateVerify` in the BLS signature draft.
+fn fast_aggregate_verify(
+    signature: &SignatureBytes,
+    public_keys: &[&PublicKey],
+    signing_root: Hash256,
+) -> Result<(), Error> {
+    if public_keys.is_empty() {
+        return Err(Error::InvalidSignature);
+    }
+    let result = signature.decompress()?.fast_aggregate_verify(
+        false,
+        signing_root.as_bytes(),
+        DST,
+        public_keys,
+    );
+    match result {
+        BLST_ERROR::BLST_SUCCESS => Ok(()),
+        _ => Err(Error::InvalidSignature),
+    }
+}
+
+impl<P: Preset> SignedBeaconBlock<P> {
+    /// Verifies the signature of the proposer over the block, where `public_key` is the key of
+    /// the proposer and `fork` the fork of the state the block is applied to.
+    ///
+    /// Returns `Err(Error::InvalidBlsPoint)` if the signature is not a valid point, and
+    /// `Err(Error::InvalidSignature)` if it is not the signature of the proposer.
+    pub fn verify_signature(
+        &self,
+        public_key: &PublicKey,
+        fork: &Fork,
+        genesis_validators_root: Hash256,
+    ) -> Result<(), Error> {
+        let epoch = self.slot().epoch(P::SlotsPerEpoch::to_u64());
+        let domain = compute_domain(
+            DOMAIN_BEACON_PROPOSER,
+            fork.version_at(epoch),
+            genesis_validators_root,
+        );
+        // The root of a block is that of its header, which is cheaper to hash again.
+        let signing_root = self.signed_block_header().message.signing_root(domain);
+        verify(self.signature(), public_key, signing_root)
+    }
+}
+
+impl SignedVoluntaryExit {
+    /// Verifies the signature of the exiting validator over the exit, where `public_key` is the
+    /// key of the validator and `fork` the fork of the state the exit is applied to.
+    ///
+    /// Since Deneb, exits are signed with the Capella fork version whatever the epoch of the
+    /// exit, as per EIP-7044. To verify those, pass a `fork` whose versions are both that of
+    /// Capella.
+    pub fn verify_signature(
+        &self,
+        public_key: &PublicKey,
+        fork: &Fork,
+        genesis_validators_root: Hash256,
+    ) -> Result<(), Error> {
+        let domain = compute_domain(
+            DOMAIN_VOLUNTARY_EXIT,
+            fork.version_at(self.message.epoch),
+            genesis_validators_root,
+        );
+        verify(
+            &self.signature,
+            public_key,
+            self.message.signing_root(domain),
+        )
+    }
+}
+
+impl SignedBlsToExecutionChange {
+    /// Verifies the signature over the change by the BLS key of the withdrawal credentials,
+    /// which is the `from_bls_pubkey` of the change.
+    ///
+    /// Changes are signed with the genesis fork version of the chain, so that they stay valid
+    /// across forks.
+    pub fn verify_signature(
+        &self,
+        genesis_fork_version: Version,
+        genesis_validators_root: Hash256,
+    ) -> Result<(), Error> {
+        let public_key = self.message.from_bls_pubkey.decompress()?;
+        let domain = compute_domain(
+            DOMAIN_BLS_TO_EXECUTION_CHANGE,
+            genesis_fork_version,
+            genesis_validators_root,
+        );
+        verify(
+            &self.signature,
+            &public_key,
+            self.message.signing_root(domain),
+        )
+    }
+}
+
+impl DepositData {
+    /// Verifies the signature of the deposit over its `DepositMessage` by the key being
+    /// deposited for, which proves possession of the key, on a chain with
+    /// `genesis_fork_version`.
+    ///
+    /// A deposit with an invalid signature is not an invalid block: the state transition skips
+    /// it, so the deposit contract cannot be used to stall the chain.
+    pub fn validate_signature(&self, genesis_fork_version: Version) -> Result<(), Error> {
+        let public_key = self.pubkey.decompress()?;
+        let domain = compute_deposit_domain(genesis_fork_version);
+        verify(
+            &self.signature,
+            &public_key,
+            self.deposit_message().signing_root(domain),
+        )
+    }
+}
+
+/// Implements `verify_signature` for an indexed attestation of any fork.
+macro_rules! impl_verify_indexed_attestation {
+    ($type: ident) => {
+        impl<P: Preset> $type<P> {
+            /// Verifies the aggregate signature of the attesters over the attestation data,
+            /// where `public_keys` are the keys of the `attesting_indices` in the same order and
+            /// `fork` the fork of the state the attestation is applied to.
+            ///
+            /// Returns `Err(Error::OutOfBounds)` if there is not one key per attester, and
+            /// `Err(Error::InvalidSignature)` if there are no attesters.
+            pub fn verify_signature(
+                &self,
+                public_keys: &[&PublicKey],
+                fork: &Fork,
+                genesis_validators_root: Hash256,
+            ) -> Result<(), Error> {
+                if public_keys.len() != self.attesting_indices.len() {
+                    return Err(Error::OutOfBounds {
+                        i: public_keys.len(),
+                        len: self.attesting_indices.len(),
+                    });
+                }
+                let domain = compute_domain(
+                    DOMAIN_BEACON_ATTESTER,
+                    fork.version_at(self.data.target.epoch),
+                    genesis_validators_root,
+                );
+                fast_aggregate_verify(&self.signature, public_keys, self.data.signing_root(domain))
+            }
+        }
+    };
+}
+
+impl_verify_indexed_attestation!(IndexedAttestation);
+impl_verify_indexed_attestation!(IndexedAttestationElectra);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        AttestationData, BlsToExecutionChange, Epoch, Gwei, PublicKeyBytes,
+        SignedBeaconBlockCapella, Slot, VariableList, VoluntaryExit, DOMAIN_DEPOSIT,
+    };
+    use blst::min_pk::{AggregateSignature, SecretKey};
+
+    fn secret_key(seed: u8) -> SecretKey {
+        SecretKey::key_gen(&[seed; 32], &[]).unwrap()
+    }
+
+    fn sign(secret_key: &SecretKey, signing_root: Hash256) -> SignatureBytes {
+        SignatureBytes::new(
+            secret_key
+                .sign(signing_root.as_bytes(), DST, &[])
+                .compress(),
+        )
+    }
+
+    fn fork() -> Fork {
+        Fork {
+            previous_version: [2, 0, 0, 0],
+            current_version: [3, 0, 0, 0],
+            epoch: Epoch::new(10),
+        }
+    }
+
+    #[test]
+    fn block_signature() {
+        let genesis_validators_root = Hash256::repeat_byte(0x42);
+        let other_key = secret_key(2).sk_to_pk();
+        let secret_key = secret_key(1);
+        let public_key = secret_key.sk_to_pk();
+
+        let mut block = SignedBeaconBlockCapella::<crate::Minimal>::default();
+        block.message.slot = Slot::new(100);
+        let domain = compute_domain(
+            DOMAIN_BEACON_PROPOSER,
+            fork().current_version,
+            genesis_validators_root,
+        );
+        block.signature = sign(&secret_key, block.message.signing_root(domain));
+        let block = SignedBeaconBlock::Capella(block);
+
+        assert_eq!(
+            block.verify_signature(&public_key, &fork(), genesis_validators_root),
+            Ok(())
+        );
+        assert_eq!(
+            block.verify_signature(&other_key, &fork(), genesis_validators_root),
+            Err(Error::InvalidSignature)
+        );
+        assert_eq!(
+            block.verify_signature(&public_key, &fork(), Hash256::zero()),
+            Err(Error::InvalidSignature)
+        );
+
+        // Slot 100 of the minimal preset is in epoch 12, after the fork.
+        let later_fork = Fork {
+            epoch: Epoch::new(13),
+            ..fork()
+        };
+        assert_eq!(
+            block.verify_signature(&public_key, &later_fork, genesis_validators_root),
+            Err(Error::InvalidSignature)
+        );
+    }
+
+    #[test]
+    fn exit_and_bls_to_execution_change_signatures() {
+        let genesis_validators_root = Hash256::repeat_byte(0x42);
+        let secret_key = secret_key(1);
+
+        let message = VoluntaryExit {
+            epoch: Epoch::new(5),
+            ..VoluntaryExit::default()
+        };
+        let domain = compute_domain(
+            DOMAIN_VOLUNTARY_EXIT,
+            fork().previous_version,
+            genesis_validators_root,
+        );
+        let exit = SignedVoluntaryExit {
+            signature: sign(&secret_key, message.signing_root(domain)),
+            message,
+        };
+        assert_eq!(
+            exit.verify_signature(&secret_key.sk_to_pk(), &fork(), genesis_validators_root),
+            Ok(())
+        );
+        assert_eq!(
+            SignedVoluntaryExit::default().verify_signature(
+                &secret_key.sk_to_pk(),
+                &fork(),
+                genesis_validators_root
+            ),
+            Err(Error::InvalidBlsPoint)
+        );
+
+        let message = BlsToExecutionChange {
+            from_bls_pubkey: PublicKeyBytes::new(secret_key.sk_to_pk().compress()),
+            ..BlsToExecutionChange::default()
+        };
+        let domain = compute_domain(
+            DOMAIN_BLS_TO_EXECUTION_CHANGE,
+            [0, 0, 0, 0],
+            genesis_validators_root,
+        );
+        let change = SignedBlsToExecutionChange {
+            signature: sign(&secret_key, message.signing_root(domain)),
+            message,
+        };
+        assert_eq!(
+            change.verify_signature([0, 0, 0, 0], genesis_validators_root),
+            Ok(())
+        );
+        assert_eq!(
+            change.verify_signature([1, 0, 0, 0], genesis_validators_root),
+            Err(Error::InvalidSignature)
+        );
+    }
+
+    #[test]
+    fn deposit_signature() {
+        let secret_key = secret_key(1);
+        let mut deposit_data = DepositData {
+            pubkey: PublicKeyBytes::new(secret_key.sk_to_pk().compress()),
+            withdrawal_credentials: crate::FixedVector::from_elem(1),
+            amount: Gwei::new(32_000_000_000),
+            ..DepositData::default()
+        };
+        // The domain ignores the genesis validators root, which is unknown before genesis.
+        let domain = compute_domain(DOMAIN_DEPOSIT, [0, 0, 0, 0], Hash256::zero());
+        assert_eq!(compute_deposit_domain([0, 0, 0, 0]), domain);
+        deposit_data.signature = sign(
+            &secret_key,
+            deposit_data.deposit_message().signing_root(domain),
+        );
+
+        assert_eq!(deposit_data.validate_signature([0, 0, 0, 0]), Ok(()));
+        assert_eq!(
+            deposit_data.validate_signature([0x90, 0, 0, 0x69]),
+            Err(Error::InvalidSignature)
+        );
+
+        let mut top_up = deposit_data.clone();
+        top_up.amount = Gwei::new(1_000_000_000);
+        assert_eq!(
+            top_up.validate_signature([0, 0, 0, 0]),
+            Err(Error::InvalidSignature)
+        );
+
+        let mut infinity = [0; 48];
+        infinity[0] = 0xc0;
+        deposit_data.pubkey = PublicKeyBytes::new(infinity);
+        assert_eq!(
+            deposit_data.validate_signature([0, 0, 0, 0]),
+            Err(Error::InvalidBlsPoint)
+        );
+    }
+
+    #[test]
+    fn indexed_attestation_signature() {
+        let genesis_validators_root = Hash256::repeat_byte(0x42);
+        let secret_keys: Vec<_> = (1..=3).map(secret_key).collect();
+        let public_keys: Vec<_> = secret_keys.iter().map(SecretKey::sk_to_pk).collect();
+        let public_keys: Vec<_> = public_keys.iter().collect();
+
+        let mut data = AttestationData::default();
+        data.target.epoch = Epoch::new(10);
+        let domain = compute_domain(
+            DOMAIN_BEACON_ATTESTER,
+            fork().current_version,
+            genesis_validators_root,
+        );
+        let signing_root = data.signing_root(domain);
+        let signatures: Vec<_> = secret_keys
+            .iter()
+            .map(|secret_key| secret_key.sign(signing_root.as_bytes(), DST, &[]))
+            .collect();
+        let signature = AggregateSignature::aggregate(&signatures.iter().collect::<Vec<_>>(), true)
+            .unwrap()
+            .to_signature();
+
+        let attestation = IndexedAttestation::<crate::Minimal> {
+            attesting_indices: VariableList::new(vec![4, 7, 9]).unwrap(),
+            data,
+            signature: SignatureBytes::new(signature.compress()),
+        };
+        assert_eq!(
+            attestation.verify_signature(&public_keys, &fork(), genesis_validators_root),
+            Ok(())
+        );
+        assert_eq!(
+            attestation.verify_signature(&public_keys[..2], &fork(), genesis_validators_root),
+            Err(Error::OutOfBounds { i: 2, len: 3 })
+        );
+
+        let mut missing_signer = attestation.clone();
+        missing_signer.signature = sign(&secret_keys[0], signing_root);
+        assert_eq!(
+            missing_signer.verify_signature(&public_keys, &fork(), genesis_validators_root),
+            Err(Error::InvalidSignature)
+        );
+
+        let empty = IndexedAttestation::<crate::Minimal> {
+            attesting_indices: VariableList::empty(),
+            ..attestation
+        };
+        assert_eq!(
+            empty.verify_signature(&[], &fork(), genesis_validators_root),
+            Err(Error::InvalidSignature)
+        );
+    }
+}
@@ -0,0 +1,137 @@
+use crate::Error;
+use serde::de::DeserializeOwned;
+
+/// Decodes a container from YAML, in the format of the `value.yaml` files of the consensus spec
+/// tests.
+///
+/// The format is the same as the JSON of the beacon API, except that integers are unquoted.
+/// Quoted integers are accepted too, so YAML written with `serde_yaml` decodes as well.
+///
+/// ## Example
+///
+/// ```
+/// use ssz_types::{from_yaml_str, Epoch, Fork};
+///
+/// let yaml = "{previous_version: '0x00000000', current_version: '0x01000000', epoch: 74240}";
+/// let fork: Fork = from_yaml_str(yaml).unwrap();
+/// assert_eq!(fork.epoch, Epoch::new(74240));
+/// ```
+pub fn from_yaml_str<T: DeserializeOwned>(yaml: &str) -> Result<T, Error> {
+    serde_yaml::from_str(yaml).map_err(|e| Error::InvalidYaml {
+        reason: e.to_string(),
+    })
+}
+
+/// Decodes a container from the bytes of a YAML document, as read from a `value.yaml` file.
+pub fn from_yaml_slice<T: DeserializeOwned>(yaml: &[u8]) -> Result<T, Error> {
+    serde_yaml::from_slice(yaml).map_err(|e| Error::InvalidYaml {
+        reason: e.to_string(),
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::beacon_block::SyncAggregate;
+    use crate::{
+        Attestation, BeaconBlockHeader, Epoch, ExecutionPayloadHeaderDeneb, Fork, Minimal, Slot,
+        Validator,
+    };
+    use tree_hash::TreeHash;
+
+    fn hex(byte: &str, len: usize) -> String {
+        format!("'0x{}'", byte.repeat(len))
+    }
+
+    #[test]
+    fn spec_test_values() {
+        let yaml = format!(
+            "slot: 12\nproposer_index: 4\nparent_root: {}\nstate_root: {}\nbody_root: {}\n",
+            hex("01", 32),
+            hex("02", 32),
+            hex("03", 32),
+        );
+        let header: BeaconBlockHeader = from_yaml_str(&yaml).unwrap();
+        assert_eq!(header.slot, Slot::new(12));
+        assert_eq!(header.proposer_index.as_u64(), 4);
+        assert_eq!(header.body_root.to_vec(), vec![3; 32]);
+        assert_eq!(from_yaml_slice(yaml.as_bytes()), Ok(header));
+
+        let yaml = format!(
+            "previous_version: '0x00000001'\ncurrent_version: '0x01000001'\nepoch: {}\n",
+            u64::MAX
+        );
+        let fork: Fork = from_yaml_str(&yaml).unwrap();
+        assert_eq!(fork.current_version, [1, 0, 0, 1]);
+        assert_eq!(fork.epoch, Epoch::new(u64::MAX));
+
+        let yaml = format!(
+            "pubkey: {}\nwithdrawal_credentials: {}\neffective_balance: 32000000000\n\
+             slashed: true\nactivation_eligibility_epoch: 1\nactivation_epoch: 2\n\
+             exit_epoch: 18446744073709551615\nwithdrawable_epoch: 18446744073709551615\n",
+            hex("aa", 48),
+            hex("01", 32),
+        );
+        let validator: Validator = from_yaml_str(&yaml).unwrap();
+        assert_eq!(validator.effective_balance.as_u64(), 32_000_000_000);
+        assert!(validator.slashed);
+
+        // Bitfields are hex, with the length bit of bit lists.
+        let yaml = format!(
+            "aggregation_bits: '0x0d'\ndata:\n  slot: 1\n  index: 0\n  beacon_block_root: {root}\n  \
+             source: {{epoch: 0, root: {root}}}\n  target: {{epoch: 0, root: {root}}}\n\
+             signature: {}\n",
+            hex("bb", 96),
+            root = hex("00", 32),
+        );
+        let attestation: Attestation<Minimal> = from_yaml_str(&yaml).unwrap();
+        assert_eq!(attestation.aggregation_bits.bits().len(), 3);
+        assert_eq!(attestation.aggregation_bits.bits().num_set_bits(), 2);
+
+        let yaml = format!(
+            "sync_committee_bits: '0xff00ff00'\nsync_committee_signature: {}\n",
+            hex("cc", 96)
+        );
+        let sync_aggregate: SyncAggregate<Minimal> = from_yaml_str(&yaml).unwrap();
+        assert_eq!(sync_aggregate.sync_committee_bits.num_set_bits(), 16);
+    }
+
+    #[test]
+    fn spec_test_u256() {
+        let header = ExecutionPayloadHeaderDeneb::<Minimal> {
+            base_fee_per_gas: crate::FixedVector::new(vec![0, 0, 0, 1]).unwrap(),
+            ..Default::default()
+        };
+
+        // Integers of more than 64 bits are unquoted too, and must not be decoded as floats.
+        let yaml = serde_yaml::to_string(&header).unwrap().replace(
+            "'6277101735386680763835789423207666416102355444464034512896'",
+            "6277101735386680763835789423207666416102355444464034512896",
+        );
+        assert!(!yaml.contains("'6277"));
+        let decoded: ExecutionPayloadHeaderDeneb<Minimal> = from_yaml_str(&yaml).unwrap();
+        assert_eq!(decoded.tree_hash_root(), header.tree_hash_root());
+        assert_eq!(decoded, header);
+    }
+
+    #[test]
+    fn invalid_values() {
+        assert!(matches!(
+            from_yaml_str::<Fork>("epoch: 1"),
+            Err(Error::InvalidYaml { .. })
+        ));
+        // Hex must have the length of the type.
+        assert!(matches!(
+            from_yaml_str::<Fork>(
+                "previous_version: '0x00'\ncurrent_version: '0x00000000'\nepoch: 1"
+            ),
+            Err(Error::InvalidYaml { .. })
+        ));
+        assert!(matches!(
+            from_yaml_str::<Fork>(
+                "previous_version: '0x00000000'\ncurrent_version: '0x00000000'\nepoch: -1"
+            ),
+            Err(Error::InvalidYaml { .. })
+        ));
+    }
+}
@@ -0,0 +1,18 @@
+//! Runs the `ssz_static` cases of the consensus spec tests extracted at
+//! `$CONSENSUS_SPEC_TESTS_DIR`, e.g. from the `mainnet.tar.gz` and `minimal.tar.gz` of a release.
+#![cfg(feature = "spec-tests")]
+
+use ssz_types::spec_tests::run_ssz_static;
+
+#[test]
+fn ssz_static() {
+    let Some(dir) = std::env::var_os("CONSENSUS_SPEC_TESTS_DIR") else {
+        eprintln!("CONSENSUS_SPEC_TESTS_DIR is not set, skipping the consensus spec tests");
+        return;
+    };
+
+    let report = run_ssz_static(dir).unwrap();
+    println!("{report}");
+    assert!(report.passed() > 0);
+    assert!(report.is_success());
+}
@@ -223,14 +223,29 @@ use std::sync::Arc;
 
 mod beacon_block_tests {
     use ssz::{Decode, Encode};
-    use ssz_types::SignedBeaconBlock;
+    use ssz_types::{SignedBeaconBlock, SignedBeaconBlockCapella};
 
     use std::fs;
 
     #[test]
     fn real_beacon_block_roundtrip_test() {
         let bytes: Vec<u8> = fs::read("state.ssz").unwrap();
-        let decoded_block = SignedBeaconBlock::from_ssz_bytes(&bytes).unwrap();
+        let decoded_block: SignedBeaconBlockCapella = Decode::from_ssz_bytes(&bytes).unwrap();
         assert_eq!(decoded_block.as_ssz_bytes().as_slice(), &bytes);
+        assert_eq!(SignedBeaconBlock::from(decoded_block).as_ssz_bytes(), bytes);
+    }
+
+    #[cfg(feature = "arbitrary")]
+    #[test]
+    fn arbitrary_beacon_block_roundtrip_test() {
+        use arbitrary::{Arbitrary, Unstructured};
+
+        let data: Vec<u8> = (0..1_u32 << 16)
+            .map(|i| (i.wrapping_mul(2_654_435_761) >> 24) as u8)
+            .collect();
+        let block: SignedBeaconBlockCapella =
+            Arbitrary::arbitrary(&mut Unstructured::new(&data)).unwrap();
+        let bytes = block.as_ssz_bytes();
+        assert_eq!(SignedBeaconBlockCapella::from_ssz_bytes(&bytes), Ok(block));
     }
 }